@@ -45,6 +45,10 @@ fn generate_errors(out: &mut Output, module: &xcbgen::defs::Module) {
         out,
         "#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]"
     );
+    outln!(
+        out,
+        r#"#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]"#
+    );
     outln!(out, "#[non_exhaustive]");
     outln!(out, "pub enum ErrorKind {{");
     out.indented(|out| {
@@ -146,6 +150,10 @@ fn generate_events(out: &mut Output, module: &xcbgen::defs::Module) {
 
     outln!(out, "/// Enumeration of all possible X11 events.");
     outln!(out, "#[derive(Debug, Clone)]");
+    outln!(
+        out,
+        r#"#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]"#
+    );
     outln!(out, "#[non_exhaustive]");
     outln!(out, "pub enum Event {{");
     out.indented(|out| {
@@ -344,6 +352,92 @@ fn generate_events(out: &mut Output, module: &xcbgen::defs::Module) {
         outln!(out, "}}");
         outln!(out, "");
 
+        outln!(
+            out,
+            "/// Serialize this event into bytes that can be sent to an X11 client.",
+        );
+        outln!(out, "///");
+        outln!(
+            out,
+            "/// The event code of extension events and the major opcode of events of the Generic Event",
+        );
+        outln!(
+            out,
+            "/// Extension are looked up via `ext_info_provider`. The sent-event bit of the response",
+        );
+        outln!(
+            out,
+            "/// type is kept, so that events sent via `SendEvent` stay recognisable.",
+        );
+        outln!(out, "pub fn serialize(");
+        outln!(out.indent(), "&self,");
+        outln!(
+            out.indent(),
+            "// Might not be used if no extension with events is enabled"
+        );
+        outln!(out.indent(), "#[allow(unused_variables)]");
+        outln!(out.indent(), "ext_info_provider: &dyn ExtInfoProvider,");
+        outln!(out, ") -> Result<Vec<u8>, SerializeError> {{");
+        out.indented(|out| {
+            outln!(out, "match self {{");
+            out.indented(|out| {
+                outln!(out, "Event::Unknown(value) => Ok(value.clone()),");
+                outln!(
+                    out,
+                    "Event::Error(value) => Ok(<[u8; 32]>::from(value).to_vec()),"
+                );
+                for ns in namespaces.iter() {
+                    let has_feature = super::ext_has_feature(&ns.header);
+                    for event_def in sorted_events(ns).iter() {
+                        if has_feature {
+                            outln!(out, "#[cfg(feature = \"{}\")]", ns.header);
+                        }
+                        let variant = format!("{}{}", get_ns_name_prefix(ns), event_def.name());
+                        let number = format!(
+                            "{}::{}_EVENT",
+                            ns.header,
+                            super::camel_case_to_upper_snake(event_def.name()),
+                        );
+                        if ns.ext_info.is_none() {
+                            outln!(
+                                out,
+                                "Event::{}(value) => Ok(serialize_event(value, {})),",
+                                variant,
+                                number,
+                            );
+                        } else if event_def.is_xge() {
+                            outln!(out, "Event::{}(value) => serialize_generic_event(", variant);
+                            outln!(out.indent(), "value,");
+                            outln!(out.indent(), "ext_info_provider,");
+                            outln!(out.indent(), "{}::X11_EXTENSION_NAME,", ns.header);
+                            outln!(out.indent(), "{},", number);
+                            outln!(out, "),");
+                        } else {
+                            // All XKB events share one event code and differ in their xkb_type
+                            let number = if ns.header == "xkb" {
+                                "0".to_string()
+                            } else {
+                                number
+                            };
+                            outln!(out, "Event::{}(value) => {{", variant);
+                            out.indented(|out| {
+                                outln!(out, "let event_code = extension_event_code(");
+                                outln!(out.indent(), "ext_info_provider,");
+                                outln!(out.indent(), "{}::X11_EXTENSION_NAME,", ns.header);
+                                outln!(out.indent(), "{},", number);
+                                outln!(out, ")?;");
+                                outln!(out, "Ok(serialize_event(value, event_code))");
+                            });
+                            outln!(out, "}}");
+                        }
+                    }
+                }
+            });
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+        outln!(out, "");
+
         outln!(
             out,
             "/// Get the sequence number contained in this X11 event",
@@ -385,6 +479,50 @@ fn generate_events(out: &mut Output, module: &xcbgen::defs::Module) {
         outln!(out, "}}");
         outln!(out, "");
 
+        outln!(
+            out,
+            "/// Get the window that this X11 event is about, if there is one.",
+        );
+        outln!(out, "///");
+        outln!(
+            out,
+            "/// This is the window on which the event was selected and thus the window that",
+        );
+        outln!(
+            out,
+            "/// the event is reported to. For example, for a `ConfigureNotify` that was",
+        );
+        outln!(
+            out,
+            "/// selected with `SubstructureNotify`, this is the parent of the configured window.",
+        );
+        outln!(out, "pub fn window(&self) -> Option<xproto::Window> {{");
+        out.indented(|out| {
+            outln!(out, "match self {{");
+            for ns in namespaces.iter() {
+                let event_defs = sorted_events(ns);
+                let has_feature = super::ext_has_feature(&ns.header);
+                for event_def in event_defs.iter() {
+                    if let Some(field) = event_window_field(event_def) {
+                        if has_feature {
+                            outln!(out.indent(), "#[cfg(feature = \"{}\")]", ns.header);
+                        }
+                        outln!(
+                            out.indent(),
+                            "Event::{}{}(value) => Some(value.{}),",
+                            get_ns_name_prefix(ns),
+                            event_def.name(),
+                            field,
+                        );
+                    }
+                }
+            }
+            outln!(out.indent(), "_ => None,");
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+        outln!(out, "");
+
         outln!(out, "/// Get the raw response type of this X11 event");
         outln!(out, "///");
         outln!(
@@ -456,6 +594,60 @@ pub fn sent_event(&self) -> bool {{
     outln!(out, "}}");
 }
 
+/// Find the field of an event that contains the window that the event is about.
+///
+/// The `event` and `parent` fields are preferred over the `window` field, since the event is
+/// reported to them when they are present. Otherwise, a field whose name ends in
+/// `window` is used. Other fields of type `WINDOW`, like `root` or `child`, are only used if there
+/// is nothing else.
+fn event_window_field(event_def: &xcbgen::defs::EventDef) -> Option<String> {
+    let full_def = event_def.get_original_full_def();
+    let fields = full_def.fields.borrow();
+    let window_fields = fields
+        .iter()
+        .filter_map(|field| match field {
+            xcbgen::defs::FieldDef::Normal(normal_field)
+                if is_window_type(&normal_field.type_.type_) =>
+            {
+                Some(normal_field.name.as_str())
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let field = ["event", "parent", "window"]
+        .iter()
+        .copied()
+        .find(|name| window_fields.contains(name))
+        .or_else(|| {
+            window_fields
+                .iter()
+                .copied()
+                .find(|name| name.ends_with("window"))
+        })
+        .or_else(|| window_fields.first().copied())?;
+    assert!(
+        field.bytes().all(|c| c.is_ascii_lowercase() || c == b'_'),
+        "Window field {} needs to be renamed",
+        field,
+    );
+    Some(field.to_string())
+}
+
+/// Check if the given type is xproto's `WINDOW`.
+fn is_window_type(type_: &xcbgen::defs::NamedTypeRef) -> bool {
+    match type_.get_resolved() {
+        xcbgen::defs::TypeRef::Xid(xid_type_def) => {
+            let xid_type_def = xid_type_def.upgrade().unwrap();
+            xid_type_def.name == "WINDOW"
+                && xid_type_def.namespace.upgrade().unwrap().header == "xproto"
+        }
+        xcbgen::defs::TypeRef::Alias(type_alias_def) => {
+            is_window_type(&type_alias_def.upgrade().unwrap().old_name)
+        }
+        _ => false,
+    }
+}
+
 fn sorted_errors(ns: &xcbgen::defs::Namespace) -> Vec<xcbgen::defs::ErrorDef> {
     let mut errors: Vec<_> = ns
         .error_defs
@@ -49,8 +49,11 @@ pub(crate) fn generate(module: &xcbgen::defs::Module) -> Vec<Generated> {
     outln!(main_proto_out, "use alloc::borrow::Cow;");
     outln!(main_proto_out, "use alloc::vec::Vec;");
     outln!(main_proto_out, "use core::convert::TryInto;");
-    outln!(main_proto_out, "use crate::errors::ParseError;");
-    outln!(main_proto_out, "use crate::RawFdContainer;");
+    outln!(
+        main_proto_out,
+        "use crate::errors::{{ParseError, SerializeError}};"
+    );
+    outln!(main_proto_out, "use crate::{{BufWithFds, RawFdContainer}};");
     outln!(
         main_proto_out,
         "use crate::x11_utils::{{TryParse, TryParseFd, X11Error, ReplyRequest, ReplyFDsRequest}};"
@@ -59,6 +62,19 @@ pub(crate) fn generate(module: &xcbgen::defs::Module) -> Vec<Generated> {
         main_proto_out,
         "use crate::x11_utils::{{ExtInfoProvider, ReplyParsingFunction, RequestHeader}};"
     );
+    outln!(
+        main_proto_out,
+        "use crate::x11_utils::{{serialize_event, serialize_reply, serialize_request, serialize_unknown_request}};"
+    );
+    outln!(
+        main_proto_out,
+        "// Might not be used if no extension with events is enabled"
+    );
+    outln!(main_proto_out, "#[allow(unused_imports)]");
+    outln!(
+        main_proto_out,
+        "use crate::x11_utils::{{extension_event_code, serialize_generic_event}};"
+    );
     outln!(main_proto_out, "");
 
     outln!(main_proto_out, "fn parse_reply<'a, R: ReplyRequest>(bytes: &'a [u8], _: &mut Vec<RawFdContainer>) -> Result<(Reply, &'a [u8]), ParseError> {{");
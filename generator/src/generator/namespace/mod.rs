@@ -14,6 +14,7 @@ mod expr_to_str;
 mod header;
 pub(super) mod helpers;
 mod parse;
+mod reply_ref;
 mod request;
 mod resource_wrapper;
 mod serialize;
@@ -0,0 +1,268 @@
+use xcbgen::defs as xcbdefs;
+
+use super::{
+    expr_to_str, gather_deducible_fields, parse, to_rust_variable_name, Derives, FieldContainer,
+    NamespaceGenerator, Output,
+};
+
+/// How a field of a reply is represented in the borrowed version of the reply.
+enum RefField {
+    /// The field has the same type as in the owned reply.
+    Owned,
+    /// A variable length list of `u8`, represented as a slice.
+    Bytes,
+    /// A variable length list of something else, represented as a `ListRef`.
+    List(String),
+}
+
+/// Emit a borrowed version of a reply, see `BorrowedReply`.
+///
+/// This is only done for replies with variable length lists, since the borrowed version
+/// of other replies would be identical to the owned version.
+pub(super) fn emit_reply_ref(
+    generator: &NamespaceGenerator<'_, '_>,
+    reply_struct_name: &str,
+    fields: &[xcbdefs::FieldDef],
+    out: &mut Output,
+) {
+    let ref_fields = match gather_ref_fields(generator, fields) {
+        Some(ref_fields) => ref_fields,
+        None => return,
+    };
+    if ref_fields
+        .iter()
+        .all(|ref_field| matches!(ref_field, RefField::Owned))
+    {
+        return;
+    }
+
+    let deducible_fields = gather_deducible_fields(fields);
+    let ref_struct_name = format!("{}Ref", reply_struct_name);
+    let visible_fields = fields
+        .iter()
+        .zip(ref_fields.iter())
+        .filter(|(field, _)| generator.field_is_visible(field, &deducible_fields))
+        .collect::<Vec<_>>();
+
+    // Lists are references and can be copied, everything else depends on the field type
+    let copy = fields
+        .iter()
+        .zip(ref_fields.iter())
+        .all(|(field, ref_field)| match ref_field {
+            RefField::Owned => {
+                let mut derives = Derives::all();
+                generator.filter_derives_for_fields(
+                    &mut derives,
+                    std::slice::from_ref(field),
+                    false,
+                );
+                derives.copy
+            }
+            RefField::Bytes | RefField::List(_) => true,
+        });
+
+    outln!(out, "");
+    outln!(
+        out,
+        "/// Borrowed version of [`{}`] that does not copy its lists.",
+        reply_struct_name
+    );
+    outln!(out, "///");
+    outln!(
+        out,
+        "/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.",
+    );
+    if copy {
+        outln!(out, "#[derive(Debug, Clone, Copy)]");
+    } else {
+        outln!(out, "#[derive(Debug, Clone)]");
+    }
+    outln!(out, "pub struct {}<'a> {{", ref_struct_name);
+    for (field, ref_field) in visible_fields.iter() {
+        let field_type = match ref_field {
+            RefField::Owned => generator.field_to_rust_type(field, ""),
+            RefField::Bytes => "&'a [u8]".into(),
+            RefField::List(element_type) => {
+                format!("crate::x11_utils::ListRef<'a, {}>", element_type)
+            }
+        };
+        outln!(
+            out.indent(),
+            "pub {}: {},",
+            to_rust_variable_name(field.name().unwrap()),
+            field_type,
+        );
+    }
+    outln!(out, "}}");
+
+    outln!(
+        out,
+        "impl<'a> crate::x11_utils::BorrowedReply<'a> for {} {{",
+        reply_struct_name
+    );
+    out.indented(|out| {
+        outln!(out, "type Ref = {}<'a>;", ref_struct_name);
+        outln!(
+            out,
+            "fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), \
+             ParseError> {{",
+        );
+        out.indented(|out| {
+            outln!(out, "let remaining = initial_value;");
+            NamespaceGenerator::emit_let_value_for_dynamic_align(fields, out);
+            for (field, ref_field) in fields.iter().zip(ref_fields.iter()) {
+                match ref_field {
+                    RefField::Owned => parse::emit_field_parse(
+                        generator,
+                        field,
+                        "",
+                        "remaining",
+                        FieldContainer::Other,
+                        out,
+                    ),
+                    // Parsing for requests already borrows lists of `u8`
+                    RefField::Bytes => parse::emit_field_parse(
+                        generator,
+                        field,
+                        "",
+                        "remaining",
+                        FieldContainer::Request(String::new()),
+                        out,
+                    ),
+                    RefField::List(element_type) => {
+                        emit_list_ref_parse(generator, field, element_type, out)
+                    }
+                }
+            }
+            for field in fields.iter() {
+                if !field
+                    .name()
+                    .map(|field_name| deducible_fields.contains_key(field_name))
+                    .unwrap_or(false)
+                {
+                    parse::emit_field_post_parse(field, out);
+                }
+            }
+            let field_names = visible_fields
+                .iter()
+                .map(|(field, _)| to_rust_variable_name(field.name().unwrap()))
+                .collect::<Vec<_>>();
+            outln!(
+                out,
+                "let result = {} {{ {} }};",
+                ref_struct_name,
+                field_names.join(", ")
+            );
+            outln!(out, "let _ = remaining;");
+            outln!(
+                out,
+                "let remaining = initial_value.get(32 + length as usize * 4..)"
+            );
+            outln!(out.indent(), ".ok_or(ParseError::InsufficientData)?;");
+            outln!(out, "Ok((result, remaining))");
+        });
+        outln!(out, "}}");
+    });
+    outln!(out, "}}");
+
+    outln!(
+        out,
+        "impl From<{}<'_>> for {} {{",
+        ref_struct_name,
+        reply_struct_name
+    );
+    out.indented(|out| {
+        outln!(out, "fn from(reply: {}<'_>) -> Self {{", ref_struct_name);
+        out.indented(|out| {
+            outln!(out, "Self {{");
+            for (field, ref_field) in visible_fields.iter() {
+                let field_name = to_rust_variable_name(field.name().unwrap());
+                match ref_field {
+                    RefField::Owned => {
+                        outln!(out.indent(), "{name}: reply.{name},", name = field_name)
+                    }
+                    RefField::Bytes => outln!(
+                        out.indent(),
+                        "{name}: reply.{name}.to_vec(),",
+                        name = field_name
+                    ),
+                    RefField::List(_) => outln!(
+                        out.indent(),
+                        "{name}: reply.{name}.iter().collect(),",
+                        name = field_name
+                    ),
+                }
+            }
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+    });
+    outln!(out, "}}");
+}
+
+/// Decide how each field is represented in the borrowed reply.
+///
+/// Returns `None` if the reply contains fields that the borrowed version does not support.
+fn gather_ref_fields(
+    generator: &NamespaceGenerator<'_, '_>,
+    fields: &[xcbdefs::FieldDef],
+) -> Option<Vec<RefField>> {
+    fields
+        .iter()
+        .map(|field| match field {
+            xcbdefs::FieldDef::Switch(_)
+            | xcbdefs::FieldDef::Fd(_)
+            | xcbdefs::FieldDef::FdList(_) => None,
+            xcbdefs::FieldDef::List(list_field) if !list_field.has_fixed_length() => {
+                if generator.rust_value_type_is_u8(&list_field.element_type) {
+                    Some(RefField::Bytes)
+                } else if parse::can_use_simple_list_parsing(generator, &list_field.element_type) {
+                    Some(RefField::List(generator.type_to_rust_type(
+                        list_field.element_type.type_.get_resolved(),
+                    )))
+                } else {
+                    None
+                }
+            }
+            _ => Some(RefField::Owned),
+        })
+        .collect()
+}
+
+fn emit_list_ref_parse(
+    generator: &NamespaceGenerator<'_, '_>,
+    field: &xcbdefs::FieldDef,
+    element_type: &str,
+    out: &mut Output,
+) {
+    let list_field = match field {
+        xcbdefs::FieldDef::List(list_field) => list_field,
+        _ => unreachable!(),
+    };
+    let rust_field_name = to_rust_variable_name(&list_field.name);
+    if let Some(ref length_expr) = list_field.length_expr {
+        outln!(
+            out,
+            "let ({}, remaining) = crate::x11_utils::ListRef::<{}>::try_parse(remaining, \
+             {}.try_to_usize()?)?;",
+            rust_field_name,
+            element_type,
+            expr_to_str(
+                generator,
+                length_expr,
+                to_rust_variable_name,
+                false,
+                None,
+                true,
+            ),
+        );
+    } else {
+        outln!(
+            out,
+            "let ({}, remaining) = \
+             crate::x11_utils::ListRef::<{}>::try_parse_remaining(remaining)?;",
+            rust_field_name,
+            element_type,
+        );
+    }
+}
@@ -2,13 +2,19 @@ use std::borrow::Cow;
 use std::collections::HashMap;
 
 use super::{
-    expr_to_str, gather_deducible_fields, get_ns_name_prefix, parse, serialize, special_cases,
-    struct_type, switch, to_rust_type_name, to_rust_variable_name, CaseInfo, DeducibleField,
-    Derives, FieldContainer, NamespaceGenerator, Output, PerModuleEnumCases, StructSizeConstraint,
+    expr_to_str, gather_deducible_fields, get_ns_name_prefix, parse, reply_ref, serialize,
+    special_cases, struct_type, switch, to_rust_type_name, to_rust_variable_name, CaseInfo,
+    DeducibleField, Derives, FieldContainer, NamespaceGenerator, Output, PerModuleEnumCases,
+    StructSizeConstraint,
 };
 
 use xcbgen::defs as xcbdefs;
 
+/// Attribute for variants of `Request` and `Reply` whose type cannot be (de)serialized by serde.
+///
+/// This is the case for everything with file descriptors.
+const SERDE_SKIP: &str = "#[cfg_attr(feature = \"serde\", serde(skip))]\n";
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum IovecConversion {
     // No conversion is required.
@@ -184,7 +190,8 @@ pub(super) fn generate_request(
         ""
     };
     enum_cases.request_variants.push(format!(
-        "{ns_prefix}{name}({header}::{name}Request{lifetime}),",
+        "{serde_skip}{ns_prefix}{name}({header}::{name}Request{lifetime}),",
+        serde_skip = if gathered.has_fds() { SERDE_SKIP } else { "" },
         ns_prefix = ns_prefix,
         name = name,
         header = generator.ns.header,
@@ -236,7 +243,12 @@ pub(super) fn generate_request(
     if let Some(ref reply) = request_def.reply {
         let reply_struct_name = format!("{}Reply", name);
         enum_cases.reply_variants.push(format!(
-            "{ns_prefix}{name}({header}::{name}Reply),",
+            "{serde_skip}{ns_prefix}{name}({header}::{name}Reply),",
+            serde_skip = if gathered.reply_has_fds {
+                SERDE_SKIP
+            } else {
+                ""
+            },
             ns_prefix = ns_prefix,
             name = name,
             header = generator.ns.header,
@@ -264,6 +276,32 @@ pub(super) fn generate_request(
             header = generator.ns.header,
         ));
         let reply_fields = reply.fields.borrow();
+        let fds = reply_fields
+            .iter()
+            .filter_map(|field| match field {
+                xcbdefs::FieldDef::Fd(fd_field) => Some(format!(
+                    "alloc::vec![reply.{}.duplicate()?]",
+                    to_rust_variable_name(&fd_field.name),
+                )),
+                xcbdefs::FieldDef::FdList(fd_list_field) => Some(format!(
+                    "reply\n        .{}\n        .iter()\n        \
+                     .map(RawFdContainer::duplicate)\n        .collect::<Result<_, _>>()?",
+                    to_rust_variable_name(&fd_list_field.name),
+                )),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let fds = match fds.len() {
+            0 => "Vec::new()".to_string(),
+            1 => fds.into_iter().next().unwrap(),
+            _ => unimplemented!("Replies with more than one FD field"),
+        };
+        enum_cases.reply_serialize_cases.push(format!(
+            "Reply::{ns_prefix}{name}(reply) => Ok((\n    serialize_reply(reply, sequence),\n    {fds},\n)),",
+            ns_prefix = ns_prefix,
+            name = name,
+            fds = fds,
+        ));
         let mut reply_derives = Derives::all();
         generator.filter_derives_for_fields(&mut reply_derives, &*reply_fields, false);
         struct_type::emit_struct_type(
@@ -281,6 +319,7 @@ pub(super) fn generate_request(
             reply.doc.as_ref(),
             proto_out,
         );
+        reply_ref::emit_reply_ref(generator, &reply_struct_name, &reply_fields, proto_out);
 
         outln!(proto_out, "");
     } else {
@@ -291,6 +330,41 @@ pub(super) fn generate_request(
         ));
     }
 
+    if gathered.has_fds() {
+        // Requests with FDs cannot be cloned, so duplicate the FDs instead
+        let fields = gathered
+            .request_args
+            .iter()
+            .map(|(field_name, field_type)| match field_type {
+                Type::Simple(type_) if type_ == "RawFdContainer" => {
+                    format!("{name}: req.{name}.duplicate()?,", name = field_name)
+                }
+                Type::Simple(type_) if type_ == "Vec<RawFdContainer>" => format!(
+                    "{name}: req\n            .{name}\n            .iter()\n            \
+                     .map(RawFdContainer::duplicate)\n            .collect::<Result<_, _>>()?,",
+                    name = field_name,
+                ),
+                _ => format!("{name}: req.{name}.clone(),", name = field_name),
+            })
+            .map(|field| format!("        {}", field))
+            .collect::<Vec<_>>()
+            .join("\n");
+        enum_cases.request_serialize_cases.push(format!(
+            "Request::{ns_prefix}{name}(req) => serialize_request(\n    \
+             {header}::{name}Request {{\n{fields}\n    }},\n    ext_info_provider,\n),",
+            ns_prefix = ns_prefix,
+            name = name,
+            header = generator.ns.header,
+            fields = fields,
+        ));
+    } else {
+        enum_cases.request_serialize_cases.push(format!(
+            "Request::{ns_prefix}{name}(req) => serialize_request(req.clone(), ext_info_provider),",
+            ns_prefix = ns_prefix,
+            name = name,
+        ));
+    }
+
     if gathered.needs_lifetime {
         enum_cases.request_into_owned_cases.push(format!(
             "Request::{ns_prefix}{name}(req) => Request::{ns_prefix}{name}(req.into_owned()),",
@@ -1167,6 +1241,8 @@ fn emit_request_function(
     if let Some(ref doc) = request_def.doc {
         generator.emit_doc(doc, out, None);
     }
+    // Lets the connection know where the request was sent from, see RequestOrigin
+    outln!(out, "#[track_caller]");
     outln!(
         out,
         "pub fn {}<{}>({}) -> Result<{}, ConnectionError>",
@@ -1183,6 +1259,14 @@ fn emit_request_function(
     outln!(out, "{{");
     #[allow(clippy::cognitive_complexity)]
     out.indented(|out| {
+        if let Some((major, minor)) = special_cases::required_extension_version(request_def) {
+            outln!(
+                out,
+                "conn.check_extension_version(X11_EXTENSION_NAME, ({}, {}))?;",
+                major,
+                minor,
+            );
+        }
         for preamble in gathered.preamble.iter() {
             outln!(out, "{}", preamble);
         }
@@ -1302,6 +1386,7 @@ fn emit_request_trait_function(
     if let Some(ref doc) = request_def.doc {
         generator.emit_doc(doc, out, Default::default());
     }
+    outln!(out, "#[track_caller]");
     outln!(
         out,
         "fn {}{}{}({}) -> Result<{}, ConnectionError>",
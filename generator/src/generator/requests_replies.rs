@@ -15,8 +15,12 @@ pub(super) struct PerModuleEnumCases {
     pub(super) reply_parse_cases: Vec<String>,
     /// Lines that belong in the definition of Request::into_owned.
     pub(super) request_into_owned_cases: Vec<String>,
+    /// Lines that belong in the definition of Request::serialize.
+    pub(super) request_serialize_cases: Vec<String>,
     /// Lines that belong in the Reply enum definition.
     pub(super) reply_variants: Vec<String>,
+    /// Lines that belong in the definition of Reply::serialize.
+    pub(super) reply_serialize_cases: Vec<String>,
     /// Impls for From<ReplyType> for Reply enum.
     pub(super) reply_from_cases: Vec<String>,
 }
@@ -29,6 +33,10 @@ pub(super) fn generate(out: &mut Output, module: &xcbdefs::Module, mut enum_case
 
     outln!(out, "/// Enumeration of all possible X11 requests.");
     outln!(out, "#[derive(Debug)]");
+    outln!(
+        out,
+        r#"#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]"#
+    );
     // clippy::large_enum_variant for XkbSetNamesRequest.
     outln!(out, "#[allow(clippy::large_enum_variant)]");
     outln!(out, "#[non_exhaustive]");
@@ -197,11 +205,71 @@ pub(super) fn generate(out: &mut Output, module: &xcbdefs::Module, mut enum_case
             outln!(out, "}}");
         });
         outln!(out, "}}");
+        outln!(out, "/// Serialize this request into bytes that can be sent to the X11 server.");
+        outln!(out, "///");
+        outln!(
+            out,
+            "/// The major opcode of extension requests is looked up via `ext_info_provider`. Requests",
+        );
+        outln!(
+            out,
+            "/// that are too large for the normal length field are encoded as required by the",
+        );
+        outln!(
+            out,
+            "/// BIG-REQUESTS extension. This function does not check that this extension is enabled.",
+        );
+        outln!(out, "///");
+        outln!(
+            out,
+            "/// Since the request is not consumed, its file descriptors (if any) are duplicated.",
+        );
+        outln!(out, "#[allow(clippy::clone_on_copy)]");
+        outln!(out, "pub fn serialize(");
+        out.indented(|out| {
+            outln!(out, "&self,");
+            outln!(out, "ext_info_provider: &dyn ExtInfoProvider,");
+        });
+        outln!(
+            out,
+            ") -> Result<BufWithFds<Vec<u8>>, SerializeError> {{"
+        );
+        out.indented(|out| {
+            outln!(out, "match self {{");
+            out.indented(|out| {
+                outln!(
+                    out,
+                    "Request::Unknown(header, body) => \
+                     Ok((serialize_unknown_request(header, body), Vec::new())),"
+                );
+                for ns in namespaces.iter() {
+                    let has_feature = super::ext_has_feature(&ns.header);
+
+                    let request_serialize_cases = enum_cases
+                        .get_mut(&ns.header)
+                        .unwrap()
+                        .request_serialize_cases
+                        .drain(..);
+                    for case in request_serialize_cases {
+                        if has_feature {
+                            outln!(out, "#[cfg(feature = \"{}\")]", ns.header);
+                        }
+                        outln!(out, "{}", case);
+                    }
+                }
+            });
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
     });
     outln!(out, "}}");
     outln!(out, "");
     outln!(out, "/// Enumeration of all possible X11 replies.");
     outln!(out, "#[derive(Debug)]");
+    outln!(
+        out,
+        r#"#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]"#
+    );
     // clippy::large_enum_variant for XkbGetKbdByNameReply.
     outln!(out, "#[allow(clippy::large_enum_variant)]");
     outln!(out, "#[non_exhaustive]");
@@ -225,6 +293,56 @@ pub(super) fn generate(out: &mut Output, module: &xcbdefs::Module, mut enum_case
         }
     });
     outln!(out, "}}");
+    outln!(out, "");
+    outln!(out, "impl Reply {{");
+    out.indented(|out| {
+        outln!(
+            out,
+            "/// Serialize this reply into bytes that can be sent to an X11 client.",
+        );
+        outln!(out, "///");
+        outln!(
+            out,
+            "/// The reply gets the given sequence number and a length field matching its contents.",
+        );
+        outln!(
+            out,
+            "/// `Reply::Void` results in no bytes, since no reply is sent for such requests.",
+        );
+        outln!(out, "///");
+        outln!(
+            out,
+            "/// Since the reply is not consumed, its file descriptors (if any) are duplicated.",
+        );
+        outln!(
+            out,
+            "pub fn serialize(&self, sequence: u16) -> Result<BufWithFds<Vec<u8>>, SerializeError> {{"
+        );
+        out.indented(|out| {
+            outln!(out, "match self {{");
+            out.indented(|out| {
+                outln!(out, "Reply::Void => Ok((Vec::new(), Vec::new())),");
+                for ns in namespaces.iter() {
+                    let has_feature = super::ext_has_feature(&ns.header);
+
+                    let reply_serialize_cases = enum_cases
+                        .get_mut(&ns.header)
+                        .unwrap()
+                        .reply_serialize_cases
+                        .drain(..);
+                    for case in reply_serialize_cases {
+                        if has_feature {
+                            outln!(out, "#[cfg(feature = \"{}\")]", ns.header);
+                        }
+                        outln!(out, "{}", case);
+                    }
+                }
+            });
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+    });
+    outln!(out, "}}");
     outln!(out, "impl From<()> for Reply {{");
     out.indented(|out| {
         outln!(out, "fn from(_: ()) -> Reply {{");
@@ -253,7 +371,16 @@ pub(super) fn generate(out: &mut Output, module: &xcbdefs::Module, mut enum_case
         out,
         "/// Get the name of a request from its extension name and opcodes.",
     );
-    outln!(out, "pub(crate) fn request_name(extension: Option<&str>, major_opcode: u8, minor_opcode: u16) -> Option<&'static str> {{");
+    outln!(out, "///");
+    outln!(
+        out,
+        "/// `extension` is the name of the extension that `major_opcode` belongs to, or `None` for",
+    );
+    outln!(
+        out,
+        "/// core protocol requests. `minor_opcode` is ignored for core protocol requests.",
+    );
+    outln!(out, "pub fn request_name(extension: Option<&str>, major_opcode: u8, minor_opcode: u16) -> Option<&'static str> {{");
     out.indented(|out| {
         outln!(out, "// Check if this is a core protocol request.");
         outln!(out, "match major_opcode {{");
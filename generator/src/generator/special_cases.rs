@@ -179,3 +179,83 @@ pub(super) fn handle_event(
         outln!(out, "}}");
     }
 }
+
+/// Get the minimum version of its extension that a request needs.
+///
+/// The XML protocol descriptions do not contain this information, so it is maintained here for
+/// some of the extensions whose requests were added over several versions. Requests of other
+/// extensions are sent without a version check. The documentation of
+/// `RequestConnection::check_extension_version` lists the extensions that are covered here.
+pub(super) fn required_extension_version(request_def: &xcbdefs::RequestDef) -> Option<(u32, u32)> {
+    let ns = request_def.namespace.upgrade().unwrap();
+    let version = match (ns.header.as_str(), request_def.name.as_str()) {
+        ("composite", "NameWindowPixmap") => (0, 2),
+        ("composite", "GetOverlayWindow") | ("composite", "ReleaseOverlayWindow") => (0, 3),
+        ("randr", "GetScreenSizeRange")
+        | ("randr", "SetScreenSize")
+        | ("randr", "GetScreenResources")
+        | ("randr", "GetOutputInfo")
+        | ("randr", "ListOutputProperties")
+        | ("randr", "QueryOutputProperty")
+        | ("randr", "ConfigureOutputProperty")
+        | ("randr", "ChangeOutputProperty")
+        | ("randr", "DeleteOutputProperty")
+        | ("randr", "GetOutputProperty")
+        | ("randr", "CreateMode")
+        | ("randr", "DestroyMode")
+        | ("randr", "AddOutputMode")
+        | ("randr", "DeleteOutputMode")
+        | ("randr", "GetCrtcInfo")
+        | ("randr", "SetCrtcConfig")
+        | ("randr", "GetCrtcGammaSize")
+        | ("randr", "GetCrtcGamma")
+        | ("randr", "SetCrtcGamma") => (1, 2),
+        ("randr", "GetScreenResourcesCurrent")
+        | ("randr", "SetCrtcTransform")
+        | ("randr", "GetCrtcTransform")
+        | ("randr", "GetPanning")
+        | ("randr", "SetPanning")
+        | ("randr", "SetOutputPrimary")
+        | ("randr", "GetOutputPrimary") => (1, 3),
+        ("randr", "GetProviders")
+        | ("randr", "GetProviderInfo")
+        | ("randr", "SetProviderOffloadSink")
+        | ("randr", "SetProviderOutputSource")
+        | ("randr", "ListProviderProperties")
+        | ("randr", "QueryProviderProperty")
+        | ("randr", "ConfigureProviderProperty")
+        | ("randr", "ChangeProviderProperty")
+        | ("randr", "DeleteProviderProperty")
+        | ("randr", "GetProviderProperty") => (1, 4),
+        ("randr", "GetMonitors") | ("randr", "SetMonitor") | ("randr", "DeleteMonitor") => (1, 5),
+        ("randr", "CreateLease") | ("randr", "FreeLease") => (1, 6),
+        ("xfixes", "CreateRegion")
+        | ("xfixes", "CreateRegionFromBitmap")
+        | ("xfixes", "CreateRegionFromWindow")
+        | ("xfixes", "CreateRegionFromGC")
+        | ("xfixes", "CreateRegionFromPicture")
+        | ("xfixes", "DestroyRegion")
+        | ("xfixes", "SetRegion")
+        | ("xfixes", "CopyRegion")
+        | ("xfixes", "UnionRegion")
+        | ("xfixes", "IntersectRegion")
+        | ("xfixes", "SubtractRegion")
+        | ("xfixes", "InvertRegion")
+        | ("xfixes", "TranslateRegion")
+        | ("xfixes", "RegionExtents")
+        | ("xfixes", "FetchRegion")
+        | ("xfixes", "SetGCClipRegion")
+        | ("xfixes", "SetWindowShapeRegion")
+        | ("xfixes", "SetPictureClipRegion")
+        | ("xfixes", "SetCursorName")
+        | ("xfixes", "GetCursorName")
+        | ("xfixes", "GetCursorImageAndName")
+        | ("xfixes", "ChangeCursor")
+        | ("xfixes", "ChangeCursorByName") => (2, 0),
+        ("xfixes", "ExpandRegion") => (3, 0),
+        ("xfixes", "HideCursor") | ("xfixes", "ShowCursor") => (4, 0),
+        ("xfixes", "CreatePointerBarrier") | ("xfixes", "DeletePointerBarrier") => (5, 0),
+        _ => return None,
+    };
+    Some(version)
+}
@@ -34,6 +34,38 @@ pub enum PollReply {
     Reply(Vec<u8>),
 }
 
+/// Identifies a special event queue of a [`Connection`].
+///
+/// See [`Connection::register_special_event`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SpecialEventId(u64);
+
+// Events of the Generic Event Extension that are kept apart from the normal events
+#[derive(Debug)]
+struct SpecialEventQueue {
+    id: SpecialEventId,
+    // The major opcode of the extension that sends the events
+    extension: u8,
+    // The event types that belong into this queue; empty for all types
+    event_types: Vec<u16>,
+    // The event ID that the events carry
+    eid: u32,
+    events: VecDeque<(SequenceNumber, Vec<u8>)>,
+}
+
+impl SpecialEventQueue {
+    fn matches(&self, packet: &[u8]) -> bool {
+        use crate::protocol::xproto::GE_GENERIC_EVENT;
+        if packet.len() < 16 || packet[0] & 0x7f != GE_GENERIC_EVENT || packet[1] != self.extension
+        {
+            return false;
+        }
+        let event_type = u16::from_ne_bytes([packet[8], packet[9]]);
+        let eid = u32::from_ne_bytes([packet[12], packet[13], packet[14], packet[15]]);
+        eid == self.eid && (self.event_types.is_empty() || self.event_types.contains(&event_type))
+    }
+}
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 struct SentRequest {
     seqno: SequenceNumber,
@@ -64,6 +96,11 @@ pub struct Connection {
 
     // FDs that were read, but not yet assigned to any reply
     pending_fds: VecDeque<RawFdContainer>,
+
+    // Queues for events that should not end up in `pending_events`
+    special_events: Vec<SpecialEventQueue>,
+    // The ID of the next special event queue that is registered
+    next_special_event_id: u64,
 }
 
 impl Connection {
@@ -81,6 +118,8 @@ impl Connection {
             pending_events: VecDeque::new(),
             pending_replies: VecDeque::new(),
             pending_fds: VecDeque::new(),
+            special_events: Vec::new(),
+            next_special_event_id: 0,
         }
     }
 
@@ -175,6 +214,15 @@ impl Connection {
         Some(full_number)
     }
 
+    /// Get the sequence number of the last packet that was received.
+    ///
+    /// After a packet was enqueued with [`Connection::enqueue_packet`], this is the full sequence
+    /// number of that packet (except for `KeymapNotify` events, which do not contain a sequence
+    /// number).
+    pub fn last_sequence_read(&self) -> SequenceNumber {
+        self.last_sequence_read
+    }
+
     /// Add FDs that were received to the internal state.
     ///
     /// This must be called before the corresponding packets are enqueued.
@@ -243,6 +291,9 @@ impl Connection {
             } else {
                 self.pending_replies.push_back((seqno, (packet, fds)));
             }
+        } else if let Some(queue) = self.special_events.iter_mut().find(|q| q.matches(&packet)) {
+            // It is an event that was requested to be kept apart
+            queue.events.push_back((seqno, packet));
         } else {
             // It is an event
             self.pending_events.push_back((seqno, packet));
@@ -319,11 +370,66 @@ impl Connection {
             .pop_front()
             .map(|(seqno, event)| (event, seqno))
     }
+
+    /// Register a queue for events of the Generic Event Extension.
+    ///
+    /// Afterwards, events from the extension with the given major opcode, one of the given event
+    /// types, and the given event ID (EID) are no longer returned by
+    /// [`Connection::poll_for_event_with_sequence`]. Instead, they are returned by
+    /// [`Connection::poll_for_special_event`]. An empty list of event types matches all event
+    /// types. Extensions like Present and XInput 2 allow to select events with a client-chosen
+    /// EID, which makes it possible to route them to the part of the program that selected them.
+    ///
+    /// Returns `None` if a queue for the same extension and EID is already registered.
+    pub fn register_special_event(
+        &mut self,
+        extension: u8,
+        event_types: &[u16],
+        eid: u32,
+    ) -> Option<SpecialEventId> {
+        if self
+            .special_events
+            .iter()
+            .any(|q| q.extension == extension && q.eid == eid)
+        {
+            return None;
+        }
+        let id = SpecialEventId(self.next_special_event_id);
+        self.next_special_event_id += 1;
+        self.special_events.push(SpecialEventQueue {
+            id,
+            extension,
+            event_types: event_types.to_vec(),
+            eid,
+            events: VecDeque::new(),
+        });
+        Some(id)
+    }
+
+    /// Remove a queue that was registered with [`Connection::register_special_event`].
+    ///
+    /// Events that are still in the queue are discarded. Later events are handled as normal
+    /// events again.
+    pub fn unregister_special_event(&mut self, id: SpecialEventId) {
+        self.special_events.retain(|q| q.id != id);
+    }
+
+    /// Get a pending event from a special event queue.
+    ///
+    /// Returns `None` if the queue is empty or does not exist.
+    pub fn poll_for_special_event(&mut self, id: SpecialEventId) -> Option<RawEventAndSeqNumber> {
+        self.special_events
+            .iter_mut()
+            .find(|q| q.id == id)
+            .and_then(|q| q.events.pop_front())
+            .map(|(seqno, event)| (event, seqno))
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::{Connection, ReplyFdKind};
+    use alloc::{vec, vec::Vec};
 
     #[test]
     fn insert_sync_no_reply() {
@@ -376,4 +482,39 @@ mod test {
         let seqno = connection.send_request(ReplyFdKind::ReplyWithoutFDs);
         assert_eq!(Some(0x10000), seqno);
     }
+
+    fn ge_event(extension: u8, event_type: u16, eid: u32, sequence: u16) -> Vec<u8> {
+        let mut packet = vec![0; 32];
+        packet[0] = crate::protocol::xproto::GE_GENERIC_EVENT;
+        packet[1] = extension;
+        packet[2..4].copy_from_slice(&sequence.to_ne_bytes());
+        packet[8..10].copy_from_slice(&event_type.to_ne_bytes());
+        packet[12..16].copy_from_slice(&eid.to_ne_bytes());
+        packet
+    }
+
+    #[test]
+    fn special_events() {
+        let mut connection = Connection::new();
+        let id = connection.register_special_event(140, &[1], 7).unwrap();
+        assert_eq!(connection.register_special_event(140, &[], 7), None);
+
+        connection.enqueue_packet(ge_event(140, 1, 7, 1));
+        connection.enqueue_packet(ge_event(140, 2, 7, 2));
+        connection.enqueue_packet(ge_event(140, 1, 8, 3));
+        connection.enqueue_packet(ge_event(141, 1, 7, 4));
+
+        let (event, seqno) = connection.poll_for_special_event(id).unwrap();
+        assert_eq!((event, seqno), (ge_event(140, 1, 7, 1), 1));
+        assert_eq!(connection.poll_for_special_event(id), None);
+        for expected in 2..=4 {
+            let (_, seqno) = connection.poll_for_event_with_sequence().unwrap();
+            assert_eq!(seqno, expected);
+        }
+
+        connection.unregister_special_event(id);
+        connection.enqueue_packet(ge_event(140, 1, 7, 5));
+        assert_eq!(connection.poll_for_special_event(id), None);
+        assert!(connection.poll_for_event_with_sequence().is_some());
+    }
 }
@@ -76,6 +76,37 @@ impl fmt::Display for ParseError {
     }
 }
 
+/// An error that occurred while serializing a request.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum SerializeError {
+    /// The request belongs to an extension that the `ExtInfoProvider` does not know.
+    ///
+    /// Without information about the extension, the major opcode of the request is unknown.
+    MissingExtension(&'static str),
+
+    /// Duplicating a file descriptor that is sent with the request failed.
+    #[cfg(feature = "std")]
+    FdDuplicationFailed(std::io::Error),
+}
+
+#[cfg(feature = "std")]
+impl Error for SerializeError {}
+
+impl fmt::Display for SerializeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SerializeError::MissingExtension(name) => {
+                write!(f, "The opcode of the {} extension is unknown", name)
+            }
+            #[cfg(feature = "std")]
+            SerializeError::FdDuplicationFailed(err) => {
+                write!(f, "Duplicating a file descriptor failed: {}", err)
+            }
+        }
+    }
+}
+
 /// An error that occurred while connecting to an X11 server
 #[derive(Debug)]
 #[non_exhaustive]
@@ -109,6 +140,14 @@ pub enum ConnectError {
     #[cfg(feature = "std")]
     IoError(std::io::Error),
 
+    /// Connecting to the X11 server failed for every address of the display.
+    ///
+    /// Each entry contains a description of an address that was tried, for example
+    /// `[::1]:6000` or `/tmp/.X11-unix/X0`, together with the error that occurred while
+    /// connecting to it.
+    #[cfg(feature = "std")]
+    ConnectFailed(std::vec::Vec<(std::string::String, std::io::Error)>),
+
     /// Invalid ID mask provided by the server.
     ///
     /// The value of `resource_id_mask` in the `Setup` provided by the server was zero.
@@ -149,6 +188,15 @@ impl fmt::Display for ConnectError {
             ConnectError::ParseError(err) => err.fmt(f),
             #[cfg(feature = "std")]
             ConnectError::IoError(err) => err.fmt(f),
+            #[cfg(feature = "std")]
+            ConnectError::ConnectFailed(attempts) => {
+                write!(f, "Connecting to the X11 server failed")?;
+                for (i, (address, err)) in attempts.iter().enumerate() {
+                    let separator = if i == 0 { ": " } else { "; " };
+                    write!(f, "{}{}: {}", separator, address, err)?;
+                }
+                Ok(())
+            }
             ConnectError::ZeroIdMask => write!(f, "XID mask was zero"),
             ConnectError::SetupFailed(err) => display(f, "X11 setup failed", &err.reason),
             ConnectError::SetupAuthenticate(err) => {
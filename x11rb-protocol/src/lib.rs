@@ -5,6 +5,18 @@
 //!
 //! This protocol does not do any I/O. If you need an X11 client library, look at
 //! <https://docs.rs/x11rb/latest/x11rb/>.
+//!
+//! # Feature flags
+//!
+//! * `std` (enabled by default): Enable code that needs the standard library, like `xauth`.
+//! * `resource_manager`: Enable the code in `resource_manager` for loading and querying the X11
+//!   resource database.
+//! * `serde`: Implement `serde::Serialize` and `serde::Deserialize` for the types in [`protocol`],
+//!   including the [`protocol::Request`], [`protocol::Reply`] and [`protocol::Event`] enums, and
+//!   for [`x11_utils::X11Error`]. Requests and replies that contain file descriptors cannot be
+//!   (de)serialized.
+//! * `all-extensions` and one feature per X11 extension, e.g. `randr`: Enable the code for X11
+//!   extensions.
 
 #![forbid(
     missing_copy_implementations,
@@ -53,6 +65,7 @@ pub mod parse_display;
 pub mod protocol;
 #[cfg(feature = "resource_manager")]
 pub mod resource_manager;
+pub mod server;
 mod utils;
 pub mod wrapper;
 #[cfg(feature = "std")]
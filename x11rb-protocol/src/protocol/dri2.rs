@@ -581,6 +581,53 @@ impl ConnectReply {
     }
 }
 
+/// Borrowed version of [`ConnectReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct ConnectReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub driver_name: &'a [u8],
+    pub alignment_pad: &'a [u8],
+    pub device_name: &'a [u8],
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for ConnectReply {
+    type Ref = ConnectReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (driver_name_length, remaining) = u32::try_parse(remaining)?;
+        let (device_name_length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        let (driver_name, remaining) = crate::x11_utils::parse_u8_list(remaining, driver_name_length.try_to_usize()?)?;
+        let (alignment_pad, remaining) = crate::x11_utils::parse_u8_list(remaining, (driver_name_length.checked_add(3u32).ok_or(ParseError::InvalidExpression)? & (!3u32)).checked_sub(driver_name_length).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (device_name, remaining) = crate::x11_utils::parse_u8_list(remaining, device_name_length.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = ConnectReplyRef { sequence, length, driver_name, alignment_pad, device_name };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<ConnectReplyRef<'_>> for ConnectReply {
+    fn from(reply: ConnectReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            driver_name: reply.driver_name.to_vec(),
+            alignment_pad: reply.alignment_pad.to_vec(),
+            device_name: reply.device_name.to_vec(),
+        }
+    }
+}
+
 /// Opcode for the Authenticate request
 pub const AUTHENTICATE_REQUEST: u8 = 2;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -960,6 +1007,52 @@ impl GetBuffersReply {
     }
 }
 
+/// Borrowed version of [`GetBuffersReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetBuffersReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub width: u32,
+    pub height: u32,
+    pub buffers: crate::x11_utils::ListRef<'a, DRI2Buffer>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetBuffersReply {
+    type Ref = GetBuffersReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (width, remaining) = u32::try_parse(remaining)?;
+        let (height, remaining) = u32::try_parse(remaining)?;
+        let (count, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (buffers, remaining) = crate::x11_utils::ListRef::<DRI2Buffer>::try_parse(remaining, count.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetBuffersReplyRef { sequence, length, width, height, buffers };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetBuffersReplyRef<'_>> for GetBuffersReply {
+    fn from(reply: GetBuffersReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            width: reply.width,
+            height: reply.height,
+            buffers: reply.buffers.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the CopyRegion request
 pub const COPY_REGION_REQUEST: u8 = 6;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -1241,6 +1334,52 @@ impl GetBuffersWithFormatReply {
     }
 }
 
+/// Borrowed version of [`GetBuffersWithFormatReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetBuffersWithFormatReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub width: u32,
+    pub height: u32,
+    pub buffers: crate::x11_utils::ListRef<'a, DRI2Buffer>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetBuffersWithFormatReply {
+    type Ref = GetBuffersWithFormatReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (width, remaining) = u32::try_parse(remaining)?;
+        let (height, remaining) = u32::try_parse(remaining)?;
+        let (count, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (buffers, remaining) = crate::x11_utils::ListRef::<DRI2Buffer>::try_parse(remaining, count.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetBuffersWithFormatReplyRef { sequence, length, width, height, buffers };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetBuffersWithFormatReplyRef<'_>> for GetBuffersWithFormatReply {
+    fn from(reply: GetBuffersWithFormatReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            width: reply.width,
+            height: reply.height,
+            buffers: reply.buffers.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the SwapBuffers request
 pub const SWAP_BUFFERS_REQUEST: u8 = 8;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
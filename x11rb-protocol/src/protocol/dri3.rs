@@ -926,6 +926,50 @@ impl GetSupportedModifiersReply {
     }
 }
 
+/// Borrowed version of [`GetSupportedModifiersReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetSupportedModifiersReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub window_modifiers: crate::x11_utils::ListRef<'a, u64>,
+    pub screen_modifiers: crate::x11_utils::ListRef<'a, u64>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetSupportedModifiersReply {
+    type Ref = GetSupportedModifiersReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (num_window_modifiers, remaining) = u32::try_parse(remaining)?;
+        let (num_screen_modifiers, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        let (window_modifiers, remaining) = crate::x11_utils::ListRef::<u64>::try_parse(remaining, num_window_modifiers.try_to_usize()?)?;
+        let (screen_modifiers, remaining) = crate::x11_utils::ListRef::<u64>::try_parse(remaining, num_screen_modifiers.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetSupportedModifiersReplyRef { sequence, length, window_modifiers, screen_modifiers };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetSupportedModifiersReplyRef<'_>> for GetSupportedModifiersReply {
+    fn from(reply: GetSupportedModifiersReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            window_modifiers: reply.window_modifiers.iter().collect(),
+            screen_modifiers: reply.screen_modifiers.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the PixmapFromBuffers request
 pub const PIXMAP_FROM_BUFFERS_REQUEST: u8 = 7;
 #[derive(Debug, PartialEq, Eq)]
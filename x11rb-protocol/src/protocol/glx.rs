@@ -1853,6 +1853,49 @@ impl GetVisualConfigsReply {
     }
 }
 
+/// Borrowed version of [`GetVisualConfigsReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetVisualConfigsReplyRef<'a> {
+    pub sequence: u16,
+    pub num_visuals: u32,
+    pub num_properties: u32,
+    pub property_list: crate::x11_utils::ListRef<'a, u32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetVisualConfigsReply {
+    type Ref = GetVisualConfigsReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (num_visuals, remaining) = u32::try_parse(remaining)?;
+        let (num_properties, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        let (property_list, remaining) = crate::x11_utils::ListRef::<u32>::try_parse(remaining, length.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetVisualConfigsReplyRef { sequence, num_visuals, num_properties, property_list };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetVisualConfigsReplyRef<'_>> for GetVisualConfigsReply {
+    fn from(reply: GetVisualConfigsReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            num_visuals: reply.num_visuals,
+            num_properties: reply.num_properties,
+            property_list: reply.property_list.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the DestroyGLXPixmap request
 pub const DESTROY_GLX_PIXMAP_REQUEST: u8 = 15;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -2125,6 +2168,49 @@ impl VendorPrivateWithReplyReply {
     }
 }
 
+/// Borrowed version of [`VendorPrivateWithReplyReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct VendorPrivateWithReplyReplyRef<'a> {
+    pub sequence: u16,
+    pub retval: u32,
+    pub data1: [u8; 24],
+    pub data2: &'a [u8],
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for VendorPrivateWithReplyReply {
+    type Ref = VendorPrivateWithReplyReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (retval, remaining) = u32::try_parse(remaining)?;
+        let (data1, remaining) = crate::x11_utils::parse_u8_list(remaining, 24)?;
+        let data1 = <[u8; 24]>::try_from(data1).unwrap();
+        let (data2, remaining) = crate::x11_utils::parse_u8_list(remaining, length.checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = VendorPrivateWithReplyReplyRef { sequence, retval, data1, data2 };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<VendorPrivateWithReplyReplyRef<'_>> for VendorPrivateWithReplyReply {
+    fn from(reply: VendorPrivateWithReplyReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            retval: reply.retval,
+            data1: reply.data1,
+            data2: reply.data2.to_vec(),
+        }
+    }
+}
+
 /// Opcode for the QueryExtensionsString request
 pub const QUERY_EXTENSIONS_STRING_REQUEST: u8 = 18;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -2389,6 +2475,47 @@ impl QueryServerStringReply {
     }
 }
 
+/// Borrowed version of [`QueryServerStringReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct QueryServerStringReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub string: &'a [u8],
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for QueryServerStringReply {
+    type Ref = QueryServerStringReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (str_len, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        let (string, remaining) = crate::x11_utils::parse_u8_list(remaining, str_len.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryServerStringReplyRef { sequence, length, string };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<QueryServerStringReplyRef<'_>> for QueryServerStringReply {
+    fn from(reply: QueryServerStringReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            string: reply.string.to_vec(),
+        }
+    }
+}
+
 /// Opcode for the ClientInfo request
 pub const CLIENT_INFO_REQUEST: u8 = 20;
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -2591,6 +2718,49 @@ impl GetFBConfigsReply {
     }
 }
 
+/// Borrowed version of [`GetFBConfigsReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetFBConfigsReplyRef<'a> {
+    pub sequence: u16,
+    pub num_fb_configs: u32,
+    pub num_properties: u32,
+    pub property_list: crate::x11_utils::ListRef<'a, u32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetFBConfigsReply {
+    type Ref = GetFBConfigsReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (num_fb_configs, remaining) = u32::try_parse(remaining)?;
+        let (num_properties, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        let (property_list, remaining) = crate::x11_utils::ListRef::<u32>::try_parse(remaining, length.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetFBConfigsReplyRef { sequence, num_fb_configs, num_properties, property_list };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetFBConfigsReplyRef<'_>> for GetFBConfigsReply {
+    fn from(reply: GetFBConfigsReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            num_fb_configs: reply.num_fb_configs,
+            num_properties: reply.num_properties,
+            property_list: reply.property_list.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the CreatePixmap request
 pub const CREATE_PIXMAP_REQUEST: u8 = 22;
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -2959,6 +3129,46 @@ impl QueryContextReply {
     }
 }
 
+/// Borrowed version of [`QueryContextReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct QueryContextReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub attribs: crate::x11_utils::ListRef<'a, u32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for QueryContextReply {
+    type Ref = QueryContextReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (num_attribs, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (attribs, remaining) = crate::x11_utils::ListRef::<u32>::try_parse(remaining, num_attribs.checked_mul(2u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryContextReplyRef { sequence, length, attribs };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<QueryContextReplyRef<'_>> for QueryContextReply {
+    fn from(reply: QueryContextReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            attribs: reply.attribs.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the MakeContextCurrent request
 pub const MAKE_CONTEXT_CURRENT_REQUEST: u8 = 26;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -3382,6 +3592,46 @@ impl GetDrawableAttributesReply {
     }
 }
 
+/// Borrowed version of [`GetDrawableAttributesReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetDrawableAttributesReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub attribs: crate::x11_utils::ListRef<'a, u32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetDrawableAttributesReply {
+    type Ref = GetDrawableAttributesReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (num_attribs, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (attribs, remaining) = crate::x11_utils::ListRef::<u32>::try_parse(remaining, num_attribs.checked_mul(2u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetDrawableAttributesReplyRef { sequence, length, attribs };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetDrawableAttributesReplyRef<'_>> for GetDrawableAttributesReply {
+    fn from(reply: GetDrawableAttributesReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            attribs: reply.attribs.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the ChangeDrawableAttributes request
 pub const CHANGE_DRAWABLE_ATTRIBUTES_REQUEST: u8 = 30;
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -4512,6 +4762,52 @@ impl RenderModeReply {
     }
 }
 
+/// Borrowed version of [`RenderModeReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct RenderModeReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub ret_val: u32,
+    pub new_mode: u32,
+    pub data: crate::x11_utils::ListRef<'a, u32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for RenderModeReply {
+    type Ref = RenderModeReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (ret_val, remaining) = u32::try_parse(remaining)?;
+        let (n, remaining) = u32::try_parse(remaining)?;
+        let (new_mode, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::ListRef::<u32>::try_parse(remaining, n.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = RenderModeReplyRef { sequence, length, ret_val, new_mode, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<RenderModeReplyRef<'_>> for RenderModeReply {
+    fn from(reply: RenderModeReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            ret_val: reply.ret_val,
+            new_mode: reply.new_mode,
+            data: reply.data.iter().collect(),
+        }
+    }
+}
+
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RM(u16);
@@ -4986,6 +5282,43 @@ impl ReadPixelsReply {
     }
 }
 
+/// Borrowed version of [`ReadPixelsReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct ReadPixelsReplyRef<'a> {
+    pub sequence: u16,
+    pub data: &'a [u8],
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for ReadPixelsReply {
+    type Ref = ReadPixelsReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(24..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, length.checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = ReadPixelsReplyRef { sequence, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<ReadPixelsReplyRef<'_>> for ReadPixelsReply {
+    fn from(reply: ReadPixelsReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            data: reply.data.to_vec(),
+        }
+    }
+}
+
 /// Opcode for the GetBooleanv request
 pub const GET_BOOLEANV_REQUEST: u8 = 112;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -5116,6 +5449,50 @@ impl GetBooleanvReply {
     }
 }
 
+/// Borrowed version of [`GetBooleanvReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetBooleanvReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: bool,
+    pub data: crate::x11_utils::ListRef<'a, bool>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetBooleanvReply {
+    type Ref = GetBooleanvReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse(remaining)?;
+        let (datum, remaining) = bool::try_parse(remaining)?;
+        let remaining = remaining.get(15..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::ListRef::<bool>::try_parse(remaining, n.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetBooleanvReplyRef { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetBooleanvReplyRef<'_>> for GetBooleanvReply {
+    fn from(reply: GetBooleanvReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            datum: reply.datum,
+            data: reply.data.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the GetClipPlane request
 pub const GET_CLIP_PLANE_REQUEST: u8 = 113;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -5239,6 +5616,43 @@ impl GetClipPlaneReply {
     }
 }
 
+/// Borrowed version of [`GetClipPlaneReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetClipPlaneReplyRef<'a> {
+    pub sequence: u16,
+    pub data: crate::x11_utils::ListRef<'a, Float64>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetClipPlaneReply {
+    type Ref = GetClipPlaneReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(24..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::ListRef::<Float64>::try_parse(remaining, length.checked_div(2u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetClipPlaneReplyRef { sequence, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetClipPlaneReplyRef<'_>> for GetClipPlaneReply {
+    fn from(reply: GetClipPlaneReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            data: reply.data.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the GetDoublev request
 pub const GET_DOUBLEV_REQUEST: u8 = 114;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -5369,9 +5783,53 @@ impl GetDoublevReply {
     }
 }
 
-/// Opcode for the GetError request
-pub const GET_ERROR_REQUEST: u8 = 115;
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// Borrowed version of [`GetDoublevReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetDoublevReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: Float64,
+    pub data: crate::x11_utils::ListRef<'a, Float64>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetDoublevReply {
+    type Ref = GetDoublevReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse(remaining)?;
+        let (datum, remaining) = Float64::try_parse(remaining)?;
+        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::ListRef::<Float64>::try_parse(remaining, n.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetDoublevReplyRef { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetDoublevReplyRef<'_>> for GetDoublevReply {
+    fn from(reply: GetDoublevReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            datum: reply.datum,
+            data: reply.data.iter().collect(),
+        }
+    }
+}
+
+/// Opcode for the GetError request
+pub const GET_ERROR_REQUEST: u8 = 115;
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetErrorRequest {
     pub context_tag: ContextTag,
@@ -5611,6 +6069,50 @@ impl GetFloatvReply {
     }
 }
 
+/// Borrowed version of [`GetFloatvReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetFloatvReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: Float32,
+    pub data: crate::x11_utils::ListRef<'a, Float32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetFloatvReply {
+    type Ref = GetFloatvReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse(remaining)?;
+        let (datum, remaining) = Float32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::ListRef::<Float32>::try_parse(remaining, n.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetFloatvReplyRef { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetFloatvReplyRef<'_>> for GetFloatvReply {
+    fn from(reply: GetFloatvReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            datum: reply.datum,
+            data: reply.data.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the GetIntegerv request
 pub const GET_INTEGERV_REQUEST: u8 = 117;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -5741,6 +6243,50 @@ impl GetIntegervReply {
     }
 }
 
+/// Borrowed version of [`GetIntegervReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetIntegervReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: i32,
+    pub data: crate::x11_utils::ListRef<'a, i32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetIntegervReply {
+    type Ref = GetIntegervReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse(remaining)?;
+        let (datum, remaining) = i32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::ListRef::<i32>::try_parse(remaining, n.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetIntegervReplyRef { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetIntegervReplyRef<'_>> for GetIntegervReply {
+    fn from(reply: GetIntegervReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            datum: reply.datum,
+            data: reply.data.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the GetLightfv request
 pub const GET_LIGHTFV_REQUEST: u8 = 118;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -5879,6 +6425,50 @@ impl GetLightfvReply {
     }
 }
 
+/// Borrowed version of [`GetLightfvReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetLightfvReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: Float32,
+    pub data: crate::x11_utils::ListRef<'a, Float32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetLightfvReply {
+    type Ref = GetLightfvReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse(remaining)?;
+        let (datum, remaining) = Float32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::ListRef::<Float32>::try_parse(remaining, n.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetLightfvReplyRef { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetLightfvReplyRef<'_>> for GetLightfvReply {
+    fn from(reply: GetLightfvReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            datum: reply.datum,
+            data: reply.data.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the GetLightiv request
 pub const GET_LIGHTIV_REQUEST: u8 = 119;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -6017,6 +6607,50 @@ impl GetLightivReply {
     }
 }
 
+/// Borrowed version of [`GetLightivReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetLightivReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: i32,
+    pub data: crate::x11_utils::ListRef<'a, i32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetLightivReply {
+    type Ref = GetLightivReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse(remaining)?;
+        let (datum, remaining) = i32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::ListRef::<i32>::try_parse(remaining, n.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetLightivReplyRef { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetLightivReplyRef<'_>> for GetLightivReply {
+    fn from(reply: GetLightivReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            datum: reply.datum,
+            data: reply.data.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the GetMapdv request
 pub const GET_MAPDV_REQUEST: u8 = 120;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -6155,6 +6789,50 @@ impl GetMapdvReply {
     }
 }
 
+/// Borrowed version of [`GetMapdvReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetMapdvReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: Float64,
+    pub data: crate::x11_utils::ListRef<'a, Float64>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetMapdvReply {
+    type Ref = GetMapdvReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse(remaining)?;
+        let (datum, remaining) = Float64::try_parse(remaining)?;
+        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::ListRef::<Float64>::try_parse(remaining, n.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetMapdvReplyRef { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetMapdvReplyRef<'_>> for GetMapdvReply {
+    fn from(reply: GetMapdvReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            datum: reply.datum,
+            data: reply.data.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the GetMapfv request
 pub const GET_MAPFV_REQUEST: u8 = 121;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -6293,6 +6971,50 @@ impl GetMapfvReply {
     }
 }
 
+/// Borrowed version of [`GetMapfvReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetMapfvReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: Float32,
+    pub data: crate::x11_utils::ListRef<'a, Float32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetMapfvReply {
+    type Ref = GetMapfvReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse(remaining)?;
+        let (datum, remaining) = Float32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::ListRef::<Float32>::try_parse(remaining, n.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetMapfvReplyRef { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetMapfvReplyRef<'_>> for GetMapfvReply {
+    fn from(reply: GetMapfvReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            datum: reply.datum,
+            data: reply.data.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the GetMapiv request
 pub const GET_MAPIV_REQUEST: u8 = 122;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -6431,6 +7153,50 @@ impl GetMapivReply {
     }
 }
 
+/// Borrowed version of [`GetMapivReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetMapivReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: i32,
+    pub data: crate::x11_utils::ListRef<'a, i32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetMapivReply {
+    type Ref = GetMapivReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse(remaining)?;
+        let (datum, remaining) = i32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::ListRef::<i32>::try_parse(remaining, n.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetMapivReplyRef { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetMapivReplyRef<'_>> for GetMapivReply {
+    fn from(reply: GetMapivReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            datum: reply.datum,
+            data: reply.data.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the GetMaterialfv request
 pub const GET_MATERIALFV_REQUEST: u8 = 123;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -6569,6 +7335,50 @@ impl GetMaterialfvReply {
     }
 }
 
+/// Borrowed version of [`GetMaterialfvReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetMaterialfvReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: Float32,
+    pub data: crate::x11_utils::ListRef<'a, Float32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetMaterialfvReply {
+    type Ref = GetMaterialfvReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse(remaining)?;
+        let (datum, remaining) = Float32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::ListRef::<Float32>::try_parse(remaining, n.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetMaterialfvReplyRef { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetMaterialfvReplyRef<'_>> for GetMaterialfvReply {
+    fn from(reply: GetMaterialfvReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            datum: reply.datum,
+            data: reply.data.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the GetMaterialiv request
 pub const GET_MATERIALIV_REQUEST: u8 = 124;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -6707,6 +7517,50 @@ impl GetMaterialivReply {
     }
 }
 
+/// Borrowed version of [`GetMaterialivReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetMaterialivReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: i32,
+    pub data: crate::x11_utils::ListRef<'a, i32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetMaterialivReply {
+    type Ref = GetMaterialivReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse(remaining)?;
+        let (datum, remaining) = i32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::ListRef::<i32>::try_parse(remaining, n.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetMaterialivReplyRef { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetMaterialivReplyRef<'_>> for GetMaterialivReply {
+    fn from(reply: GetMaterialivReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            datum: reply.datum,
+            data: reply.data.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the GetPixelMapfv request
 pub const GET_PIXEL_MAPFV_REQUEST: u8 = 125;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -6837,6 +7691,50 @@ impl GetPixelMapfvReply {
     }
 }
 
+/// Borrowed version of [`GetPixelMapfvReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetPixelMapfvReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: Float32,
+    pub data: crate::x11_utils::ListRef<'a, Float32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetPixelMapfvReply {
+    type Ref = GetPixelMapfvReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse(remaining)?;
+        let (datum, remaining) = Float32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::ListRef::<Float32>::try_parse(remaining, n.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetPixelMapfvReplyRef { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetPixelMapfvReplyRef<'_>> for GetPixelMapfvReply {
+    fn from(reply: GetPixelMapfvReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            datum: reply.datum,
+            data: reply.data.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the GetPixelMapuiv request
 pub const GET_PIXEL_MAPUIV_REQUEST: u8 = 126;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -6967,6 +7865,50 @@ impl GetPixelMapuivReply {
     }
 }
 
+/// Borrowed version of [`GetPixelMapuivReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetPixelMapuivReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: u32,
+    pub data: crate::x11_utils::ListRef<'a, u32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetPixelMapuivReply {
+    type Ref = GetPixelMapuivReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse(remaining)?;
+        let (datum, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::ListRef::<u32>::try_parse(remaining, n.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetPixelMapuivReplyRef { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetPixelMapuivReplyRef<'_>> for GetPixelMapuivReply {
+    fn from(reply: GetPixelMapuivReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            datum: reply.datum,
+            data: reply.data.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the GetPixelMapusv request
 pub const GET_PIXEL_MAPUSV_REQUEST: u8 = 127;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -7097,6 +8039,50 @@ impl GetPixelMapusvReply {
     }
 }
 
+/// Borrowed version of [`GetPixelMapusvReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetPixelMapusvReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: u16,
+    pub data: crate::x11_utils::ListRef<'a, u16>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetPixelMapusvReply {
+    type Ref = GetPixelMapusvReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse(remaining)?;
+        let (datum, remaining) = u16::try_parse(remaining)?;
+        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::ListRef::<u16>::try_parse(remaining, n.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetPixelMapusvReplyRef { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetPixelMapusvReplyRef<'_>> for GetPixelMapusvReply {
+    fn from(reply: GetPixelMapusvReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            datum: reply.datum,
+            data: reply.data.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the GetPolygonStipple request
 pub const GET_POLYGON_STIPPLE_REQUEST: u8 = 128;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -7222,6 +8208,43 @@ impl GetPolygonStippleReply {
     }
 }
 
+/// Borrowed version of [`GetPolygonStippleReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetPolygonStippleReplyRef<'a> {
+    pub sequence: u16,
+    pub data: &'a [u8],
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetPolygonStippleReply {
+    type Ref = GetPolygonStippleReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(24..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, length.checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetPolygonStippleReplyRef { sequence, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetPolygonStippleReplyRef<'_>> for GetPolygonStippleReply {
+    fn from(reply: GetPolygonStippleReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            data: reply.data.to_vec(),
+        }
+    }
+}
+
 /// Opcode for the GetString request
 pub const GET_STRING_REQUEST: u8 = 129;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -7350,6 +8373,47 @@ impl GetStringReply {
     }
 }
 
+/// Borrowed version of [`GetStringReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetStringReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub string: &'a [u8],
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetStringReply {
+    type Ref = GetStringReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        let (string, remaining) = crate::x11_utils::parse_u8_list(remaining, n.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetStringReplyRef { sequence, length, string };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetStringReplyRef<'_>> for GetStringReply {
+    fn from(reply: GetStringReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            string: reply.string.to_vec(),
+        }
+    }
+}
+
 /// Opcode for the GetTexEnvfv request
 pub const GET_TEX_ENVFV_REQUEST: u8 = 130;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -7488,6 +8552,50 @@ impl GetTexEnvfvReply {
     }
 }
 
+/// Borrowed version of [`GetTexEnvfvReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetTexEnvfvReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: Float32,
+    pub data: crate::x11_utils::ListRef<'a, Float32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetTexEnvfvReply {
+    type Ref = GetTexEnvfvReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse(remaining)?;
+        let (datum, remaining) = Float32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::ListRef::<Float32>::try_parse(remaining, n.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetTexEnvfvReplyRef { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetTexEnvfvReplyRef<'_>> for GetTexEnvfvReply {
+    fn from(reply: GetTexEnvfvReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            datum: reply.datum,
+            data: reply.data.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the GetTexEnviv request
 pub const GET_TEX_ENVIV_REQUEST: u8 = 131;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -7626,6 +8734,50 @@ impl GetTexEnvivReply {
     }
 }
 
+/// Borrowed version of [`GetTexEnvivReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetTexEnvivReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: i32,
+    pub data: crate::x11_utils::ListRef<'a, i32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetTexEnvivReply {
+    type Ref = GetTexEnvivReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse(remaining)?;
+        let (datum, remaining) = i32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::ListRef::<i32>::try_parse(remaining, n.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetTexEnvivReplyRef { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetTexEnvivReplyRef<'_>> for GetTexEnvivReply {
+    fn from(reply: GetTexEnvivReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            datum: reply.datum,
+            data: reply.data.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the GetTexGendv request
 pub const GET_TEX_GENDV_REQUEST: u8 = 132;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -7764,6 +8916,50 @@ impl GetTexGendvReply {
     }
 }
 
+/// Borrowed version of [`GetTexGendvReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetTexGendvReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: Float64,
+    pub data: crate::x11_utils::ListRef<'a, Float64>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetTexGendvReply {
+    type Ref = GetTexGendvReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse(remaining)?;
+        let (datum, remaining) = Float64::try_parse(remaining)?;
+        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::ListRef::<Float64>::try_parse(remaining, n.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetTexGendvReplyRef { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetTexGendvReplyRef<'_>> for GetTexGendvReply {
+    fn from(reply: GetTexGendvReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            datum: reply.datum,
+            data: reply.data.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the GetTexGenfv request
 pub const GET_TEX_GENFV_REQUEST: u8 = 133;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -7902,6 +9098,50 @@ impl GetTexGenfvReply {
     }
 }
 
+/// Borrowed version of [`GetTexGenfvReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetTexGenfvReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: Float32,
+    pub data: crate::x11_utils::ListRef<'a, Float32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetTexGenfvReply {
+    type Ref = GetTexGenfvReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse(remaining)?;
+        let (datum, remaining) = Float32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::ListRef::<Float32>::try_parse(remaining, n.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetTexGenfvReplyRef { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetTexGenfvReplyRef<'_>> for GetTexGenfvReply {
+    fn from(reply: GetTexGenfvReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            datum: reply.datum,
+            data: reply.data.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the GetTexGeniv request
 pub const GET_TEX_GENIV_REQUEST: u8 = 134;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -8040,6 +9280,50 @@ impl GetTexGenivReply {
     }
 }
 
+/// Borrowed version of [`GetTexGenivReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetTexGenivReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: i32,
+    pub data: crate::x11_utils::ListRef<'a, i32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetTexGenivReply {
+    type Ref = GetTexGenivReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse(remaining)?;
+        let (datum, remaining) = i32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::ListRef::<i32>::try_parse(remaining, n.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetTexGenivReplyRef { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetTexGenivReplyRef<'_>> for GetTexGenivReply {
+    fn from(reply: GetTexGenivReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            datum: reply.datum,
+            data: reply.data.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the GetTexImage request
 pub const GET_TEX_IMAGE_REQUEST: u8 = 135;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -8208,6 +9492,53 @@ impl GetTexImageReply {
     }
 }
 
+/// Borrowed version of [`GetTexImageReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetTexImageReplyRef<'a> {
+    pub sequence: u16,
+    pub width: i32,
+    pub height: i32,
+    pub depth: i32,
+    pub data: &'a [u8],
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetTexImageReply {
+    type Ref = GetTexImageReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
+        let (width, remaining) = i32::try_parse(remaining)?;
+        let (height, remaining) = i32::try_parse(remaining)?;
+        let (depth, remaining) = i32::try_parse(remaining)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, length.checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetTexImageReplyRef { sequence, width, height, depth, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetTexImageReplyRef<'_>> for GetTexImageReply {
+    fn from(reply: GetTexImageReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            width: reply.width,
+            height: reply.height,
+            depth: reply.depth,
+            data: reply.data.to_vec(),
+        }
+    }
+}
+
 /// Opcode for the GetTexParameterfv request
 pub const GET_TEX_PARAMETERFV_REQUEST: u8 = 136;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -8346,6 +9677,50 @@ impl GetTexParameterfvReply {
     }
 }
 
+/// Borrowed version of [`GetTexParameterfvReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetTexParameterfvReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: Float32,
+    pub data: crate::x11_utils::ListRef<'a, Float32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetTexParameterfvReply {
+    type Ref = GetTexParameterfvReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse(remaining)?;
+        let (datum, remaining) = Float32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::ListRef::<Float32>::try_parse(remaining, n.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetTexParameterfvReplyRef { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetTexParameterfvReplyRef<'_>> for GetTexParameterfvReply {
+    fn from(reply: GetTexParameterfvReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            datum: reply.datum,
+            data: reply.data.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the GetTexParameteriv request
 pub const GET_TEX_PARAMETERIV_REQUEST: u8 = 137;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -8484,6 +9859,50 @@ impl GetTexParameterivReply {
     }
 }
 
+/// Borrowed version of [`GetTexParameterivReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetTexParameterivReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: i32,
+    pub data: crate::x11_utils::ListRef<'a, i32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetTexParameterivReply {
+    type Ref = GetTexParameterivReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse(remaining)?;
+        let (datum, remaining) = i32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::ListRef::<i32>::try_parse(remaining, n.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetTexParameterivReplyRef { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetTexParameterivReplyRef<'_>> for GetTexParameterivReply {
+    fn from(reply: GetTexParameterivReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            datum: reply.datum,
+            data: reply.data.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the GetTexLevelParameterfv request
 pub const GET_TEX_LEVEL_PARAMETERFV_REQUEST: u8 = 138;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -8630,6 +10049,50 @@ impl GetTexLevelParameterfvReply {
     }
 }
 
+/// Borrowed version of [`GetTexLevelParameterfvReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetTexLevelParameterfvReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: Float32,
+    pub data: crate::x11_utils::ListRef<'a, Float32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetTexLevelParameterfvReply {
+    type Ref = GetTexLevelParameterfvReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse(remaining)?;
+        let (datum, remaining) = Float32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::ListRef::<Float32>::try_parse(remaining, n.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetTexLevelParameterfvReplyRef { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetTexLevelParameterfvReplyRef<'_>> for GetTexLevelParameterfvReply {
+    fn from(reply: GetTexLevelParameterfvReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            datum: reply.datum,
+            data: reply.data.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the GetTexLevelParameteriv request
 pub const GET_TEX_LEVEL_PARAMETERIV_REQUEST: u8 = 139;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -8776,6 +10239,50 @@ impl GetTexLevelParameterivReply {
     }
 }
 
+/// Borrowed version of [`GetTexLevelParameterivReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetTexLevelParameterivReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: i32,
+    pub data: crate::x11_utils::ListRef<'a, i32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetTexLevelParameterivReply {
+    type Ref = GetTexLevelParameterivReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse(remaining)?;
+        let (datum, remaining) = i32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::ListRef::<i32>::try_parse(remaining, n.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetTexLevelParameterivReplyRef { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetTexLevelParameterivReplyRef<'_>> for GetTexLevelParameterivReply {
+    fn from(reply: GetTexLevelParameterivReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            datum: reply.datum,
+            data: reply.data.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the IsEnabled request
 pub const IS_ENABLED_REQUEST: u8 = 140;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -9209,6 +10716,46 @@ impl AreTexturesResidentReply {
     }
 }
 
+/// Borrowed version of [`AreTexturesResidentReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct AreTexturesResidentReplyRef<'a> {
+    pub sequence: u16,
+    pub ret_val: Bool32,
+    pub data: crate::x11_utils::ListRef<'a, bool>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for AreTexturesResidentReply {
+    type Ref = AreTexturesResidentReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (ret_val, remaining) = Bool32::try_parse(remaining)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::ListRef::<bool>::try_parse(remaining, length.checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = AreTexturesResidentReplyRef { sequence, ret_val, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<AreTexturesResidentReplyRef<'_>> for AreTexturesResidentReply {
+    fn from(reply: AreTexturesResidentReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            ret_val: reply.ret_val,
+            data: reply.data.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the DeleteTextures request
 pub const DELETE_TEXTURES_REQUEST: u8 = 144;
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -9405,6 +10952,43 @@ impl GenTexturesReply {
     }
 }
 
+/// Borrowed version of [`GenTexturesReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GenTexturesReplyRef<'a> {
+    pub sequence: u16,
+    pub data: crate::x11_utils::ListRef<'a, u32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GenTexturesReply {
+    type Ref = GenTexturesReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(24..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::ListRef::<u32>::try_parse(remaining, length.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GenTexturesReplyRef { sequence, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GenTexturesReplyRef<'_>> for GenTexturesReply {
+    fn from(reply: GenTexturesReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            data: reply.data.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the IsTexture request
 pub const IS_TEXTURE_REQUEST: u8 = 146;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -9679,6 +11263,47 @@ impl GetColorTableReply {
     }
 }
 
+/// Borrowed version of [`GetColorTableReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetColorTableReplyRef<'a> {
+    pub sequence: u16,
+    pub width: i32,
+    pub data: &'a [u8],
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetColorTableReply {
+    type Ref = GetColorTableReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
+        let (width, remaining) = i32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, length.checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetColorTableReplyRef { sequence, width, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetColorTableReplyRef<'_>> for GetColorTableReply {
+    fn from(reply: GetColorTableReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            width: reply.width,
+            data: reply.data.to_vec(),
+        }
+    }
+}
+
 /// Opcode for the GetColorTableParameterfv request
 pub const GET_COLOR_TABLE_PARAMETERFV_REQUEST: u8 = 148;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -9817,6 +11442,50 @@ impl GetColorTableParameterfvReply {
     }
 }
 
+/// Borrowed version of [`GetColorTableParameterfvReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetColorTableParameterfvReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: Float32,
+    pub data: crate::x11_utils::ListRef<'a, Float32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetColorTableParameterfvReply {
+    type Ref = GetColorTableParameterfvReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse(remaining)?;
+        let (datum, remaining) = Float32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::ListRef::<Float32>::try_parse(remaining, n.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetColorTableParameterfvReplyRef { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetColorTableParameterfvReplyRef<'_>> for GetColorTableParameterfvReply {
+    fn from(reply: GetColorTableParameterfvReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            datum: reply.datum,
+            data: reply.data.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the GetColorTableParameteriv request
 pub const GET_COLOR_TABLE_PARAMETERIV_REQUEST: u8 = 149;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -9955,6 +11624,50 @@ impl GetColorTableParameterivReply {
     }
 }
 
+/// Borrowed version of [`GetColorTableParameterivReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetColorTableParameterivReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: i32,
+    pub data: crate::x11_utils::ListRef<'a, i32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetColorTableParameterivReply {
+    type Ref = GetColorTableParameterivReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse(remaining)?;
+        let (datum, remaining) = i32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::ListRef::<i32>::try_parse(remaining, n.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetColorTableParameterivReplyRef { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetColorTableParameterivReplyRef<'_>> for GetColorTableParameterivReply {
+    fn from(reply: GetColorTableParameterivReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            datum: reply.datum,
+            data: reply.data.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the GetConvolutionFilter request
 pub const GET_CONVOLUTION_FILTER_REQUEST: u8 = 150;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -10112,6 +11825,50 @@ impl GetConvolutionFilterReply {
     }
 }
 
+/// Borrowed version of [`GetConvolutionFilterReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetConvolutionFilterReplyRef<'a> {
+    pub sequence: u16,
+    pub width: i32,
+    pub height: i32,
+    pub data: &'a [u8],
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetConvolutionFilterReply {
+    type Ref = GetConvolutionFilterReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
+        let (width, remaining) = i32::try_parse(remaining)?;
+        let (height, remaining) = i32::try_parse(remaining)?;
+        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, length.checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetConvolutionFilterReplyRef { sequence, width, height, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetConvolutionFilterReplyRef<'_>> for GetConvolutionFilterReply {
+    fn from(reply: GetConvolutionFilterReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            width: reply.width,
+            height: reply.height,
+            data: reply.data.to_vec(),
+        }
+    }
+}
+
 /// Opcode for the GetConvolutionParameterfv request
 pub const GET_CONVOLUTION_PARAMETERFV_REQUEST: u8 = 151;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -10250,6 +12007,50 @@ impl GetConvolutionParameterfvReply {
     }
 }
 
+/// Borrowed version of [`GetConvolutionParameterfvReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetConvolutionParameterfvReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: Float32,
+    pub data: crate::x11_utils::ListRef<'a, Float32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetConvolutionParameterfvReply {
+    type Ref = GetConvolutionParameterfvReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse(remaining)?;
+        let (datum, remaining) = Float32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::ListRef::<Float32>::try_parse(remaining, n.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetConvolutionParameterfvReplyRef { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetConvolutionParameterfvReplyRef<'_>> for GetConvolutionParameterfvReply {
+    fn from(reply: GetConvolutionParameterfvReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            datum: reply.datum,
+            data: reply.data.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the GetConvolutionParameteriv request
 pub const GET_CONVOLUTION_PARAMETERIV_REQUEST: u8 = 152;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -10388,6 +12189,50 @@ impl GetConvolutionParameterivReply {
     }
 }
 
+/// Borrowed version of [`GetConvolutionParameterivReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetConvolutionParameterivReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: i32,
+    pub data: crate::x11_utils::ListRef<'a, i32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetConvolutionParameterivReply {
+    type Ref = GetConvolutionParameterivReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse(remaining)?;
+        let (datum, remaining) = i32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::ListRef::<i32>::try_parse(remaining, n.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetConvolutionParameterivReplyRef { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetConvolutionParameterivReplyRef<'_>> for GetConvolutionParameterivReply {
+    fn from(reply: GetConvolutionParameterivReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            datum: reply.datum,
+            data: reply.data.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the GetSeparableFilter request
 pub const GET_SEPARABLE_FILTER_REQUEST: u8 = 153;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -10545,6 +12390,50 @@ impl GetSeparableFilterReply {
     }
 }
 
+/// Borrowed version of [`GetSeparableFilterReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetSeparableFilterReplyRef<'a> {
+    pub sequence: u16,
+    pub row_w: i32,
+    pub col_h: i32,
+    pub rows_and_cols: &'a [u8],
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetSeparableFilterReply {
+    type Ref = GetSeparableFilterReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
+        let (row_w, remaining) = i32::try_parse(remaining)?;
+        let (col_h, remaining) = i32::try_parse(remaining)?;
+        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
+        let (rows_and_cols, remaining) = crate::x11_utils::parse_u8_list(remaining, length.checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetSeparableFilterReplyRef { sequence, row_w, col_h, rows_and_cols };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetSeparableFilterReplyRef<'_>> for GetSeparableFilterReply {
+    fn from(reply: GetSeparableFilterReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            row_w: reply.row_w,
+            col_h: reply.col_h,
+            rows_and_cols: reply.rows_and_cols.to_vec(),
+        }
+    }
+}
+
 /// Opcode for the GetHistogram request
 pub const GET_HISTOGRAM_REQUEST: u8 = 154;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -10703,6 +12592,47 @@ impl GetHistogramReply {
     }
 }
 
+/// Borrowed version of [`GetHistogramReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetHistogramReplyRef<'a> {
+    pub sequence: u16,
+    pub width: i32,
+    pub data: &'a [u8],
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetHistogramReply {
+    type Ref = GetHistogramReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
+        let (width, remaining) = i32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, length.checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetHistogramReplyRef { sequence, width, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetHistogramReplyRef<'_>> for GetHistogramReply {
+    fn from(reply: GetHistogramReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            width: reply.width,
+            data: reply.data.to_vec(),
+        }
+    }
+}
+
 /// Opcode for the GetHistogramParameterfv request
 pub const GET_HISTOGRAM_PARAMETERFV_REQUEST: u8 = 155;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -10825,19 +12755,63 @@ impl Serialize for GetHistogramParameterfvReply {
         self.data.serialize_into(bytes);
     }
 }
-impl GetHistogramParameterfvReply {
-    /// Get the value of the `n` field.
-    ///
-    /// The `n` field is used as the length field of the `data` field.
-    /// This function computes the field's value again based on the length of the list.
-    ///
-    /// # Panics
-    ///
-    /// Panics if the value cannot be represented in the target type. This
-    /// cannot happen with values of the struct received from the X11 server.
-    pub fn n(&self) -> u32 {
-        self.data.len()
-            .try_into().unwrap()
+impl GetHistogramParameterfvReply {
+    /// Get the value of the `n` field.
+    ///
+    /// The `n` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n(&self) -> u32 {
+        self.data.len()
+            .try_into().unwrap()
+    }
+}
+
+/// Borrowed version of [`GetHistogramParameterfvReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetHistogramParameterfvReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: Float32,
+    pub data: crate::x11_utils::ListRef<'a, Float32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetHistogramParameterfvReply {
+    type Ref = GetHistogramParameterfvReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse(remaining)?;
+        let (datum, remaining) = Float32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::ListRef::<Float32>::try_parse(remaining, n.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetHistogramParameterfvReplyRef { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetHistogramParameterfvReplyRef<'_>> for GetHistogramParameterfvReply {
+    fn from(reply: GetHistogramParameterfvReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            datum: reply.datum,
+            data: reply.data.iter().collect(),
+        }
     }
 }
 
@@ -10979,6 +12953,50 @@ impl GetHistogramParameterivReply {
     }
 }
 
+/// Borrowed version of [`GetHistogramParameterivReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetHistogramParameterivReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: i32,
+    pub data: crate::x11_utils::ListRef<'a, i32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetHistogramParameterivReply {
+    type Ref = GetHistogramParameterivReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse(remaining)?;
+        let (datum, remaining) = i32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::ListRef::<i32>::try_parse(remaining, n.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetHistogramParameterivReplyRef { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetHistogramParameterivReplyRef<'_>> for GetHistogramParameterivReply {
+    fn from(reply: GetHistogramParameterivReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            datum: reply.datum,
+            data: reply.data.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the GetMinmax request
 pub const GET_MINMAX_REQUEST: u8 = 157;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -11132,6 +13150,43 @@ impl GetMinmaxReply {
     }
 }
 
+/// Borrowed version of [`GetMinmaxReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetMinmaxReplyRef<'a> {
+    pub sequence: u16,
+    pub data: &'a [u8],
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetMinmaxReply {
+    type Ref = GetMinmaxReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(24..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, length.checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetMinmaxReplyRef { sequence, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetMinmaxReplyRef<'_>> for GetMinmaxReply {
+    fn from(reply: GetMinmaxReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            data: reply.data.to_vec(),
+        }
+    }
+}
+
 /// Opcode for the GetMinmaxParameterfv request
 pub const GET_MINMAX_PARAMETERFV_REQUEST: u8 = 158;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -11270,6 +13325,50 @@ impl GetMinmaxParameterfvReply {
     }
 }
 
+/// Borrowed version of [`GetMinmaxParameterfvReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetMinmaxParameterfvReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: Float32,
+    pub data: crate::x11_utils::ListRef<'a, Float32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetMinmaxParameterfvReply {
+    type Ref = GetMinmaxParameterfvReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse(remaining)?;
+        let (datum, remaining) = Float32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::ListRef::<Float32>::try_parse(remaining, n.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetMinmaxParameterfvReplyRef { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetMinmaxParameterfvReplyRef<'_>> for GetMinmaxParameterfvReply {
+    fn from(reply: GetMinmaxParameterfvReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            datum: reply.datum,
+            data: reply.data.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the GetMinmaxParameteriv request
 pub const GET_MINMAX_PARAMETERIV_REQUEST: u8 = 159;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -11408,6 +13507,50 @@ impl GetMinmaxParameterivReply {
     }
 }
 
+/// Borrowed version of [`GetMinmaxParameterivReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetMinmaxParameterivReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: i32,
+    pub data: crate::x11_utils::ListRef<'a, i32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetMinmaxParameterivReply {
+    type Ref = GetMinmaxParameterivReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse(remaining)?;
+        let (datum, remaining) = i32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::ListRef::<i32>::try_parse(remaining, n.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetMinmaxParameterivReplyRef { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetMinmaxParameterivReplyRef<'_>> for GetMinmaxParameterivReply {
+    fn from(reply: GetMinmaxParameterivReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            datum: reply.datum,
+            data: reply.data.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the GetCompressedTexImageARB request
 pub const GET_COMPRESSED_TEX_IMAGE_ARB_REQUEST: u8 = 160;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -11546,6 +13689,47 @@ impl GetCompressedTexImageARBReply {
     }
 }
 
+/// Borrowed version of [`GetCompressedTexImageARBReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetCompressedTexImageARBReplyRef<'a> {
+    pub sequence: u16,
+    pub size: i32,
+    pub data: &'a [u8],
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetCompressedTexImageARBReply {
+    type Ref = GetCompressedTexImageARBReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
+        let (size, remaining) = i32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, length.checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetCompressedTexImageARBReplyRef { sequence, size, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetCompressedTexImageARBReplyRef<'_>> for GetCompressedTexImageARBReply {
+    fn from(reply: GetCompressedTexImageARBReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            size: reply.size,
+            data: reply.data.to_vec(),
+        }
+    }
+}
+
 /// Opcode for the DeleteQueriesARB request
 pub const DELETE_QUERIES_ARB_REQUEST: u8 = 161;
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -11742,6 +13926,43 @@ impl GenQueriesARBReply {
     }
 }
 
+/// Borrowed version of [`GenQueriesARBReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GenQueriesARBReplyRef<'a> {
+    pub sequence: u16,
+    pub data: crate::x11_utils::ListRef<'a, u32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GenQueriesARBReply {
+    type Ref = GenQueriesARBReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(24..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::ListRef::<u32>::try_parse(remaining, length.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GenQueriesARBReplyRef { sequence, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GenQueriesARBReplyRef<'_>> for GenQueriesARBReply {
+    fn from(reply: GenQueriesARBReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            data: reply.data.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the IsQueryARB request
 pub const IS_QUERY_ARB_REQUEST: u8 = 163;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -12000,6 +14221,50 @@ impl GetQueryivARBReply {
     }
 }
 
+/// Borrowed version of [`GetQueryivARBReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetQueryivARBReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: i32,
+    pub data: crate::x11_utils::ListRef<'a, i32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetQueryivARBReply {
+    type Ref = GetQueryivARBReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse(remaining)?;
+        let (datum, remaining) = i32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::ListRef::<i32>::try_parse(remaining, n.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetQueryivARBReplyRef { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetQueryivARBReplyRef<'_>> for GetQueryivARBReply {
+    fn from(reply: GetQueryivARBReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            datum: reply.datum,
+            data: reply.data.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the GetQueryObjectivARB request
 pub const GET_QUERY_OBJECTIV_ARB_REQUEST: u8 = 165;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -12138,6 +14403,50 @@ impl GetQueryObjectivARBReply {
     }
 }
 
+/// Borrowed version of [`GetQueryObjectivARBReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetQueryObjectivARBReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: i32,
+    pub data: crate::x11_utils::ListRef<'a, i32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetQueryObjectivARBReply {
+    type Ref = GetQueryObjectivARBReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse(remaining)?;
+        let (datum, remaining) = i32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::ListRef::<i32>::try_parse(remaining, n.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetQueryObjectivARBReplyRef { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetQueryObjectivARBReplyRef<'_>> for GetQueryObjectivARBReply {
+    fn from(reply: GetQueryObjectivARBReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            datum: reply.datum,
+            data: reply.data.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the GetQueryObjectuivARB request
 pub const GET_QUERY_OBJECTUIV_ARB_REQUEST: u8 = 166;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -12276,3 +14585,47 @@ impl GetQueryObjectuivARBReply {
     }
 }
 
+/// Borrowed version of [`GetQueryObjectuivARBReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetQueryObjectuivARBReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: u32,
+    pub data: crate::x11_utils::ListRef<'a, u32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetQueryObjectuivARBReply {
+    type Ref = GetQueryObjectuivARBReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse(remaining)?;
+        let (datum, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::ListRef::<u32>::try_parse(remaining, n.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetQueryObjectuivARBReplyRef { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetQueryObjectuivARBReplyRef<'_>> for GetQueryObjectuivARBReply {
+    fn from(reply: GetQueryObjectuivARBReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            datum: reply.datum,
+            data: reply.data.iter().collect(),
+        }
+    }
+}
+
@@ -14,10 +14,14 @@
 use alloc::borrow::Cow;
 use alloc::vec::Vec;
 use core::convert::TryInto;
-use crate::errors::ParseError;
-use crate::RawFdContainer;
+use crate::errors::{ParseError, SerializeError};
+use crate::{BufWithFds, RawFdContainer};
 use crate::x11_utils::{TryParse, TryParseFd, X11Error, ReplyRequest, ReplyFDsRequest};
 use crate::x11_utils::{ExtInfoProvider, ReplyParsingFunction, RequestHeader};
+use crate::x11_utils::{serialize_event, serialize_reply, serialize_request, serialize_unknown_request};
+// Might not be used if no extension with events is enabled
+#[allow(unused_imports)]
+use crate::x11_utils::{extension_event_code, serialize_generic_event};
 
 fn parse_reply<'a, R: ReplyRequest>(bytes: &'a [u8], _: &mut Vec<RawFdContainer>) -> Result<(Reply, &'a [u8]), ParseError> {
     let (reply, remaining) = R::Reply::try_parse(bytes)?;
@@ -90,6 +94,7 @@ pub mod xvmc;
 
 /// Enumeration of all possible X11 requests.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(clippy::large_enum_variant)]
 #[non_exhaustive]
 pub enum Request<'input> {
@@ -292,16 +297,19 @@ pub enum Request<'input> {
     #[cfg(feature = "dri3")]
     Dri3Open(dri3::OpenRequest),
     #[cfg(feature = "dri3")]
+    #[cfg_attr(feature = "serde", serde(skip))]
     Dri3PixmapFromBuffer(dri3::PixmapFromBufferRequest),
     #[cfg(feature = "dri3")]
     Dri3BufferFromPixmap(dri3::BufferFromPixmapRequest),
     #[cfg(feature = "dri3")]
+    #[cfg_attr(feature = "serde", serde(skip))]
     Dri3FenceFromFD(dri3::FenceFromFDRequest),
     #[cfg(feature = "dri3")]
     Dri3FDFromFence(dri3::FDFromFenceRequest),
     #[cfg(feature = "dri3")]
     Dri3GetSupportedModifiers(dri3::GetSupportedModifiersRequest),
     #[cfg(feature = "dri3")]
+    #[cfg_attr(feature = "serde", serde(skip))]
     Dri3PixmapFromBuffers(dri3::PixmapFromBuffersRequest),
     #[cfg(feature = "dri3")]
     Dri3BuffersFromPixmap(dri3::BuffersFromPixmapRequest),
@@ -741,6 +749,7 @@ pub enum Request<'input> {
     #[cfg(feature = "shm")]
     ShmCreatePixmap(shm::CreatePixmapRequest),
     #[cfg(feature = "shm")]
+    #[cfg_attr(feature = "serde", serde(skip))]
     ShmAttachFd(shm::AttachFdRequest),
     #[cfg(feature = "shm")]
     ShmCreateSegment(shm::CreateSegmentRequest),
@@ -4498,10 +4507,1258 @@ impl<'input> Request<'input> {
             Request::XvmcListSubpictureTypes(req) => Request::XvmcListSubpictureTypes(req),
         }
     }
+    /// Serialize this request into bytes that can be sent to the X11 server.
+    ///
+    /// The major opcode of extension requests is looked up via `ext_info_provider`. Requests
+    /// that are too large for the normal length field are encoded as required by the
+    /// BIG-REQUESTS extension. This function does not check that this extension is enabled.
+    ///
+    /// Since the request is not consumed, its file descriptors (if any) are duplicated.
+    #[allow(clippy::clone_on_copy)]
+    pub fn serialize(
+        &self,
+        ext_info_provider: &dyn ExtInfoProvider,
+    ) -> Result<BufWithFds<Vec<u8>>, SerializeError> {
+        match self {
+            Request::Unknown(header, body) => Ok((serialize_unknown_request(header, body), Vec::new())),
+            Request::CreateWindow(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::ChangeWindowAttributes(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::GetWindowAttributes(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::DestroyWindow(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::DestroySubwindows(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::ChangeSaveSet(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::ReparentWindow(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::MapWindow(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::MapSubwindows(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::UnmapWindow(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::UnmapSubwindows(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::ConfigureWindow(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::CirculateWindow(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::GetGeometry(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::QueryTree(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::InternAtom(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::GetAtomName(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::ChangeProperty(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::DeleteProperty(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::GetProperty(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::ListProperties(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::SetSelectionOwner(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::GetSelectionOwner(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::ConvertSelection(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::SendEvent(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::GrabPointer(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::UngrabPointer(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::GrabButton(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::UngrabButton(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::ChangeActivePointerGrab(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::GrabKeyboard(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::UngrabKeyboard(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::GrabKey(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::UngrabKey(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::AllowEvents(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::GrabServer(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::UngrabServer(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::QueryPointer(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::GetMotionEvents(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::TranslateCoordinates(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::WarpPointer(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::SetInputFocus(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::GetInputFocus(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::QueryKeymap(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::OpenFont(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::CloseFont(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::QueryFont(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::QueryTextExtents(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::ListFonts(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::ListFontsWithInfo(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::SetFontPath(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::GetFontPath(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::CreatePixmap(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::FreePixmap(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::CreateGC(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::ChangeGC(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::CopyGC(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::SetDashes(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::SetClipRectangles(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::FreeGC(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::ClearArea(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::CopyArea(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::CopyPlane(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::PolyPoint(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::PolyLine(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::PolySegment(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::PolyRectangle(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::PolyArc(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::FillPoly(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::PolyFillRectangle(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::PolyFillArc(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::PutImage(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::GetImage(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::PolyText8(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::PolyText16(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::ImageText8(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::ImageText16(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::CreateColormap(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::FreeColormap(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::CopyColormapAndFree(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::InstallColormap(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::UninstallColormap(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::ListInstalledColormaps(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::AllocColor(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::AllocNamedColor(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::AllocColorCells(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::AllocColorPlanes(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::FreeColors(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::StoreColors(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::StoreNamedColor(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::QueryColors(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::LookupColor(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::CreateCursor(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::CreateGlyphCursor(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::FreeCursor(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::RecolorCursor(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::QueryBestSize(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::QueryExtension(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::ListExtensions(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::ChangeKeyboardMapping(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::GetKeyboardMapping(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::ChangeKeyboardControl(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::GetKeyboardControl(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::Bell(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::ChangePointerControl(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::GetPointerControl(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::SetScreenSaver(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::GetScreenSaver(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::ChangeHosts(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::ListHosts(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::SetAccessControl(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::SetCloseDownMode(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::KillClient(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::RotateProperties(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::ForceScreenSaver(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::SetPointerMapping(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::GetPointerMapping(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::SetModifierMapping(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::GetModifierMapping(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::NoOperation(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::BigreqEnable(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "composite")]
+            Request::CompositeQueryVersion(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "composite")]
+            Request::CompositeRedirectWindow(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "composite")]
+            Request::CompositeRedirectSubwindows(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "composite")]
+            Request::CompositeUnredirectWindow(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "composite")]
+            Request::CompositeUnredirectSubwindows(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "composite")]
+            Request::CompositeCreateRegionFromBorderClip(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "composite")]
+            Request::CompositeNameWindowPixmap(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "composite")]
+            Request::CompositeGetOverlayWindow(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "composite")]
+            Request::CompositeReleaseOverlayWindow(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "damage")]
+            Request::DamageQueryVersion(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "damage")]
+            Request::DamageCreate(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "damage")]
+            Request::DamageDestroy(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "damage")]
+            Request::DamageSubtract(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "damage")]
+            Request::DamageAdd(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "dpms")]
+            Request::DpmsGetVersion(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "dpms")]
+            Request::DpmsCapable(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "dpms")]
+            Request::DpmsGetTimeouts(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "dpms")]
+            Request::DpmsSetTimeouts(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "dpms")]
+            Request::DpmsEnable(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "dpms")]
+            Request::DpmsDisable(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "dpms")]
+            Request::DpmsForceLevel(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "dpms")]
+            Request::DpmsInfo(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "dri2")]
+            Request::Dri2QueryVersion(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "dri2")]
+            Request::Dri2Connect(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "dri2")]
+            Request::Dri2Authenticate(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "dri2")]
+            Request::Dri2CreateDrawable(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "dri2")]
+            Request::Dri2DestroyDrawable(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "dri2")]
+            Request::Dri2GetBuffers(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "dri2")]
+            Request::Dri2CopyRegion(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "dri2")]
+            Request::Dri2GetBuffersWithFormat(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "dri2")]
+            Request::Dri2SwapBuffers(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "dri2")]
+            Request::Dri2GetMSC(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "dri2")]
+            Request::Dri2WaitMSC(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "dri2")]
+            Request::Dri2WaitSBC(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "dri2")]
+            Request::Dri2SwapInterval(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "dri2")]
+            Request::Dri2GetParam(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "dri3")]
+            Request::Dri3QueryVersion(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "dri3")]
+            Request::Dri3Open(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "dri3")]
+            Request::Dri3PixmapFromBuffer(req) => serialize_request(
+                dri3::PixmapFromBufferRequest {
+                    pixmap: req.pixmap.clone(),
+                    drawable: req.drawable.clone(),
+                    size: req.size.clone(),
+                    width: req.width.clone(),
+                    height: req.height.clone(),
+                    stride: req.stride.clone(),
+                    depth: req.depth.clone(),
+                    bpp: req.bpp.clone(),
+                    pixmap_fd: req.pixmap_fd.duplicate()?,
+                },
+                ext_info_provider,
+            ),
+            #[cfg(feature = "dri3")]
+            Request::Dri3BufferFromPixmap(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "dri3")]
+            Request::Dri3FenceFromFD(req) => serialize_request(
+                dri3::FenceFromFDRequest {
+                    drawable: req.drawable.clone(),
+                    fence: req.fence.clone(),
+                    initially_triggered: req.initially_triggered.clone(),
+                    fence_fd: req.fence_fd.duplicate()?,
+                },
+                ext_info_provider,
+            ),
+            #[cfg(feature = "dri3")]
+            Request::Dri3FDFromFence(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "dri3")]
+            Request::Dri3GetSupportedModifiers(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "dri3")]
+            Request::Dri3PixmapFromBuffers(req) => serialize_request(
+                dri3::PixmapFromBuffersRequest {
+                    pixmap: req.pixmap.clone(),
+                    window: req.window.clone(),
+                    width: req.width.clone(),
+                    height: req.height.clone(),
+                    stride0: req.stride0.clone(),
+                    offset0: req.offset0.clone(),
+                    stride1: req.stride1.clone(),
+                    offset1: req.offset1.clone(),
+                    stride2: req.stride2.clone(),
+                    offset2: req.offset2.clone(),
+                    stride3: req.stride3.clone(),
+                    offset3: req.offset3.clone(),
+                    depth: req.depth.clone(),
+                    bpp: req.bpp.clone(),
+                    modifier: req.modifier.clone(),
+                    buffers: req
+                        .buffers
+                        .iter()
+                        .map(RawFdContainer::duplicate)
+                        .collect::<Result<_, _>>()?,
+                },
+                ext_info_provider,
+            ),
+            #[cfg(feature = "dri3")]
+            Request::Dri3BuffersFromPixmap(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::GeQueryVersion(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxRender(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxRenderLarge(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxCreateContext(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxDestroyContext(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxMakeCurrent(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxIsDirect(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxQueryVersion(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxWaitGL(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxWaitX(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxCopyContext(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxSwapBuffers(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxUseXFont(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxCreateGLXPixmap(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetVisualConfigs(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxDestroyGLXPixmap(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxVendorPrivate(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxVendorPrivateWithReply(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxQueryExtensionsString(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxQueryServerString(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxClientInfo(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetFBConfigs(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxCreatePixmap(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxDestroyPixmap(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxCreateNewContext(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxQueryContext(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxMakeContextCurrent(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxCreatePbuffer(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxDestroyPbuffer(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetDrawableAttributes(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxChangeDrawableAttributes(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxCreateWindow(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxDeleteWindow(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxSetClientInfoARB(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxCreateContextAttribsARB(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxSetClientInfo2ARB(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxNewList(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxEndList(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxDeleteLists(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGenLists(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxFeedbackBuffer(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxSelectBuffer(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxRenderMode(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxFinish(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxPixelStoref(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxPixelStorei(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxReadPixels(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetBooleanv(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetClipPlane(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetDoublev(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetError(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetFloatv(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetIntegerv(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetLightfv(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetLightiv(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetMapdv(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetMapfv(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetMapiv(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetMaterialfv(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetMaterialiv(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetPixelMapfv(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetPixelMapuiv(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetPixelMapusv(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetPolygonStipple(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetString(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetTexEnvfv(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetTexEnviv(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetTexGendv(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetTexGenfv(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetTexGeniv(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetTexImage(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetTexParameterfv(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetTexParameteriv(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetTexLevelParameterfv(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetTexLevelParameteriv(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxIsEnabled(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxIsList(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxFlush(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxAreTexturesResident(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxDeleteTextures(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGenTextures(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxIsTexture(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetColorTable(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetColorTableParameterfv(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetColorTableParameteriv(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetConvolutionFilter(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetConvolutionParameterfv(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetConvolutionParameteriv(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetSeparableFilter(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetHistogram(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetHistogramParameterfv(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetHistogramParameteriv(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetMinmax(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetMinmaxParameterfv(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetMinmaxParameteriv(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetCompressedTexImageARB(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxDeleteQueriesARB(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGenQueriesARB(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxIsQueryARB(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetQueryivARB(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetQueryObjectivARB(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "glx")]
+            Request::GlxGetQueryObjectuivARB(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "present")]
+            Request::PresentQueryVersion(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "present")]
+            Request::PresentPixmap(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "present")]
+            Request::PresentNotifyMSC(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "present")]
+            Request::PresentSelectInput(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "present")]
+            Request::PresentQueryCapabilities(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrQueryVersion(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrSetScreenConfig(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrSelectInput(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrGetScreenInfo(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrGetScreenSizeRange(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrSetScreenSize(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrGetScreenResources(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrGetOutputInfo(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrListOutputProperties(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrQueryOutputProperty(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrConfigureOutputProperty(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrChangeOutputProperty(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrDeleteOutputProperty(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrGetOutputProperty(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrCreateMode(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrDestroyMode(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrAddOutputMode(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrDeleteOutputMode(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrGetCrtcInfo(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrSetCrtcConfig(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrGetCrtcGammaSize(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrGetCrtcGamma(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrSetCrtcGamma(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrGetScreenResourcesCurrent(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrSetCrtcTransform(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrGetCrtcTransform(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrGetPanning(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrSetPanning(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrSetOutputPrimary(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrGetOutputPrimary(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrGetProviders(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrGetProviderInfo(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrSetProviderOffloadSink(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrSetProviderOutputSource(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrListProviderProperties(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrQueryProviderProperty(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrConfigureProviderProperty(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrChangeProviderProperty(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrDeleteProviderProperty(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrGetProviderProperty(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrGetMonitors(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrSetMonitor(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrDeleteMonitor(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrCreateLease(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "randr")]
+            Request::RandrFreeLease(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "record")]
+            Request::RecordQueryVersion(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "record")]
+            Request::RecordCreateContext(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "record")]
+            Request::RecordRegisterClients(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "record")]
+            Request::RecordUnregisterClients(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "record")]
+            Request::RecordGetContext(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "record")]
+            Request::RecordEnableContext(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "record")]
+            Request::RecordDisableContext(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "record")]
+            Request::RecordFreeContext(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "render")]
+            Request::RenderQueryVersion(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "render")]
+            Request::RenderQueryPictFormats(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "render")]
+            Request::RenderQueryPictIndexValues(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "render")]
+            Request::RenderCreatePicture(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "render")]
+            Request::RenderChangePicture(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "render")]
+            Request::RenderSetPictureClipRectangles(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "render")]
+            Request::RenderFreePicture(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "render")]
+            Request::RenderComposite(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "render")]
+            Request::RenderTrapezoids(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "render")]
+            Request::RenderTriangles(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "render")]
+            Request::RenderTriStrip(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "render")]
+            Request::RenderTriFan(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "render")]
+            Request::RenderCreateGlyphSet(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "render")]
+            Request::RenderReferenceGlyphSet(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "render")]
+            Request::RenderFreeGlyphSet(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "render")]
+            Request::RenderAddGlyphs(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "render")]
+            Request::RenderFreeGlyphs(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "render")]
+            Request::RenderCompositeGlyphs8(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "render")]
+            Request::RenderCompositeGlyphs16(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "render")]
+            Request::RenderCompositeGlyphs32(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "render")]
+            Request::RenderFillRectangles(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "render")]
+            Request::RenderCreateCursor(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "render")]
+            Request::RenderSetPictureTransform(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "render")]
+            Request::RenderQueryFilters(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "render")]
+            Request::RenderSetPictureFilter(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "render")]
+            Request::RenderCreateAnimCursor(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "render")]
+            Request::RenderAddTraps(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "render")]
+            Request::RenderCreateSolidFill(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "render")]
+            Request::RenderCreateLinearGradient(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "render")]
+            Request::RenderCreateRadialGradient(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "render")]
+            Request::RenderCreateConicalGradient(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "res")]
+            Request::ResQueryVersion(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "res")]
+            Request::ResQueryClients(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "res")]
+            Request::ResQueryClientResources(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "res")]
+            Request::ResQueryClientPixmapBytes(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "res")]
+            Request::ResQueryClientIds(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "res")]
+            Request::ResQueryResourceBytes(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "screensaver")]
+            Request::ScreensaverQueryVersion(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "screensaver")]
+            Request::ScreensaverQueryInfo(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "screensaver")]
+            Request::ScreensaverSelectInput(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "screensaver")]
+            Request::ScreensaverSetAttributes(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "screensaver")]
+            Request::ScreensaverUnsetAttributes(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "screensaver")]
+            Request::ScreensaverSuspend(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "shape")]
+            Request::ShapeQueryVersion(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "shape")]
+            Request::ShapeRectangles(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "shape")]
+            Request::ShapeMask(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "shape")]
+            Request::ShapeCombine(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "shape")]
+            Request::ShapeOffset(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "shape")]
+            Request::ShapeQueryExtents(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "shape")]
+            Request::ShapeSelectInput(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "shape")]
+            Request::ShapeInputSelected(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "shape")]
+            Request::ShapeGetRectangles(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "shm")]
+            Request::ShmQueryVersion(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "shm")]
+            Request::ShmAttach(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "shm")]
+            Request::ShmDetach(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "shm")]
+            Request::ShmPutImage(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "shm")]
+            Request::ShmGetImage(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "shm")]
+            Request::ShmCreatePixmap(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "shm")]
+            Request::ShmAttachFd(req) => serialize_request(
+                shm::AttachFdRequest {
+                    shmseg: req.shmseg.clone(),
+                    shm_fd: req.shm_fd.duplicate()?,
+                    read_only: req.read_only.clone(),
+                },
+                ext_info_provider,
+            ),
+            #[cfg(feature = "shm")]
+            Request::ShmCreateSegment(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "sync")]
+            Request::SyncInitialize(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "sync")]
+            Request::SyncListSystemCounters(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "sync")]
+            Request::SyncCreateCounter(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "sync")]
+            Request::SyncDestroyCounter(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "sync")]
+            Request::SyncQueryCounter(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "sync")]
+            Request::SyncAwait(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "sync")]
+            Request::SyncChangeCounter(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "sync")]
+            Request::SyncSetCounter(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "sync")]
+            Request::SyncCreateAlarm(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "sync")]
+            Request::SyncChangeAlarm(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "sync")]
+            Request::SyncDestroyAlarm(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "sync")]
+            Request::SyncQueryAlarm(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "sync")]
+            Request::SyncSetPriority(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "sync")]
+            Request::SyncGetPriority(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "sync")]
+            Request::SyncCreateFence(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "sync")]
+            Request::SyncTriggerFence(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "sync")]
+            Request::SyncResetFence(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "sync")]
+            Request::SyncDestroyFence(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "sync")]
+            Request::SyncQueryFence(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "sync")]
+            Request::SyncAwaitFence(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::XcMiscGetVersion(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::XcMiscGetXIDRange(req) => serialize_request(req.clone(), ext_info_provider),
+            Request::XcMiscGetXIDList(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xevie")]
+            Request::XevieQueryVersion(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xevie")]
+            Request::XevieStart(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xevie")]
+            Request::XevieEnd(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xevie")]
+            Request::XevieSend(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xevie")]
+            Request::XevieSelectInput(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xf86dri")]
+            Request::Xf86driQueryVersion(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xf86dri")]
+            Request::Xf86driQueryDirectRenderingCapable(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xf86dri")]
+            Request::Xf86driOpenConnection(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xf86dri")]
+            Request::Xf86driCloseConnection(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xf86dri")]
+            Request::Xf86driGetClientDriverName(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xf86dri")]
+            Request::Xf86driCreateContext(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xf86dri")]
+            Request::Xf86driDestroyContext(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xf86dri")]
+            Request::Xf86driCreateDrawable(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xf86dri")]
+            Request::Xf86driDestroyDrawable(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xf86dri")]
+            Request::Xf86driGetDrawableInfo(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xf86dri")]
+            Request::Xf86driGetDeviceInfo(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xf86dri")]
+            Request::Xf86driAuthConnection(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xf86vidmode")]
+            Request::Xf86vidmodeQueryVersion(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xf86vidmode")]
+            Request::Xf86vidmodeGetModeLine(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xf86vidmode")]
+            Request::Xf86vidmodeModModeLine(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xf86vidmode")]
+            Request::Xf86vidmodeSwitchMode(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xf86vidmode")]
+            Request::Xf86vidmodeGetMonitor(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xf86vidmode")]
+            Request::Xf86vidmodeLockModeSwitch(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xf86vidmode")]
+            Request::Xf86vidmodeGetAllModeLines(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xf86vidmode")]
+            Request::Xf86vidmodeAddModeLine(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xf86vidmode")]
+            Request::Xf86vidmodeDeleteModeLine(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xf86vidmode")]
+            Request::Xf86vidmodeValidateModeLine(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xf86vidmode")]
+            Request::Xf86vidmodeSwitchToMode(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xf86vidmode")]
+            Request::Xf86vidmodeGetViewPort(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xf86vidmode")]
+            Request::Xf86vidmodeSetViewPort(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xf86vidmode")]
+            Request::Xf86vidmodeGetDotClocks(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xf86vidmode")]
+            Request::Xf86vidmodeSetClientVersion(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xf86vidmode")]
+            Request::Xf86vidmodeSetGamma(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xf86vidmode")]
+            Request::Xf86vidmodeGetGamma(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xf86vidmode")]
+            Request::Xf86vidmodeGetGammaRamp(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xf86vidmode")]
+            Request::Xf86vidmodeSetGammaRamp(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xf86vidmode")]
+            Request::Xf86vidmodeGetGammaRampSize(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xf86vidmode")]
+            Request::Xf86vidmodeGetPermissions(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xfixes")]
+            Request::XfixesQueryVersion(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xfixes")]
+            Request::XfixesChangeSaveSet(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xfixes")]
+            Request::XfixesSelectSelectionInput(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xfixes")]
+            Request::XfixesSelectCursorInput(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xfixes")]
+            Request::XfixesGetCursorImage(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xfixes")]
+            Request::XfixesCreateRegion(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xfixes")]
+            Request::XfixesCreateRegionFromBitmap(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xfixes")]
+            Request::XfixesCreateRegionFromWindow(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xfixes")]
+            Request::XfixesCreateRegionFromGC(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xfixes")]
+            Request::XfixesCreateRegionFromPicture(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xfixes")]
+            Request::XfixesDestroyRegion(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xfixes")]
+            Request::XfixesSetRegion(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xfixes")]
+            Request::XfixesCopyRegion(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xfixes")]
+            Request::XfixesUnionRegion(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xfixes")]
+            Request::XfixesIntersectRegion(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xfixes")]
+            Request::XfixesSubtractRegion(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xfixes")]
+            Request::XfixesInvertRegion(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xfixes")]
+            Request::XfixesTranslateRegion(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xfixes")]
+            Request::XfixesRegionExtents(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xfixes")]
+            Request::XfixesFetchRegion(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xfixes")]
+            Request::XfixesSetGCClipRegion(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xfixes")]
+            Request::XfixesSetWindowShapeRegion(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xfixes")]
+            Request::XfixesSetPictureClipRegion(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xfixes")]
+            Request::XfixesSetCursorName(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xfixes")]
+            Request::XfixesGetCursorName(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xfixes")]
+            Request::XfixesGetCursorImageAndName(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xfixes")]
+            Request::XfixesChangeCursor(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xfixes")]
+            Request::XfixesChangeCursorByName(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xfixes")]
+            Request::XfixesExpandRegion(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xfixes")]
+            Request::XfixesHideCursor(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xfixes")]
+            Request::XfixesShowCursor(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xfixes")]
+            Request::XfixesCreatePointerBarrier(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xfixes")]
+            Request::XfixesDeletePointerBarrier(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinerama")]
+            Request::XineramaQueryVersion(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinerama")]
+            Request::XineramaGetState(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinerama")]
+            Request::XineramaGetScreenCount(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinerama")]
+            Request::XineramaGetScreenSize(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinerama")]
+            Request::XineramaIsActive(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinerama")]
+            Request::XineramaQueryScreens(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputGetExtensionVersion(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputListInputDevices(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputOpenDevice(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputCloseDevice(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputSetDeviceMode(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputSelectExtensionEvent(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputGetSelectedExtensionEvents(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputChangeDeviceDontPropagateList(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputGetDeviceDontPropagateList(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputGetDeviceMotionEvents(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputChangeKeyboardDevice(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputChangePointerDevice(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputGrabDevice(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputUngrabDevice(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputGrabDeviceKey(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputUngrabDeviceKey(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputGrabDeviceButton(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputUngrabDeviceButton(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputAllowDeviceEvents(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputGetDeviceFocus(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputSetDeviceFocus(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputGetFeedbackControl(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputChangeFeedbackControl(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputGetDeviceKeyMapping(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputChangeDeviceKeyMapping(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputGetDeviceModifierMapping(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputSetDeviceModifierMapping(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputGetDeviceButtonMapping(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputSetDeviceButtonMapping(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputQueryDeviceState(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputDeviceBell(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputSetDeviceValuators(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputGetDeviceControl(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputChangeDeviceControl(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputListDeviceProperties(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputChangeDeviceProperty(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputDeleteDeviceProperty(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputGetDeviceProperty(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputXIQueryPointer(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputXIWarpPointer(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputXIChangeCursor(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputXIChangeHierarchy(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputXISetClientPointer(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputXIGetClientPointer(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputXISelectEvents(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputXIQueryVersion(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputXIQueryDevice(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputXISetFocus(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputXIGetFocus(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputXIGrabDevice(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputXIUngrabDevice(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputXIAllowEvents(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputXIPassiveGrabDevice(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputXIPassiveUngrabDevice(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputXIListProperties(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputXIChangeProperty(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputXIDeleteProperty(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputXIGetProperty(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputXIGetSelectedEvents(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputXIBarrierReleasePointer(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xinput")]
+            Request::XinputSendExtensionEvent(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xkb")]
+            Request::XkbUseExtension(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xkb")]
+            Request::XkbSelectEvents(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xkb")]
+            Request::XkbBell(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xkb")]
+            Request::XkbGetState(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xkb")]
+            Request::XkbLatchLockState(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xkb")]
+            Request::XkbGetControls(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xkb")]
+            Request::XkbSetControls(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xkb")]
+            Request::XkbGetMap(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xkb")]
+            Request::XkbSetMap(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xkb")]
+            Request::XkbGetCompatMap(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xkb")]
+            Request::XkbSetCompatMap(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xkb")]
+            Request::XkbGetIndicatorState(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xkb")]
+            Request::XkbGetIndicatorMap(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xkb")]
+            Request::XkbSetIndicatorMap(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xkb")]
+            Request::XkbGetNamedIndicator(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xkb")]
+            Request::XkbSetNamedIndicator(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xkb")]
+            Request::XkbGetNames(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xkb")]
+            Request::XkbSetNames(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xkb")]
+            Request::XkbPerClientFlags(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xkb")]
+            Request::XkbListComponents(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xkb")]
+            Request::XkbGetKbdByName(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xkb")]
+            Request::XkbGetDeviceInfo(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xkb")]
+            Request::XkbSetDeviceInfo(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xkb")]
+            Request::XkbSetDebuggingFlags(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xprint")]
+            Request::XprintPrintQueryVersion(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xprint")]
+            Request::XprintPrintGetPrinterList(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xprint")]
+            Request::XprintPrintRehashPrinterList(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xprint")]
+            Request::XprintCreateContext(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xprint")]
+            Request::XprintPrintSetContext(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xprint")]
+            Request::XprintPrintGetContext(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xprint")]
+            Request::XprintPrintDestroyContext(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xprint")]
+            Request::XprintPrintGetScreenOfContext(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xprint")]
+            Request::XprintPrintStartJob(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xprint")]
+            Request::XprintPrintEndJob(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xprint")]
+            Request::XprintPrintStartDoc(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xprint")]
+            Request::XprintPrintEndDoc(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xprint")]
+            Request::XprintPrintPutDocumentData(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xprint")]
+            Request::XprintPrintGetDocumentData(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xprint")]
+            Request::XprintPrintStartPage(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xprint")]
+            Request::XprintPrintEndPage(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xprint")]
+            Request::XprintPrintSelectInput(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xprint")]
+            Request::XprintPrintInputSelected(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xprint")]
+            Request::XprintPrintGetAttributes(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xprint")]
+            Request::XprintPrintGetOneAttributes(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xprint")]
+            Request::XprintPrintSetAttributes(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xprint")]
+            Request::XprintPrintGetPageDimensions(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xprint")]
+            Request::XprintPrintQueryScreens(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xprint")]
+            Request::XprintPrintSetImageResolution(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xprint")]
+            Request::XprintPrintGetImageResolution(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xselinux")]
+            Request::XselinuxQueryVersion(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xselinux")]
+            Request::XselinuxSetDeviceCreateContext(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xselinux")]
+            Request::XselinuxGetDeviceCreateContext(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xselinux")]
+            Request::XselinuxSetDeviceContext(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xselinux")]
+            Request::XselinuxGetDeviceContext(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xselinux")]
+            Request::XselinuxSetWindowCreateContext(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xselinux")]
+            Request::XselinuxGetWindowCreateContext(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xselinux")]
+            Request::XselinuxGetWindowContext(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xselinux")]
+            Request::XselinuxSetPropertyCreateContext(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xselinux")]
+            Request::XselinuxGetPropertyCreateContext(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xselinux")]
+            Request::XselinuxSetPropertyUseContext(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xselinux")]
+            Request::XselinuxGetPropertyUseContext(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xselinux")]
+            Request::XselinuxGetPropertyContext(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xselinux")]
+            Request::XselinuxGetPropertyDataContext(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xselinux")]
+            Request::XselinuxListProperties(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xselinux")]
+            Request::XselinuxSetSelectionCreateContext(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xselinux")]
+            Request::XselinuxGetSelectionCreateContext(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xselinux")]
+            Request::XselinuxSetSelectionUseContext(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xselinux")]
+            Request::XselinuxGetSelectionUseContext(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xselinux")]
+            Request::XselinuxGetSelectionContext(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xselinux")]
+            Request::XselinuxGetSelectionDataContext(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xselinux")]
+            Request::XselinuxListSelections(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xselinux")]
+            Request::XselinuxGetClientContext(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xtest")]
+            Request::XtestGetVersion(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xtest")]
+            Request::XtestCompareCursor(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xtest")]
+            Request::XtestFakeInput(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xtest")]
+            Request::XtestGrabControl(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xv")]
+            Request::XvQueryExtension(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xv")]
+            Request::XvQueryAdaptors(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xv")]
+            Request::XvQueryEncodings(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xv")]
+            Request::XvGrabPort(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xv")]
+            Request::XvUngrabPort(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xv")]
+            Request::XvPutVideo(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xv")]
+            Request::XvPutStill(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xv")]
+            Request::XvGetVideo(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xv")]
+            Request::XvGetStill(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xv")]
+            Request::XvStopVideo(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xv")]
+            Request::XvSelectVideoNotify(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xv")]
+            Request::XvSelectPortNotify(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xv")]
+            Request::XvQueryBestSize(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xv")]
+            Request::XvSetPortAttribute(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xv")]
+            Request::XvGetPortAttribute(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xv")]
+            Request::XvQueryPortAttributes(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xv")]
+            Request::XvListImageFormats(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xv")]
+            Request::XvQueryImageAttributes(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xv")]
+            Request::XvPutImage(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xv")]
+            Request::XvShmPutImage(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xvmc")]
+            Request::XvmcQueryVersion(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xvmc")]
+            Request::XvmcListSurfaceTypes(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xvmc")]
+            Request::XvmcCreateContext(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xvmc")]
+            Request::XvmcDestroyContext(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xvmc")]
+            Request::XvmcCreateSurface(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xvmc")]
+            Request::XvmcDestroySurface(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xvmc")]
+            Request::XvmcCreateSubpicture(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xvmc")]
+            Request::XvmcDestroySubpicture(req) => serialize_request(req.clone(), ext_info_provider),
+            #[cfg(feature = "xvmc")]
+            Request::XvmcListSubpictureTypes(req) => serialize_request(req.clone(), ext_info_provider),
+        }
+    }
 }
 
 /// Enumeration of all possible X11 replies.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(clippy::large_enum_variant)]
 #[non_exhaustive]
 pub enum Reply {
@@ -4586,14 +5843,18 @@ pub enum Reply {
     #[cfg(feature = "dri3")]
     Dri3QueryVersion(dri3::QueryVersionReply),
     #[cfg(feature = "dri3")]
+    #[cfg_attr(feature = "serde", serde(skip))]
     Dri3Open(dri3::OpenReply),
     #[cfg(feature = "dri3")]
+    #[cfg_attr(feature = "serde", serde(skip))]
     Dri3BufferFromPixmap(dri3::BufferFromPixmapReply),
     #[cfg(feature = "dri3")]
+    #[cfg_attr(feature = "serde", serde(skip))]
     Dri3FDFromFence(dri3::FDFromFenceReply),
     #[cfg(feature = "dri3")]
     Dri3GetSupportedModifiers(dri3::GetSupportedModifiersReply),
     #[cfg(feature = "dri3")]
+    #[cfg_attr(feature = "serde", serde(skip))]
     Dri3BuffersFromPixmap(dri3::BuffersFromPixmapReply),
     GeQueryVersion(ge::QueryVersionReply),
     #[cfg(feature = "glx")]
@@ -4785,6 +6046,7 @@ pub enum Reply {
     #[cfg(feature = "randr")]
     RandrGetMonitors(randr::GetMonitorsReply),
     #[cfg(feature = "randr")]
+    #[cfg_attr(feature = "serde", serde(skip))]
     RandrCreateLease(randr::CreateLeaseReply),
     #[cfg(feature = "record")]
     RecordQueryVersion(record::QueryVersionReply),
@@ -4829,6 +6091,7 @@ pub enum Reply {
     #[cfg(feature = "shm")]
     ShmGetImage(shm::GetImageReply),
     #[cfg(feature = "shm")]
+    #[cfg_attr(feature = "serde", serde(skip))]
     ShmCreateSegment(shm::CreateSegmentReply),
     #[cfg(feature = "sync")]
     SyncInitialize(sync::InitializeReply),
@@ -5102,6 +6365,1579 @@ pub enum Reply {
     #[cfg(feature = "xvmc")]
     XvmcListSubpictureTypes(xvmc::ListSubpictureTypesReply),
 }
+
+impl Reply {
+    /// Serialize this reply into bytes that can be sent to an X11 client.
+    ///
+    /// The reply gets the given sequence number and a length field matching its contents.
+    /// `Reply::Void` results in no bytes, since no reply is sent for such requests.
+    ///
+    /// Since the reply is not consumed, its file descriptors (if any) are duplicated.
+    pub fn serialize(&self, sequence: u16) -> Result<BufWithFds<Vec<u8>>, SerializeError> {
+        match self {
+            Reply::Void => Ok((Vec::new(), Vec::new())),
+            Reply::GetWindowAttributes(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            Reply::GetGeometry(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            Reply::QueryTree(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            Reply::InternAtom(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            Reply::GetAtomName(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            Reply::GetProperty(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            Reply::ListProperties(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            Reply::GetSelectionOwner(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            Reply::GrabPointer(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            Reply::GrabKeyboard(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            Reply::QueryPointer(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            Reply::GetMotionEvents(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            Reply::TranslateCoordinates(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            Reply::GetInputFocus(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            Reply::QueryKeymap(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            Reply::QueryFont(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            Reply::QueryTextExtents(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            Reply::ListFonts(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            Reply::ListFontsWithInfo(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            Reply::GetFontPath(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            Reply::GetImage(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            Reply::ListInstalledColormaps(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            Reply::AllocColor(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            Reply::AllocNamedColor(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            Reply::AllocColorCells(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            Reply::AllocColorPlanes(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            Reply::QueryColors(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            Reply::LookupColor(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            Reply::QueryBestSize(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            Reply::QueryExtension(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            Reply::ListExtensions(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            Reply::GetKeyboardMapping(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            Reply::GetKeyboardControl(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            Reply::GetPointerControl(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            Reply::GetScreenSaver(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            Reply::ListHosts(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            Reply::SetPointerMapping(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            Reply::GetPointerMapping(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            Reply::SetModifierMapping(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            Reply::GetModifierMapping(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            Reply::BigreqEnable(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "composite")]
+            Reply::CompositeQueryVersion(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "composite")]
+            Reply::CompositeGetOverlayWindow(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "damage")]
+            Reply::DamageQueryVersion(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "dpms")]
+            Reply::DpmsGetVersion(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "dpms")]
+            Reply::DpmsCapable(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "dpms")]
+            Reply::DpmsGetTimeouts(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "dpms")]
+            Reply::DpmsInfo(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "dri2")]
+            Reply::Dri2QueryVersion(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "dri2")]
+            Reply::Dri2Connect(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "dri2")]
+            Reply::Dri2Authenticate(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "dri2")]
+            Reply::Dri2GetBuffers(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "dri2")]
+            Reply::Dri2CopyRegion(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "dri2")]
+            Reply::Dri2GetBuffersWithFormat(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "dri2")]
+            Reply::Dri2SwapBuffers(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "dri2")]
+            Reply::Dri2GetMSC(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "dri2")]
+            Reply::Dri2WaitMSC(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "dri2")]
+            Reply::Dri2WaitSBC(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "dri2")]
+            Reply::Dri2GetParam(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "dri3")]
+            Reply::Dri3QueryVersion(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "dri3")]
+            Reply::Dri3Open(reply) => Ok((
+                serialize_reply(reply, sequence),
+                alloc::vec![reply.device_fd.duplicate()?],
+            )),
+            #[cfg(feature = "dri3")]
+            Reply::Dri3BufferFromPixmap(reply) => Ok((
+                serialize_reply(reply, sequence),
+                alloc::vec![reply.pixmap_fd.duplicate()?],
+            )),
+            #[cfg(feature = "dri3")]
+            Reply::Dri3FDFromFence(reply) => Ok((
+                serialize_reply(reply, sequence),
+                alloc::vec![reply.fence_fd.duplicate()?],
+            )),
+            #[cfg(feature = "dri3")]
+            Reply::Dri3GetSupportedModifiers(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "dri3")]
+            Reply::Dri3BuffersFromPixmap(reply) => Ok((
+                serialize_reply(reply, sequence),
+                reply
+                    .buffers
+                    .iter()
+                    .map(RawFdContainer::duplicate)
+                    .collect::<Result<_, _>>()?,
+            )),
+            Reply::GeQueryVersion(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxMakeCurrent(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxIsDirect(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxQueryVersion(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetVisualConfigs(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxVendorPrivateWithReply(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxQueryExtensionsString(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxQueryServerString(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetFBConfigs(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxQueryContext(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxMakeContextCurrent(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetDrawableAttributes(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGenLists(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxRenderMode(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxFinish(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxReadPixels(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetBooleanv(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetClipPlane(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetDoublev(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetError(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetFloatv(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetIntegerv(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetLightfv(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetLightiv(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetMapdv(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetMapfv(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetMapiv(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetMaterialfv(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetMaterialiv(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetPixelMapfv(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetPixelMapuiv(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetPixelMapusv(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetPolygonStipple(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetString(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetTexEnvfv(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetTexEnviv(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetTexGendv(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetTexGenfv(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetTexGeniv(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetTexImage(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetTexParameterfv(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetTexParameteriv(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetTexLevelParameterfv(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetTexLevelParameteriv(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxIsEnabled(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxIsList(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxAreTexturesResident(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGenTextures(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxIsTexture(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetColorTable(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetColorTableParameterfv(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetColorTableParameteriv(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetConvolutionFilter(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetConvolutionParameterfv(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetConvolutionParameteriv(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetSeparableFilter(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetHistogram(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetHistogramParameterfv(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetHistogramParameteriv(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetMinmax(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetMinmaxParameterfv(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetMinmaxParameteriv(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetCompressedTexImageARB(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGenQueriesARB(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxIsQueryARB(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetQueryivARB(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetQueryObjectivARB(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "glx")]
+            Reply::GlxGetQueryObjectuivARB(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "present")]
+            Reply::PresentQueryVersion(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "present")]
+            Reply::PresentQueryCapabilities(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "randr")]
+            Reply::RandrQueryVersion(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "randr")]
+            Reply::RandrSetScreenConfig(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "randr")]
+            Reply::RandrGetScreenInfo(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "randr")]
+            Reply::RandrGetScreenSizeRange(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "randr")]
+            Reply::RandrGetScreenResources(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "randr")]
+            Reply::RandrGetOutputInfo(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "randr")]
+            Reply::RandrListOutputProperties(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "randr")]
+            Reply::RandrQueryOutputProperty(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "randr")]
+            Reply::RandrGetOutputProperty(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "randr")]
+            Reply::RandrCreateMode(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "randr")]
+            Reply::RandrGetCrtcInfo(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "randr")]
+            Reply::RandrSetCrtcConfig(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "randr")]
+            Reply::RandrGetCrtcGammaSize(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "randr")]
+            Reply::RandrGetCrtcGamma(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "randr")]
+            Reply::RandrGetScreenResourcesCurrent(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "randr")]
+            Reply::RandrGetCrtcTransform(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "randr")]
+            Reply::RandrGetPanning(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "randr")]
+            Reply::RandrSetPanning(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "randr")]
+            Reply::RandrGetOutputPrimary(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "randr")]
+            Reply::RandrGetProviders(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "randr")]
+            Reply::RandrGetProviderInfo(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "randr")]
+            Reply::RandrListProviderProperties(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "randr")]
+            Reply::RandrQueryProviderProperty(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "randr")]
+            Reply::RandrGetProviderProperty(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "randr")]
+            Reply::RandrGetMonitors(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "randr")]
+            Reply::RandrCreateLease(reply) => Ok((
+                serialize_reply(reply, sequence),
+                alloc::vec![reply.master_fd.duplicate()?],
+            )),
+            #[cfg(feature = "record")]
+            Reply::RecordQueryVersion(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "record")]
+            Reply::RecordGetContext(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "record")]
+            Reply::RecordEnableContext(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "render")]
+            Reply::RenderQueryVersion(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "render")]
+            Reply::RenderQueryPictFormats(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "render")]
+            Reply::RenderQueryPictIndexValues(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "render")]
+            Reply::RenderQueryFilters(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "res")]
+            Reply::ResQueryVersion(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "res")]
+            Reply::ResQueryClients(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "res")]
+            Reply::ResQueryClientResources(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "res")]
+            Reply::ResQueryClientPixmapBytes(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "res")]
+            Reply::ResQueryClientIds(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "res")]
+            Reply::ResQueryResourceBytes(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "screensaver")]
+            Reply::ScreensaverQueryVersion(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "screensaver")]
+            Reply::ScreensaverQueryInfo(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "shape")]
+            Reply::ShapeQueryVersion(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "shape")]
+            Reply::ShapeQueryExtents(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "shape")]
+            Reply::ShapeInputSelected(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "shape")]
+            Reply::ShapeGetRectangles(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "shm")]
+            Reply::ShmQueryVersion(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "shm")]
+            Reply::ShmGetImage(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "shm")]
+            Reply::ShmCreateSegment(reply) => Ok((
+                serialize_reply(reply, sequence),
+                alloc::vec![reply.shm_fd.duplicate()?],
+            )),
+            #[cfg(feature = "sync")]
+            Reply::SyncInitialize(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "sync")]
+            Reply::SyncListSystemCounters(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "sync")]
+            Reply::SyncQueryCounter(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "sync")]
+            Reply::SyncQueryAlarm(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "sync")]
+            Reply::SyncGetPriority(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "sync")]
+            Reply::SyncQueryFence(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            Reply::XcMiscGetVersion(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            Reply::XcMiscGetXIDRange(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            Reply::XcMiscGetXIDList(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xevie")]
+            Reply::XevieQueryVersion(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xevie")]
+            Reply::XevieStart(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xevie")]
+            Reply::XevieEnd(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xevie")]
+            Reply::XevieSend(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xevie")]
+            Reply::XevieSelectInput(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xf86dri")]
+            Reply::Xf86driQueryVersion(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xf86dri")]
+            Reply::Xf86driQueryDirectRenderingCapable(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xf86dri")]
+            Reply::Xf86driOpenConnection(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xf86dri")]
+            Reply::Xf86driGetClientDriverName(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xf86dri")]
+            Reply::Xf86driCreateContext(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xf86dri")]
+            Reply::Xf86driCreateDrawable(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xf86dri")]
+            Reply::Xf86driGetDrawableInfo(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xf86dri")]
+            Reply::Xf86driGetDeviceInfo(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xf86dri")]
+            Reply::Xf86driAuthConnection(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xf86vidmode")]
+            Reply::Xf86vidmodeQueryVersion(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xf86vidmode")]
+            Reply::Xf86vidmodeGetModeLine(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xf86vidmode")]
+            Reply::Xf86vidmodeGetMonitor(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xf86vidmode")]
+            Reply::Xf86vidmodeGetAllModeLines(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xf86vidmode")]
+            Reply::Xf86vidmodeValidateModeLine(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xf86vidmode")]
+            Reply::Xf86vidmodeGetViewPort(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xf86vidmode")]
+            Reply::Xf86vidmodeGetDotClocks(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xf86vidmode")]
+            Reply::Xf86vidmodeGetGamma(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xf86vidmode")]
+            Reply::Xf86vidmodeGetGammaRamp(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xf86vidmode")]
+            Reply::Xf86vidmodeGetGammaRampSize(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xf86vidmode")]
+            Reply::Xf86vidmodeGetPermissions(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xfixes")]
+            Reply::XfixesQueryVersion(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xfixes")]
+            Reply::XfixesGetCursorImage(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xfixes")]
+            Reply::XfixesFetchRegion(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xfixes")]
+            Reply::XfixesGetCursorName(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xfixes")]
+            Reply::XfixesGetCursorImageAndName(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xinerama")]
+            Reply::XineramaQueryVersion(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xinerama")]
+            Reply::XineramaGetState(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xinerama")]
+            Reply::XineramaGetScreenCount(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xinerama")]
+            Reply::XineramaGetScreenSize(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xinerama")]
+            Reply::XineramaIsActive(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xinerama")]
+            Reply::XineramaQueryScreens(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xinput")]
+            Reply::XinputGetExtensionVersion(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xinput")]
+            Reply::XinputListInputDevices(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xinput")]
+            Reply::XinputOpenDevice(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xinput")]
+            Reply::XinputSetDeviceMode(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xinput")]
+            Reply::XinputGetSelectedExtensionEvents(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xinput")]
+            Reply::XinputGetDeviceDontPropagateList(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xinput")]
+            Reply::XinputGetDeviceMotionEvents(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xinput")]
+            Reply::XinputChangeKeyboardDevice(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xinput")]
+            Reply::XinputChangePointerDevice(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xinput")]
+            Reply::XinputGrabDevice(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xinput")]
+            Reply::XinputGetDeviceFocus(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xinput")]
+            Reply::XinputGetFeedbackControl(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xinput")]
+            Reply::XinputGetDeviceKeyMapping(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xinput")]
+            Reply::XinputGetDeviceModifierMapping(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xinput")]
+            Reply::XinputSetDeviceModifierMapping(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xinput")]
+            Reply::XinputGetDeviceButtonMapping(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xinput")]
+            Reply::XinputSetDeviceButtonMapping(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xinput")]
+            Reply::XinputQueryDeviceState(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xinput")]
+            Reply::XinputSetDeviceValuators(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xinput")]
+            Reply::XinputGetDeviceControl(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xinput")]
+            Reply::XinputChangeDeviceControl(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xinput")]
+            Reply::XinputListDeviceProperties(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xinput")]
+            Reply::XinputGetDeviceProperty(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xinput")]
+            Reply::XinputXIQueryPointer(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xinput")]
+            Reply::XinputXIGetClientPointer(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xinput")]
+            Reply::XinputXIQueryVersion(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xinput")]
+            Reply::XinputXIQueryDevice(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xinput")]
+            Reply::XinputXIGetFocus(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xinput")]
+            Reply::XinputXIGrabDevice(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xinput")]
+            Reply::XinputXIPassiveGrabDevice(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xinput")]
+            Reply::XinputXIListProperties(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xinput")]
+            Reply::XinputXIGetProperty(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xinput")]
+            Reply::XinputXIGetSelectedEvents(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xkb")]
+            Reply::XkbUseExtension(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xkb")]
+            Reply::XkbGetState(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xkb")]
+            Reply::XkbGetControls(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xkb")]
+            Reply::XkbGetMap(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xkb")]
+            Reply::XkbGetCompatMap(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xkb")]
+            Reply::XkbGetIndicatorState(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xkb")]
+            Reply::XkbGetIndicatorMap(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xkb")]
+            Reply::XkbGetNamedIndicator(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xkb")]
+            Reply::XkbGetNames(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xkb")]
+            Reply::XkbPerClientFlags(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xkb")]
+            Reply::XkbListComponents(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xkb")]
+            Reply::XkbGetKbdByName(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xkb")]
+            Reply::XkbGetDeviceInfo(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xkb")]
+            Reply::XkbSetDebuggingFlags(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xprint")]
+            Reply::XprintPrintQueryVersion(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xprint")]
+            Reply::XprintPrintGetPrinterList(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xprint")]
+            Reply::XprintPrintGetContext(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xprint")]
+            Reply::XprintPrintGetScreenOfContext(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xprint")]
+            Reply::XprintPrintGetDocumentData(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xprint")]
+            Reply::XprintPrintInputSelected(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xprint")]
+            Reply::XprintPrintGetAttributes(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xprint")]
+            Reply::XprintPrintGetOneAttributes(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xprint")]
+            Reply::XprintPrintGetPageDimensions(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xprint")]
+            Reply::XprintPrintQueryScreens(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xprint")]
+            Reply::XprintPrintSetImageResolution(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xprint")]
+            Reply::XprintPrintGetImageResolution(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xselinux")]
+            Reply::XselinuxQueryVersion(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xselinux")]
+            Reply::XselinuxGetDeviceCreateContext(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xselinux")]
+            Reply::XselinuxGetDeviceContext(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xselinux")]
+            Reply::XselinuxGetWindowCreateContext(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xselinux")]
+            Reply::XselinuxGetWindowContext(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xselinux")]
+            Reply::XselinuxGetPropertyCreateContext(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xselinux")]
+            Reply::XselinuxGetPropertyUseContext(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xselinux")]
+            Reply::XselinuxGetPropertyContext(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xselinux")]
+            Reply::XselinuxGetPropertyDataContext(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xselinux")]
+            Reply::XselinuxListProperties(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xselinux")]
+            Reply::XselinuxGetSelectionCreateContext(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xselinux")]
+            Reply::XselinuxGetSelectionUseContext(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xselinux")]
+            Reply::XselinuxGetSelectionContext(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xselinux")]
+            Reply::XselinuxGetSelectionDataContext(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xselinux")]
+            Reply::XselinuxListSelections(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xselinux")]
+            Reply::XselinuxGetClientContext(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xtest")]
+            Reply::XtestGetVersion(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xtest")]
+            Reply::XtestCompareCursor(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xv")]
+            Reply::XvQueryExtension(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xv")]
+            Reply::XvQueryAdaptors(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xv")]
+            Reply::XvQueryEncodings(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xv")]
+            Reply::XvGrabPort(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xv")]
+            Reply::XvQueryBestSize(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xv")]
+            Reply::XvGetPortAttribute(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xv")]
+            Reply::XvQueryPortAttributes(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xv")]
+            Reply::XvListImageFormats(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xv")]
+            Reply::XvQueryImageAttributes(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xvmc")]
+            Reply::XvmcQueryVersion(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xvmc")]
+            Reply::XvmcListSurfaceTypes(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xvmc")]
+            Reply::XvmcCreateContext(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xvmc")]
+            Reply::XvmcCreateSurface(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xvmc")]
+            Reply::XvmcCreateSubpicture(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+            #[cfg(feature = "xvmc")]
+            Reply::XvmcListSubpictureTypes(reply) => Ok((
+                serialize_reply(reply, sequence),
+                Vec::new(),
+            )),
+        }
+    }
+}
 impl From<()> for Reply {
     fn from(_: ()) -> Reply {
         Reply::Void
@@ -6984,7 +9820,10 @@ impl From<xvmc::ListSubpictureTypesReply> for Reply {
 }
 
 /// Get the name of a request from its extension name and opcodes.
-pub(crate) fn request_name(extension: Option<&str>, major_opcode: u8, minor_opcode: u16) -> Option<&'static str> {
+///
+/// `extension` is the name of the extension that `major_opcode` belongs to, or `None` for
+/// core protocol requests. `minor_opcode` is ignored for core protocol requests.
+pub fn request_name(extension: Option<&str>, major_opcode: u8, minor_opcode: u16) -> Option<&'static str> {
     // Check if this is a core protocol request.
     match major_opcode {
         1 => return Some("CreateWindow"),
@@ -8176,6 +11015,7 @@ pub(crate) fn request_name(extension: Option<&str>, major_opcode: u8, minor_opco
 
 /// Enumeration of all possible X11 error kinds.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum ErrorKind {
     Unknown(u8),
@@ -8456,6 +11296,7 @@ impl ErrorKind {
 
 /// Enumeration of all possible X11 events.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum Event {
     Unknown(Vec<u8>),
@@ -8898,6 +11739,699 @@ impl Event {
         }
     }
 
+    /// Serialize this event into bytes that can be sent to an X11 client.
+    ///
+    /// The event code of extension events and the major opcode of events of the Generic Event
+    /// Extension are looked up via `ext_info_provider`. The sent-event bit of the response
+    /// type is kept, so that events sent via `SendEvent` stay recognisable.
+    pub fn serialize(
+        &self,
+        // Might not be used if no extension with events is enabled
+        #[allow(unused_variables)]
+        ext_info_provider: &dyn ExtInfoProvider,
+    ) -> Result<Vec<u8>, SerializeError> {
+        match self {
+            Event::Unknown(value) => Ok(value.clone()),
+            Event::Error(value) => Ok(<[u8; 32]>::from(value).to_vec()),
+            Event::ButtonPress(value) => Ok(serialize_event(value, xproto::BUTTON_PRESS_EVENT)),
+            Event::ButtonRelease(value) => Ok(serialize_event(value, xproto::BUTTON_RELEASE_EVENT)),
+            Event::CirculateNotify(value) => Ok(serialize_event(value, xproto::CIRCULATE_NOTIFY_EVENT)),
+            Event::CirculateRequest(value) => Ok(serialize_event(value, xproto::CIRCULATE_REQUEST_EVENT)),
+            Event::ClientMessage(value) => Ok(serialize_event(value, xproto::CLIENT_MESSAGE_EVENT)),
+            Event::ColormapNotify(value) => Ok(serialize_event(value, xproto::COLORMAP_NOTIFY_EVENT)),
+            Event::ConfigureNotify(value) => Ok(serialize_event(value, xproto::CONFIGURE_NOTIFY_EVENT)),
+            Event::ConfigureRequest(value) => Ok(serialize_event(value, xproto::CONFIGURE_REQUEST_EVENT)),
+            Event::CreateNotify(value) => Ok(serialize_event(value, xproto::CREATE_NOTIFY_EVENT)),
+            Event::DestroyNotify(value) => Ok(serialize_event(value, xproto::DESTROY_NOTIFY_EVENT)),
+            Event::EnterNotify(value) => Ok(serialize_event(value, xproto::ENTER_NOTIFY_EVENT)),
+            Event::Expose(value) => Ok(serialize_event(value, xproto::EXPOSE_EVENT)),
+            Event::FocusIn(value) => Ok(serialize_event(value, xproto::FOCUS_IN_EVENT)),
+            Event::FocusOut(value) => Ok(serialize_event(value, xproto::FOCUS_OUT_EVENT)),
+            Event::GeGeneric(value) => Ok(serialize_event(value, xproto::GE_GENERIC_EVENT)),
+            Event::GraphicsExposure(value) => Ok(serialize_event(value, xproto::GRAPHICS_EXPOSURE_EVENT)),
+            Event::GravityNotify(value) => Ok(serialize_event(value, xproto::GRAVITY_NOTIFY_EVENT)),
+            Event::KeyPress(value) => Ok(serialize_event(value, xproto::KEY_PRESS_EVENT)),
+            Event::KeyRelease(value) => Ok(serialize_event(value, xproto::KEY_RELEASE_EVENT)),
+            Event::KeymapNotify(value) => Ok(serialize_event(value, xproto::KEYMAP_NOTIFY_EVENT)),
+            Event::LeaveNotify(value) => Ok(serialize_event(value, xproto::LEAVE_NOTIFY_EVENT)),
+            Event::MapNotify(value) => Ok(serialize_event(value, xproto::MAP_NOTIFY_EVENT)),
+            Event::MapRequest(value) => Ok(serialize_event(value, xproto::MAP_REQUEST_EVENT)),
+            Event::MappingNotify(value) => Ok(serialize_event(value, xproto::MAPPING_NOTIFY_EVENT)),
+            Event::MotionNotify(value) => Ok(serialize_event(value, xproto::MOTION_NOTIFY_EVENT)),
+            Event::NoExposure(value) => Ok(serialize_event(value, xproto::NO_EXPOSURE_EVENT)),
+            Event::PropertyNotify(value) => Ok(serialize_event(value, xproto::PROPERTY_NOTIFY_EVENT)),
+            Event::ReparentNotify(value) => Ok(serialize_event(value, xproto::REPARENT_NOTIFY_EVENT)),
+            Event::ResizeRequest(value) => Ok(serialize_event(value, xproto::RESIZE_REQUEST_EVENT)),
+            Event::SelectionClear(value) => Ok(serialize_event(value, xproto::SELECTION_CLEAR_EVENT)),
+            Event::SelectionNotify(value) => Ok(serialize_event(value, xproto::SELECTION_NOTIFY_EVENT)),
+            Event::SelectionRequest(value) => Ok(serialize_event(value, xproto::SELECTION_REQUEST_EVENT)),
+            Event::UnmapNotify(value) => Ok(serialize_event(value, xproto::UNMAP_NOTIFY_EVENT)),
+            Event::VisibilityNotify(value) => Ok(serialize_event(value, xproto::VISIBILITY_NOTIFY_EVENT)),
+            #[cfg(feature = "damage")]
+            Event::DamageNotify(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    damage::X11_EXTENSION_NAME,
+                    damage::NOTIFY_EVENT,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "dri2")]
+            Event::Dri2BufferSwapComplete(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    dri2::X11_EXTENSION_NAME,
+                    dri2::BUFFER_SWAP_COMPLETE_EVENT,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "dri2")]
+            Event::Dri2InvalidateBuffers(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    dri2::X11_EXTENSION_NAME,
+                    dri2::INVALIDATE_BUFFERS_EVENT,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "glx")]
+            Event::GlxBufferSwapComplete(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    glx::X11_EXTENSION_NAME,
+                    glx::BUFFER_SWAP_COMPLETE_EVENT,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "glx")]
+            Event::GlxPbufferClobber(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    glx::X11_EXTENSION_NAME,
+                    glx::PBUFFER_CLOBBER_EVENT,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "present")]
+            Event::PresentCompleteNotify(value) => serialize_generic_event(
+                value,
+                ext_info_provider,
+                present::X11_EXTENSION_NAME,
+                present::COMPLETE_NOTIFY_EVENT,
+            ),
+            #[cfg(feature = "present")]
+            Event::PresentConfigureNotify(value) => serialize_generic_event(
+                value,
+                ext_info_provider,
+                present::X11_EXTENSION_NAME,
+                present::CONFIGURE_NOTIFY_EVENT,
+            ),
+            #[cfg(feature = "present")]
+            Event::PresentGeneric(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    present::X11_EXTENSION_NAME,
+                    present::GENERIC_EVENT,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "present")]
+            Event::PresentIdleNotify(value) => serialize_generic_event(
+                value,
+                ext_info_provider,
+                present::X11_EXTENSION_NAME,
+                present::IDLE_NOTIFY_EVENT,
+            ),
+            #[cfg(feature = "present")]
+            Event::PresentRedirectNotify(value) => serialize_generic_event(
+                value,
+                ext_info_provider,
+                present::X11_EXTENSION_NAME,
+                present::REDIRECT_NOTIFY_EVENT,
+            ),
+            #[cfg(feature = "randr")]
+            Event::RandrNotify(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    randr::X11_EXTENSION_NAME,
+                    randr::NOTIFY_EVENT,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "randr")]
+            Event::RandrScreenChangeNotify(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    randr::X11_EXTENSION_NAME,
+                    randr::SCREEN_CHANGE_NOTIFY_EVENT,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "screensaver")]
+            Event::ScreensaverNotify(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    screensaver::X11_EXTENSION_NAME,
+                    screensaver::NOTIFY_EVENT,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "shape")]
+            Event::ShapeNotify(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    shape::X11_EXTENSION_NAME,
+                    shape::NOTIFY_EVENT,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "shm")]
+            Event::ShmCompletion(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    shm::X11_EXTENSION_NAME,
+                    shm::COMPLETION_EVENT,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "sync")]
+            Event::SyncAlarmNotify(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    sync::X11_EXTENSION_NAME,
+                    sync::ALARM_NOTIFY_EVENT,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "sync")]
+            Event::SyncCounterNotify(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    sync::X11_EXTENSION_NAME,
+                    sync::COUNTER_NOTIFY_EVENT,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "xfixes")]
+            Event::XfixesCursorNotify(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    xfixes::X11_EXTENSION_NAME,
+                    xfixes::CURSOR_NOTIFY_EVENT,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "xfixes")]
+            Event::XfixesSelectionNotify(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    xfixes::X11_EXTENSION_NAME,
+                    xfixes::SELECTION_NOTIFY_EVENT,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputBarrierHit(value) => serialize_generic_event(
+                value,
+                ext_info_provider,
+                xinput::X11_EXTENSION_NAME,
+                xinput::BARRIER_HIT_EVENT,
+            ),
+            #[cfg(feature = "xinput")]
+            Event::XinputBarrierLeave(value) => serialize_generic_event(
+                value,
+                ext_info_provider,
+                xinput::X11_EXTENSION_NAME,
+                xinput::BARRIER_LEAVE_EVENT,
+            ),
+            #[cfg(feature = "xinput")]
+            Event::XinputButtonPress(value) => serialize_generic_event(
+                value,
+                ext_info_provider,
+                xinput::X11_EXTENSION_NAME,
+                xinput::BUTTON_PRESS_EVENT,
+            ),
+            #[cfg(feature = "xinput")]
+            Event::XinputButtonRelease(value) => serialize_generic_event(
+                value,
+                ext_info_provider,
+                xinput::X11_EXTENSION_NAME,
+                xinput::BUTTON_RELEASE_EVENT,
+            ),
+            #[cfg(feature = "xinput")]
+            Event::XinputChangeDeviceNotify(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    xinput::X11_EXTENSION_NAME,
+                    xinput::CHANGE_DEVICE_NOTIFY_EVENT,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceButtonPress(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    xinput::X11_EXTENSION_NAME,
+                    xinput::DEVICE_BUTTON_PRESS_EVENT,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceButtonRelease(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    xinput::X11_EXTENSION_NAME,
+                    xinput::DEVICE_BUTTON_RELEASE_EVENT,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceButtonStateNotify(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    xinput::X11_EXTENSION_NAME,
+                    xinput::DEVICE_BUTTON_STATE_NOTIFY_EVENT,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceChanged(value) => serialize_generic_event(
+                value,
+                ext_info_provider,
+                xinput::X11_EXTENSION_NAME,
+                xinput::DEVICE_CHANGED_EVENT,
+            ),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceFocusIn(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    xinput::X11_EXTENSION_NAME,
+                    xinput::DEVICE_FOCUS_IN_EVENT,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceFocusOut(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    xinput::X11_EXTENSION_NAME,
+                    xinput::DEVICE_FOCUS_OUT_EVENT,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceKeyPress(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    xinput::X11_EXTENSION_NAME,
+                    xinput::DEVICE_KEY_PRESS_EVENT,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceKeyRelease(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    xinput::X11_EXTENSION_NAME,
+                    xinput::DEVICE_KEY_RELEASE_EVENT,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceKeyStateNotify(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    xinput::X11_EXTENSION_NAME,
+                    xinput::DEVICE_KEY_STATE_NOTIFY_EVENT,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceMappingNotify(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    xinput::X11_EXTENSION_NAME,
+                    xinput::DEVICE_MAPPING_NOTIFY_EVENT,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceMotionNotify(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    xinput::X11_EXTENSION_NAME,
+                    xinput::DEVICE_MOTION_NOTIFY_EVENT,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputDevicePresenceNotify(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    xinput::X11_EXTENSION_NAME,
+                    xinput::DEVICE_PRESENCE_NOTIFY_EVENT,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputDevicePropertyNotify(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    xinput::X11_EXTENSION_NAME,
+                    xinput::DEVICE_PROPERTY_NOTIFY_EVENT,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceStateNotify(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    xinput::X11_EXTENSION_NAME,
+                    xinput::DEVICE_STATE_NOTIFY_EVENT,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceValuator(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    xinput::X11_EXTENSION_NAME,
+                    xinput::DEVICE_VALUATOR_EVENT,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputEnter(value) => serialize_generic_event(
+                value,
+                ext_info_provider,
+                xinput::X11_EXTENSION_NAME,
+                xinput::ENTER_EVENT,
+            ),
+            #[cfg(feature = "xinput")]
+            Event::XinputFocusIn(value) => serialize_generic_event(
+                value,
+                ext_info_provider,
+                xinput::X11_EXTENSION_NAME,
+                xinput::FOCUS_IN_EVENT,
+            ),
+            #[cfg(feature = "xinput")]
+            Event::XinputFocusOut(value) => serialize_generic_event(
+                value,
+                ext_info_provider,
+                xinput::X11_EXTENSION_NAME,
+                xinput::FOCUS_OUT_EVENT,
+            ),
+            #[cfg(feature = "xinput")]
+            Event::XinputHierarchy(value) => serialize_generic_event(
+                value,
+                ext_info_provider,
+                xinput::X11_EXTENSION_NAME,
+                xinput::HIERARCHY_EVENT,
+            ),
+            #[cfg(feature = "xinput")]
+            Event::XinputKeyPress(value) => serialize_generic_event(
+                value,
+                ext_info_provider,
+                xinput::X11_EXTENSION_NAME,
+                xinput::KEY_PRESS_EVENT,
+            ),
+            #[cfg(feature = "xinput")]
+            Event::XinputKeyRelease(value) => serialize_generic_event(
+                value,
+                ext_info_provider,
+                xinput::X11_EXTENSION_NAME,
+                xinput::KEY_RELEASE_EVENT,
+            ),
+            #[cfg(feature = "xinput")]
+            Event::XinputLeave(value) => serialize_generic_event(
+                value,
+                ext_info_provider,
+                xinput::X11_EXTENSION_NAME,
+                xinput::LEAVE_EVENT,
+            ),
+            #[cfg(feature = "xinput")]
+            Event::XinputMotion(value) => serialize_generic_event(
+                value,
+                ext_info_provider,
+                xinput::X11_EXTENSION_NAME,
+                xinput::MOTION_EVENT,
+            ),
+            #[cfg(feature = "xinput")]
+            Event::XinputProperty(value) => serialize_generic_event(
+                value,
+                ext_info_provider,
+                xinput::X11_EXTENSION_NAME,
+                xinput::PROPERTY_EVENT,
+            ),
+            #[cfg(feature = "xinput")]
+            Event::XinputProximityIn(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    xinput::X11_EXTENSION_NAME,
+                    xinput::PROXIMITY_IN_EVENT,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputProximityOut(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    xinput::X11_EXTENSION_NAME,
+                    xinput::PROXIMITY_OUT_EVENT,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputRawButtonPress(value) => serialize_generic_event(
+                value,
+                ext_info_provider,
+                xinput::X11_EXTENSION_NAME,
+                xinput::RAW_BUTTON_PRESS_EVENT,
+            ),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawButtonRelease(value) => serialize_generic_event(
+                value,
+                ext_info_provider,
+                xinput::X11_EXTENSION_NAME,
+                xinput::RAW_BUTTON_RELEASE_EVENT,
+            ),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawKeyPress(value) => serialize_generic_event(
+                value,
+                ext_info_provider,
+                xinput::X11_EXTENSION_NAME,
+                xinput::RAW_KEY_PRESS_EVENT,
+            ),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawKeyRelease(value) => serialize_generic_event(
+                value,
+                ext_info_provider,
+                xinput::X11_EXTENSION_NAME,
+                xinput::RAW_KEY_RELEASE_EVENT,
+            ),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawMotion(value) => serialize_generic_event(
+                value,
+                ext_info_provider,
+                xinput::X11_EXTENSION_NAME,
+                xinput::RAW_MOTION_EVENT,
+            ),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawTouchBegin(value) => serialize_generic_event(
+                value,
+                ext_info_provider,
+                xinput::X11_EXTENSION_NAME,
+                xinput::RAW_TOUCH_BEGIN_EVENT,
+            ),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawTouchEnd(value) => serialize_generic_event(
+                value,
+                ext_info_provider,
+                xinput::X11_EXTENSION_NAME,
+                xinput::RAW_TOUCH_END_EVENT,
+            ),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawTouchUpdate(value) => serialize_generic_event(
+                value,
+                ext_info_provider,
+                xinput::X11_EXTENSION_NAME,
+                xinput::RAW_TOUCH_UPDATE_EVENT,
+            ),
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchBegin(value) => serialize_generic_event(
+                value,
+                ext_info_provider,
+                xinput::X11_EXTENSION_NAME,
+                xinput::TOUCH_BEGIN_EVENT,
+            ),
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchEnd(value) => serialize_generic_event(
+                value,
+                ext_info_provider,
+                xinput::X11_EXTENSION_NAME,
+                xinput::TOUCH_END_EVENT,
+            ),
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchOwnership(value) => serialize_generic_event(
+                value,
+                ext_info_provider,
+                xinput::X11_EXTENSION_NAME,
+                xinput::TOUCH_OWNERSHIP_EVENT,
+            ),
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchUpdate(value) => serialize_generic_event(
+                value,
+                ext_info_provider,
+                xinput::X11_EXTENSION_NAME,
+                xinput::TOUCH_UPDATE_EVENT,
+            ),
+            #[cfg(feature = "xkb")]
+            Event::XkbAccessXNotify(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    xkb::X11_EXTENSION_NAME,
+                    0,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "xkb")]
+            Event::XkbActionMessage(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    xkb::X11_EXTENSION_NAME,
+                    0,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "xkb")]
+            Event::XkbBellNotify(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    xkb::X11_EXTENSION_NAME,
+                    0,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "xkb")]
+            Event::XkbCompatMapNotify(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    xkb::X11_EXTENSION_NAME,
+                    0,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "xkb")]
+            Event::XkbControlsNotify(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    xkb::X11_EXTENSION_NAME,
+                    0,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "xkb")]
+            Event::XkbExtensionDeviceNotify(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    xkb::X11_EXTENSION_NAME,
+                    0,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "xkb")]
+            Event::XkbIndicatorMapNotify(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    xkb::X11_EXTENSION_NAME,
+                    0,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "xkb")]
+            Event::XkbIndicatorStateNotify(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    xkb::X11_EXTENSION_NAME,
+                    0,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "xkb")]
+            Event::XkbMapNotify(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    xkb::X11_EXTENSION_NAME,
+                    0,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "xkb")]
+            Event::XkbNamesNotify(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    xkb::X11_EXTENSION_NAME,
+                    0,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "xkb")]
+            Event::XkbNewKeyboardNotify(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    xkb::X11_EXTENSION_NAME,
+                    0,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "xkb")]
+            Event::XkbStateNotify(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    xkb::X11_EXTENSION_NAME,
+                    0,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "xprint")]
+            Event::XprintAttributNotify(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    xprint::X11_EXTENSION_NAME,
+                    xprint::ATTRIBUT_NOTIFY_EVENT,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "xprint")]
+            Event::XprintNotify(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    xprint::X11_EXTENSION_NAME,
+                    xprint::NOTIFY_EVENT,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "xv")]
+            Event::XvPortNotify(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    xv::X11_EXTENSION_NAME,
+                    xv::PORT_NOTIFY_EVENT,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+            #[cfg(feature = "xv")]
+            Event::XvVideoNotify(value) => {
+                let event_code = extension_event_code(
+                    ext_info_provider,
+                    xv::X11_EXTENSION_NAME,
+                    xv::VIDEO_NOTIFY_EVENT,
+                )?;
+                Ok(serialize_event(value, event_code))
+            }
+        }
+    }
+
     /// Get the sequence number contained in this X11 event
     pub fn wire_sequence_number(&self) -> Option<u16> {
         match self {
@@ -9096,6 +12630,114 @@ impl Event {
         }
     }
 
+    /// Get the window that this X11 event is about, if there is one.
+    ///
+    /// This is the window on which the event was selected and thus the window that
+    /// the event is reported to. For example, for a `ConfigureNotify` that was
+    /// selected with `SubstructureNotify`, this is the parent of the configured window.
+    pub fn window(&self) -> Option<xproto::Window> {
+        match self {
+            Event::ButtonPress(value) => Some(value.event),
+            Event::ButtonRelease(value) => Some(value.event),
+            Event::CirculateNotify(value) => Some(value.event),
+            Event::CirculateRequest(value) => Some(value.event),
+            Event::ClientMessage(value) => Some(value.window),
+            Event::ColormapNotify(value) => Some(value.window),
+            Event::ConfigureNotify(value) => Some(value.event),
+            Event::ConfigureRequest(value) => Some(value.parent),
+            Event::CreateNotify(value) => Some(value.parent),
+            Event::DestroyNotify(value) => Some(value.event),
+            Event::EnterNotify(value) => Some(value.event),
+            Event::Expose(value) => Some(value.window),
+            Event::FocusIn(value) => Some(value.event),
+            Event::FocusOut(value) => Some(value.event),
+            Event::GravityNotify(value) => Some(value.event),
+            Event::KeyPress(value) => Some(value.event),
+            Event::KeyRelease(value) => Some(value.event),
+            Event::LeaveNotify(value) => Some(value.event),
+            Event::MapNotify(value) => Some(value.event),
+            Event::MapRequest(value) => Some(value.parent),
+            Event::MotionNotify(value) => Some(value.event),
+            Event::PropertyNotify(value) => Some(value.window),
+            Event::ReparentNotify(value) => Some(value.event),
+            Event::ResizeRequest(value) => Some(value.window),
+            Event::SelectionClear(value) => Some(value.owner),
+            Event::SelectionNotify(value) => Some(value.requestor),
+            Event::SelectionRequest(value) => Some(value.owner),
+            Event::UnmapNotify(value) => Some(value.event),
+            Event::VisibilityNotify(value) => Some(value.window),
+            #[cfg(feature = "present")]
+            Event::PresentCompleteNotify(value) => Some(value.window),
+            #[cfg(feature = "present")]
+            Event::PresentConfigureNotify(value) => Some(value.window),
+            #[cfg(feature = "present")]
+            Event::PresentIdleNotify(value) => Some(value.window),
+            #[cfg(feature = "present")]
+            Event::PresentRedirectNotify(value) => Some(value.window),
+            #[cfg(feature = "randr")]
+            Event::RandrScreenChangeNotify(value) => Some(value.request_window),
+            #[cfg(feature = "screensaver")]
+            Event::ScreensaverNotify(value) => Some(value.window),
+            #[cfg(feature = "shape")]
+            Event::ShapeNotify(value) => Some(value.affected_window),
+            #[cfg(feature = "xfixes")]
+            Event::XfixesCursorNotify(value) => Some(value.window),
+            #[cfg(feature = "xfixes")]
+            Event::XfixesSelectionNotify(value) => Some(value.window),
+            #[cfg(feature = "xinput")]
+            Event::XinputBarrierHit(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputBarrierLeave(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputButtonPress(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputButtonRelease(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceButtonPress(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceButtonRelease(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceFocusIn(value) => Some(value.window),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceFocusOut(value) => Some(value.window),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceKeyPress(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceKeyRelease(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceMotionNotify(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputEnter(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputFocusIn(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputFocusOut(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputKeyPress(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputKeyRelease(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputLeave(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputMotion(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputProximityIn(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputProximityOut(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchBegin(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchEnd(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchOwnership(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchUpdate(value) => Some(value.event),
+            #[cfg(feature = "xkb")]
+            Event::XkbBellNotify(value) => Some(value.window),
+            _ => None,
+        }
+    }
+
     /// Get the raw response type of this X11 event
     ///
     /// Response types have seven bits in X11. The eight bit indicates whether
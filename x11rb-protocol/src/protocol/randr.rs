@@ -886,6 +886,72 @@ impl GetScreenInfoReply {
     }
 }
 
+/// Borrowed version of [`GetScreenInfoReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetScreenInfoReplyRef<'a> {
+    pub rotations: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub root: xproto::Window,
+    pub timestamp: xproto::Timestamp,
+    pub config_timestamp: xproto::Timestamp,
+    pub size_id: u16,
+    pub rotation: u16,
+    pub rate: u16,
+    pub n_info: u16,
+    pub sizes: crate::x11_utils::ListRef<'a, ScreenSize>,
+    pub rates: crate::x11_utils::ListRef<'a, RefreshRates>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetScreenInfoReply {
+    type Ref = GetScreenInfoReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let (rotations, remaining) = u8::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (root, remaining) = xproto::Window::try_parse(remaining)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
+        let (config_timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
+        let (n_sizes, remaining) = u16::try_parse(remaining)?;
+        let (size_id, remaining) = u16::try_parse(remaining)?;
+        let (rotation, remaining) = u16::try_parse(remaining)?;
+        let (rate, remaining) = u16::try_parse(remaining)?;
+        let (n_info, remaining) = u16::try_parse(remaining)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (sizes, remaining) = crate::x11_utils::ListRef::<ScreenSize>::try_parse(remaining, n_sizes.try_to_usize()?)?;
+        let (rates, remaining) = crate::x11_utils::ListRef::<RefreshRates>::try_parse(remaining, u32::from(n_info).checked_sub(u32::from(n_sizes)).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetScreenInfoReplyRef { rotations, sequence, length, root, timestamp, config_timestamp, size_id, rotation, rate, n_info, sizes, rates };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetScreenInfoReplyRef<'_>> for GetScreenInfoReply {
+    fn from(reply: GetScreenInfoReplyRef<'_>) -> Self {
+        Self {
+            rotations: reply.rotations,
+            sequence: reply.sequence,
+            length: reply.length,
+            root: reply.root,
+            timestamp: reply.timestamp,
+            config_timestamp: reply.config_timestamp,
+            size_id: reply.size_id,
+            rotation: reply.rotation,
+            rate: reply.rate,
+            n_info: reply.n_info,
+            sizes: reply.sizes.iter().collect(),
+            rates: reply.rates.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the GetScreenSizeRange request
 pub const GET_SCREEN_SIZE_RANGE_REQUEST: u8 = 6;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -1477,6 +1543,64 @@ impl GetScreenResourcesReply {
     }
 }
 
+/// Borrowed version of [`GetScreenResourcesReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetScreenResourcesReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub timestamp: xproto::Timestamp,
+    pub config_timestamp: xproto::Timestamp,
+    pub crtcs: crate::x11_utils::ListRef<'a, Crtc>,
+    pub outputs: crate::x11_utils::ListRef<'a, Output>,
+    pub modes: crate::x11_utils::ListRef<'a, ModeInfo>,
+    pub names: &'a [u8],
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetScreenResourcesReply {
+    type Ref = GetScreenResourcesReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
+        let (config_timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
+        let (num_crtcs, remaining) = u16::try_parse(remaining)?;
+        let (num_outputs, remaining) = u16::try_parse(remaining)?;
+        let (num_modes, remaining) = u16::try_parse(remaining)?;
+        let (names_len, remaining) = u16::try_parse(remaining)?;
+        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
+        let (crtcs, remaining) = crate::x11_utils::ListRef::<Crtc>::try_parse(remaining, num_crtcs.try_to_usize()?)?;
+        let (outputs, remaining) = crate::x11_utils::ListRef::<Output>::try_parse(remaining, num_outputs.try_to_usize()?)?;
+        let (modes, remaining) = crate::x11_utils::ListRef::<ModeInfo>::try_parse(remaining, num_modes.try_to_usize()?)?;
+        let (names, remaining) = crate::x11_utils::parse_u8_list(remaining, names_len.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetScreenResourcesReplyRef { sequence, length, timestamp, config_timestamp, crtcs, outputs, modes, names };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetScreenResourcesReplyRef<'_>> for GetScreenResourcesReply {
+    fn from(reply: GetScreenResourcesReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            timestamp: reply.timestamp,
+            config_timestamp: reply.config_timestamp,
+            crtcs: reply.crtcs.iter().collect(),
+            outputs: reply.outputs.iter().collect(),
+            modes: reply.modes.iter().collect(),
+            names: reply.names.to_vec(),
+        }
+    }
+}
+
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Connection(u8);
@@ -1744,6 +1868,83 @@ impl GetOutputInfoReply {
     }
 }
 
+/// Borrowed version of [`GetOutputInfoReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetOutputInfoReplyRef<'a> {
+    pub status: SetConfig,
+    pub sequence: u16,
+    pub length: u32,
+    pub timestamp: xproto::Timestamp,
+    pub crtc: Crtc,
+    pub mm_width: u32,
+    pub mm_height: u32,
+    pub connection: Connection,
+    pub subpixel_order: render::SubPixel,
+    pub num_preferred: u16,
+    pub crtcs: crate::x11_utils::ListRef<'a, Crtc>,
+    pub modes: crate::x11_utils::ListRef<'a, Mode>,
+    pub clones: crate::x11_utils::ListRef<'a, Output>,
+    pub name: &'a [u8],
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetOutputInfoReply {
+    type Ref = GetOutputInfoReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let (status, remaining) = u8::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
+        let (crtc, remaining) = Crtc::try_parse(remaining)?;
+        let (mm_width, remaining) = u32::try_parse(remaining)?;
+        let (mm_height, remaining) = u32::try_parse(remaining)?;
+        let (connection, remaining) = u8::try_parse(remaining)?;
+        let (subpixel_order, remaining) = u8::try_parse(remaining)?;
+        let (num_crtcs, remaining) = u16::try_parse(remaining)?;
+        let (num_modes, remaining) = u16::try_parse(remaining)?;
+        let (num_preferred, remaining) = u16::try_parse(remaining)?;
+        let (num_clones, remaining) = u16::try_parse(remaining)?;
+        let (name_len, remaining) = u16::try_parse(remaining)?;
+        let (crtcs, remaining) = crate::x11_utils::ListRef::<Crtc>::try_parse(remaining, num_crtcs.try_to_usize()?)?;
+        let (modes, remaining) = crate::x11_utils::ListRef::<Mode>::try_parse(remaining, num_modes.try_to_usize()?)?;
+        let (clones, remaining) = crate::x11_utils::ListRef::<Output>::try_parse(remaining, num_clones.try_to_usize()?)?;
+        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let status = status.into();
+        let connection = connection.into();
+        let subpixel_order = subpixel_order.into();
+        let result = GetOutputInfoReplyRef { status, sequence, length, timestamp, crtc, mm_width, mm_height, connection, subpixel_order, num_preferred, crtcs, modes, clones, name };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetOutputInfoReplyRef<'_>> for GetOutputInfoReply {
+    fn from(reply: GetOutputInfoReplyRef<'_>) -> Self {
+        Self {
+            status: reply.status,
+            sequence: reply.sequence,
+            length: reply.length,
+            timestamp: reply.timestamp,
+            crtc: reply.crtc,
+            mm_width: reply.mm_width,
+            mm_height: reply.mm_height,
+            connection: reply.connection,
+            subpixel_order: reply.subpixel_order,
+            num_preferred: reply.num_preferred,
+            crtcs: reply.crtcs.iter().collect(),
+            modes: reply.modes.iter().collect(),
+            clones: reply.clones.iter().collect(),
+            name: reply.name.to_vec(),
+        }
+    }
+}
+
 /// Opcode for the ListOutputProperties request
 pub const LIST_OUTPUT_PROPERTIES_REQUEST: u8 = 10;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -1861,6 +2062,46 @@ impl ListOutputPropertiesReply {
     }
 }
 
+/// Borrowed version of [`ListOutputPropertiesReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct ListOutputPropertiesReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub atoms: crate::x11_utils::ListRef<'a, xproto::Atom>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for ListOutputPropertiesReply {
+    type Ref = ListOutputPropertiesReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (num_atoms, remaining) = u16::try_parse(remaining)?;
+        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
+        let (atoms, remaining) = crate::x11_utils::ListRef::<xproto::Atom>::try_parse(remaining, num_atoms.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = ListOutputPropertiesReplyRef { sequence, length, atoms };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<ListOutputPropertiesReplyRef<'_>> for ListOutputPropertiesReply {
+    fn from(reply: ListOutputPropertiesReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            atoms: reply.atoms.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the QueryOutputProperty request
 pub const QUERY_OUTPUT_PROPERTY_REQUEST: u8 = 11;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -1992,6 +2233,52 @@ impl QueryOutputPropertyReply {
     }
 }
 
+/// Borrowed version of [`QueryOutputPropertyReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct QueryOutputPropertyReplyRef<'a> {
+    pub sequence: u16,
+    pub pending: bool,
+    pub range: bool,
+    pub immutable: bool,
+    pub valid_values: crate::x11_utils::ListRef<'a, i32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for QueryOutputPropertyReply {
+    type Ref = QueryOutputPropertyReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (pending, remaining) = bool::try_parse(remaining)?;
+        let (range, remaining) = bool::try_parse(remaining)?;
+        let (immutable, remaining) = bool::try_parse(remaining)?;
+        let remaining = remaining.get(21..).ok_or(ParseError::InsufficientData)?;
+        let (valid_values, remaining) = crate::x11_utils::ListRef::<i32>::try_parse(remaining, length.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryOutputPropertyReplyRef { sequence, pending, range, immutable, valid_values };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<QueryOutputPropertyReplyRef<'_>> for QueryOutputPropertyReply {
+    fn from(reply: QueryOutputPropertyReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            pending: reply.pending,
+            range: reply.range,
+            immutable: reply.immutable,
+            valid_values: reply.valid_values.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the ConfigureOutputProperty request
 pub const CONFIGURE_OUTPUT_PROPERTY_REQUEST: u8 = 12;
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -2417,6 +2704,56 @@ impl Serialize for GetOutputPropertyReply {
     }
 }
 
+/// Borrowed version of [`GetOutputPropertyReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetOutputPropertyReplyRef<'a> {
+    pub format: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub type_: xproto::Atom,
+    pub bytes_after: u32,
+    pub num_items: u32,
+    pub data: &'a [u8],
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetOutputPropertyReply {
+    type Ref = GetOutputPropertyReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let (format, remaining) = u8::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (type_, remaining) = xproto::Atom::try_parse(remaining)?;
+        let (bytes_after, remaining) = u32::try_parse(remaining)?;
+        let (num_items, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, num_items.checked_mul(u32::from(format).checked_div(8u32).ok_or(ParseError::InvalidExpression)?).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetOutputPropertyReplyRef { format, sequence, length, type_, bytes_after, num_items, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetOutputPropertyReplyRef<'_>> for GetOutputPropertyReply {
+    fn from(reply: GetOutputPropertyReplyRef<'_>) -> Self {
+        Self {
+            format: reply.format,
+            sequence: reply.sequence,
+            length: reply.length,
+            type_: reply.type_,
+            bytes_after: reply.bytes_after,
+            num_items: reply.num_items,
+            data: reply.data.to_vec(),
+        }
+    }
+}
+
 /// Opcode for the CreateMode request
 pub const CREATE_MODE_REQUEST: u8 = 16;
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -2944,6 +3281,76 @@ impl GetCrtcInfoReply {
     }
 }
 
+/// Borrowed version of [`GetCrtcInfoReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetCrtcInfoReplyRef<'a> {
+    pub status: SetConfig,
+    pub sequence: u16,
+    pub length: u32,
+    pub timestamp: xproto::Timestamp,
+    pub x: i16,
+    pub y: i16,
+    pub width: u16,
+    pub height: u16,
+    pub mode: Mode,
+    pub rotation: u16,
+    pub rotations: u16,
+    pub outputs: crate::x11_utils::ListRef<'a, Output>,
+    pub possible: crate::x11_utils::ListRef<'a, Output>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetCrtcInfoReply {
+    type Ref = GetCrtcInfoReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let (status, remaining) = u8::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
+        let (x, remaining) = i16::try_parse(remaining)?;
+        let (y, remaining) = i16::try_parse(remaining)?;
+        let (width, remaining) = u16::try_parse(remaining)?;
+        let (height, remaining) = u16::try_parse(remaining)?;
+        let (mode, remaining) = Mode::try_parse(remaining)?;
+        let (rotation, remaining) = u16::try_parse(remaining)?;
+        let (rotations, remaining) = u16::try_parse(remaining)?;
+        let (num_outputs, remaining) = u16::try_parse(remaining)?;
+        let (num_possible_outputs, remaining) = u16::try_parse(remaining)?;
+        let (outputs, remaining) = crate::x11_utils::ListRef::<Output>::try_parse(remaining, num_outputs.try_to_usize()?)?;
+        let (possible, remaining) = crate::x11_utils::ListRef::<Output>::try_parse(remaining, num_possible_outputs.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let status = status.into();
+        let result = GetCrtcInfoReplyRef { status, sequence, length, timestamp, x, y, width, height, mode, rotation, rotations, outputs, possible };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetCrtcInfoReplyRef<'_>> for GetCrtcInfoReply {
+    fn from(reply: GetCrtcInfoReplyRef<'_>) -> Self {
+        Self {
+            status: reply.status,
+            sequence: reply.sequence,
+            length: reply.length,
+            timestamp: reply.timestamp,
+            x: reply.x,
+            y: reply.y,
+            width: reply.width,
+            height: reply.height,
+            mode: reply.mode,
+            rotation: reply.rotation,
+            rotations: reply.rotations,
+            outputs: reply.outputs.iter().collect(),
+            possible: reply.possible.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the SetCrtcConfig request
 pub const SET_CRTC_CONFIG_REQUEST: u8 = 21;
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -3412,6 +3819,52 @@ impl GetCrtcGammaReply {
     }
 }
 
+/// Borrowed version of [`GetCrtcGammaReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetCrtcGammaReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub red: crate::x11_utils::ListRef<'a, u16>,
+    pub green: crate::x11_utils::ListRef<'a, u16>,
+    pub blue: crate::x11_utils::ListRef<'a, u16>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetCrtcGammaReply {
+    type Ref = GetCrtcGammaReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (size, remaining) = u16::try_parse(remaining)?;
+        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
+        let (red, remaining) = crate::x11_utils::ListRef::<u16>::try_parse(remaining, size.try_to_usize()?)?;
+        let (green, remaining) = crate::x11_utils::ListRef::<u16>::try_parse(remaining, size.try_to_usize()?)?;
+        let (blue, remaining) = crate::x11_utils::ListRef::<u16>::try_parse(remaining, size.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetCrtcGammaReplyRef { sequence, length, red, green, blue };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetCrtcGammaReplyRef<'_>> for GetCrtcGammaReply {
+    fn from(reply: GetCrtcGammaReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            red: reply.red.iter().collect(),
+            green: reply.green.iter().collect(),
+            blue: reply.blue.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the SetCrtcGamma request
 pub const SET_CRTC_GAMMA_REQUEST: u8 = 24;
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -3682,6 +4135,64 @@ impl GetScreenResourcesCurrentReply {
     }
 }
 
+/// Borrowed version of [`GetScreenResourcesCurrentReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetScreenResourcesCurrentReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub timestamp: xproto::Timestamp,
+    pub config_timestamp: xproto::Timestamp,
+    pub crtcs: crate::x11_utils::ListRef<'a, Crtc>,
+    pub outputs: crate::x11_utils::ListRef<'a, Output>,
+    pub modes: crate::x11_utils::ListRef<'a, ModeInfo>,
+    pub names: &'a [u8],
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetScreenResourcesCurrentReply {
+    type Ref = GetScreenResourcesCurrentReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
+        let (config_timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
+        let (num_crtcs, remaining) = u16::try_parse(remaining)?;
+        let (num_outputs, remaining) = u16::try_parse(remaining)?;
+        let (num_modes, remaining) = u16::try_parse(remaining)?;
+        let (names_len, remaining) = u16::try_parse(remaining)?;
+        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
+        let (crtcs, remaining) = crate::x11_utils::ListRef::<Crtc>::try_parse(remaining, num_crtcs.try_to_usize()?)?;
+        let (outputs, remaining) = crate::x11_utils::ListRef::<Output>::try_parse(remaining, num_outputs.try_to_usize()?)?;
+        let (modes, remaining) = crate::x11_utils::ListRef::<ModeInfo>::try_parse(remaining, num_modes.try_to_usize()?)?;
+        let (names, remaining) = crate::x11_utils::parse_u8_list(remaining, names_len.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetScreenResourcesCurrentReplyRef { sequence, length, timestamp, config_timestamp, crtcs, outputs, modes, names };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetScreenResourcesCurrentReplyRef<'_>> for GetScreenResourcesCurrentReply {
+    fn from(reply: GetScreenResourcesCurrentReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            timestamp: reply.timestamp,
+            config_timestamp: reply.config_timestamp,
+            crtcs: reply.crtcs.iter().collect(),
+            outputs: reply.outputs.iter().collect(),
+            modes: reply.modes.iter().collect(),
+            names: reply.names.to_vec(),
+        }
+    }
+}
+
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Transform(u8);
@@ -4078,6 +4589,77 @@ impl GetCrtcTransformReply {
     }
 }
 
+/// Borrowed version of [`GetCrtcTransformReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetCrtcTransformReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub pending_transform: render::Transform,
+    pub has_transforms: bool,
+    pub current_transform: render::Transform,
+    pub pending_filter_name: &'a [u8],
+    pub pending_params: crate::x11_utils::ListRef<'a, render::Fixed>,
+    pub current_filter_name: &'a [u8],
+    pub current_params: crate::x11_utils::ListRef<'a, render::Fixed>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetCrtcTransformReply {
+    type Ref = GetCrtcTransformReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let value = remaining;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (pending_transform, remaining) = render::Transform::try_parse(remaining)?;
+        let (has_transforms, remaining) = bool::try_parse(remaining)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (current_transform, remaining) = render::Transform::try_parse(remaining)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (pending_len, remaining) = u16::try_parse(remaining)?;
+        let (pending_nparams, remaining) = u16::try_parse(remaining)?;
+        let (current_len, remaining) = u16::try_parse(remaining)?;
+        let (current_nparams, remaining) = u16::try_parse(remaining)?;
+        let (pending_filter_name, remaining) = crate::x11_utils::parse_u8_list(remaining, pending_len.try_to_usize()?)?;
+        // Align offset to multiple of 4
+        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
+        let misalignment = (4 - (offset % 4)) % 4;
+        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let (pending_params, remaining) = crate::x11_utils::ListRef::<render::Fixed>::try_parse(remaining, pending_nparams.try_to_usize()?)?;
+        let (current_filter_name, remaining) = crate::x11_utils::parse_u8_list(remaining, current_len.try_to_usize()?)?;
+        // Align offset to multiple of 4
+        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
+        let misalignment = (4 - (offset % 4)) % 4;
+        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let (current_params, remaining) = crate::x11_utils::ListRef::<render::Fixed>::try_parse(remaining, current_nparams.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetCrtcTransformReplyRef { sequence, length, pending_transform, has_transforms, current_transform, pending_filter_name, pending_params, current_filter_name, current_params };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetCrtcTransformReplyRef<'_>> for GetCrtcTransformReply {
+    fn from(reply: GetCrtcTransformReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            pending_transform: reply.pending_transform,
+            has_transforms: reply.has_transforms,
+            current_transform: reply.current_transform,
+            pending_filter_name: reply.pending_filter_name.to_vec(),
+            pending_params: reply.pending_params.iter().collect(),
+            current_filter_name: reply.current_filter_name.to_vec(),
+            current_params: reply.current_params.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the GetPanning request
 pub const GET_PANNING_REQUEST: u8 = 28;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -4753,6 +5335,49 @@ impl GetProvidersReply {
     }
 }
 
+/// Borrowed version of [`GetProvidersReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetProvidersReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub timestamp: xproto::Timestamp,
+    pub providers: crate::x11_utils::ListRef<'a, Provider>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetProvidersReply {
+    type Ref = GetProvidersReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
+        let (num_providers, remaining) = u16::try_parse(remaining)?;
+        let remaining = remaining.get(18..).ok_or(ParseError::InsufficientData)?;
+        let (providers, remaining) = crate::x11_utils::ListRef::<Provider>::try_parse(remaining, num_providers.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetProvidersReplyRef { sequence, length, timestamp, providers };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetProvidersReplyRef<'_>> for GetProvidersReply {
+    fn from(reply: GetProvidersReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            timestamp: reply.timestamp,
+            providers: reply.providers.iter().collect(),
+        }
+    }
+}
+
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ProviderCapability(u8);
@@ -5011,6 +5636,69 @@ impl GetProviderInfoReply {
     }
 }
 
+/// Borrowed version of [`GetProviderInfoReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetProviderInfoReplyRef<'a> {
+    pub status: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub timestamp: xproto::Timestamp,
+    pub capabilities: u32,
+    pub crtcs: crate::x11_utils::ListRef<'a, Crtc>,
+    pub outputs: crate::x11_utils::ListRef<'a, Output>,
+    pub associated_providers: crate::x11_utils::ListRef<'a, Provider>,
+    pub associated_capability: crate::x11_utils::ListRef<'a, u32>,
+    pub name: &'a [u8],
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetProviderInfoReply {
+    type Ref = GetProviderInfoReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let (status, remaining) = u8::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
+        let (capabilities, remaining) = u32::try_parse(remaining)?;
+        let (num_crtcs, remaining) = u16::try_parse(remaining)?;
+        let (num_outputs, remaining) = u16::try_parse(remaining)?;
+        let (num_associated_providers, remaining) = u16::try_parse(remaining)?;
+        let (name_len, remaining) = u16::try_parse(remaining)?;
+        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
+        let (crtcs, remaining) = crate::x11_utils::ListRef::<Crtc>::try_parse(remaining, num_crtcs.try_to_usize()?)?;
+        let (outputs, remaining) = crate::x11_utils::ListRef::<Output>::try_parse(remaining, num_outputs.try_to_usize()?)?;
+        let (associated_providers, remaining) = crate::x11_utils::ListRef::<Provider>::try_parse(remaining, num_associated_providers.try_to_usize()?)?;
+        let (associated_capability, remaining) = crate::x11_utils::ListRef::<u32>::try_parse(remaining, num_associated_providers.try_to_usize()?)?;
+        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetProviderInfoReplyRef { status, sequence, length, timestamp, capabilities, crtcs, outputs, associated_providers, associated_capability, name };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetProviderInfoReplyRef<'_>> for GetProviderInfoReply {
+    fn from(reply: GetProviderInfoReplyRef<'_>) -> Self {
+        Self {
+            status: reply.status,
+            sequence: reply.sequence,
+            length: reply.length,
+            timestamp: reply.timestamp,
+            capabilities: reply.capabilities,
+            crtcs: reply.crtcs.iter().collect(),
+            outputs: reply.outputs.iter().collect(),
+            associated_providers: reply.associated_providers.iter().collect(),
+            associated_capability: reply.associated_capability.iter().collect(),
+            name: reply.name.to_vec(),
+        }
+    }
+}
+
 /// Opcode for the SetProviderOffloadSink request
 pub const SET_PROVIDER_OFFLOAD_SINK_REQUEST: u8 = 34;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -5266,6 +5954,46 @@ impl ListProviderPropertiesReply {
     }
 }
 
+/// Borrowed version of [`ListProviderPropertiesReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct ListProviderPropertiesReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub atoms: crate::x11_utils::ListRef<'a, xproto::Atom>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for ListProviderPropertiesReply {
+    type Ref = ListProviderPropertiesReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (num_atoms, remaining) = u16::try_parse(remaining)?;
+        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
+        let (atoms, remaining) = crate::x11_utils::ListRef::<xproto::Atom>::try_parse(remaining, num_atoms.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = ListProviderPropertiesReplyRef { sequence, length, atoms };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<ListProviderPropertiesReplyRef<'_>> for ListProviderPropertiesReply {
+    fn from(reply: ListProviderPropertiesReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            atoms: reply.atoms.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the QueryProviderProperty request
 pub const QUERY_PROVIDER_PROPERTY_REQUEST: u8 = 37;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -5397,6 +6125,52 @@ impl QueryProviderPropertyReply {
     }
 }
 
+/// Borrowed version of [`QueryProviderPropertyReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct QueryProviderPropertyReplyRef<'a> {
+    pub sequence: u16,
+    pub pending: bool,
+    pub range: bool,
+    pub immutable: bool,
+    pub valid_values: crate::x11_utils::ListRef<'a, i32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for QueryProviderPropertyReply {
+    type Ref = QueryProviderPropertyReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (pending, remaining) = bool::try_parse(remaining)?;
+        let (range, remaining) = bool::try_parse(remaining)?;
+        let (immutable, remaining) = bool::try_parse(remaining)?;
+        let remaining = remaining.get(21..).ok_or(ParseError::InsufficientData)?;
+        let (valid_values, remaining) = crate::x11_utils::ListRef::<i32>::try_parse(remaining, length.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryProviderPropertyReplyRef { sequence, pending, range, immutable, valid_values };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<QueryProviderPropertyReplyRef<'_>> for QueryProviderPropertyReply {
+    fn from(reply: QueryProviderPropertyReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            pending: reply.pending,
+            range: reply.range,
+            immutable: reply.immutable,
+            valid_values: reply.valid_values.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the ConfigureProviderProperty request
 pub const CONFIGURE_PROVIDER_PROPERTY_REQUEST: u8 = 38;
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -5821,6 +6595,56 @@ impl Serialize for GetProviderPropertyReply {
     }
 }
 
+/// Borrowed version of [`GetProviderPropertyReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetProviderPropertyReplyRef<'a> {
+    pub format: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub type_: xproto::Atom,
+    pub bytes_after: u32,
+    pub num_items: u32,
+    pub data: &'a [u8],
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetProviderPropertyReply {
+    type Ref = GetProviderPropertyReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let (format, remaining) = u8::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (type_, remaining) = xproto::Atom::try_parse(remaining)?;
+        let (bytes_after, remaining) = u32::try_parse(remaining)?;
+        let (num_items, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, num_items.checked_mul(u32::from(format).checked_div(8u32).ok_or(ParseError::InvalidExpression)?).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetProviderPropertyReplyRef { format, sequence, length, type_, bytes_after, num_items, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetProviderPropertyReplyRef<'_>> for GetProviderPropertyReply {
+    fn from(reply: GetProviderPropertyReplyRef<'_>) -> Self {
+        Self {
+            format: reply.format,
+            sequence: reply.sequence,
+            length: reply.length,
+            type_: reply.type_,
+            bytes_after: reply.bytes_after,
+            num_items: reply.num_items,
+            data: reply.data.to_vec(),
+        }
+    }
+}
+
 /// Opcode for the ScreenChangeNotify event
 pub const SCREEN_CHANGE_NOTIFY_EVENT: u8 = 0;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -6698,6 +7522,52 @@ impl GetMonitorsReply {
     }
 }
 
+/// Borrowed version of [`GetMonitorsReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetMonitorsReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub timestamp: xproto::Timestamp,
+    pub n_outputs: u32,
+    pub monitors: crate::x11_utils::ListRef<'a, MonitorInfo>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetMonitorsReply {
+    type Ref = GetMonitorsReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
+        let (n_monitors, remaining) = u32::try_parse(remaining)?;
+        let (n_outputs, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (monitors, remaining) = crate::x11_utils::ListRef::<MonitorInfo>::try_parse(remaining, n_monitors.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetMonitorsReplyRef { sequence, length, timestamp, n_outputs, monitors };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetMonitorsReplyRef<'_>> for GetMonitorsReply {
+    fn from(reply: GetMonitorsReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            timestamp: reply.timestamp,
+            n_outputs: reply.n_outputs,
+            monitors: reply.monitors.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the SetMonitor request
 pub const SET_MONITOR_REQUEST: u8 = 43;
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -901,6 +901,52 @@ impl GetContextReply {
     }
 }
 
+/// Borrowed version of [`GetContextReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetContextReplyRef<'a> {
+    pub enabled: bool,
+    pub sequence: u16,
+    pub length: u32,
+    pub element_header: ElementHeader,
+    pub intercepted_clients: crate::x11_utils::ListRef<'a, ClientInfo>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetContextReply {
+    type Ref = GetContextReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let (enabled, remaining) = bool::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (element_header, remaining) = ElementHeader::try_parse(remaining)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (num_intercepted_clients, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        let (intercepted_clients, remaining) = crate::x11_utils::ListRef::<ClientInfo>::try_parse(remaining, num_intercepted_clients.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetContextReplyRef { enabled, sequence, length, element_header, intercepted_clients };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetContextReplyRef<'_>> for GetContextReply {
+    fn from(reply: GetContextReplyRef<'_>) -> Self {
+        Self {
+            enabled: reply.enabled,
+            sequence: reply.sequence,
+            length: reply.length,
+            element_header: reply.element_header,
+            intercepted_clients: reply.intercepted_clients.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the EnableContext request
 pub const ENABLE_CONTEXT_REQUEST: u8 = 5;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -1036,6 +1082,61 @@ impl EnableContextReply {
     }
 }
 
+/// Borrowed version of [`EnableContextReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct EnableContextReplyRef<'a> {
+    pub category: u8,
+    pub sequence: u16,
+    pub element_header: ElementHeader,
+    pub client_swapped: bool,
+    pub xid_base: u32,
+    pub server_time: u32,
+    pub rec_sequence_num: u32,
+    pub data: &'a [u8],
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for EnableContextReply {
+    type Ref = EnableContextReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let (category, remaining) = u8::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (element_header, remaining) = ElementHeader::try_parse(remaining)?;
+        let (client_swapped, remaining) = bool::try_parse(remaining)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (xid_base, remaining) = u32::try_parse(remaining)?;
+        let (server_time, remaining) = u32::try_parse(remaining)?;
+        let (rec_sequence_num, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, length.checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = EnableContextReplyRef { category, sequence, element_header, client_swapped, xid_base, server_time, rec_sequence_num, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<EnableContextReplyRef<'_>> for EnableContextReply {
+    fn from(reply: EnableContextReplyRef<'_>) -> Self {
+        Self {
+            category: reply.category,
+            sequence: reply.sequence,
+            element_header: reply.element_header,
+            client_swapped: reply.client_swapped,
+            xid_base: reply.xid_base,
+            server_time: reply.server_time,
+            rec_sequence_num: reply.rec_sequence_num,
+            data: reply.data.to_vec(),
+        }
+    }
+}
+
 /// Opcode for the DisableContext request
 pub const DISABLE_CONTEXT_REQUEST: u8 = 6;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -1682,6 +1682,46 @@ impl QueryPictIndexValuesReply {
     }
 }
 
+/// Borrowed version of [`QueryPictIndexValuesReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct QueryPictIndexValuesReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub values: crate::x11_utils::ListRef<'a, Indexvalue>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for QueryPictIndexValuesReply {
+    type Ref = QueryPictIndexValuesReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (num_values, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (values, remaining) = crate::x11_utils::ListRef::<Indexvalue>::try_parse(remaining, num_values.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryPictIndexValuesReplyRef { sequence, length, values };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<QueryPictIndexValuesReplyRef<'_>> for QueryPictIndexValuesReply {
+    fn from(reply: QueryPictIndexValuesReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            values: reply.values.iter().collect(),
+        }
+    }
+}
+
 /// Auxiliary and optional information for the `create_picture` function
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -4394,6 +4434,50 @@ impl QueryFiltersReply {
     }
 }
 
+/// Borrowed version of [`QueryFiltersReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct QueryFiltersReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub aliases: crate::x11_utils::ListRef<'a, u16>,
+    pub filters: crate::x11_utils::ListRef<'a, xproto::Str>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for QueryFiltersReply {
+    type Ref = QueryFiltersReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (num_aliases, remaining) = u32::try_parse(remaining)?;
+        let (num_filters, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        let (aliases, remaining) = crate::x11_utils::ListRef::<u16>::try_parse(remaining, num_aliases.try_to_usize()?)?;
+        let (filters, remaining) = crate::x11_utils::ListRef::<xproto::Str>::try_parse(remaining, num_filters.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryFiltersReplyRef { sequence, length, aliases, filters };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<QueryFiltersReplyRef<'_>> for QueryFiltersReply {
+    fn from(reply: QueryFiltersReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            aliases: reply.aliases.iter().collect(),
+            filters: reply.filters.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the SetPictureFilter request
 pub const SET_PICTURE_FILTER_REQUEST: u8 = 30;
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
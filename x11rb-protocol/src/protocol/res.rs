@@ -620,6 +620,46 @@ impl QueryClientsReply {
     }
 }
 
+/// Borrowed version of [`QueryClientsReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct QueryClientsReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub clients: crate::x11_utils::ListRef<'a, Client>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for QueryClientsReply {
+    type Ref = QueryClientsReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (num_clients, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (clients, remaining) = crate::x11_utils::ListRef::<Client>::try_parse(remaining, num_clients.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryClientsReplyRef { sequence, length, clients };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<QueryClientsReplyRef<'_>> for QueryClientsReply {
+    fn from(reply: QueryClientsReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            clients: reply.clients.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the QueryClientResources request
 pub const QUERY_CLIENT_RESOURCES_REQUEST: u8 = 2;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -737,6 +777,46 @@ impl QueryClientResourcesReply {
     }
 }
 
+/// Borrowed version of [`QueryClientResourcesReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct QueryClientResourcesReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub types: crate::x11_utils::ListRef<'a, Type>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for QueryClientResourcesReply {
+    type Ref = QueryClientResourcesReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (num_types, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (types, remaining) = crate::x11_utils::ListRef::<Type>::try_parse(remaining, num_types.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryClientResourcesReplyRef { sequence, length, types };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<QueryClientResourcesReplyRef<'_>> for QueryClientResourcesReply {
+    fn from(reply: QueryClientResourcesReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            types: reply.types.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the QueryClientPixmapBytes request
 pub const QUERY_CLIENT_PIXMAP_BYTES_REQUEST: u8 = 3;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -986,6 +1066,46 @@ impl QueryClientIdsReply {
     }
 }
 
+/// Borrowed version of [`QueryClientIdsReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct QueryClientIdsReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub ids: crate::x11_utils::ListRef<'a, ClientIdValue>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for QueryClientIdsReply {
+    type Ref = QueryClientIdsReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (num_ids, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (ids, remaining) = crate::x11_utils::ListRef::<ClientIdValue>::try_parse(remaining, num_ids.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryClientIdsReplyRef { sequence, length, ids };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<QueryClientIdsReplyRef<'_>> for QueryClientIdsReply {
+    fn from(reply: QueryClientIdsReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            ids: reply.ids.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the QueryResourceBytes request
 pub const QUERY_RESOURCE_BYTES_REQUEST: u8 = 5;
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -1124,3 +1244,43 @@ impl QueryResourceBytesReply {
     }
 }
 
+/// Borrowed version of [`QueryResourceBytesReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct QueryResourceBytesReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub sizes: crate::x11_utils::ListRef<'a, ResourceSizeValue>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for QueryResourceBytesReply {
+    type Ref = QueryResourceBytesReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (num_sizes, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (sizes, remaining) = crate::x11_utils::ListRef::<ResourceSizeValue>::try_parse(remaining, num_sizes.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryResourceBytesReplyRef { sequence, length, sizes };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<QueryResourceBytesReplyRef<'_>> for QueryResourceBytesReply {
+    fn from(reply: QueryResourceBytesReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            sizes: reply.sizes.iter().collect(),
+        }
+    }
+}
+
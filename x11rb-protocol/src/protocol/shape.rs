@@ -1256,3 +1256,46 @@ impl GetRectanglesReply {
     }
 }
 
+/// Borrowed version of [`GetRectanglesReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetRectanglesReplyRef<'a> {
+    pub ordering: xproto::ClipOrdering,
+    pub sequence: u16,
+    pub length: u32,
+    pub rectangles: crate::x11_utils::ListRef<'a, xproto::Rectangle>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetRectanglesReply {
+    type Ref = GetRectanglesReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let (ordering, remaining) = u8::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (rectangles_len, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (rectangles, remaining) = crate::x11_utils::ListRef::<xproto::Rectangle>::try_parse(remaining, rectangles_len.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let ordering = ordering.into();
+        let result = GetRectanglesReplyRef { ordering, sequence, length, rectangles };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetRectanglesReplyRef<'_>> for GetRectanglesReply {
+    fn from(reply: GetRectanglesReplyRef<'_>) -> Self {
+        Self {
+            ordering: reply.ordering,
+            sequence: reply.sequence,
+            length: reply.length,
+            rectangles: reply.rectangles.iter().collect(),
+        }
+    }
+}
+
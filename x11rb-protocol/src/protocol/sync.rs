@@ -732,6 +732,46 @@ impl ListSystemCountersReply {
     }
 }
 
+/// Borrowed version of [`ListSystemCountersReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct ListSystemCountersReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub counters: crate::x11_utils::ListRef<'a, Systemcounter>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for ListSystemCountersReply {
+    type Ref = ListSystemCountersReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (counters_len, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (counters, remaining) = crate::x11_utils::ListRef::<Systemcounter>::try_parse(remaining, counters_len.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = ListSystemCountersReplyRef { sequence, length, counters };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<ListSystemCountersReplyRef<'_>> for ListSystemCountersReply {
+    fn from(reply: ListSystemCountersReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            counters: reply.counters.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the CreateCounter request
 pub const CREATE_COUNTER_REQUEST: u8 = 2;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
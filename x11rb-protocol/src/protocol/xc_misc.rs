@@ -380,3 +380,43 @@ impl GetXIDListReply {
     }
 }
 
+/// Borrowed version of [`GetXIDListReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetXIDListReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub ids: crate::x11_utils::ListRef<'a, u32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetXIDListReply {
+    type Ref = GetXIDListReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (ids_len, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (ids, remaining) = crate::x11_utils::ListRef::<u32>::try_parse(remaining, ids_len.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetXIDListReplyRef { sequence, length, ids };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetXIDListReplyRef<'_>> for GetXIDListReply {
+    fn from(reply: GetXIDListReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            ids: reply.ids.iter().collect(),
+        }
+    }
+}
+
@@ -425,6 +425,52 @@ impl OpenConnectionReply {
     }
 }
 
+/// Borrowed version of [`OpenConnectionReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct OpenConnectionReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub sarea_handle_low: u32,
+    pub sarea_handle_high: u32,
+    pub bus_id: &'a [u8],
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for OpenConnectionReply {
+    type Ref = OpenConnectionReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (sarea_handle_low, remaining) = u32::try_parse(remaining)?;
+        let (sarea_handle_high, remaining) = u32::try_parse(remaining)?;
+        let (bus_id_len, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (bus_id, remaining) = crate::x11_utils::parse_u8_list(remaining, bus_id_len.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = OpenConnectionReplyRef { sequence, length, sarea_handle_low, sarea_handle_high, bus_id };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<OpenConnectionReplyRef<'_>> for OpenConnectionReply {
+    fn from(reply: OpenConnectionReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            sarea_handle_low: reply.sarea_handle_low,
+            sarea_handle_high: reply.sarea_handle_high,
+            bus_id: reply.bus_id.to_vec(),
+        }
+    }
+}
+
 /// Opcode for the CloseConnection request
 pub const CLOSE_CONNECTION_REQUEST: u8 = 3;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -605,6 +651,55 @@ impl GetClientDriverNameReply {
     }
 }
 
+/// Borrowed version of [`GetClientDriverNameReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetClientDriverNameReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub client_driver_major_version: u32,
+    pub client_driver_minor_version: u32,
+    pub client_driver_patch_version: u32,
+    pub client_driver_name: &'a [u8],
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetClientDriverNameReply {
+    type Ref = GetClientDriverNameReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (client_driver_major_version, remaining) = u32::try_parse(remaining)?;
+        let (client_driver_minor_version, remaining) = u32::try_parse(remaining)?;
+        let (client_driver_patch_version, remaining) = u32::try_parse(remaining)?;
+        let (client_driver_name_len, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
+        let (client_driver_name, remaining) = crate::x11_utils::parse_u8_list(remaining, client_driver_name_len.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetClientDriverNameReplyRef { sequence, length, client_driver_major_version, client_driver_minor_version, client_driver_patch_version, client_driver_name };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetClientDriverNameReplyRef<'_>> for GetClientDriverNameReply {
+    fn from(reply: GetClientDriverNameReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            client_driver_major_version: reply.client_driver_major_version,
+            client_driver_minor_version: reply.client_driver_minor_version,
+            client_driver_patch_version: reply.client_driver_patch_version,
+            client_driver_name: reply.client_driver_name.to_vec(),
+        }
+    }
+}
+
 /// Opcode for the CreateContext request
 pub const CREATE_CONTEXT_REQUEST: u8 = 5;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -1141,6 +1236,73 @@ impl GetDrawableInfoReply {
     }
 }
 
+/// Borrowed version of [`GetDrawableInfoReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetDrawableInfoReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub drawable_table_index: u32,
+    pub drawable_table_stamp: u32,
+    pub drawable_origin_x: i16,
+    pub drawable_origin_y: i16,
+    pub drawable_size_w: i16,
+    pub drawable_size_h: i16,
+    pub back_x: i16,
+    pub back_y: i16,
+    pub clip_rects: crate::x11_utils::ListRef<'a, DrmClipRect>,
+    pub back_clip_rects: crate::x11_utils::ListRef<'a, DrmClipRect>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetDrawableInfoReply {
+    type Ref = GetDrawableInfoReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (drawable_table_index, remaining) = u32::try_parse(remaining)?;
+        let (drawable_table_stamp, remaining) = u32::try_parse(remaining)?;
+        let (drawable_origin_x, remaining) = i16::try_parse(remaining)?;
+        let (drawable_origin_y, remaining) = i16::try_parse(remaining)?;
+        let (drawable_size_w, remaining) = i16::try_parse(remaining)?;
+        let (drawable_size_h, remaining) = i16::try_parse(remaining)?;
+        let (num_clip_rects, remaining) = u32::try_parse(remaining)?;
+        let (back_x, remaining) = i16::try_parse(remaining)?;
+        let (back_y, remaining) = i16::try_parse(remaining)?;
+        let (num_back_clip_rects, remaining) = u32::try_parse(remaining)?;
+        let (clip_rects, remaining) = crate::x11_utils::ListRef::<DrmClipRect>::try_parse(remaining, num_clip_rects.try_to_usize()?)?;
+        let (back_clip_rects, remaining) = crate::x11_utils::ListRef::<DrmClipRect>::try_parse(remaining, num_back_clip_rects.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetDrawableInfoReplyRef { sequence, length, drawable_table_index, drawable_table_stamp, drawable_origin_x, drawable_origin_y, drawable_size_w, drawable_size_h, back_x, back_y, clip_rects, back_clip_rects };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetDrawableInfoReplyRef<'_>> for GetDrawableInfoReply {
+    fn from(reply: GetDrawableInfoReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            drawable_table_index: reply.drawable_table_index,
+            drawable_table_stamp: reply.drawable_table_stamp,
+            drawable_origin_x: reply.drawable_origin_x,
+            drawable_origin_y: reply.drawable_origin_y,
+            drawable_size_w: reply.drawable_size_w,
+            drawable_size_h: reply.drawable_size_h,
+            back_x: reply.back_x,
+            back_y: reply.back_y,
+            clip_rects: reply.clip_rects.iter().collect(),
+            back_clip_rects: reply.back_clip_rects.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the GetDeviceInfo request
 pub const GET_DEVICE_INFO_REQUEST: u8 = 10;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -1271,6 +1433,60 @@ impl GetDeviceInfoReply {
     }
 }
 
+/// Borrowed version of [`GetDeviceInfoReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetDeviceInfoReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub framebuffer_handle_low: u32,
+    pub framebuffer_handle_high: u32,
+    pub framebuffer_origin_offset: u32,
+    pub framebuffer_size: u32,
+    pub framebuffer_stride: u32,
+    pub device_private: crate::x11_utils::ListRef<'a, u32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetDeviceInfoReply {
+    type Ref = GetDeviceInfoReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (framebuffer_handle_low, remaining) = u32::try_parse(remaining)?;
+        let (framebuffer_handle_high, remaining) = u32::try_parse(remaining)?;
+        let (framebuffer_origin_offset, remaining) = u32::try_parse(remaining)?;
+        let (framebuffer_size, remaining) = u32::try_parse(remaining)?;
+        let (framebuffer_stride, remaining) = u32::try_parse(remaining)?;
+        let (device_private_size, remaining) = u32::try_parse(remaining)?;
+        let (device_private, remaining) = crate::x11_utils::ListRef::<u32>::try_parse(remaining, device_private_size.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetDeviceInfoReplyRef { sequence, length, framebuffer_handle_low, framebuffer_handle_high, framebuffer_origin_offset, framebuffer_size, framebuffer_stride, device_private };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetDeviceInfoReplyRef<'_>> for GetDeviceInfoReply {
+    fn from(reply: GetDeviceInfoReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            framebuffer_handle_low: reply.framebuffer_handle_low,
+            framebuffer_handle_high: reply.framebuffer_handle_high,
+            framebuffer_origin_offset: reply.framebuffer_origin_offset,
+            framebuffer_size: reply.framebuffer_size,
+            framebuffer_stride: reply.framebuffer_stride,
+            device_private: reply.device_private.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the AuthConnection request
 pub const AUTH_CONNECTION_REQUEST: u8 = 11;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
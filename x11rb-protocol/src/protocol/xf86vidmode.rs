@@ -612,6 +612,80 @@ impl GetModeLineReply {
     }
 }
 
+/// Borrowed version of [`GetModeLineReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetModeLineReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub dotclock: Dotclock,
+    pub hdisplay: u16,
+    pub hsyncstart: u16,
+    pub hsyncend: u16,
+    pub htotal: u16,
+    pub hskew: u16,
+    pub vdisplay: u16,
+    pub vsyncstart: u16,
+    pub vsyncend: u16,
+    pub vtotal: u16,
+    pub flags: u32,
+    pub private: &'a [u8],
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetModeLineReply {
+    type Ref = GetModeLineReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (dotclock, remaining) = Dotclock::try_parse(remaining)?;
+        let (hdisplay, remaining) = u16::try_parse(remaining)?;
+        let (hsyncstart, remaining) = u16::try_parse(remaining)?;
+        let (hsyncend, remaining) = u16::try_parse(remaining)?;
+        let (htotal, remaining) = u16::try_parse(remaining)?;
+        let (hskew, remaining) = u16::try_parse(remaining)?;
+        let (vdisplay, remaining) = u16::try_parse(remaining)?;
+        let (vsyncstart, remaining) = u16::try_parse(remaining)?;
+        let (vsyncend, remaining) = u16::try_parse(remaining)?;
+        let (vtotal, remaining) = u16::try_parse(remaining)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (flags, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (privsize, remaining) = u32::try_parse(remaining)?;
+        let (private, remaining) = crate::x11_utils::parse_u8_list(remaining, privsize.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetModeLineReplyRef { sequence, length, dotclock, hdisplay, hsyncstart, hsyncend, htotal, hskew, vdisplay, vsyncstart, vsyncend, vtotal, flags, private };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetModeLineReplyRef<'_>> for GetModeLineReply {
+    fn from(reply: GetModeLineReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            dotclock: reply.dotclock,
+            hdisplay: reply.hdisplay,
+            hsyncstart: reply.hsyncstart,
+            hsyncend: reply.hsyncend,
+            htotal: reply.htotal,
+            hskew: reply.hskew,
+            vdisplay: reply.vdisplay,
+            vsyncstart: reply.vsyncstart,
+            vsyncend: reply.vsyncend,
+            vtotal: reply.vtotal,
+            flags: reply.flags,
+            private: reply.private.to_vec(),
+        }
+    }
+}
+
 /// Opcode for the ModModeLine request
 pub const MOD_MODE_LINE_REQUEST: u8 = 2;
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -1012,6 +1086,61 @@ impl GetMonitorReply {
     }
 }
 
+/// Borrowed version of [`GetMonitorReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetMonitorReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub hsync: crate::x11_utils::ListRef<'a, Syncrange>,
+    pub vsync: crate::x11_utils::ListRef<'a, Syncrange>,
+    pub vendor: &'a [u8],
+    pub alignment_pad: &'a [u8],
+    pub model: &'a [u8],
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetMonitorReply {
+    type Ref = GetMonitorReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (vendor_length, remaining) = u8::try_parse(remaining)?;
+        let (model_length, remaining) = u8::try_parse(remaining)?;
+        let (num_hsync, remaining) = u8::try_parse(remaining)?;
+        let (num_vsync, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (hsync, remaining) = crate::x11_utils::ListRef::<Syncrange>::try_parse(remaining, num_hsync.try_to_usize()?)?;
+        let (vsync, remaining) = crate::x11_utils::ListRef::<Syncrange>::try_parse(remaining, num_vsync.try_to_usize()?)?;
+        let (vendor, remaining) = crate::x11_utils::parse_u8_list(remaining, vendor_length.try_to_usize()?)?;
+        let (alignment_pad, remaining) = crate::x11_utils::parse_u8_list(remaining, (u32::from(vendor_length).checked_add(3u32).ok_or(ParseError::InvalidExpression)? & (!3u32)).checked_sub(u32::from(vendor_length)).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (model, remaining) = crate::x11_utils::parse_u8_list(remaining, model_length.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetMonitorReplyRef { sequence, length, hsync, vsync, vendor, alignment_pad, model };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetMonitorReplyRef<'_>> for GetMonitorReply {
+    fn from(reply: GetMonitorReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            hsync: reply.hsync.iter().collect(),
+            vsync: reply.vsync.iter().collect(),
+            vendor: reply.vendor.to_vec(),
+            alignment_pad: reply.alignment_pad.to_vec(),
+            model: reply.model.to_vec(),
+        }
+    }
+}
+
 /// Opcode for the LockModeSwitch request
 pub const LOCK_MODE_SWITCH_REQUEST: u8 = 5;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -1187,6 +1316,46 @@ impl GetAllModeLinesReply {
     }
 }
 
+/// Borrowed version of [`GetAllModeLinesReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetAllModeLinesReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub modeinfo: crate::x11_utils::ListRef<'a, ModeInfo>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetAllModeLinesReply {
+    type Ref = GetAllModeLinesReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (modecount, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (modeinfo, remaining) = crate::x11_utils::ListRef::<ModeInfo>::try_parse(remaining, modecount.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetAllModeLinesReplyRef { sequence, length, modeinfo };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetAllModeLinesReplyRef<'_>> for GetAllModeLinesReply {
+    fn from(reply: GetAllModeLinesReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            modeinfo: reply.modeinfo.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the AddModeLine request
 pub const ADD_MODE_LINE_REQUEST: u8 = 7;
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -2364,6 +2533,54 @@ impl Serialize for GetDotClocksReply {
     }
 }
 
+/// Borrowed version of [`GetDotClocksReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetDotClocksReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub flags: u32,
+    pub clocks: u32,
+    pub maxclocks: u32,
+    pub clock: crate::x11_utils::ListRef<'a, u32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetDotClocksReply {
+    type Ref = GetDotClocksReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (flags, remaining) = u32::try_parse(remaining)?;
+        let (clocks, remaining) = u32::try_parse(remaining)?;
+        let (maxclocks, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (clock, remaining) = crate::x11_utils::ListRef::<u32>::try_parse(remaining, 1u32.checked_sub(flags & 1u32).ok_or(ParseError::InvalidExpression)?.checked_mul(clocks).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetDotClocksReplyRef { sequence, length, flags, clocks, maxclocks, clock };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetDotClocksReplyRef<'_>> for GetDotClocksReply {
+    fn from(reply: GetDotClocksReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            flags: reply.flags,
+            clocks: reply.clocks,
+            maxclocks: reply.maxclocks,
+            clock: reply.clock.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the SetClientVersion request
 pub const SET_CLIENT_VERSION_REQUEST: u8 = 14;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -2794,6 +3011,54 @@ impl Serialize for GetGammaRampReply {
     }
 }
 
+/// Borrowed version of [`GetGammaRampReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetGammaRampReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub size: u16,
+    pub red: crate::x11_utils::ListRef<'a, u16>,
+    pub green: crate::x11_utils::ListRef<'a, u16>,
+    pub blue: crate::x11_utils::ListRef<'a, u16>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetGammaRampReply {
+    type Ref = GetGammaRampReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (size, remaining) = u16::try_parse(remaining)?;
+        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
+        let (red, remaining) = crate::x11_utils::ListRef::<u16>::try_parse(remaining, (u32::from(size).checked_add(1u32).ok_or(ParseError::InvalidExpression)? & (!1u32)).try_to_usize()?)?;
+        let (green, remaining) = crate::x11_utils::ListRef::<u16>::try_parse(remaining, (u32::from(size).checked_add(1u32).ok_or(ParseError::InvalidExpression)? & (!1u32)).try_to_usize()?)?;
+        let (blue, remaining) = crate::x11_utils::ListRef::<u16>::try_parse(remaining, (u32::from(size).checked_add(1u32).ok_or(ParseError::InvalidExpression)? & (!1u32)).try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetGammaRampReplyRef { sequence, length, size, red, green, blue };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetGammaRampReplyRef<'_>> for GetGammaRampReply {
+    fn from(reply: GetGammaRampReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            size: reply.size,
+            red: reply.red.iter().collect(),
+            green: reply.green.iter().collect(),
+            blue: reply.blue.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the SetGammaRamp request
 pub const SET_GAMMA_RAMP_REQUEST: u8 = 18;
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
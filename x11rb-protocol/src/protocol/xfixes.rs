@@ -1199,6 +1199,66 @@ impl Serialize for GetCursorImageReply {
     }
 }
 
+/// Borrowed version of [`GetCursorImageReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetCursorImageReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub x: i16,
+    pub y: i16,
+    pub width: u16,
+    pub height: u16,
+    pub xhot: u16,
+    pub yhot: u16,
+    pub cursor_serial: u32,
+    pub cursor_image: crate::x11_utils::ListRef<'a, u32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetCursorImageReply {
+    type Ref = GetCursorImageReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (x, remaining) = i16::try_parse(remaining)?;
+        let (y, remaining) = i16::try_parse(remaining)?;
+        let (width, remaining) = u16::try_parse(remaining)?;
+        let (height, remaining) = u16::try_parse(remaining)?;
+        let (xhot, remaining) = u16::try_parse(remaining)?;
+        let (yhot, remaining) = u16::try_parse(remaining)?;
+        let (cursor_serial, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
+        let (cursor_image, remaining) = crate::x11_utils::ListRef::<u32>::try_parse(remaining, u32::from(width).checked_mul(u32::from(height)).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetCursorImageReplyRef { sequence, length, x, y, width, height, xhot, yhot, cursor_serial, cursor_image };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetCursorImageReplyRef<'_>> for GetCursorImageReply {
+    fn from(reply: GetCursorImageReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            x: reply.x,
+            y: reply.y,
+            width: reply.width,
+            height: reply.height,
+            xhot: reply.xhot,
+            yhot: reply.yhot,
+            cursor_serial: reply.cursor_serial,
+            cursor_image: reply.cursor_image.iter().collect(),
+        }
+    }
+}
+
 pub type Region = u32;
 
 /// Opcode for the BadRegion error
@@ -2301,6 +2361,46 @@ impl FetchRegionReply {
     }
 }
 
+/// Borrowed version of [`FetchRegionReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct FetchRegionReplyRef<'a> {
+    pub sequence: u16,
+    pub extents: xproto::Rectangle,
+    pub rectangles: crate::x11_utils::ListRef<'a, xproto::Rectangle>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for FetchRegionReply {
+    type Ref = FetchRegionReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (extents, remaining) = xproto::Rectangle::try_parse(remaining)?;
+        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        let (rectangles, remaining) = crate::x11_utils::ListRef::<xproto::Rectangle>::try_parse(remaining, length.checked_div(2u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = FetchRegionReplyRef { sequence, extents, rectangles };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<FetchRegionReplyRef<'_>> for FetchRegionReply {
+    fn from(reply: FetchRegionReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            extents: reply.extents,
+            rectangles: reply.rectangles.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the SetGCClipRegion request
 pub const SET_GC_CLIP_REGION_REQUEST: u8 = 20;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -2725,6 +2825,49 @@ impl GetCursorNameReply {
     }
 }
 
+/// Borrowed version of [`GetCursorNameReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetCursorNameReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub atom: xproto::Atom,
+    pub name: &'a [u8],
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetCursorNameReply {
+    type Ref = GetCursorNameReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (atom, remaining) = xproto::Atom::try_parse(remaining)?;
+        let (nbytes, remaining) = u16::try_parse(remaining)?;
+        let remaining = remaining.get(18..).ok_or(ParseError::InsufficientData)?;
+        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, nbytes.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetCursorNameReplyRef { sequence, length, atom, name };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetCursorNameReplyRef<'_>> for GetCursorNameReply {
+    fn from(reply: GetCursorNameReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            atom: reply.atom,
+            name: reply.name.to_vec(),
+        }
+    }
+}
+
 /// Opcode for the GetCursorImageAndName request
 pub const GET_CURSOR_IMAGE_AND_NAME_REQUEST: u8 = 25;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -2862,6 +3005,73 @@ impl GetCursorImageAndNameReply {
     }
 }
 
+/// Borrowed version of [`GetCursorImageAndNameReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetCursorImageAndNameReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub x: i16,
+    pub y: i16,
+    pub width: u16,
+    pub height: u16,
+    pub xhot: u16,
+    pub yhot: u16,
+    pub cursor_serial: u32,
+    pub cursor_atom: xproto::Atom,
+    pub cursor_image: crate::x11_utils::ListRef<'a, u32>,
+    pub name: &'a [u8],
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetCursorImageAndNameReply {
+    type Ref = GetCursorImageAndNameReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (x, remaining) = i16::try_parse(remaining)?;
+        let (y, remaining) = i16::try_parse(remaining)?;
+        let (width, remaining) = u16::try_parse(remaining)?;
+        let (height, remaining) = u16::try_parse(remaining)?;
+        let (xhot, remaining) = u16::try_parse(remaining)?;
+        let (yhot, remaining) = u16::try_parse(remaining)?;
+        let (cursor_serial, remaining) = u32::try_parse(remaining)?;
+        let (cursor_atom, remaining) = xproto::Atom::try_parse(remaining)?;
+        let (nbytes, remaining) = u16::try_parse(remaining)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (cursor_image, remaining) = crate::x11_utils::ListRef::<u32>::try_parse(remaining, u32::from(width).checked_mul(u32::from(height)).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, nbytes.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetCursorImageAndNameReplyRef { sequence, length, x, y, width, height, xhot, yhot, cursor_serial, cursor_atom, cursor_image, name };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetCursorImageAndNameReplyRef<'_>> for GetCursorImageAndNameReply {
+    fn from(reply: GetCursorImageAndNameReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            x: reply.x,
+            y: reply.y,
+            width: reply.width,
+            height: reply.height,
+            xhot: reply.xhot,
+            yhot: reply.yhot,
+            cursor_serial: reply.cursor_serial,
+            cursor_atom: reply.cursor_atom,
+            cursor_image: reply.cursor_image.iter().collect(),
+            name: reply.name.to_vec(),
+        }
+    }
+}
+
 /// Opcode for the ChangeCursor request
 pub const CHANGE_CURSOR_REQUEST: u8 = 26;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
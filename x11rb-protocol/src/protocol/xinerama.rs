@@ -782,3 +782,43 @@ impl QueryScreensReply {
     }
 }
 
+/// Borrowed version of [`QueryScreensReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct QueryScreensReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub screen_info: crate::x11_utils::ListRef<'a, ScreenInfo>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for QueryScreensReply {
+    type Ref = QueryScreensReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (number, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (screen_info, remaining) = crate::x11_utils::ListRef::<ScreenInfo>::try_parse(remaining, number.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryScreensReplyRef { sequence, length, screen_info };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<QueryScreensReplyRef<'_>> for QueryScreensReply {
+    fn from(reply: QueryScreensReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            screen_info: reply.screen_info.iter().collect(),
+        }
+    }
+}
+
@@ -1076,6 +1076,59 @@ impl ListInputDevicesReply {
     }
 }
 
+/// Borrowed version of [`ListInputDevicesReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct ListInputDevicesReplyRef<'a> {
+    pub xi_reply_type: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub devices: crate::x11_utils::ListRef<'a, DeviceInfo>,
+    pub infos: crate::x11_utils::ListRef<'a, InputInfo>,
+    pub names: crate::x11_utils::ListRef<'a, xproto::Str>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for ListInputDevicesReply {
+    type Ref = ListInputDevicesReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let value = remaining;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (devices_len, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(23..).ok_or(ParseError::InsufficientData)?;
+        let (devices, remaining) = crate::x11_utils::ListRef::<DeviceInfo>::try_parse(remaining, devices_len.try_to_usize()?)?;
+        let (infos, remaining) = crate::x11_utils::ListRef::<InputInfo>::try_parse(remaining, devices.iter().try_fold(0u32, |acc, x| acc.checked_add(u32::from(x.num_class_info)).ok_or(ParseError::InvalidExpression))?.try_to_usize()?)?;
+        let (names, remaining) = crate::x11_utils::ListRef::<xproto::Str>::try_parse(remaining, devices_len.try_to_usize()?)?;
+        // Align offset to multiple of 4
+        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
+        let misalignment = (4 - (offset % 4)) % 4;
+        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = ListInputDevicesReplyRef { xi_reply_type, sequence, length, devices, infos, names };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<ListInputDevicesReplyRef<'_>> for ListInputDevicesReply {
+    fn from(reply: ListInputDevicesReplyRef<'_>) -> Self {
+        Self {
+            xi_reply_type: reply.xi_reply_type,
+            sequence: reply.sequence,
+            length: reply.length,
+            devices: reply.devices.iter().collect(),
+            infos: reply.infos.iter().collect(),
+            names: reply.names.iter().collect(),
+        }
+    }
+}
+
 pub type EventTypeBase = u8;
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -1235,6 +1288,53 @@ impl OpenDeviceReply {
     }
 }
 
+/// Borrowed version of [`OpenDeviceReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct OpenDeviceReplyRef<'a> {
+    pub xi_reply_type: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub class_info: crate::x11_utils::ListRef<'a, InputClassInfo>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for OpenDeviceReply {
+    type Ref = OpenDeviceReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let value = remaining;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (num_classes, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(23..).ok_or(ParseError::InsufficientData)?;
+        let (class_info, remaining) = crate::x11_utils::ListRef::<InputClassInfo>::try_parse(remaining, num_classes.try_to_usize()?)?;
+        // Align offset to multiple of 4
+        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
+        let misalignment = (4 - (offset % 4)) % 4;
+        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = OpenDeviceReplyRef { xi_reply_type, sequence, length, class_info };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<OpenDeviceReplyRef<'_>> for OpenDeviceReply {
+    fn from(reply: OpenDeviceReplyRef<'_>) -> Self {
+        Self {
+            xi_reply_type: reply.xi_reply_type,
+            sequence: reply.sequence,
+            length: reply.length,
+            class_info: reply.class_info.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the CloseDevice request
 pub const CLOSE_DEVICE_REQUEST: u8 = 4;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -1644,6 +1744,52 @@ impl GetSelectedExtensionEventsReply {
     }
 }
 
+/// Borrowed version of [`GetSelectedExtensionEventsReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetSelectedExtensionEventsReplyRef<'a> {
+    pub xi_reply_type: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub this_classes: crate::x11_utils::ListRef<'a, EventClass>,
+    pub all_classes: crate::x11_utils::ListRef<'a, EventClass>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetSelectedExtensionEventsReply {
+    type Ref = GetSelectedExtensionEventsReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (num_this_classes, remaining) = u16::try_parse(remaining)?;
+        let (num_all_classes, remaining) = u16::try_parse(remaining)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (this_classes, remaining) = crate::x11_utils::ListRef::<EventClass>::try_parse(remaining, num_this_classes.try_to_usize()?)?;
+        let (all_classes, remaining) = crate::x11_utils::ListRef::<EventClass>::try_parse(remaining, num_all_classes.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetSelectedExtensionEventsReplyRef { xi_reply_type, sequence, length, this_classes, all_classes };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetSelectedExtensionEventsReplyRef<'_>> for GetSelectedExtensionEventsReply {
+    fn from(reply: GetSelectedExtensionEventsReplyRef<'_>) -> Self {
+        Self {
+            xi_reply_type: reply.xi_reply_type,
+            sequence: reply.sequence,
+            length: reply.length,
+            this_classes: reply.this_classes.iter().collect(),
+            all_classes: reply.all_classes.iter().collect(),
+        }
+    }
+}
+
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PropagateMode(u8);
@@ -1902,6 +2048,48 @@ impl GetDeviceDontPropagateListReply {
     }
 }
 
+/// Borrowed version of [`GetDeviceDontPropagateListReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetDeviceDontPropagateListReplyRef<'a> {
+    pub xi_reply_type: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub classes: crate::x11_utils::ListRef<'a, EventClass>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetDeviceDontPropagateListReply {
+    type Ref = GetDeviceDontPropagateListReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (num_classes, remaining) = u16::try_parse(remaining)?;
+        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
+        let (classes, remaining) = crate::x11_utils::ListRef::<EventClass>::try_parse(remaining, num_classes.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetDeviceDontPropagateListReplyRef { xi_reply_type, sequence, length, classes };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetDeviceDontPropagateListReplyRef<'_>> for GetDeviceDontPropagateListReply {
+    fn from(reply: GetDeviceDontPropagateListReplyRef<'_>) -> Self {
+        Self {
+            xi_reply_type: reply.xi_reply_type,
+            sequence: reply.sequence,
+            length: reply.length,
+            classes: reply.classes.iter().collect(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DeviceTimeCoord {
@@ -4509,6 +4697,48 @@ impl GetFeedbackControlReply {
     }
 }
 
+/// Borrowed version of [`GetFeedbackControlReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetFeedbackControlReplyRef<'a> {
+    pub xi_reply_type: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub feedbacks: crate::x11_utils::ListRef<'a, FeedbackState>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetFeedbackControlReply {
+    type Ref = GetFeedbackControlReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (num_feedbacks, remaining) = u16::try_parse(remaining)?;
+        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
+        let (feedbacks, remaining) = crate::x11_utils::ListRef::<FeedbackState>::try_parse(remaining, num_feedbacks.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetFeedbackControlReplyRef { xi_reply_type, sequence, length, feedbacks };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetFeedbackControlReplyRef<'_>> for GetFeedbackControlReply {
+    fn from(reply: GetFeedbackControlReplyRef<'_>) -> Self {
+        Self {
+            xi_reply_type: reply.xi_reply_type,
+            sequence: reply.sequence,
+            length: reply.length,
+            feedbacks: reply.feedbacks.iter().collect(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct KbdFeedbackCtl {
@@ -5584,6 +5814,48 @@ impl GetDeviceKeyMappingReply {
     }
 }
 
+/// Borrowed version of [`GetDeviceKeyMappingReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetDeviceKeyMappingReplyRef<'a> {
+    pub xi_reply_type: u8,
+    pub sequence: u16,
+    pub keysyms_per_keycode: u8,
+    pub keysyms: crate::x11_utils::ListRef<'a, xproto::Keysym>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetDeviceKeyMappingReply {
+    type Ref = GetDeviceKeyMappingReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (keysyms_per_keycode, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(23..).ok_or(ParseError::InsufficientData)?;
+        let (keysyms, remaining) = crate::x11_utils::ListRef::<xproto::Keysym>::try_parse(remaining, length.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetDeviceKeyMappingReplyRef { xi_reply_type, sequence, keysyms_per_keycode, keysyms };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetDeviceKeyMappingReplyRef<'_>> for GetDeviceKeyMappingReply {
+    fn from(reply: GetDeviceKeyMappingReplyRef<'_>) -> Self {
+        Self {
+            xi_reply_type: reply.xi_reply_type,
+            sequence: reply.sequence,
+            keysyms_per_keycode: reply.keysyms_per_keycode,
+            keysyms: reply.keysyms.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the ChangeDeviceKeyMapping request
 pub const CHANGE_DEVICE_KEY_MAPPING_REQUEST: u8 = 25;
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -5789,6 +6061,48 @@ impl GetDeviceModifierMappingReply {
     }
 }
 
+/// Borrowed version of [`GetDeviceModifierMappingReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetDeviceModifierMappingReplyRef<'a> {
+    pub xi_reply_type: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub keymaps: &'a [u8],
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetDeviceModifierMappingReply {
+    type Ref = GetDeviceModifierMappingReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (keycodes_per_modifier, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(23..).ok_or(ParseError::InsufficientData)?;
+        let (keymaps, remaining) = crate::x11_utils::parse_u8_list(remaining, u32::from(keycodes_per_modifier).checked_mul(8u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetDeviceModifierMappingReplyRef { xi_reply_type, sequence, length, keymaps };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetDeviceModifierMappingReplyRef<'_>> for GetDeviceModifierMappingReply {
+    fn from(reply: GetDeviceModifierMappingReplyRef<'_>) -> Self {
+        Self {
+            xi_reply_type: reply.xi_reply_type,
+            sequence: reply.sequence,
+            length: reply.length,
+            keymaps: reply.keymaps.to_vec(),
+        }
+    }
+}
+
 /// Opcode for the SetDeviceModifierMapping request
 pub const SET_DEVICE_MODIFIER_MAPPING_REQUEST: u8 = 27;
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -6070,6 +6384,53 @@ impl GetDeviceButtonMappingReply {
     }
 }
 
+/// Borrowed version of [`GetDeviceButtonMappingReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetDeviceButtonMappingReplyRef<'a> {
+    pub xi_reply_type: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub map: &'a [u8],
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetDeviceButtonMappingReply {
+    type Ref = GetDeviceButtonMappingReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let value = remaining;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (map_size, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(23..).ok_or(ParseError::InsufficientData)?;
+        let (map, remaining) = crate::x11_utils::parse_u8_list(remaining, map_size.try_to_usize()?)?;
+        // Align offset to multiple of 4
+        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
+        let misalignment = (4 - (offset % 4)) % 4;
+        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetDeviceButtonMappingReplyRef { xi_reply_type, sequence, length, map };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetDeviceButtonMappingReplyRef<'_>> for GetDeviceButtonMappingReply {
+    fn from(reply: GetDeviceButtonMappingReplyRef<'_>) -> Self {
+        Self {
+            xi_reply_type: reply.xi_reply_type,
+            sequence: reply.sequence,
+            length: reply.length,
+            map: reply.map.to_vec(),
+        }
+    }
+}
+
 /// Opcode for the SetDeviceButtonMapping request
 pub const SET_DEVICE_BUTTON_MAPPING_REQUEST: u8 = 29;
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -6905,6 +7266,48 @@ impl QueryDeviceStateReply {
     }
 }
 
+/// Borrowed version of [`QueryDeviceStateReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct QueryDeviceStateReplyRef<'a> {
+    pub xi_reply_type: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub classes: crate::x11_utils::ListRef<'a, InputState>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for QueryDeviceStateReply {
+    type Ref = QueryDeviceStateReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (num_classes, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(23..).ok_or(ParseError::InsufficientData)?;
+        let (classes, remaining) = crate::x11_utils::ListRef::<InputState>::try_parse(remaining, num_classes.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryDeviceStateReplyRef { xi_reply_type, sequence, length, classes };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<QueryDeviceStateReplyRef<'_>> for QueryDeviceStateReply {
+    fn from(reply: QueryDeviceStateReplyRef<'_>) -> Self {
+        Self {
+            xi_reply_type: reply.xi_reply_type,
+            sequence: reply.sequence,
+            length: reply.length,
+            classes: reply.classes.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the DeviceBell request
 pub const DEVICE_BELL_REQUEST: u8 = 32;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -9003,6 +9406,48 @@ impl ListDevicePropertiesReply {
     }
 }
 
+/// Borrowed version of [`ListDevicePropertiesReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct ListDevicePropertiesReplyRef<'a> {
+    pub xi_reply_type: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub atoms: crate::x11_utils::ListRef<'a, xproto::Atom>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for ListDevicePropertiesReply {
+    type Ref = ListDevicePropertiesReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (num_atoms, remaining) = u16::try_parse(remaining)?;
+        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
+        let (atoms, remaining) = crate::x11_utils::ListRef::<xproto::Atom>::try_parse(remaining, num_atoms.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = ListDevicePropertiesReplyRef { xi_reply_type, sequence, length, atoms };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<ListDevicePropertiesReplyRef<'_>> for ListDevicePropertiesReply {
+    fn from(reply: ListDevicePropertiesReplyRef<'_>) -> Self {
+        Self {
+            xi_reply_type: reply.xi_reply_type,
+            sequence: reply.sequence,
+            length: reply.length,
+            atoms: reply.atoms.iter().collect(),
+        }
+    }
+}
+
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PropertyFormat(u8);
@@ -9938,6 +10383,73 @@ impl XIQueryPointerReply {
     }
 }
 
+/// Borrowed version of [`XIQueryPointerReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct XIQueryPointerReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub root: xproto::Window,
+    pub child: xproto::Window,
+    pub root_x: Fp1616,
+    pub root_y: Fp1616,
+    pub win_x: Fp1616,
+    pub win_y: Fp1616,
+    pub same_screen: bool,
+    pub mods: ModifierInfo,
+    pub group: GroupInfo,
+    pub buttons: crate::x11_utils::ListRef<'a, u32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for XIQueryPointerReply {
+    type Ref = XIQueryPointerReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (root, remaining) = xproto::Window::try_parse(remaining)?;
+        let (child, remaining) = xproto::Window::try_parse(remaining)?;
+        let (root_x, remaining) = Fp1616::try_parse(remaining)?;
+        let (root_y, remaining) = Fp1616::try_parse(remaining)?;
+        let (win_x, remaining) = Fp1616::try_parse(remaining)?;
+        let (win_y, remaining) = Fp1616::try_parse(remaining)?;
+        let (same_screen, remaining) = bool::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (buttons_len, remaining) = u16::try_parse(remaining)?;
+        let (mods, remaining) = ModifierInfo::try_parse(remaining)?;
+        let (group, remaining) = GroupInfo::try_parse(remaining)?;
+        let (buttons, remaining) = crate::x11_utils::ListRef::<u32>::try_parse(remaining, buttons_len.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = XIQueryPointerReplyRef { sequence, length, root, child, root_x, root_y, win_x, win_y, same_screen, mods, group, buttons };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<XIQueryPointerReplyRef<'_>> for XIQueryPointerReply {
+    fn from(reply: XIQueryPointerReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            root: reply.root,
+            child: reply.child,
+            root_x: reply.root_x,
+            root_y: reply.root_y,
+            win_x: reply.win_x,
+            win_y: reply.win_y,
+            same_screen: reply.same_screen,
+            mods: reply.mods,
+            group: reply.group,
+            buttons: reply.buttons.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the XIWarpPointer request
 pub const XI_WARP_POINTER_REQUEST: u8 = 41;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -12641,6 +13153,46 @@ impl XIQueryDeviceReply {
     }
 }
 
+/// Borrowed version of [`XIQueryDeviceReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct XIQueryDeviceReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub infos: crate::x11_utils::ListRef<'a, XIDeviceInfo>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for XIQueryDeviceReply {
+    type Ref = XIQueryDeviceReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (num_infos, remaining) = u16::try_parse(remaining)?;
+        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
+        let (infos, remaining) = crate::x11_utils::ListRef::<XIDeviceInfo>::try_parse(remaining, num_infos.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = XIQueryDeviceReplyRef { sequence, length, infos };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<XIQueryDeviceReplyRef<'_>> for XIQueryDeviceReply {
+    fn from(reply: XIQueryDeviceReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            infos: reply.infos.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the XISetFocus request
 pub const XI_SET_FOCUS_REQUEST: u8 = 49;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -13759,6 +14311,46 @@ impl XIPassiveGrabDeviceReply {
     }
 }
 
+/// Borrowed version of [`XIPassiveGrabDeviceReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct XIPassiveGrabDeviceReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub modifiers: crate::x11_utils::ListRef<'a, GrabModifierInfo>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for XIPassiveGrabDeviceReply {
+    type Ref = XIPassiveGrabDeviceReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (num_modifiers, remaining) = u16::try_parse(remaining)?;
+        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
+        let (modifiers, remaining) = crate::x11_utils::ListRef::<GrabModifierInfo>::try_parse(remaining, num_modifiers.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = XIPassiveGrabDeviceReplyRef { sequence, length, modifiers };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<XIPassiveGrabDeviceReplyRef<'_>> for XIPassiveGrabDeviceReply {
+    fn from(reply: XIPassiveGrabDeviceReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            modifiers: reply.modifiers.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the XIPassiveUngrabDevice request
 pub const XI_PASSIVE_UNGRAB_DEVICE_REQUEST: u8 = 55;
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -13976,6 +14568,46 @@ impl XIListPropertiesReply {
     }
 }
 
+/// Borrowed version of [`XIListPropertiesReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct XIListPropertiesReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub properties: crate::x11_utils::ListRef<'a, xproto::Atom>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for XIListPropertiesReply {
+    type Ref = XIListPropertiesReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (num_properties, remaining) = u16::try_parse(remaining)?;
+        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
+        let (properties, remaining) = crate::x11_utils::ListRef::<xproto::Atom>::try_parse(remaining, num_properties.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = XIListPropertiesReplyRef { sequence, length, properties };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<XIListPropertiesReplyRef<'_>> for XIListPropertiesReply {
+    fn from(reply: XIListPropertiesReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            properties: reply.properties.iter().collect(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum XIChangePropertyAux {
@@ -14644,6 +15276,46 @@ impl XIGetSelectedEventsReply {
     }
 }
 
+/// Borrowed version of [`XIGetSelectedEventsReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct XIGetSelectedEventsReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub masks: crate::x11_utils::ListRef<'a, EventMask>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for XIGetSelectedEventsReply {
+    type Ref = XIGetSelectedEventsReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (num_masks, remaining) = u16::try_parse(remaining)?;
+        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
+        let (masks, remaining) = crate::x11_utils::ListRef::<EventMask>::try_parse(remaining, num_masks.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = XIGetSelectedEventsReplyRef { sequence, length, masks };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<XIGetSelectedEventsReplyRef<'_>> for XIGetSelectedEventsReply {
+    fn from(reply: XIGetSelectedEventsReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            masks: reply.masks.iter().collect(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BarrierReleasePointerInfo {
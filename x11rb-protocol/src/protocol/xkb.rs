@@ -8765,6 +8765,61 @@ impl GetCompatMapReply {
     }
 }
 
+/// Borrowed version of [`GetCompatMapReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetCompatMapReplyRef<'a> {
+    pub device_id: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub groups_rtrn: u8,
+    pub first_si_rtrn: u16,
+    pub n_total_si: u16,
+    pub si_rtrn: crate::x11_utils::ListRef<'a, SymInterpret>,
+    pub group_rtrn: crate::x11_utils::ListRef<'a, ModDef>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetCompatMapReply {
+    type Ref = GetCompatMapReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let (device_id, remaining) = u8::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (groups_rtrn, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (first_si_rtrn, remaining) = u16::try_parse(remaining)?;
+        let (n_si_rtrn, remaining) = u16::try_parse(remaining)?;
+        let (n_total_si, remaining) = u16::try_parse(remaining)?;
+        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        let (si_rtrn, remaining) = crate::x11_utils::ListRef::<SymInterpret>::try_parse(remaining, n_si_rtrn.try_to_usize()?)?;
+        let (group_rtrn, remaining) = crate::x11_utils::ListRef::<ModDef>::try_parse(remaining, groups_rtrn.count_ones().try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetCompatMapReplyRef { device_id, sequence, length, groups_rtrn, first_si_rtrn, n_total_si, si_rtrn, group_rtrn };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetCompatMapReplyRef<'_>> for GetCompatMapReply {
+    fn from(reply: GetCompatMapReplyRef<'_>) -> Self {
+        Self {
+            device_id: reply.device_id,
+            sequence: reply.sequence,
+            length: reply.length,
+            groups_rtrn: reply.groups_rtrn,
+            first_si_rtrn: reply.first_si_rtrn,
+            n_total_si: reply.n_total_si,
+            si_rtrn: reply.si_rtrn.iter().collect(),
+            group_rtrn: reply.group_rtrn.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the SetCompatMap request
 pub const SET_COMPAT_MAP_REQUEST: u8 = 11;
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -9128,6 +9183,56 @@ impl Serialize for GetIndicatorMapReply {
     }
 }
 
+/// Borrowed version of [`GetIndicatorMapReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetIndicatorMapReplyRef<'a> {
+    pub device_id: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub which: u32,
+    pub real_indicators: u32,
+    pub n_indicators: u8,
+    pub maps: crate::x11_utils::ListRef<'a, IndicatorMap>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetIndicatorMapReply {
+    type Ref = GetIndicatorMapReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let (device_id, remaining) = u8::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (which, remaining) = u32::try_parse(remaining)?;
+        let (real_indicators, remaining) = u32::try_parse(remaining)?;
+        let (n_indicators, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(15..).ok_or(ParseError::InsufficientData)?;
+        let (maps, remaining) = crate::x11_utils::ListRef::<IndicatorMap>::try_parse(remaining, which.count_ones().try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetIndicatorMapReplyRef { device_id, sequence, length, which, real_indicators, n_indicators, maps };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetIndicatorMapReplyRef<'_>> for GetIndicatorMapReply {
+    fn from(reply: GetIndicatorMapReplyRef<'_>) -> Self {
+        Self {
+            device_id: reply.device_id,
+            sequence: reply.sequence,
+            length: reply.length,
+            which: reply.which,
+            real_indicators: reply.real_indicators,
+            n_indicators: reply.n_indicators,
+            maps: reply.maps.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the SetIndicatorMap request
 pub const SET_INDICATOR_MAP_REQUEST: u8 = 14;
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -10924,6 +11029,71 @@ impl ListComponentsReply {
     }
 }
 
+/// Borrowed version of [`ListComponentsReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct ListComponentsReplyRef<'a> {
+    pub device_id: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub extra: u16,
+    pub keymaps: crate::x11_utils::ListRef<'a, Listing>,
+    pub keycodes: crate::x11_utils::ListRef<'a, Listing>,
+    pub types: crate::x11_utils::ListRef<'a, Listing>,
+    pub compat_maps: crate::x11_utils::ListRef<'a, Listing>,
+    pub symbols: crate::x11_utils::ListRef<'a, Listing>,
+    pub geometries: crate::x11_utils::ListRef<'a, Listing>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for ListComponentsReply {
+    type Ref = ListComponentsReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let (device_id, remaining) = u8::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (n_keymaps, remaining) = u16::try_parse(remaining)?;
+        let (n_keycodes, remaining) = u16::try_parse(remaining)?;
+        let (n_types, remaining) = u16::try_parse(remaining)?;
+        let (n_compat_maps, remaining) = u16::try_parse(remaining)?;
+        let (n_symbols, remaining) = u16::try_parse(remaining)?;
+        let (n_geometries, remaining) = u16::try_parse(remaining)?;
+        let (extra, remaining) = u16::try_parse(remaining)?;
+        let remaining = remaining.get(10..).ok_or(ParseError::InsufficientData)?;
+        let (keymaps, remaining) = crate::x11_utils::ListRef::<Listing>::try_parse(remaining, n_keymaps.try_to_usize()?)?;
+        let (keycodes, remaining) = crate::x11_utils::ListRef::<Listing>::try_parse(remaining, n_keycodes.try_to_usize()?)?;
+        let (types, remaining) = crate::x11_utils::ListRef::<Listing>::try_parse(remaining, n_types.try_to_usize()?)?;
+        let (compat_maps, remaining) = crate::x11_utils::ListRef::<Listing>::try_parse(remaining, n_compat_maps.try_to_usize()?)?;
+        let (symbols, remaining) = crate::x11_utils::ListRef::<Listing>::try_parse(remaining, n_symbols.try_to_usize()?)?;
+        let (geometries, remaining) = crate::x11_utils::ListRef::<Listing>::try_parse(remaining, n_geometries.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = ListComponentsReplyRef { device_id, sequence, length, extra, keymaps, keycodes, types, compat_maps, symbols, geometries };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<ListComponentsReplyRef<'_>> for ListComponentsReply {
+    fn from(reply: ListComponentsReplyRef<'_>) -> Self {
+        Self {
+            device_id: reply.device_id,
+            sequence: reply.sequence,
+            length: reply.length,
+            extra: reply.extra,
+            keymaps: reply.keymaps.iter().collect(),
+            keycodes: reply.keycodes.iter().collect(),
+            types: reply.types.iter().collect(),
+            compat_maps: reply.compat_maps.iter().collect(),
+            symbols: reply.symbols.iter().collect(),
+            geometries: reply.geometries.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the GetKbdByName request
 pub const GET_KBD_BY_NAME_REQUEST: u8 = 23;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -12271,6 +12441,94 @@ impl GetDeviceInfoReply {
     }
 }
 
+/// Borrowed version of [`GetDeviceInfoReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetDeviceInfoReplyRef<'a> {
+    pub device_id: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub present: u16,
+    pub supported: u16,
+    pub unsupported: u16,
+    pub first_btn_wanted: u8,
+    pub n_btns_wanted: u8,
+    pub first_btn_rtrn: u8,
+    pub total_btns: u8,
+    pub has_own_state: bool,
+    pub dflt_kbd_fb: u16,
+    pub dflt_led_fb: u16,
+    pub dev_type: xproto::Atom,
+    pub name: &'a [u8],
+    pub btn_actions: crate::x11_utils::ListRef<'a, Action>,
+    pub leds: crate::x11_utils::ListRef<'a, DeviceLedInfo>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetDeviceInfoReply {
+    type Ref = GetDeviceInfoReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let value = remaining;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let (device_id, remaining) = u8::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (present, remaining) = u16::try_parse(remaining)?;
+        let (supported, remaining) = u16::try_parse(remaining)?;
+        let (unsupported, remaining) = u16::try_parse(remaining)?;
+        let (n_device_led_f_bs, remaining) = u16::try_parse(remaining)?;
+        let (first_btn_wanted, remaining) = u8::try_parse(remaining)?;
+        let (n_btns_wanted, remaining) = u8::try_parse(remaining)?;
+        let (first_btn_rtrn, remaining) = u8::try_parse(remaining)?;
+        let (n_btns_rtrn, remaining) = u8::try_parse(remaining)?;
+        let (total_btns, remaining) = u8::try_parse(remaining)?;
+        let (has_own_state, remaining) = bool::try_parse(remaining)?;
+        let (dflt_kbd_fb, remaining) = u16::try_parse(remaining)?;
+        let (dflt_led_fb, remaining) = u16::try_parse(remaining)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (dev_type, remaining) = xproto::Atom::try_parse(remaining)?;
+        let (name_len, remaining) = u16::try_parse(remaining)?;
+        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?)?;
+        // Align offset to multiple of 4
+        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
+        let misalignment = (4 - (offset % 4)) % 4;
+        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let (btn_actions, remaining) = crate::x11_utils::ListRef::<Action>::try_parse(remaining, n_btns_rtrn.try_to_usize()?)?;
+        let (leds, remaining) = crate::x11_utils::ListRef::<DeviceLedInfo>::try_parse(remaining, n_device_led_f_bs.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetDeviceInfoReplyRef { device_id, sequence, length, present, supported, unsupported, first_btn_wanted, n_btns_wanted, first_btn_rtrn, total_btns, has_own_state, dflt_kbd_fb, dflt_led_fb, dev_type, name, btn_actions, leds };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetDeviceInfoReplyRef<'_>> for GetDeviceInfoReply {
+    fn from(reply: GetDeviceInfoReplyRef<'_>) -> Self {
+        Self {
+            device_id: reply.device_id,
+            sequence: reply.sequence,
+            length: reply.length,
+            present: reply.present,
+            supported: reply.supported,
+            unsupported: reply.unsupported,
+            first_btn_wanted: reply.first_btn_wanted,
+            n_btns_wanted: reply.n_btns_wanted,
+            first_btn_rtrn: reply.first_btn_rtrn,
+            total_btns: reply.total_btns,
+            has_own_state: reply.has_own_state,
+            dflt_kbd_fb: reply.dflt_kbd_fb,
+            dflt_led_fb: reply.dflt_led_fb,
+            dev_type: reply.dev_type,
+            name: reply.name.to_vec(),
+            btn_actions: reply.btn_actions.iter().collect(),
+            leds: reply.leds.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the SetDeviceInfo request
 pub const SET_DEVICE_INFO_REQUEST: u8 = 25;
 #[derive(Debug, Clone)]
@@ -628,6 +628,46 @@ impl PrintGetPrinterListReply {
     }
 }
 
+/// Borrowed version of [`PrintGetPrinterListReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct PrintGetPrinterListReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub printers: crate::x11_utils::ListRef<'a, Printer>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for PrintGetPrinterListReply {
+    type Ref = PrintGetPrinterListReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (list_count, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (printers, remaining) = crate::x11_utils::ListRef::<Printer>::try_parse(remaining, list_count.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = PrintGetPrinterListReplyRef { sequence, length, printers };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<PrintGetPrinterListReplyRef<'_>> for PrintGetPrinterListReply {
+    fn from(reply: PrintGetPrinterListReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            printers: reply.printers.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the PrintRehashPrinterList request
 pub const PRINT_REHASH_PRINTER_LIST_REQUEST: u8 = 20;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -1506,6 +1546,52 @@ impl PrintGetDocumentDataReply {
     }
 }
 
+/// Borrowed version of [`PrintGetDocumentDataReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct PrintGetDocumentDataReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub status_code: u32,
+    pub finished_flag: u32,
+    pub data: &'a [u8],
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for PrintGetDocumentDataReply {
+    type Ref = PrintGetDocumentDataReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (status_code, remaining) = u32::try_parse(remaining)?;
+        let (finished_flag, remaining) = u32::try_parse(remaining)?;
+        let (data_len, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, data_len.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = PrintGetDocumentDataReplyRef { sequence, length, status_code, finished_flag, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<PrintGetDocumentDataReplyRef<'_>> for PrintGetDocumentDataReply {
+    fn from(reply: PrintGetDocumentDataReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            status_code: reply.status_code,
+            finished_flag: reply.finished_flag,
+            data: reply.data.to_vec(),
+        }
+    }
+}
+
 /// Opcode for the PrintStartPage request
 pub const PRINT_START_PAGE_REQUEST: u8 = 13;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -1921,6 +2007,46 @@ impl PrintGetAttributesReply {
     }
 }
 
+/// Borrowed version of [`PrintGetAttributesReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct PrintGetAttributesReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub attributes: &'a [u8],
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for PrintGetAttributesReply {
+    type Ref = PrintGetAttributesReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (string_len, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (attributes, remaining) = crate::x11_utils::parse_u8_list(remaining, string_len.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = PrintGetAttributesReplyRef { sequence, length, attributes };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<PrintGetAttributesReplyRef<'_>> for PrintGetAttributesReply {
+    fn from(reply: PrintGetAttributesReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            attributes: reply.attributes.to_vec(),
+        }
+    }
+}
+
 /// Opcode for the PrintGetOneAttributes request
 pub const PRINT_GET_ONE_ATTRIBUTES_REQUEST: u8 = 19;
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -2069,6 +2195,46 @@ impl PrintGetOneAttributesReply {
     }
 }
 
+/// Borrowed version of [`PrintGetOneAttributesReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct PrintGetOneAttributesReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub value: &'a [u8],
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for PrintGetOneAttributesReply {
+    type Ref = PrintGetOneAttributesReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (value_len, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (value, remaining) = crate::x11_utils::parse_u8_list(remaining, value_len.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = PrintGetOneAttributesReplyRef { sequence, length, value };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<PrintGetOneAttributesReplyRef<'_>> for PrintGetOneAttributesReply {
+    fn from(reply: PrintGetOneAttributesReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            value: reply.value.to_vec(),
+        }
+    }
+}
+
 /// Opcode for the PrintSetAttributes request
 pub const PRINT_SET_ATTRIBUTES_REQUEST: u8 = 18;
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -2407,6 +2573,46 @@ impl PrintQueryScreensReply {
     }
 }
 
+/// Borrowed version of [`PrintQueryScreensReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct PrintQueryScreensReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub roots: crate::x11_utils::ListRef<'a, xproto::Window>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for PrintQueryScreensReply {
+    type Ref = PrintQueryScreensReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (list_count, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (roots, remaining) = crate::x11_utils::ListRef::<xproto::Window>::try_parse(remaining, list_count.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = PrintQueryScreensReplyRef { sequence, length, roots };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<PrintQueryScreensReplyRef<'_>> for PrintQueryScreensReply {
+    fn from(reply: PrintQueryScreensReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            roots: reply.roots.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the PrintSetImageResolution request
 pub const PRINT_SET_IMAGE_RESOLUTION_REQUEST: u8 = 23;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
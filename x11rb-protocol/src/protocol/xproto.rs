@@ -9694,6 +9694,52 @@ impl QueryTreeReply {
     }
 }
 
+/// Borrowed version of [`QueryTreeReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct QueryTreeReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub root: Window,
+    pub parent: Window,
+    pub children: crate::x11_utils::ListRef<'a, Window>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for QueryTreeReply {
+    type Ref = QueryTreeReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (root, remaining) = Window::try_parse(remaining)?;
+        let (parent, remaining) = Window::try_parse(remaining)?;
+        let (children_len, remaining) = u16::try_parse(remaining)?;
+        let remaining = remaining.get(14..).ok_or(ParseError::InsufficientData)?;
+        let (children, remaining) = crate::x11_utils::ListRef::<Window>::try_parse(remaining, children_len.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryTreeReplyRef { sequence, length, root, parent, children };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<QueryTreeReplyRef<'_>> for QueryTreeReply {
+    fn from(reply: QueryTreeReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            root: reply.root,
+            parent: reply.parent,
+            children: reply.children.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the InternAtom request
 pub const INTERN_ATOM_REQUEST: u8 = 16;
 /// Get atom identifier by name.
@@ -9990,6 +10036,46 @@ impl GetAtomNameReply {
     }
 }
 
+/// Borrowed version of [`GetAtomNameReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetAtomNameReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub name: &'a [u8],
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetAtomNameReply {
+    type Ref = GetAtomNameReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (name_len, remaining) = u16::try_parse(remaining)?;
+        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
+        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetAtomNameReplyRef { sequence, length, name };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetAtomNameReplyRef<'_>> for GetAtomNameReply {
+    fn from(reply: GetAtomNameReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            name: reply.name.to_vec(),
+        }
+    }
+}
+
 /// # Fields
 ///
 /// * `Replace` - Discard the previous property value and store the new data.
@@ -10728,6 +10814,56 @@ impl Serialize for GetPropertyReply {
     }
 }
 
+/// Borrowed version of [`GetPropertyReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetPropertyReplyRef<'a> {
+    pub format: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub type_: Atom,
+    pub bytes_after: u32,
+    pub value_len: u32,
+    pub value: &'a [u8],
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetPropertyReply {
+    type Ref = GetPropertyReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let (format, remaining) = u8::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (type_, remaining) = Atom::try_parse(remaining)?;
+        let (bytes_after, remaining) = u32::try_parse(remaining)?;
+        let (value_len, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (value, remaining) = crate::x11_utils::parse_u8_list(remaining, value_len.checked_mul(u32::from(format).checked_div(8u32).ok_or(ParseError::InvalidExpression)?).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetPropertyReplyRef { format, sequence, length, type_, bytes_after, value_len, value };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetPropertyReplyRef<'_>> for GetPropertyReply {
+    fn from(reply: GetPropertyReplyRef<'_>) -> Self {
+        Self {
+            format: reply.format,
+            sequence: reply.sequence,
+            length: reply.length,
+            type_: reply.type_,
+            bytes_after: reply.bytes_after,
+            value_len: reply.value_len,
+            value: reply.value.to_vec(),
+        }
+    }
+}
+
 /// Opcode for the ListProperties request
 pub const LIST_PROPERTIES_REQUEST: u8 = 21;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -10848,6 +10984,46 @@ impl ListPropertiesReply {
     }
 }
 
+/// Borrowed version of [`ListPropertiesReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct ListPropertiesReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub atoms: crate::x11_utils::ListRef<'a, Atom>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for ListPropertiesReply {
+    type Ref = ListPropertiesReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (atoms_len, remaining) = u16::try_parse(remaining)?;
+        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
+        let (atoms, remaining) = crate::x11_utils::ListRef::<Atom>::try_parse(remaining, atoms_len.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = ListPropertiesReplyRef { sequence, length, atoms };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<ListPropertiesReplyRef<'_>> for ListPropertiesReply {
+    fn from(reply: ListPropertiesReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            atoms: reply.atoms.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the SetSelectionOwner request
 pub const SET_SELECTION_OWNER_REQUEST: u8 = 22;
 /// Sets the owner of a selection.
@@ -13500,6 +13676,46 @@ impl GetMotionEventsReply {
     }
 }
 
+/// Borrowed version of [`GetMotionEventsReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetMotionEventsReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub events: crate::x11_utils::ListRef<'a, Timecoord>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetMotionEventsReply {
+    type Ref = GetMotionEventsReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (events_len, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (events, remaining) = crate::x11_utils::ListRef::<Timecoord>::try_parse(remaining, events_len.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetMotionEventsReplyRef { sequence, length, events };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetMotionEventsReplyRef<'_>> for GetMotionEventsReply {
+    fn from(reply: GetMotionEventsReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            events: reply.events.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the TranslateCoordinates request
 pub const TRANSLATE_COORDINATES_REQUEST: u8 = 40;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -14697,6 +14913,85 @@ impl QueryFontReply {
     }
 }
 
+/// Borrowed version of [`QueryFontReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct QueryFontReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub min_bounds: Charinfo,
+    pub max_bounds: Charinfo,
+    pub min_char_or_byte2: u16,
+    pub max_char_or_byte2: u16,
+    pub default_char: u16,
+    pub draw_direction: FontDraw,
+    pub min_byte1: u8,
+    pub max_byte1: u8,
+    pub all_chars_exist: bool,
+    pub font_ascent: i16,
+    pub font_descent: i16,
+    pub properties: crate::x11_utils::ListRef<'a, Fontprop>,
+    pub char_infos: crate::x11_utils::ListRef<'a, Charinfo>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for QueryFontReply {
+    type Ref = QueryFontReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (min_bounds, remaining) = Charinfo::try_parse(remaining)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (max_bounds, remaining) = Charinfo::try_parse(remaining)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (min_char_or_byte2, remaining) = u16::try_parse(remaining)?;
+        let (max_char_or_byte2, remaining) = u16::try_parse(remaining)?;
+        let (default_char, remaining) = u16::try_parse(remaining)?;
+        let (properties_len, remaining) = u16::try_parse(remaining)?;
+        let (draw_direction, remaining) = u8::try_parse(remaining)?;
+        let (min_byte1, remaining) = u8::try_parse(remaining)?;
+        let (max_byte1, remaining) = u8::try_parse(remaining)?;
+        let (all_chars_exist, remaining) = bool::try_parse(remaining)?;
+        let (font_ascent, remaining) = i16::try_parse(remaining)?;
+        let (font_descent, remaining) = i16::try_parse(remaining)?;
+        let (char_infos_len, remaining) = u32::try_parse(remaining)?;
+        let (properties, remaining) = crate::x11_utils::ListRef::<Fontprop>::try_parse(remaining, properties_len.try_to_usize()?)?;
+        let (char_infos, remaining) = crate::x11_utils::ListRef::<Charinfo>::try_parse(remaining, char_infos_len.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let draw_direction = draw_direction.into();
+        let result = QueryFontReplyRef { sequence, length, min_bounds, max_bounds, min_char_or_byte2, max_char_or_byte2, default_char, draw_direction, min_byte1, max_byte1, all_chars_exist, font_ascent, font_descent, properties, char_infos };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<QueryFontReplyRef<'_>> for QueryFontReply {
+    fn from(reply: QueryFontReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            min_bounds: reply.min_bounds,
+            max_bounds: reply.max_bounds,
+            min_char_or_byte2: reply.min_char_or_byte2,
+            max_char_or_byte2: reply.max_char_or_byte2,
+            default_char: reply.default_char,
+            draw_direction: reply.draw_direction,
+            min_byte1: reply.min_byte1,
+            max_byte1: reply.max_byte1,
+            all_chars_exist: reply.all_chars_exist,
+            font_ascent: reply.font_ascent,
+            font_descent: reply.font_descent,
+            properties: reply.properties.iter().collect(),
+            char_infos: reply.char_infos.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the QueryTextExtents request
 pub const QUERY_TEXT_EXTENTS_REQUEST: u8 = 48;
 /// get text extents.
@@ -15112,6 +15407,46 @@ impl ListFontsReply {
     }
 }
 
+/// Borrowed version of [`ListFontsReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct ListFontsReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub names: crate::x11_utils::ListRef<'a, Str>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for ListFontsReply {
+    type Ref = ListFontsReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (names_len, remaining) = u16::try_parse(remaining)?;
+        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
+        let (names, remaining) = crate::x11_utils::ListRef::<Str>::try_parse(remaining, names_len.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = ListFontsReplyRef { sequence, length, names };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<ListFontsReplyRef<'_>> for ListFontsReply {
+    fn from(reply: ListFontsReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            names: reply.names.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the ListFontsWithInfo request
 pub const LIST_FONTS_WITH_INFO_REQUEST: u8 = 50;
 /// get matching font names and information.
@@ -15331,6 +15666,87 @@ impl ListFontsWithInfoReply {
     }
 }
 
+/// Borrowed version of [`ListFontsWithInfoReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct ListFontsWithInfoReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub min_bounds: Charinfo,
+    pub max_bounds: Charinfo,
+    pub min_char_or_byte2: u16,
+    pub max_char_or_byte2: u16,
+    pub default_char: u16,
+    pub draw_direction: FontDraw,
+    pub min_byte1: u8,
+    pub max_byte1: u8,
+    pub all_chars_exist: bool,
+    pub font_ascent: i16,
+    pub font_descent: i16,
+    pub replies_hint: u32,
+    pub properties: crate::x11_utils::ListRef<'a, Fontprop>,
+    pub name: &'a [u8],
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for ListFontsWithInfoReply {
+    type Ref = ListFontsWithInfoReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let (name_len, remaining) = u8::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (min_bounds, remaining) = Charinfo::try_parse(remaining)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (max_bounds, remaining) = Charinfo::try_parse(remaining)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (min_char_or_byte2, remaining) = u16::try_parse(remaining)?;
+        let (max_char_or_byte2, remaining) = u16::try_parse(remaining)?;
+        let (default_char, remaining) = u16::try_parse(remaining)?;
+        let (properties_len, remaining) = u16::try_parse(remaining)?;
+        let (draw_direction, remaining) = u8::try_parse(remaining)?;
+        let (min_byte1, remaining) = u8::try_parse(remaining)?;
+        let (max_byte1, remaining) = u8::try_parse(remaining)?;
+        let (all_chars_exist, remaining) = bool::try_parse(remaining)?;
+        let (font_ascent, remaining) = i16::try_parse(remaining)?;
+        let (font_descent, remaining) = i16::try_parse(remaining)?;
+        let (replies_hint, remaining) = u32::try_parse(remaining)?;
+        let (properties, remaining) = crate::x11_utils::ListRef::<Fontprop>::try_parse(remaining, properties_len.try_to_usize()?)?;
+        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let draw_direction = draw_direction.into();
+        let result = ListFontsWithInfoReplyRef { sequence, length, min_bounds, max_bounds, min_char_or_byte2, max_char_or_byte2, default_char, draw_direction, min_byte1, max_byte1, all_chars_exist, font_ascent, font_descent, replies_hint, properties, name };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<ListFontsWithInfoReplyRef<'_>> for ListFontsWithInfoReply {
+    fn from(reply: ListFontsWithInfoReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            min_bounds: reply.min_bounds,
+            max_bounds: reply.max_bounds,
+            min_char_or_byte2: reply.min_char_or_byte2,
+            max_char_or_byte2: reply.max_char_or_byte2,
+            default_char: reply.default_char,
+            draw_direction: reply.draw_direction,
+            min_byte1: reply.min_byte1,
+            max_byte1: reply.max_byte1,
+            all_chars_exist: reply.all_chars_exist,
+            font_ascent: reply.font_ascent,
+            font_descent: reply.font_descent,
+            replies_hint: reply.replies_hint,
+            properties: reply.properties.iter().collect(),
+            name: reply.name.to_vec(),
+        }
+    }
+}
+
 /// Opcode for the SetFontPath request
 pub const SET_FONT_PATH_REQUEST: u8 = 51;
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -15511,6 +15927,46 @@ impl GetFontPathReply {
     }
 }
 
+/// Borrowed version of [`GetFontPathReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetFontPathReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub path: crate::x11_utils::ListRef<'a, Str>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetFontPathReply {
+    type Ref = GetFontPathReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (path_len, remaining) = u16::try_parse(remaining)?;
+        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
+        let (path, remaining) = crate::x11_utils::ListRef::<Str>::try_parse(remaining, path_len.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetFontPathReplyRef { sequence, length, path };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetFontPathReplyRef<'_>> for GetFontPathReply {
+    fn from(reply: GetFontPathReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            path: reply.path.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the CreatePixmap request
 pub const CREATE_PIXMAP_REQUEST: u8 = 53;
 /// Creates a pixmap.
@@ -19603,6 +20059,48 @@ impl GetImageReply {
     }
 }
 
+/// Borrowed version of [`GetImageReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetImageReplyRef<'a> {
+    pub depth: u8,
+    pub sequence: u16,
+    pub visual: Visualid,
+    pub data: &'a [u8],
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetImageReply {
+    type Ref = GetImageReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let (depth, remaining) = u8::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (visual, remaining) = Visualid::try_parse(remaining)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, length.checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetImageReplyRef { depth, sequence, visual, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetImageReplyRef<'_>> for GetImageReply {
+    fn from(reply: GetImageReplyRef<'_>) -> Self {
+        Self {
+            depth: reply.depth,
+            sequence: reply.sequence,
+            visual: reply.visual,
+            data: reply.data.to_vec(),
+        }
+    }
+}
+
 /// Opcode for the PolyText8 request
 pub const POLY_TEXT8_REQUEST: u8 = 74;
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -20528,6 +21026,46 @@ impl ListInstalledColormapsReply {
     }
 }
 
+/// Borrowed version of [`ListInstalledColormapsReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct ListInstalledColormapsReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub cmaps: crate::x11_utils::ListRef<'a, Colormap>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for ListInstalledColormapsReply {
+    type Ref = ListInstalledColormapsReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (cmaps_len, remaining) = u16::try_parse(remaining)?;
+        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
+        let (cmaps, remaining) = crate::x11_utils::ListRef::<Colormap>::try_parse(remaining, cmaps_len.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = ListInstalledColormapsReplyRef { sequence, length, cmaps };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<ListInstalledColormapsReplyRef<'_>> for ListInstalledColormapsReply {
+    fn from(reply: ListInstalledColormapsReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            cmaps: reply.cmaps.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the AllocColor request
 pub const ALLOC_COLOR_REQUEST: u8 = 84;
 /// Allocate a color.
@@ -21030,6 +21568,50 @@ impl AllocColorCellsReply {
     }
 }
 
+/// Borrowed version of [`AllocColorCellsReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct AllocColorCellsReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub pixels: crate::x11_utils::ListRef<'a, u32>,
+    pub masks: crate::x11_utils::ListRef<'a, u32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for AllocColorCellsReply {
+    type Ref = AllocColorCellsReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (pixels_len, remaining) = u16::try_parse(remaining)?;
+        let (masks_len, remaining) = u16::try_parse(remaining)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (pixels, remaining) = crate::x11_utils::ListRef::<u32>::try_parse(remaining, pixels_len.try_to_usize()?)?;
+        let (masks, remaining) = crate::x11_utils::ListRef::<u32>::try_parse(remaining, masks_len.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = AllocColorCellsReplyRef { sequence, length, pixels, masks };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<AllocColorCellsReplyRef<'_>> for AllocColorCellsReply {
+    fn from(reply: AllocColorCellsReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            pixels: reply.pixels.iter().collect(),
+            masks: reply.masks.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the AllocColorPlanes request
 pub const ALLOC_COLOR_PLANES_REQUEST: u8 = 87;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -21188,6 +21770,56 @@ impl AllocColorPlanesReply {
     }
 }
 
+/// Borrowed version of [`AllocColorPlanesReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct AllocColorPlanesReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub red_mask: u32,
+    pub green_mask: u32,
+    pub blue_mask: u32,
+    pub pixels: crate::x11_utils::ListRef<'a, u32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for AllocColorPlanesReply {
+    type Ref = AllocColorPlanesReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (pixels_len, remaining) = u16::try_parse(remaining)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (red_mask, remaining) = u32::try_parse(remaining)?;
+        let (green_mask, remaining) = u32::try_parse(remaining)?;
+        let (blue_mask, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
+        let (pixels, remaining) = crate::x11_utils::ListRef::<u32>::try_parse(remaining, pixels_len.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = AllocColorPlanesReplyRef { sequence, length, red_mask, green_mask, blue_mask, pixels };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<AllocColorPlanesReplyRef<'_>> for AllocColorPlanesReply {
+    fn from(reply: AllocColorPlanesReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            red_mask: reply.red_mask,
+            green_mask: reply.green_mask,
+            blue_mask: reply.blue_mask,
+            pixels: reply.pixels.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the FreeColors request
 pub const FREE_COLORS_REQUEST: u8 = 88;
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -21742,6 +22374,46 @@ impl QueryColorsReply {
     }
 }
 
+/// Borrowed version of [`QueryColorsReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct QueryColorsReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub colors: crate::x11_utils::ListRef<'a, Rgb>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for QueryColorsReply {
+    type Ref = QueryColorsReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (colors_len, remaining) = u16::try_parse(remaining)?;
+        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
+        let (colors, remaining) = crate::x11_utils::ListRef::<Rgb>::try_parse(remaining, colors_len.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryColorsReplyRef { sequence, length, colors };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<QueryColorsReplyRef<'_>> for QueryColorsReply {
+    fn from(reply: QueryColorsReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            colors: reply.colors.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the LookupColor request
 pub const LOOKUP_COLOR_REQUEST: u8 = 92;
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -22924,6 +23596,45 @@ impl ListExtensionsReply {
     }
 }
 
+/// Borrowed version of [`ListExtensionsReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct ListExtensionsReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub names: crate::x11_utils::ListRef<'a, Str>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for ListExtensionsReply {
+    type Ref = ListExtensionsReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let (names_len, remaining) = u8::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(24..).ok_or(ParseError::InsufficientData)?;
+        let (names, remaining) = crate::x11_utils::ListRef::<Str>::try_parse(remaining, names_len.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = ListExtensionsReplyRef { sequence, length, names };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<ListExtensionsReplyRef<'_>> for ListExtensionsReply {
+    fn from(reply: ListExtensionsReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            names: reply.names.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the ChangeKeyboardMapping request
 pub const CHANGE_KEYBOARD_MAPPING_REQUEST: u8 = 100;
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -23127,6 +23838,45 @@ impl GetKeyboardMappingReply {
     }
 }
 
+/// Borrowed version of [`GetKeyboardMappingReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetKeyboardMappingReplyRef<'a> {
+    pub keysyms_per_keycode: u8,
+    pub sequence: u16,
+    pub keysyms: crate::x11_utils::ListRef<'a, Keysym>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetKeyboardMappingReply {
+    type Ref = GetKeyboardMappingReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let (keysyms_per_keycode, remaining) = u8::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(24..).ok_or(ParseError::InsufficientData)?;
+        let (keysyms, remaining) = crate::x11_utils::ListRef::<Keysym>::try_parse(remaining, length.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetKeyboardMappingReplyRef { keysyms_per_keycode, sequence, keysyms };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetKeyboardMappingReplyRef<'_>> for GetKeyboardMappingReply {
+    fn from(reply: GetKeyboardMappingReplyRef<'_>) -> Self {
+        Self {
+            keysyms_per_keycode: reply.keysyms_per_keycode,
+            sequence: reply.sequence,
+            keysyms: reply.keysyms.iter().collect(),
+        }
+    }
+}
+
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct KB(u8);
@@ -24714,6 +25464,49 @@ impl ListHostsReply {
     }
 }
 
+/// Borrowed version of [`ListHostsReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct ListHostsReplyRef<'a> {
+    pub mode: AccessControl,
+    pub sequence: u16,
+    pub length: u32,
+    pub hosts: crate::x11_utils::ListRef<'a, Host>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for ListHostsReply {
+    type Ref = ListHostsReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let (mode, remaining) = u8::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (hosts_len, remaining) = u16::try_parse(remaining)?;
+        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
+        let (hosts, remaining) = crate::x11_utils::ListRef::<Host>::try_parse(remaining, hosts_len.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let mode = mode.into();
+        let result = ListHostsReplyRef { mode, sequence, length, hosts };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<ListHostsReplyRef<'_>> for ListHostsReply {
+    fn from(reply: ListHostsReplyRef<'_>) -> Self {
+        Self {
+            mode: reply.mode,
+            sequence: reply.sequence,
+            length: reply.length,
+            hosts: reply.hosts.iter().collect(),
+        }
+    }
+}
+
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AccessControl(u8);
@@ -25550,6 +26343,45 @@ impl GetPointerMappingReply {
     }
 }
 
+/// Borrowed version of [`GetPointerMappingReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetPointerMappingReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub map: &'a [u8],
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetPointerMappingReply {
+    type Ref = GetPointerMappingReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let (map_len, remaining) = u8::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(24..).ok_or(ParseError::InsufficientData)?;
+        let (map, remaining) = crate::x11_utils::parse_u8_list(remaining, map_len.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetPointerMappingReplyRef { sequence, length, map };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetPointerMappingReplyRef<'_>> for GetPointerMappingReply {
+    fn from(reply: GetPointerMappingReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            map: reply.map.to_vec(),
+        }
+    }
+}
+
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MapIndex(u8);
@@ -25850,6 +26682,45 @@ impl GetModifierMappingReply {
     }
 }
 
+/// Borrowed version of [`GetModifierMappingReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct GetModifierMappingReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub keycodes: &'a [u8],
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for GetModifierMappingReply {
+    type Ref = GetModifierMappingReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let (keycodes_per_modifier, remaining) = u8::try_parse(remaining)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(24..).ok_or(ParseError::InsufficientData)?;
+        let (keycodes, remaining) = crate::x11_utils::parse_u8_list(remaining, u32::from(keycodes_per_modifier).checked_mul(8u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetModifierMappingReplyRef { sequence, length, keycodes };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<GetModifierMappingReplyRef<'_>> for GetModifierMappingReply {
+    fn from(reply: GetModifierMappingReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            keycodes: reply.keycodes.to_vec(),
+        }
+    }
+}
+
 /// Opcode for the NoOperation request
 pub const NO_OPERATION_REQUEST: u8 = 127;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -1555,6 +1555,46 @@ impl QueryAdaptorsReply {
     }
 }
 
+/// Borrowed version of [`QueryAdaptorsReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct QueryAdaptorsReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub info: crate::x11_utils::ListRef<'a, AdaptorInfo>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for QueryAdaptorsReply {
+    type Ref = QueryAdaptorsReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (num_adaptors, remaining) = u16::try_parse(remaining)?;
+        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
+        let (info, remaining) = crate::x11_utils::ListRef::<AdaptorInfo>::try_parse(remaining, num_adaptors.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryAdaptorsReplyRef { sequence, length, info };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<QueryAdaptorsReplyRef<'_>> for QueryAdaptorsReply {
+    fn from(reply: QueryAdaptorsReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            info: reply.info.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the QueryEncodings request
 pub const QUERY_ENCODINGS_REQUEST: u8 = 2;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -1672,6 +1712,46 @@ impl QueryEncodingsReply {
     }
 }
 
+/// Borrowed version of [`QueryEncodingsReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct QueryEncodingsReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub info: crate::x11_utils::ListRef<'a, EncodingInfo>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for QueryEncodingsReply {
+    type Ref = QueryEncodingsReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (num_encodings, remaining) = u16::try_parse(remaining)?;
+        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
+        let (info, remaining) = crate::x11_utils::ListRef::<EncodingInfo>::try_parse(remaining, num_encodings.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryEncodingsReplyRef { sequence, length, info };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<QueryEncodingsReplyRef<'_>> for QueryEncodingsReply {
+    fn from(reply: QueryEncodingsReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            info: reply.info.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the GrabPort request
 pub const GRAB_PORT_REQUEST: u8 = 3;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -2959,6 +3039,49 @@ impl QueryPortAttributesReply {
     }
 }
 
+/// Borrowed version of [`QueryPortAttributesReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct QueryPortAttributesReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub text_size: u32,
+    pub attributes: crate::x11_utils::ListRef<'a, AttributeInfo>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for QueryPortAttributesReply {
+    type Ref = QueryPortAttributesReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (num_attributes, remaining) = u32::try_parse(remaining)?;
+        let (text_size, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        let (attributes, remaining) = crate::x11_utils::ListRef::<AttributeInfo>::try_parse(remaining, num_attributes.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryPortAttributesReplyRef { sequence, length, text_size, attributes };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<QueryPortAttributesReplyRef<'_>> for QueryPortAttributesReply {
+    fn from(reply: QueryPortAttributesReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            text_size: reply.text_size,
+            attributes: reply.attributes.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the ListImageFormats request
 pub const LIST_IMAGE_FORMATS_REQUEST: u8 = 16;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -3076,6 +3199,46 @@ impl ListImageFormatsReply {
     }
 }
 
+/// Borrowed version of [`ListImageFormatsReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct ListImageFormatsReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub format: crate::x11_utils::ListRef<'a, ImageFormatInfo>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for ListImageFormatsReply {
+    type Ref = ListImageFormatsReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (num_formats, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (format, remaining) = crate::x11_utils::ListRef::<ImageFormatInfo>::try_parse(remaining, num_formats.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = ListImageFormatsReplyRef { sequence, length, format };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<ListImageFormatsReplyRef<'_>> for ListImageFormatsReply {
+    fn from(reply: ListImageFormatsReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            format: reply.format.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the QueryImageAttributes request
 pub const QUERY_IMAGE_ATTRIBUTES_REQUEST: u8 = 17;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -3226,6 +3389,58 @@ impl QueryImageAttributesReply {
     }
 }
 
+/// Borrowed version of [`QueryImageAttributesReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct QueryImageAttributesReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub data_size: u32,
+    pub width: u16,
+    pub height: u16,
+    pub pitches: crate::x11_utils::ListRef<'a, u32>,
+    pub offsets: crate::x11_utils::ListRef<'a, u32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for QueryImageAttributesReply {
+    type Ref = QueryImageAttributesReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (num_planes, remaining) = u32::try_parse(remaining)?;
+        let (data_size, remaining) = u32::try_parse(remaining)?;
+        let (width, remaining) = u16::try_parse(remaining)?;
+        let (height, remaining) = u16::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (pitches, remaining) = crate::x11_utils::ListRef::<u32>::try_parse(remaining, num_planes.try_to_usize()?)?;
+        let (offsets, remaining) = crate::x11_utils::ListRef::<u32>::try_parse(remaining, num_planes.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryImageAttributesReplyRef { sequence, length, data_size, width, height, pitches, offsets };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<QueryImageAttributesReplyRef<'_>> for QueryImageAttributesReply {
+    fn from(reply: QueryImageAttributesReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            data_size: reply.data_size,
+            width: reply.width,
+            height: reply.height,
+            pitches: reply.pitches.iter().collect(),
+            offsets: reply.offsets.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the PutImage request
 pub const PUT_IMAGE_REQUEST: u8 = 18;
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
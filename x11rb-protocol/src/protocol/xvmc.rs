@@ -349,6 +349,46 @@ impl ListSurfaceTypesReply {
     }
 }
 
+/// Borrowed version of [`ListSurfaceTypesReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct ListSurfaceTypesReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub surfaces: crate::x11_utils::ListRef<'a, SurfaceInfo>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for ListSurfaceTypesReply {
+    type Ref = ListSurfaceTypesReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (num, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (surfaces, remaining) = crate::x11_utils::ListRef::<SurfaceInfo>::try_parse(remaining, num.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = ListSurfaceTypesReplyRef { sequence, length, surfaces };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<ListSurfaceTypesReplyRef<'_>> for ListSurfaceTypesReply {
+    fn from(reply: ListSurfaceTypesReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            surfaces: reply.surfaces.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the CreateContext request
 pub const CREATE_CONTEXT_REQUEST: u8 = 2;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -508,6 +548,52 @@ impl CreateContextReply {
     }
 }
 
+/// Borrowed version of [`CreateContextReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct CreateContextReplyRef<'a> {
+    pub sequence: u16,
+    pub width_actual: u16,
+    pub height_actual: u16,
+    pub flags_return: u32,
+    pub priv_data: crate::x11_utils::ListRef<'a, u32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for CreateContextReply {
+    type Ref = CreateContextReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (width_actual, remaining) = u16::try_parse(remaining)?;
+        let (height_actual, remaining) = u16::try_parse(remaining)?;
+        let (flags_return, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (priv_data, remaining) = crate::x11_utils::ListRef::<u32>::try_parse(remaining, length.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = CreateContextReplyRef { sequence, width_actual, height_actual, flags_return, priv_data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<CreateContextReplyRef<'_>> for CreateContextReply {
+    fn from(reply: CreateContextReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            width_actual: reply.width_actual,
+            height_actual: reply.height_actual,
+            flags_return: reply.flags_return,
+            priv_data: reply.priv_data.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the DestroyContext request
 pub const DESTROY_CONTEXT_REQUEST: u8 = 3;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -683,6 +769,43 @@ impl CreateSurfaceReply {
     }
 }
 
+/// Borrowed version of [`CreateSurfaceReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct CreateSurfaceReplyRef<'a> {
+    pub sequence: u16,
+    pub priv_data: crate::x11_utils::ListRef<'a, u32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for CreateSurfaceReply {
+    type Ref = CreateSurfaceReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(24..).ok_or(ParseError::InsufficientData)?;
+        let (priv_data, remaining) = crate::x11_utils::ListRef::<u32>::try_parse(remaining, length.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = CreateSurfaceReplyRef { sequence, priv_data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<CreateSurfaceReplyRef<'_>> for CreateSurfaceReply {
+    fn from(reply: CreateSurfaceReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            priv_data: reply.priv_data.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the DestroySurface request
 pub const DESTROY_SURFACE_REQUEST: u8 = 5;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -894,6 +1017,59 @@ impl CreateSubpictureReply {
     }
 }
 
+/// Borrowed version of [`CreateSubpictureReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct CreateSubpictureReplyRef<'a> {
+    pub sequence: u16,
+    pub width_actual: u16,
+    pub height_actual: u16,
+    pub num_palette_entries: u16,
+    pub entry_bytes: u16,
+    pub component_order: [u8; 4],
+    pub priv_data: crate::x11_utils::ListRef<'a, u32>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for CreateSubpictureReply {
+    type Ref = CreateSubpictureReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (width_actual, remaining) = u16::try_parse(remaining)?;
+        let (height_actual, remaining) = u16::try_parse(remaining)?;
+        let (num_palette_entries, remaining) = u16::try_parse(remaining)?;
+        let (entry_bytes, remaining) = u16::try_parse(remaining)?;
+        let (component_order, remaining) = crate::x11_utils::parse_u8_list(remaining, 4)?;
+        let component_order = <[u8; 4]>::try_from(component_order).unwrap();
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (priv_data, remaining) = crate::x11_utils::ListRef::<u32>::try_parse(remaining, length.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = CreateSubpictureReplyRef { sequence, width_actual, height_actual, num_palette_entries, entry_bytes, component_order, priv_data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<CreateSubpictureReplyRef<'_>> for CreateSubpictureReply {
+    fn from(reply: CreateSubpictureReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            width_actual: reply.width_actual,
+            height_actual: reply.height_actual,
+            num_palette_entries: reply.num_palette_entries,
+            entry_bytes: reply.entry_bytes,
+            component_order: reply.component_order,
+            priv_data: reply.priv_data.iter().collect(),
+        }
+    }
+}
+
 /// Opcode for the DestroySubpicture request
 pub const DESTROY_SUBPICTURE_REQUEST: u8 = 7;
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -1072,3 +1248,43 @@ impl ListSubpictureTypesReply {
     }
 }
 
+/// Borrowed version of [`ListSubpictureTypesReply`] that does not copy its lists.
+///
+/// See [`BorrowedReply`](crate::x11_utils::BorrowedReply) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct ListSubpictureTypesReplyRef<'a> {
+    pub sequence: u16,
+    pub length: u32,
+    pub types: crate::x11_utils::ListRef<'a, xv::ImageFormatInfo>,
+}
+impl<'a> crate::x11_utils::BorrowedReply<'a> for ListSubpictureTypesReply {
+    type Ref = ListSubpictureTypesReplyRef<'a>;
+    fn try_parse_ref(initial_value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (length, remaining) = u32::try_parse(remaining)?;
+        let (num, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (types, remaining) = crate::x11_utils::ListRef::<xv::ImageFormatInfo>::try_parse(remaining, num.try_to_usize()?)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = ListSubpictureTypesReplyRef { sequence, length, types };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl From<ListSubpictureTypesReplyRef<'_>> for ListSubpictureTypesReply {
+    fn from(reply: ListSubpictureTypesReplyRef<'_>) -> Self {
+        Self {
+            sequence: reply.sequence,
+            length: reply.length,
+            types: reply.types.iter().collect(),
+        }
+    }
+}
+
@@ -0,0 +1,590 @@
+//! Helper types for implementing an X11 server.
+//!
+//! [`ServerConnection`] is the server-side counterpart to [`crate::connect::Connect`] and
+//! [`crate::connection::Connection`]. It keeps the protocol state of a single client connection,
+//! but does not do any I/O itself.
+
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+
+use crate::errors::{ParseError, SerializeError};
+use crate::protocol::xproto::{Setup, SetupAuthenticate, SetupFailed, SetupRequest};
+use crate::protocol::{Event, Reply, Request};
+use crate::utils::RawFdContainer;
+use crate::x11_utils::{
+    parse_request_header, BigRequests, ExtInfoProvider, RequestHeader, Serialize, TryParse,
+    X11Error,
+};
+use crate::{BufWithFds, SequenceNumber};
+
+// X11 interprets capital B as big endian, and lowercase l as little endian.
+#[cfg(target_endian = "little")]
+const BYTE_ORDER: u8 = b'l';
+#[cfg(not(target_endian = "little"))]
+const BYTE_ORDER: u8 = b'B';
+#[cfg(target_endian = "little")]
+const FOREIGN_BYTE_ORDER: u8 = b'B';
+#[cfg(not(target_endian = "little"))]
+const FOREIGN_BYTE_ORDER: u8 = b'l';
+
+// protocol version
+const PROTOCOL_MAJOR_VERSION: u16 = 11;
+const PROTOCOL_MINOR_VERSION: u16 = 0;
+
+/// The length of the fixed part of a `SetupRequest`.
+const SETUP_REQUEST_HEADER_LENGTH: usize = 12;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    /// Waiting for the client to send its `SetupRequest`.
+    AwaitingSetupRequest,
+    /// The `SetupRequest` was received, but not answered yet.
+    AwaitingSetupAnswer { byte_order: u8 },
+    /// The connection was set up and requests are exchanged.
+    Connected,
+    /// The setup was refused. Nothing more happens on this connection.
+    Closed,
+}
+
+/// A request that was received from a client.
+#[derive(Debug, Clone)]
+pub struct ReceivedRequest {
+    /// The sequence number that the server assigned to the request.
+    pub sequence: SequenceNumber,
+    /// The header of the request.
+    pub header: RequestHeader,
+    /// The remaining bytes of the request after its header.
+    pub body: Vec<u8>,
+}
+
+/// A pure-rust, sans-I/O implementation of the server side of the X11 protocol.
+///
+/// This object keeps the state of the connection to one client. Bytes and file descriptors that
+/// were received from the client are given to [`Self::enqueue_bytes`] and [`Self::enqueue_fds`].
+/// Everything that should be sent to the client is returned as bytes by the various methods.
+///
+/// The connection starts with the client's `SetupRequest`, which is returned by
+/// [`Self::poll_setup_request`]. It has to be answered with [`Self::accept`],
+/// [`Self::refuse`] or [`Self::request_authentication`]. Afterwards, [`Self::poll_request`]
+/// returns the client's requests together with their sequence numbers.
+///
+/// Only clients that use the native byte order can be served, since all of the protocol
+/// implementation uses the native byte order. Clients with another byte order can only be
+/// refused. [`Self::client_byte_order_is_native`] tells which case applies.
+///
+/// # Example
+///
+/// ```rust
+/// # use x11rb_protocol::server::ServerConnection;
+/// # fn main() -> Result<(), x11rb_protocol::errors::ParseError> {
+/// # fn get_setup() -> x11rb_protocol::protocol::xproto::Setup { Default::default() }
+/// # let received = x11rb_protocol::connect::Connect::with_authorization(Vec::new(), Vec::new()).1;
+/// let mut server = ServerConnection::new();
+/// server.enqueue_bytes(&received);
+/// if let Some(setup_request) = server.poll_setup_request()? {
+///     // This server does not care about authorization
+///     let _ = setup_request.authorization_protocol_name;
+///     let reply = if server.client_byte_order_is_native() {
+///         server.accept(&get_setup())
+///     } else {
+///         server.refuse(b"Unsupported byte order")
+///     };
+///     // send `reply` to the client
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ServerConnection {
+    state: State,
+    // Bytes that were received, but not yet processed
+    input: Vec<u8>,
+    // File descriptors that were received, but not yet used by a request
+    fds: Vec<RawFdContainer>,
+    big_requests: BigRequests,
+    // The sequence number of the last request that was received
+    last_sequence: SequenceNumber,
+}
+
+impl Default for ServerConnection {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ServerConnection {
+    /// Create a new `ServerConnection` for a client that just connected.
+    pub fn new() -> Self {
+        Self {
+            state: State::AwaitingSetupRequest,
+            input: Vec::new(),
+            fds: Vec::new(),
+            big_requests: BigRequests::NotEnabled,
+            last_sequence: 0,
+        }
+    }
+
+    /// Add bytes that were received from the client.
+    pub fn enqueue_bytes(&mut self, data: &[u8]) {
+        self.input.extend_from_slice(data);
+    }
+
+    /// Add file descriptors that were received from the client.
+    pub fn enqueue_fds(&mut self, fds: Vec<RawFdContainer>) {
+        self.fds.extend(fds);
+    }
+
+    /// Get the `SetupRequest` of the client, if it was received completely.
+    ///
+    /// The request is returned only once. Afterwards, it has to be answered with
+    /// [`Self::accept`], [`Self::refuse`] or [`Self::request_authentication`].
+    ///
+    /// If the client uses a foreign byte order, the numbers in the returned request are
+    /// converted to the native byte order.
+    pub fn poll_setup_request(&mut self) -> Result<Option<SetupRequest>, ParseError> {
+        if self.state != State::AwaitingSetupRequest
+            || self.input.len() < SETUP_REQUEST_HEADER_LENGTH
+        {
+            return Ok(None);
+        }
+        let byte_order = self.input[0];
+        let swap = match byte_order {
+            BYTE_ORDER => false,
+            FOREIGN_BYTE_ORDER => true,
+            _ => return Err(ParseError::InvalidValue),
+        };
+        let mut header = [0; SETUP_REQUEST_HEADER_LENGTH];
+        header.copy_from_slice(&self.input[..SETUP_REQUEST_HEADER_LENGTH]);
+        if swap {
+            // protocol versions and the lengths of the authorization name and data
+            for field in header[2..10].chunks_mut(2) {
+                field.swap(0, 1);
+            }
+        }
+        let name_len = usize::from(u16::from_ne_bytes([header[6], header[7]]));
+        let data_len = usize::from(u16::from_ne_bytes([header[8], header[9]]));
+        let length = SETUP_REQUEST_HEADER_LENGTH + pad(name_len) + pad(data_len);
+        if self.input.len() < length {
+            return Ok(None);
+        }
+        let mut request = self.input.drain(..length).collect::<Vec<_>>();
+        request[..SETUP_REQUEST_HEADER_LENGTH].copy_from_slice(&header);
+        let (request, _) = SetupRequest::try_parse(&request)?;
+        self.state = State::AwaitingSetupAnswer { byte_order };
+        Ok(Some(request))
+    }
+
+    /// Does the client use the same byte order as this program?
+    ///
+    /// Returns `false` if the `SetupRequest` was not received yet.
+    pub fn client_byte_order_is_native(&self) -> bool {
+        match self.state {
+            State::AwaitingSetupRequest => false,
+            State::AwaitingSetupAnswer { byte_order } => byte_order == BYTE_ORDER,
+            State::Connected => true,
+            State::Closed => false,
+        }
+    }
+
+    /// Accept the client's `SetupRequest` and produce the `Setup` that should be sent to it.
+    ///
+    /// The `status` and `length` fields of `setup` are ignored and computed instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no `SetupRequest` was received or if the client uses a foreign byte order.
+    pub fn accept(&mut self, setup: &Setup) -> Vec<u8> {
+        assert_eq!(
+            self.state,
+            State::AwaitingSetupAnswer {
+                byte_order: BYTE_ORDER
+            },
+            "accept() requires a SetupRequest in the native byte order",
+        );
+        self.state = State::Connected;
+        let mut setup = setup.clone();
+        setup.status = 1;
+        let mut result = setup.serialize();
+        set_setup_length(&mut result);
+        result
+    }
+
+    /// Refuse the client's `SetupRequest` with the given reason.
+    ///
+    /// The returned `SetupFailed` uses the byte order of the client. Nothing more can happen on
+    /// this connection afterwards.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no `SetupRequest` was received or if `reason` is longer than 255 bytes.
+    pub fn refuse(&mut self, reason: &[u8]) -> Vec<u8> {
+        let byte_order = self.take_setup_answer_state();
+        let failed = SetupFailed {
+            status: 0,
+            protocol_major_version: PROTOCOL_MAJOR_VERSION,
+            protocol_minor_version: PROTOCOL_MINOR_VERSION,
+            length: 0,
+            reason: reason.to_vec(),
+        };
+        let mut result = failed.serialize();
+        set_setup_length(&mut result);
+        if byte_order != BYTE_ORDER {
+            // protocol versions and the length
+            for field in result[2..8].chunks_mut(2) {
+                field.swap(0, 1);
+            }
+        }
+        result
+    }
+
+    /// Ask the client for further authentication.
+    ///
+    /// What happens afterwards depends on the authentication protocol and is not handled by this
+    /// type. This connection cannot be used anymore.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no `SetupRequest` was received or if the client uses a foreign byte order.
+    pub fn request_authentication(&mut self, reason: &[u8]) -> Vec<u8> {
+        let byte_order = self.take_setup_answer_state();
+        assert_eq!(
+            byte_order, BYTE_ORDER,
+            "request_authentication() requires a SetupRequest in the native byte order",
+        );
+        let mut reason = reason.to_vec();
+        reason.resize(pad(reason.len()), 0);
+        SetupAuthenticate { status: 2, reason }.serialize()
+    }
+
+    fn take_setup_answer_state(&mut self) -> u8 {
+        match self.state {
+            State::AwaitingSetupAnswer { byte_order } => {
+                self.state = State::Closed;
+                byte_order
+            }
+            state => panic!("Cannot answer a SetupRequest in state {:?}", state),
+        }
+    }
+
+    /// Accept requests in the format of the BIG-REQUESTS extension.
+    ///
+    /// This should be called when the client's `BigReqEnable` request is answered.
+    pub fn enable_big_requests(&mut self) {
+        self.big_requests = BigRequests::Enabled;
+    }
+
+    /// Get the next request of the client, if it was received completely.
+    ///
+    /// Each request gets the next sequence number.
+    pub fn poll_request(&mut self) -> Result<Option<ReceivedRequest>, ParseError> {
+        if self.state != State::Connected || self.input.len() < 4 {
+            return Ok(None);
+        }
+        let length = match u16::from_ne_bytes([self.input[2], self.input[3]]) {
+            0 if self.big_requests == BigRequests::Enabled => {
+                if self.input.len() < 8 {
+                    return Ok(None);
+                }
+                let length = [self.input[4], self.input[5], self.input[6], self.input[7]];
+                usize::try_from(u32::from_ne_bytes(length))
+                    .map_err(|_| ParseError::ConversionFailed)?
+            }
+            0 => return Err(ParseError::InvalidValue),
+            length => usize::from(length),
+        };
+        let length = length
+            .checked_mul(4)
+            .ok_or(ParseError::ConversionFailed)?
+            .max(4);
+        if self.input.len() < length {
+            return Ok(None);
+        }
+        let request = self.input.drain(..length).collect::<Vec<_>>();
+        let (header, body) = parse_request_header(&request, self.big_requests)?;
+        self.last_sequence += 1;
+        Ok(Some(ReceivedRequest {
+            sequence: self.last_sequence,
+            header,
+            body: body.to_vec(),
+        }))
+    }
+
+    /// Parse a received request into its concrete type.
+    ///
+    /// File descriptors that the request carries are taken from the ones given to
+    /// [`Self::enqueue_fds`].
+    pub fn parse_request<'r>(
+        &mut self,
+        request: &'r ReceivedRequest,
+        ext_info_provider: &dyn ExtInfoProvider,
+    ) -> Result<Request<'r>, ParseError> {
+        Request::parse(
+            request.header,
+            &request.body,
+            &mut self.fds,
+            ext_info_provider,
+        )
+    }
+
+    /// Get the sequence number of the last request that was received.
+    pub fn last_sequence_number(&self) -> SequenceNumber {
+        self.last_sequence
+    }
+
+    /// Encode the reply to the request with the given sequence number.
+    pub fn encode_reply(
+        &self,
+        reply: &Reply,
+        sequence: SequenceNumber,
+    ) -> Result<BufWithFds<Vec<u8>>, SerializeError> {
+        reply.serialize(sequence as u16)
+    }
+
+    /// Encode an error for the request with the given sequence number.
+    pub fn encode_error(&self, error: &X11Error, sequence: SequenceNumber) -> [u8; 32] {
+        let mut result = <[u8; 32]>::from(error);
+        result[2..4].copy_from_slice(&(sequence as u16).to_ne_bytes());
+        result
+    }
+
+    /// Encode an event.
+    ///
+    /// Events carry the sequence number of the last request that was received.
+    pub fn encode_event(
+        &self,
+        event: &Event,
+        ext_info_provider: &dyn ExtInfoProvider,
+    ) -> Result<Vec<u8>, SerializeError> {
+        let mut result = event.serialize(ext_info_provider)?;
+        // KeymapNotify does not have a sequence number
+        if event.wire_sequence_number().is_some() {
+            result[2..4].copy_from_slice(&(self.last_sequence as u16).to_ne_bytes());
+        }
+        Ok(result)
+    }
+}
+
+/// Round `length` up to a multiple of four.
+fn pad(length: usize) -> usize {
+    (length + 3) / 4 * 4
+}
+
+/// Pad a serialized `Setup` or `SetupFailed` and set its length field.
+fn set_setup_length(buf: &mut Vec<u8>) {
+    buf.resize(pad(buf.len()), 0);
+    let length = u16::try_from((buf.len() - 8) / 4).expect("Setup is too large");
+    buf[6..8].copy_from_slice(&length.to_ne_bytes());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ServerConnection, FOREIGN_BYTE_ORDER};
+    use crate::connect::Connect;
+    use crate::errors::ParseError;
+    use crate::protocol::xproto::{ImageOrder, Setup, SetupRequest};
+    use crate::x11_utils::{ExtInfoProvider, ExtensionInformation, Serialize};
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    struct NoExtensions;
+
+    impl ExtInfoProvider for NoExtensions {
+        fn get_from_major_opcode(&self, _: u8) -> Option<(&str, ExtensionInformation)> {
+            None
+        }
+        fn get_from_event_code(&self, _: u8) -> Option<(&str, ExtensionInformation)> {
+            None
+        }
+        fn get_from_error_code(&self, _: u8) -> Option<(&str, ExtensionInformation)> {
+            None
+        }
+    }
+
+    fn test_setup() -> Setup {
+        Setup {
+            status: 0,
+            protocol_major_version: 11,
+            protocol_minor_version: 0,
+            length: 0,
+            release_number: 0,
+            resource_id_base: 1,
+            resource_id_mask: 1,
+            motion_buffer_size: 0,
+            maximum_request_length: 0,
+            image_byte_order: ImageOrder::LSB_FIRST,
+            bitmap_format_bit_order: ImageOrder::LSB_FIRST,
+            bitmap_format_scanline_unit: 32,
+            bitmap_format_scanline_pad: 32,
+            min_keycode: 0,
+            max_keycode: 0,
+            vendor: b"Test".to_vec(),
+            pixmap_formats: vec![],
+            roots: vec![],
+        }
+    }
+
+    fn connected() -> ServerConnection {
+        let mut server = ServerConnection::new();
+        let (_, setup_request) = Connect::with_authorization(Vec::new(), Vec::new());
+        server.enqueue_bytes(&setup_request);
+        let _ = server.poll_setup_request().unwrap().unwrap();
+        let _ = server.accept(&test_setup());
+        server
+    }
+
+    #[test]
+    fn setup_with_client() {
+        let (mut connect, setup_request) =
+            Connect::with_authorization(b"name".to_vec(), b"secret data".to_vec());
+
+        let mut server = ServerConnection::new();
+        // The request arrives in two pieces
+        server.enqueue_bytes(&setup_request[..10]);
+        assert_eq!(server.poll_setup_request(), Ok(None));
+        server.enqueue_bytes(&setup_request[10..]);
+        let request = server.poll_setup_request().unwrap().unwrap();
+        assert_eq!(request.authorization_protocol_name, b"name");
+        assert_eq!(request.authorization_protocol_data, b"secret data");
+        assert!(server.client_byte_order_is_native());
+
+        let mut setup = server.accept(&test_setup());
+        let mut len = 0;
+        while !connect.advance(len) {
+            let buffer = connect.buffer();
+            len = buffer.len();
+            buffer.copy_from_slice(&setup[..len]);
+            let _ = setup.drain(..len);
+        }
+        let received = connect.into_setup().unwrap();
+        assert_eq!(received.status, 1);
+        assert_eq!(received.vendor, b"Test");
+    }
+
+    #[test]
+    fn foreign_byte_order() {
+        let request = SetupRequest {
+            byte_order: FOREIGN_BYTE_ORDER,
+            protocol_major_version: u16::from_be_bytes(11u16.to_ne_bytes()),
+            protocol_minor_version: 0,
+            authorization_protocol_name: vec![],
+            authorization_protocol_data: vec![1; 2],
+        };
+        let mut bytes = request.serialize();
+        bytes[8..10].reverse();
+
+        let mut server = ServerConnection::new();
+        server.enqueue_bytes(&bytes);
+        let request = server.poll_setup_request().unwrap().unwrap();
+        assert_eq!(request.protocol_major_version, 11);
+        assert_eq!(request.authorization_protocol_data, [1, 1]);
+        assert!(!server.client_byte_order_is_native());
+
+        let failed = server.refuse(b"nope");
+        assert_eq!(failed[..2], [0, 4]);
+        // The numbers use the client's byte order
+        let mut version = 11u16.to_ne_bytes();
+        version.reverse();
+        assert_eq!(failed[2..4], version);
+        assert_eq!(failed.len(), 12);
+    }
+
+    #[test]
+    fn invalid_byte_order() {
+        let mut server = ServerConnection::new();
+        server.enqueue_bytes(&[0; 12]);
+        assert_eq!(server.poll_setup_request(), Err(ParseError::InvalidValue));
+    }
+
+    #[test]
+    fn request_authentication() {
+        let mut server = ServerConnection::new();
+        let (_, setup_request) = Connect::with_authorization(Vec::new(), Vec::new());
+        server.enqueue_bytes(&setup_request);
+        let _ = server.poll_setup_request().unwrap().unwrap();
+        let answer = server.request_authentication(b"more");
+        assert_eq!(answer[..6], [2, 0, 0, 0, 0, 0]);
+        assert_eq!(answer[6..8], 1u16.to_ne_bytes());
+        assert_eq!(answer[8..], *b"more");
+    }
+
+    #[test]
+    fn requests_get_sequence_numbers() {
+        let mut server = connected();
+        // GetInputFocus and half of a MapWindow request
+        server.enqueue_bytes(&[43, 0, 1, 0, 8, 0, 2, 0, 1, 2]);
+        let request = server.poll_request().unwrap().unwrap();
+        assert_eq!(request.sequence, 1);
+        assert_eq!(request.header.major_opcode, 43);
+        assert!(matches!(server.poll_request(), Ok(None)));
+
+        server.enqueue_bytes(&[3, 4]);
+        let request = server.poll_request().unwrap().unwrap();
+        assert_eq!(request.sequence, 2);
+        assert_eq!(request.body, [1, 2, 3, 4]);
+        match server.parse_request(&request, &NoExtensions).unwrap() {
+            crate::protocol::Request::MapWindow(req) => {
+                assert_eq!(req.window, u32::from_ne_bytes([1, 2, 3, 4]))
+            }
+            req => panic!("unexpected request {:?}", req),
+        }
+        assert_eq!(server.last_sequence_number(), 2);
+    }
+
+    #[test]
+    fn big_requests() {
+        let mut server = connected();
+        let mut request = vec![8, 0, 0, 0];
+        request.extend_from_slice(&3u32.to_ne_bytes());
+        request.extend_from_slice(&[1, 2, 3, 4]);
+
+        server.enqueue_bytes(&request);
+        assert!(matches!(
+            server.poll_request(),
+            Err(ParseError::InvalidValue)
+        ));
+
+        let mut server = connected();
+        server.enable_big_requests();
+        server.enqueue_bytes(&request[..8]);
+        assert!(matches!(server.poll_request(), Ok(None)));
+        server.enqueue_bytes(&request[8..]);
+        let request = server.poll_request().unwrap().unwrap();
+        assert_eq!(request.header.remaining_length, 1);
+        assert_eq!(request.body, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn encode_with_sequence() {
+        use crate::protocol::xproto::{GetInputFocusReply, MapNotifyEvent};
+        use crate::protocol::{ErrorKind, Event, Reply};
+        use crate::x11_utils::X11Error;
+
+        let mut server = connected();
+        server.enqueue_bytes(&[43, 0, 1, 0, 43, 0, 1, 0]);
+        let first = server.poll_request().unwrap().unwrap();
+        let _ = server.poll_request().unwrap().unwrap();
+
+        let reply = Reply::from(GetInputFocusReply::default());
+        let (reply, _) = server.encode_reply(&reply, first.sequence).unwrap();
+        assert_eq!(reply[2..4], 1u16.to_ne_bytes());
+
+        let error = X11Error {
+            error_kind: ErrorKind::Value,
+            error_code: 2,
+            sequence: 0,
+            bad_value: 0,
+            minor_opcode: 0,
+            major_opcode: 43,
+            extension_name: None,
+            request_name: None,
+        };
+        let error = server.encode_error(&error, first.sequence);
+        assert_eq!(error[..2], [0, 2]);
+        assert_eq!(error[2..4], 1u16.to_ne_bytes());
+
+        let event = Event::MapNotify(MapNotifyEvent::default());
+        let event = server.encode_event(&event, &NoExtensions).unwrap();
+        assert_eq!(event[0], 19);
+        assert_eq!(event[2..4], 2u16.to_ne_bytes());
+    }
+}
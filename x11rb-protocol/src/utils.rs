@@ -43,6 +43,14 @@ mod raw_fd_container {
             Ok(Self::new(nix::unistd::dup(self.0)?))
         }
 
+        /// Duplicate the file descriptor for serializing a request that is not consumed.
+        // Only used if one of the extensions that use FD passing is enabled
+        #[allow(dead_code)]
+        pub(crate) fn duplicate(&self) -> Result<Self, crate::errors::SerializeError> {
+            self.try_clone()
+                .map_err(crate::errors::SerializeError::FdDuplicationFailed)
+        }
+
         /// Get the `RawFd` out of this `RawFdContainer`.
         ///
         /// This function would be an implementation of `IntoRawFd` if that were possible. However, it
@@ -94,6 +102,15 @@ mod raw_fd_container {
             match self.0 {}
         }
     }
+
+    impl RawFdContainer {
+        /// Duplicate the file descriptor for serializing a request that is not consumed.
+        // Only used if one of the extensions that use FD passing is enabled
+        #[allow(dead_code)]
+        pub(crate) fn duplicate(&self) -> Result<Self, crate::errors::SerializeError> {
+            match self.0 {}
+        }
+    }
 }
 
 pub use raw_fd_container::RawFdContainer;
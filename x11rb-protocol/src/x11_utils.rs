@@ -4,18 +4,23 @@
 //! [`Serialize`] traits. These traits are used internally for parsing incoming data and producing
 //! outgoing data when talking with the X11 server.
 
-use core::convert::TryInto;
+use core::convert::{TryFrom, TryInto};
+use core::marker::PhantomData;
 
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 
-use crate::errors::ParseError;
+use crate::errors::{ParseError, SerializeError};
 use crate::protocol::{request_name, ErrorKind};
 use crate::utils::RawFdContainer;
 use crate::BufWithFds;
 
 /// Representation of an X11 error packet that was sent by the server.
+///
+/// With the `serde` feature, `request_name` is serialized, but it is recomputed from the other
+/// fields during deserialization.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct X11Error {
     /// The kind of error that occurred.
     pub error_kind: ErrorKind,
@@ -69,6 +74,46 @@ impl X11Error {
     }
 }
 
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for X11Error {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        // The request name cannot be deserialized into a `&'static str`
+        #[derive(serde::Deserialize)]
+        #[serde(rename = "X11Error")]
+        struct Fields {
+            error_kind: ErrorKind,
+            error_code: u8,
+            sequence: u16,
+            bad_value: u32,
+            minor_opcode: u16,
+            major_opcode: u8,
+            extension_name: Option<String>,
+            #[allow(dead_code)]
+            request_name: Option<String>,
+        }
+
+        let fields = Fields::deserialize(deserializer)?;
+        let request_name = request_name(
+            fields.extension_name.as_deref(),
+            fields.major_opcode,
+            fields.minor_opcode,
+        );
+        Ok(X11Error {
+            error_kind: fields.error_kind,
+            error_code: fields.error_code,
+            sequence: fields.sequence,
+            bad_value: fields.bad_value,
+            minor_opcode: fields.minor_opcode,
+            major_opcode: fields.major_opcode,
+            extension_name: fields.extension_name,
+            request_name,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tryparse_x11error_test {
     use super::{ErrorKind, ExtInfoProvider, ParseError, X11Error};
@@ -211,6 +256,19 @@ pub trait ExtInfoProvider {
     /// Returns the information of the extension that whose
     /// error number range includes `error_number`.
     fn get_from_error_code(&self, error_code: u8) -> Option<(&str, ExtensionInformation)>;
+
+    /// Returns the information of the extension with the given name.
+    ///
+    /// The default implementation looks at all major opcodes that extensions can have via
+    /// `get_from_major_opcode`.
+    fn get_from_name(&self, extension_name: &str) -> Option<ExtensionInformation> {
+        (128..=255).find_map(
+            |major_opcode| match self.get_from_major_opcode(major_opcode) {
+                Some((name, info)) if name == extension_name => Some(info),
+                _ => None,
+            },
+        )
+    }
 }
 
 /// A type implementing this trait can be parsed from some raw bytes.
@@ -250,6 +308,7 @@ impl<T: TryParse> TryParseFd for T {
 
 /// A representation of the header of a request.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RequestHeader {
     /// The major opcode of the request.
     pub major_opcode: u8,
@@ -275,6 +334,131 @@ pub trait Request {
     fn serialize(self, extension_opcode: u8) -> BufWithFds<Vec<u8>>;
 }
 
+/// Serialize a request with the major opcode that `ext_info_provider` knows for its extension.
+///
+/// This is used by [`crate::protocol::Request::serialize`].
+pub(crate) fn serialize_request<R: Request>(
+    request: R,
+    ext_info_provider: &dyn ExtInfoProvider,
+) -> Result<BufWithFds<Vec<u8>>, SerializeError> {
+    let major_opcode = match R::EXTENSION_NAME {
+        None => 0,
+        Some(name) => {
+            ext_info_provider
+                .get_from_name(name)
+                .ok_or(SerializeError::MissingExtension(name))?
+                .major_opcode
+        }
+    };
+    let (mut buf, fds) = request.serialize(major_opcode);
+    set_request_length(&mut buf);
+    Ok((buf, fds))
+}
+
+/// Serialize a request that is only known by its header and its raw body.
+///
+/// This is used by [`crate::protocol::Request::serialize`].
+pub(crate) fn serialize_unknown_request(header: &RequestHeader, body: &[u8]) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(4 + body.len());
+    buf.extend_from_slice(&[header.major_opcode, header.minor_opcode, 0, 0]);
+    buf.extend_from_slice(body);
+    set_request_length(&mut buf);
+    buf
+}
+
+/// Pad a serialized request and set its length field.
+///
+/// Requests that are too large for the normal length field are encoded in the format of the
+/// BIG-REQUESTS extension: The normal length field is zero and an additional 32 bit length field
+/// follows the first four bytes.
+fn set_request_length(buf: &mut Vec<u8>) {
+    buf.resize((buf.len() + 3) / 4 * 4, 0);
+    let length = buf.len() / 4;
+    match u16::try_from(length) {
+        Ok(length) => buf[2..4].copy_from_slice(&length.to_ne_bytes()),
+        Err(_) => {
+            let length = u32::try_from(length + 1).expect("X11 request larger than 2^34 bytes?!?");
+            buf[2..4].copy_from_slice(&[0, 0]);
+            let _ = buf.splice(4..4, length.to_ne_bytes().iter().copied());
+        }
+    }
+}
+
+/// Get the event code of an extension's event from its number inside of the extension.
+///
+/// This is used by [`crate::protocol::Event::serialize`].
+// Only used if an extension with events is enabled
+#[allow(dead_code)]
+pub(crate) fn extension_event_code(
+    ext_info_provider: &dyn ExtInfoProvider,
+    extension_name: &'static str,
+    event_number: u8,
+) -> Result<u8, SerializeError> {
+    let info = ext_info_provider
+        .get_from_name(extension_name)
+        .ok_or(SerializeError::MissingExtension(extension_name))?;
+    Ok(info.first_event + event_number)
+}
+
+/// Serialize an event with the given event code.
+///
+/// The sent-event bit of the event's `response_type` is kept.
+///
+/// This is used by [`crate::protocol::Event::serialize`].
+pub(crate) fn serialize_event<E: Serialize>(event: &E, event_code: u8) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(32);
+    event.serialize_into(&mut buf);
+    buf.resize(buf.len().max(32), 0);
+    buf[0] = (buf[0] & 0x80) | event_code;
+    buf
+}
+
+/// Serialize an event of the Generic Event Extension.
+///
+/// The sent-event bit of the event's `response_type` is kept. The major opcode of the extension,
+/// the event type and the length field are set according to the event.
+///
+/// This is used by [`crate::protocol::Event::serialize`].
+// Only used if an extension with events is enabled
+#[allow(dead_code)]
+pub(crate) fn serialize_generic_event<E: Serialize>(
+    event: &E,
+    ext_info_provider: &dyn ExtInfoProvider,
+    extension_name: &'static str,
+    event_type: u16,
+) -> Result<Vec<u8>, SerializeError> {
+    let info = ext_info_provider
+        .get_from_name(extension_name)
+        .ok_or(SerializeError::MissingExtension(extension_name))?;
+    let mut buf = serialize_event(event, crate::protocol::xproto::GE_GENERIC_EVENT);
+    buf[1] = info.major_opcode;
+    set_response_length(&mut buf);
+    buf[8..10].copy_from_slice(&event_type.to_ne_bytes());
+    Ok(buf)
+}
+
+/// Serialize a reply with the given sequence number.
+///
+/// This is used by [`crate::protocol::Reply::serialize`].
+pub(crate) fn serialize_reply<R: Serialize>(reply: &R, sequence: u16) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(32);
+    reply.serialize_into(&mut buf);
+    buf.resize(buf.len().max(32), 0);
+    buf[2..4].copy_from_slice(&sequence.to_ne_bytes());
+    set_response_length(&mut buf);
+    buf
+}
+
+/// Pad a serialized reply or generic event and set its length field.
+///
+/// The length field counts the 4 byte units after the first 32 bytes.
+fn set_response_length(buf: &mut Vec<u8>) {
+    buf.resize((buf.len() + 3) / 4 * 4, 0);
+    let length =
+        u32::try_from((buf.len() - 32) / 4).expect("X11 response larger than 2^34 bytes?!?");
+    buf[4..8].copy_from_slice(&length.to_ne_bytes());
+}
+
 /// A type alias for reply parsers (matches the signature of TryParseFd).
 pub type ReplyParsingFunction =
     for<'a> fn(
@@ -297,6 +481,21 @@ pub trait ReplyFDsRequest: Request {
     type Reply: Into<crate::protocol::Reply> + TryParseFd;
 }
 
+/// A reply that can be parsed without copying its lists.
+///
+/// The lists in the borrowed version of a reply refer to the buffer that the reply was parsed
+/// from. This trait is implemented by the owned reply type, e.g. `GetPropertyReply` has
+/// `GetPropertyReplyRef<'a>` as its borrowed version.
+///
+/// Borrowed versions are only generated for replies that contain lists.
+pub trait BorrowedReply<'a>: Sized {
+    /// The borrowed version of this reply.
+    type Ref: Into<Self>;
+
+    /// Parse the borrowed version of this reply from some raw bytes.
+    fn try_parse_ref(value: &'a [u8]) -> Result<(Self::Ref, &'a [u8]), ParseError>;
+}
+
 /// A type implementing this trait can be serialized into X11 raw bytes.
 pub trait Serialize {
     /// The value returned by `serialize`.
@@ -561,6 +760,181 @@ pub(crate) fn parse_u8_list(data: &[u8], list_length: usize) -> Result<(&[u8], &
     }
 }
 
+/// A list of objects that is parsed lazily from a borrowed buffer.
+///
+/// This is used for the lists in borrowed replies, see [`BorrowedReply`]. The wire format of all
+/// elements is checked when the list is created, so iterating over the list cannot fail.
+pub struct ListRef<'a, T> {
+    data: &'a [u8],
+    len: usize,
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<'a, T: TryParse> ListRef<'a, T> {
+    /// Parse a list of `list_length` objects from the given data.
+    pub fn try_parse(data: &'a [u8], list_length: usize) -> Result<(Self, &'a [u8]), ParseError> {
+        let mut remaining = data;
+        for _ in 0..list_length {
+            remaining = T::try_parse(remaining)?.1;
+        }
+        Ok((Self::new(data, remaining, list_length), remaining))
+    }
+
+    /// Parse a list of objects that fills all of the given data.
+    pub fn try_parse_remaining(data: &'a [u8]) -> Result<(Self, &'a [u8]), ParseError> {
+        let mut remaining = data;
+        let mut list_length = 0;
+        while !remaining.is_empty() {
+            remaining = T::try_parse(remaining)?.1;
+            list_length += 1;
+        }
+        Ok((Self::new(data, remaining, list_length), remaining))
+    }
+
+    fn new(data: &'a [u8], remaining: &'a [u8], len: usize) -> Self {
+        Self {
+            data: &data[..data.len() - remaining.len()],
+            len,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Get the number of objects in this list.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check whether this list is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Get the wire format of all objects in this list.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Get an iterator that parses the objects in this list.
+    pub fn iter(&self) -> ListRefIter<'a, T> {
+        ListRefIter {
+            data: self.data,
+            len: self.len,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> Clone for ListRef<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ListRef<'_, T> {}
+
+impl<T: TryParse + core::fmt::Debug> core::fmt::Debug for ListRef<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, T: TryParse> IntoIterator for ListRef<'a, T> {
+    type Item = T;
+    type IntoIter = ListRefIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T: TryParse> IntoIterator for &ListRef<'a, T> {
+    type Item = T;
+    type IntoIter = ListRefIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the objects in a [`ListRef`].
+pub struct ListRefIter<'a, T> {
+    data: &'a [u8],
+    len: usize,
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for ListRefIter<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data,
+            len: self.len,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> core::fmt::Debug for ListRefIter<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ListRefIter")
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+impl<T: TryParse> Iterator for ListRefIter<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        // The list was already checked when the ListRef was created
+        let (value, remaining) = T::try_parse(self.data).expect("ListRef contains invalid data");
+        self.data = remaining;
+        self.len -= 1;
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T: TryParse> ExactSizeIterator for ListRefIter<'_, T> {}
+
+#[cfg(test)]
+mod list_ref_tests {
+    use super::{ListRef, ParseError};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_parse() {
+        let data = [1, 0, 2, 0, 3, 0, 42];
+        let (list, remaining) = ListRef::<u16>::try_parse(&data, 3).unwrap();
+        assert_eq!(remaining, [42]);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.as_bytes(), &data[..6]);
+        let values = list.iter().map(u16::from_le).collect::<Vec<_>>();
+        assert_eq!(values, [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_parse_too_short() {
+        let data = [1, 0, 2];
+        let result = ListRef::<u16>::try_parse(&data, 2);
+        assert_eq!(result.unwrap_err(), ParseError::InsufficientData);
+    }
+
+    #[test]
+    fn test_parse_remaining() {
+        let data = [1, 2, 3, 4, 5, 6, 7, 8];
+        let (list, remaining) = ListRef::<u32>::try_parse_remaining(&data).unwrap();
+        assert!(remaining.is_empty());
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.iter().len(), 2);
+    }
+}
+
 impl<T: Serialize> Serialize for [T] {
     type Bytes = Vec<u8>;
     fn serialize(&self) -> Self::Bytes {
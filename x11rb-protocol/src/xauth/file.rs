@@ -0,0 +1,708 @@
+//! Code for reading and writing `~/.Xauthority`.
+
+use alloc::{vec, vec::Vec};
+use core::convert::TryFrom;
+use std::env::var_os;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufReader, BufWriter, Error, ErrorKind, Read, Write};
+use std::path::{Path, PathBuf};
+use std::thread::sleep;
+use std::time::{Duration, SystemTime};
+
+use super::{AuthEntry, Family};
+
+/// Read a single `u16` from an `~/.Xauthority` file.
+///
+/// The file stores these entries in big endian.
+fn read_u16<R: Read>(read: &mut R) -> Result<u16, Error> {
+    let mut buffer = [0; 2];
+    read.read_exact(&mut buffer)?;
+    Ok(u16::from_be_bytes(buffer))
+}
+
+/// Read a single "byte array" from an `~/.Xauthority` file.
+///
+/// The file stores these as a length field followed by a number of bytes that contain the
+/// actual data.
+fn read_string<R: Read>(read: &mut R) -> Result<Vec<u8>, Error> {
+    let length = read_u16(read)?;
+    let mut result = vec![0; length.into()];
+    read.read_exact(&mut result[..])?;
+    Ok(result)
+}
+
+/// Read a single entry from an `~/.Xauthority` file.
+///
+/// This function tries to return `Ok(None)` when the end of the file is reached. However, the
+/// code also treats a single byte as 'end of file', because things were simpler to implement
+/// like this.
+pub(super) fn read_entry<R: Read>(read: &mut R) -> Result<Option<AuthEntry>, Error> {
+    let family = match read_u16(read) {
+        Ok(family) => family,
+        Err(ref e) if e.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e),
+    }
+    .into();
+    let address = read_string(read)?;
+    let number = read_string(read)?;
+    let name = read_string(read)?;
+    let data = read_string(read)?;
+    Ok(Some(AuthEntry {
+        family,
+        address,
+        number,
+        name,
+        data,
+    }))
+}
+
+/// Write a single "byte array" to an `~/.Xauthority` file.
+///
+/// An error is returned if `string` is longer than what fits into the `u16` length field.
+fn write_string<W: Write>(write: &mut W, string: &[u8]) -> Result<(), Error> {
+    let length = u16::try_from(string.len()).map_err(|_| {
+        Error::new(
+            ErrorKind::InvalidInput,
+            "Field of an Xauthority entry is longer than 65535 bytes",
+        )
+    })?;
+    write.write_all(&length.to_be_bytes())?;
+    write.write_all(string)
+}
+
+/// Write a single entry to an `~/.Xauthority` file.
+pub(super) fn write_entry<W: Write>(write: &mut W, entry: &AuthEntry) -> Result<(), Error> {
+    write.write_all(&u16::from(entry.family).to_be_bytes())?;
+    write_string(write, &entry.address)?;
+    write_string(write, &entry.number)?;
+    write_string(write, &entry.name)?;
+    write_string(write, &entry.data)
+}
+
+/// Get the file name for `~/.Xauthority` based on environment variables.
+///
+/// The code in libXau contains a special case for Windows (looks like cygwin) that is not
+/// handled here (yet?).
+fn get_xauthority_file_name() -> Option<PathBuf> {
+    if let Some(name) = var_os("XAUTHORITY") {
+        return Some(name.into());
+    }
+    var_os("HOME").map(|prefix| {
+        let mut result = PathBuf::new();
+        result.push(prefix);
+        result.push(".Xauthority");
+        result
+    })
+}
+
+/// Append `suffix` to the file name of `path`.
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(suffix);
+    name.into()
+}
+
+/// An iterator over the entries of an `.Xauthority` file
+#[derive(Debug)]
+pub(crate) struct XAuthorityEntries(BufReader<File>);
+
+impl XAuthorityEntries {
+    /// Open `~/.Xauthority` for reading.
+    ///
+    /// This function returns `Ok(None)` when the location of the `.Xauthority` file could not
+    /// be determined. If opening the file failed (for example, because it does not exist),
+    /// that error is returned.
+    pub(crate) fn new() -> Result<Option<XAuthorityEntries>, Error> {
+        get_xauthority_file_name()
+            .map(File::open)
+            .transpose()?
+            // At this point we have Option<File> and errors while opening the file were
+            // returned to the caller.
+            .map(|file| Ok(XAuthorityEntries(BufReader::new(file))))
+            .transpose()
+    }
+}
+
+impl Iterator for XAuthorityEntries {
+    type Item = Result<AuthEntry, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        read_entry(&mut self.0).transpose()
+    }
+}
+
+/// The contents of an `.Xauthority` file.
+///
+/// This is a list of [`AuthEntry`]s. The order of the entries matters, because the first matching
+/// entry is used when connecting to an X11 server.
+///
+/// The methods of this type offer the same operations as `xauth add`, `xauth list`, `xauth merge`
+/// and `xauth remove`. Files are written atomically while holding a lock that is compatible with
+/// libXau, see [`XauthorityLock`].
+///
+/// # Example
+///
+/// ```no_run
+/// use x11rb_protocol::xauth::{generate_cookie, AuthEntry, Family, Xauthority};
+///
+/// # fn main() -> Result<(), std::io::Error> {
+/// let path = Xauthority::default_path().expect("No home directory");
+/// Xauthority::modify(&path, |xauthority| {
+///     let entry = AuthEntry {
+///         family: Family::LOCAL,
+///         address: b"myhost".to_vec(),
+///         number: b"1".to_vec(),
+///         name: b"MIT-MAGIC-COOKIE-1".to_vec(),
+///         data: generate_cookie()?,
+///     };
+///     xauthority.add(entry);
+///     Ok(())
+/// })?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Xauthority {
+    entries: Vec<AuthEntry>,
+}
+
+impl Xauthority {
+    /// Create an empty `Xauthority`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the location of the `.Xauthority` file of the current user.
+    ///
+    /// This is `$XAUTHORITY` if set, otherwise `$HOME/.Xauthority`. `None` is returned if neither
+    /// variable is set.
+    pub fn default_path() -> Option<PathBuf> {
+        get_xauthority_file_name()
+    }
+
+    /// Parse the binary `.Xauthority` format.
+    pub fn parse(mut data: &[u8]) -> Result<Self, Error> {
+        Self::read_from(&mut data)
+    }
+
+    /// Read entries in the binary `.Xauthority` format until the end of `read`.
+    pub fn read_from<R: Read>(read: R) -> Result<Self, Error> {
+        let mut read = read;
+        let mut entries = Vec::new();
+        while let Some(entry) = read_entry(&mut read)? {
+            entries.push(entry);
+        }
+        Ok(Self { entries })
+    }
+
+    /// Read an `.Xauthority` file.
+    ///
+    /// A file that does not exist is treated like an empty file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        match File::open(path) {
+            Ok(file) => Self::read_from(BufReader::new(file)),
+            Err(ref e) if e.kind() == ErrorKind::NotFound => Ok(Self::new()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Serialize the entries to the binary `.Xauthority` format.
+    ///
+    /// An error is returned if a field of an entry is longer than 65535 bytes.
+    pub fn serialize(&self) -> Result<Vec<u8>, Error> {
+        let mut result = Vec::new();
+        self.write_to(&mut result)?;
+        Ok(result)
+    }
+
+    /// Write the entries in the binary `.Xauthority` format to `write`.
+    pub fn write_to<W: Write>(&self, write: W) -> Result<(), Error> {
+        let mut write = write;
+        for entry in &self.entries {
+            write_entry(&mut write, entry)?;
+        }
+        Ok(())
+    }
+
+    /// Write an `.Xauthority` file.
+    ///
+    /// This locks the file with [`XauthorityLock`] and then replaces it atomically. Use
+    /// [`Xauthority::modify`] to read, change and write a file without another process changing
+    /// it in between.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let path = path.as_ref();
+        let _lock = XauthorityLock::acquire(path)?;
+        self.replace_file(path)
+    }
+
+    /// Read, change and write an `.Xauthority` file while holding a lock on it.
+    ///
+    /// The file is only written if `modify` returns `Ok`.
+    pub fn modify<T>(
+        path: impl AsRef<Path>,
+        modify: impl FnOnce(&mut Self) -> Result<T, Error>,
+    ) -> Result<T, Error> {
+        let path = path.as_ref();
+        let _lock = XauthorityLock::acquire(path)?;
+        let mut xauthority = Self::load(path)?;
+        let result = modify(&mut xauthority)?;
+        xauthority.replace_file(path)?;
+        Ok(result)
+    }
+
+    /// Atomically replace the file at `path` with the entries.
+    ///
+    /// Like `xauth`, the entries are first written to `path` with a `-n` suffix. This file is then
+    /// renamed to `path`. The caller must hold the lock.
+    fn replace_file(&self, path: &Path) -> Result<(), Error> {
+        let temp_path = with_suffix(path, "-n");
+        let result = (|| {
+            let mut options = OpenOptions::new();
+            let _ = options.write(true).create(true).truncate(true);
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::OpenOptionsExt;
+                let _ = options.mode(0o600);
+            }
+            let mut file = BufWriter::new(options.open(&temp_path)?);
+            self.write_to(&mut file)?;
+            file.flush()?;
+            file.get_ref().sync_all()?;
+            drop(file);
+            fs::rename(&temp_path, path)
+        })();
+        if result.is_err() {
+            let _ = fs::remove_file(&temp_path);
+        }
+        result
+    }
+
+    /// Get all entries.
+    pub fn entries(&self) -> &[AuthEntry] {
+        &self.entries
+    }
+
+    /// Get an iterator over all entries.
+    pub fn iter(&self) -> impl Iterator<Item = &AuthEntry> {
+        self.entries.iter()
+    }
+
+    /// Get the first entry that matches the given address and display.
+    ///
+    /// See [`AuthEntry::matches`] for the rules.
+    pub fn find(&self, family: Family, address: &[u8], display: u16) -> Option<&AuthEntry> {
+        self.entries
+            .iter()
+            .find(|entry| entry.matches(family, address, display))
+    }
+
+    /// Add an entry.
+    ///
+    /// Like `xauth add`, this replaces an existing entry with the same family, address, display
+    /// number and authentication name. Otherwise, the entry is added at the end.
+    pub fn add(&mut self, entry: AuthEntry) {
+        let existing = self.entries.iter_mut().find(|existing| {
+            existing.family == entry.family
+                && existing.address == entry.address
+                && existing.number == entry.number
+                && existing.name == entry.name
+        });
+        match existing {
+            Some(existing) => *existing = entry,
+            None => self.entries.push(entry),
+        }
+    }
+
+    /// Add all entries of `other`, like `xauth merge`.
+    ///
+    /// See [`Xauthority::add`] for how existing entries are handled.
+    pub fn merge(&mut self, other: Xauthority) {
+        for entry in other.entries {
+            self.add(entry);
+        }
+    }
+
+    /// Remove all entries for the given family, address and display number, like `xauth remove`.
+    ///
+    /// In contrast to [`Xauthority::find`], no wildcards are applied. The number of removed entries
+    /// is returned.
+    pub fn remove(&mut self, family: Family, address: &[u8], number: &[u8]) -> usize {
+        let old_len = self.entries.len();
+        self.entries.retain(|entry| {
+            entry.family != family || entry.address != address || entry.number != number
+        });
+        old_len - self.entries.len()
+    }
+
+    /// Only keep the entries for which `keep` returns `true`.
+    pub fn retain(&mut self, keep: impl FnMut(&AuthEntry) -> bool) {
+        self.entries.retain(keep)
+    }
+}
+
+impl From<Vec<AuthEntry>> for Xauthority {
+    fn from(entries: Vec<AuthEntry>) -> Self {
+        Self { entries }
+    }
+}
+
+impl From<Xauthority> for Vec<AuthEntry> {
+    fn from(xauthority: Xauthority) -> Self {
+        xauthority.entries
+    }
+}
+
+impl<'a> IntoIterator for &'a Xauthority {
+    type Item = &'a AuthEntry;
+    type IntoIter = core::slice::Iter<'a, AuthEntry>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.iter()
+    }
+}
+
+/// A lock on an `.Xauthority` file.
+///
+/// The lock uses the same protocol as `XauLockAuth()` from libXau, so that it excludes `xauth`,
+/// display managers and other users of libXau: A file with the suffix `-c` is created and then
+/// hard-linked to a file with the suffix `-l`. The lock is held while the link exists. Lock files
+/// that are older than the dead time are considered stale and removed.
+///
+/// The lock is released when this value is dropped.
+#[derive(Debug)]
+pub struct XauthorityLock {
+    creat_name: PathBuf,
+    link_name: PathBuf,
+}
+
+impl XauthorityLock {
+    /// The default number of attempts to acquire the lock, as used by `xauth`.
+    pub const DEFAULT_RETRIES: u32 = 10;
+
+    /// The default time to wait between attempts to acquire the lock, as used by `xauth`.
+    pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(2);
+
+    /// The default age after which lock files are considered stale, as used by `xauth`.
+    pub const DEFAULT_DEAD_TIME: Duration = Duration::from_secs(600);
+
+    /// Lock the `.Xauthority` file at `path` with the default parameters.
+    pub fn acquire(path: impl AsRef<Path>) -> Result<Self, Error> {
+        Self::acquire_with(
+            path,
+            Self::DEFAULT_RETRIES,
+            Self::DEFAULT_TIMEOUT,
+            Self::DEFAULT_DEAD_TIME,
+        )
+    }
+
+    /// Lock the `.Xauthority` file at `path`.
+    ///
+    /// Up to `retries` attempts are made to acquire the lock, with `timeout` between them. Lock
+    /// files older than `dead_time` are removed first. An error of kind
+    /// [`ErrorKind::TimedOut`] is returned if the lock could not be acquired.
+    pub fn acquire_with(
+        path: impl AsRef<Path>,
+        retries: u32,
+        timeout: Duration,
+        dead_time: Duration,
+    ) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let creat_name = with_suffix(path, "-c");
+        let link_name = with_suffix(path, "-l");
+
+        // Remove stale lock files
+        if let Ok(metadata) = fs::metadata(&creat_name) {
+            let age = metadata
+                .modified()
+                .ok()
+                .and_then(|modified| SystemTime::now().duration_since(modified).ok());
+            if dead_time == Duration::from_secs(0) || age.map_or(false, |age| age > dead_time) {
+                let _ = fs::remove_file(&creat_name);
+                let _ = fs::remove_file(&link_name);
+            }
+        }
+
+        let mut created = false;
+        for attempt in 0..retries {
+            if !created {
+                let mut options = OpenOptions::new();
+                let _ = options.write(true).create_new(true);
+                #[cfg(unix)]
+                {
+                    use std::os::unix::fs::OpenOptionsExt;
+                    let _ = options.mode(0o600);
+                }
+                match options.open(&creat_name) {
+                    Ok(_) => created = true,
+                    Err(ref e)
+                        if e.kind() == ErrorKind::AlreadyExists
+                            || e.kind() == ErrorKind::PermissionDenied => {}
+                    Err(e) => return Err(e),
+                }
+            }
+            if created {
+                match fs::hard_link(&creat_name, &link_name) {
+                    Ok(()) => {
+                        return Ok(Self {
+                            creat_name,
+                            link_name,
+                        })
+                    }
+                    // Somebody removed our file as stale, create it again
+                    Err(ref e) if e.kind() == ErrorKind::NotFound => {
+                        created = false;
+                        continue;
+                    }
+                    Err(ref e) if e.kind() == ErrorKind::AlreadyExists => {}
+                    Err(e) => return Err(e),
+                }
+            }
+            if attempt + 1 < retries {
+                sleep(timeout);
+            }
+        }
+        Err(Error::new(
+            ErrorKind::TimedOut,
+            "Timed out while trying to lock the Xauthority file",
+        ))
+    }
+}
+
+impl Drop for XauthorityLock {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.creat_name);
+        let _ = fs::remove_file(&self.link_name);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{AuthEntry, Family};
+    use super::{read_entry, Xauthority, XauthorityLock};
+    use alloc::{format, vec, vec::Vec};
+    use std::io::{Cursor, ErrorKind};
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    fn entry(address: &[u8], number: &[u8], name: &[u8], data: &[u8]) -> AuthEntry {
+        AuthEntry {
+            family: Family::LOCAL,
+            address: address.to_vec(),
+            number: number.to_vec(),
+            name: name.to_vec(),
+            data: data.to_vec(),
+        }
+    }
+
+    /// Get a path for an `.Xauthority` file in a new temporary directory.
+    fn temp_path(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("x11rb-xauthority-{}-{}", std::process::id(), name));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir(&dir).unwrap();
+        dir.join("Xauthority")
+    }
+
+    #[test]
+    fn test_read() {
+        // Data generated via xauth -f /tmp/file add :1 bar deadbeef
+        let data = [
+            0x01, 0x00, 0x00, 0x07, 0x5a, 0x77, 0x65, 0x69, 0x4c, 0x45, 0x44, 0x00, 0x01, 0x31,
+            0x00, 0x03, 0x62, 0x61, 0x72, 0x00, 0x04, 0xde, 0xad, 0xbe, 0xef,
+        ];
+        let mut cursor = Cursor::new(&data[..]);
+        let entry = read_entry(&mut cursor).unwrap();
+        assert_eq!(
+            entry,
+            Some(AuthEntry {
+                family: Family::LOCAL,
+                address: b"ZweiLED".to_vec(),
+                number: b"1".to_vec(),
+                name: b"bar".to_vec(),
+                data: u32::to_be_bytes(0xdead_beef).to_vec(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_read_iterate() {
+        // Data generated via:
+        //   xauth -f /tmp/file add :1 bar deadbeef
+        //   xauth -f /tmp/file add 1.2.3.4:2 baz aabbccdd
+        let data = [
+            0x01, 0x00, 0x00, 0x07, 0x5a, 0x77, 0x65, 0x69, 0x4c, 0x45, 0x44, 0x00, 0x01, 0x31,
+            0x00, 0x03, 0x62, 0x61, 0x72, 0x00, 0x04, 0xde, 0xad, 0xbe, 0xef, 0x00, 0x00, 0x00,
+            0x04, 0x01, 0x02, 0x03, 0x04, 0x00, 0x01, 0x32, 0x00, 0x03, 0x62, 0x61, 0x7a, 0x00,
+            0x04, 0xaa, 0xbb, 0xcc, 0xdd,
+        ];
+        let mut cursor = Cursor::new(&data[..]);
+        for expected in &[
+            AuthEntry {
+                family: Family::LOCAL,
+                address: b"ZweiLED".to_vec(),
+                number: b"1".to_vec(),
+                name: b"bar".to_vec(),
+                data: u32::to_be_bytes(0xdead_beef).to_vec(),
+            },
+            AuthEntry {
+                family: Family::INTERNET,
+                address: vec![1, 2, 3, 4],
+                number: b"2".to_vec(),
+                name: b"baz".to_vec(),
+                data: u32::to_be_bytes(0xaabb_ccdd).to_vec(),
+            },
+        ] {
+            let entry = read_entry(&mut cursor).unwrap();
+            assert_eq!(entry.as_ref(), Some(expected));
+        }
+        let entry = read_entry(&mut cursor).unwrap();
+        assert_eq!(entry, None);
+    }
+
+    #[test]
+    fn serialize_roundtrip() {
+        // Data generated via:
+        //   xauth -f /tmp/file add :1 bar deadbeef
+        //   xauth -f /tmp/file add 1.2.3.4:2 baz aabbccdd
+        let data = [
+            0x01, 0x00, 0x00, 0x07, 0x5a, 0x77, 0x65, 0x69, 0x4c, 0x45, 0x44, 0x00, 0x01, 0x31,
+            0x00, 0x03, 0x62, 0x61, 0x72, 0x00, 0x04, 0xde, 0xad, 0xbe, 0xef, 0x00, 0x00, 0x00,
+            0x04, 0x01, 0x02, 0x03, 0x04, 0x00, 0x01, 0x32, 0x00, 0x03, 0x62, 0x61, 0x7a, 0x00,
+            0x04, 0xaa, 0xbb, 0xcc, 0xdd,
+        ];
+        let xauthority = Xauthority::parse(&data).unwrap();
+        assert_eq!(xauthority.entries().len(), 2);
+        assert_eq!(xauthority.serialize().unwrap(), data);
+    }
+
+    #[test]
+    fn serialize_too_long() {
+        let xauthority = Xauthority::from(vec![entry(&[0; 0x10000], b"", b"", b"")]);
+        let err = xauthority.serialize().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn parse_truncated() {
+        assert!(Xauthority::parse(&[0x01, 0x00, 0x00, 0x07, 0x5a]).is_err());
+    }
+
+    #[test]
+    fn add_replaces() {
+        let mut xauthority = Xauthority::new();
+        xauthority.add(entry(b"host", b"1", b"name", b"old"));
+        xauthority.add(entry(b"host", b"2", b"name", b"other display"));
+        xauthority.add(entry(b"host", b"1", b"other", b"other name"));
+        xauthority.add(entry(b"host", b"1", b"name", b"new"));
+        let data = xauthority.iter().map(|e| &e.data[..]).collect::<Vec<_>>();
+        assert_eq!(
+            data,
+            [&b"new"[..], &b"other display"[..], &b"other name"[..]]
+        );
+    }
+
+    #[test]
+    fn merge() {
+        let mut xauthority = Xauthority::from(vec![
+            entry(b"host", b"1", b"name", b"old"),
+            entry(b"host", b"2", b"name", b"kept"),
+        ]);
+        xauthority.merge(Xauthority::from(vec![
+            entry(b"host", b"1", b"name", b"new"),
+            entry(b"host", b"3", b"name", b"added"),
+        ]));
+        let data = xauthority.iter().map(|e| &e.data[..]).collect::<Vec<_>>();
+        assert_eq!(data, [&b"new"[..], &b"kept"[..], &b"added"[..]]);
+    }
+
+    #[test]
+    fn remove() {
+        let mut xauthority = Xauthority::from(vec![
+            entry(b"host", b"1", b"name", b""),
+            entry(b"host", b"1", b"other", b""),
+            entry(b"host", b"", b"name", b""),
+            entry(b"other", b"1", b"name", b""),
+        ]);
+        assert_eq!(xauthority.remove(Family::LOCAL, b"host", b"1"), 2);
+        assert_eq!(xauthority.entries().len(), 2);
+        assert_eq!(xauthority.remove(Family::LOCAL, b"host", b"1"), 0);
+    }
+
+    #[test]
+    fn find() {
+        let xauthority = Xauthority::from(vec![
+            entry(b"host", b"1", b"first", b""),
+            entry(b"host", b"", b"wildcard", b""),
+        ]);
+        let find = |display| {
+            xauthority
+                .find(Family::LOCAL, b"host", display)
+                .map(|e| &e.name[..])
+        };
+        assert_eq!(find(1), Some(&b"first"[..]));
+        assert_eq!(find(2), Some(&b"wildcard"[..]));
+        assert_eq!(xauthority.find(Family::LOCAL, b"other", 1), None);
+    }
+
+    #[test]
+    fn save_and_load() {
+        let path = temp_path("save");
+        assert_eq!(Xauthority::load(&path).unwrap(), Xauthority::new());
+
+        let xauthority = Xauthority::from(vec![entry(b"host", b"1", b"name", b"data")]);
+        xauthority.save(&path).unwrap();
+        assert_eq!(Xauthority::load(&path).unwrap(), xauthority);
+
+        let removed = Xauthority::modify(&path, |xauthority| {
+            xauthority.add(entry(b"host", b"2", b"name", b"data"));
+            Ok(xauthority.remove(Family::LOCAL, b"host", b"1"))
+        })
+        .unwrap();
+        assert_eq!(removed, 1);
+        let entries = Vec::from(Xauthority::load(&path).unwrap());
+        assert_eq!(entries, [entry(b"host", b"2", b"name", b"data")]);
+
+        // Only the file itself is left behind
+        let dir = path.parent().unwrap();
+        assert_eq!(std::fs::read_dir(dir).unwrap().count(), 1);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn lock_excludes() {
+        let path = temp_path("lock");
+        let lock = XauthorityLock::acquire(&path).unwrap();
+        let dead_time = Duration::from_secs(600);
+        let err = XauthorityLock::acquire_with(&path, 2, Duration::from_millis(1), dead_time)
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::TimedOut);
+
+        // The failed attempt did not remove the lock files
+        let dir = path.parent().unwrap();
+        assert_eq!(std::fs::read_dir(dir).unwrap().count(), 2);
+        drop(lock);
+        assert_eq!(std::fs::read_dir(dir).unwrap().count(), 0);
+
+        let _lock = XauthorityLock::acquire(&path).unwrap();
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn stale_lock_is_removed() {
+        let path = temp_path("stale");
+        let lock = XauthorityLock::acquire(&path).unwrap();
+        // A dead time of zero forces removal of existing lock files, as in libXau
+        let lock2 =
+            XauthorityLock::acquire_with(&path, 1, Duration::from_secs(0), Duration::from_secs(0))
+                .unwrap();
+        drop(lock2);
+        drop(lock);
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
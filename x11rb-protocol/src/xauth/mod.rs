@@ -0,0 +1,386 @@
+//! Helpers for working with `~/.Xauthority`.
+//!
+//! [`get_auth`] and [`get_auth_for_client`] look up the authorization information for connecting
+//! to an X11 server. [`Xauthority`] allows to read, change and write `.Xauthority` files.
+
+#![cfg(feature = "std")]
+
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use std::io::Error;
+use std::net::SocketAddr;
+
+use crate::protocol::xproto::Family as X11Family;
+
+const MIT_MAGIC_COOKIE_1: &[u8] = b"MIT-MAGIC-COOKIE-1";
+const XDM_AUTHORIZATION_1: &[u8] = b"XDM-AUTHORIZATION-1";
+
+/// A family describes how to interpret some bytes as an address in an `AuthEntry`.
+///
+/// Compared to [`super::protocol::xproto::Family`], this is a `u16` and not an `u8` since
+/// that's what is used in `~/.Xauthority` files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Family(u16);
+
+impl Family {
+    /// IPv4 connection to the server
+    pub const INTERNET: Self = Self(0);
+    /// DECnet
+    pub const DEC_NET: Self = Self(1);
+    /// Chaosnet connection
+    pub const CHAOS: Self = Self(2);
+    /// Family without predefined meaning, but interpreted by the server, for example a user name
+    pub const SERVER_INTERPRETED: Self = Self(5);
+    /// IPv6 connection to the server
+    pub const INTERNET6: Self = Self(6);
+    /// Wildcard matching any protocol family
+    pub const WILD: Self = Self(65535);
+    /// For local non-net authentication
+    pub const LOCAL: Self = Self(256);
+    /// TODO: No idea what this means exactly
+    pub const NETNAME: Self = Self(254);
+    /// Kerberos 5 principal name
+    pub const KRB5_PRINCIPAL: Self = Self(253);
+    /// For local non-net authentication
+    pub const LOCAL_HOST: Self = Self(252);
+}
+
+impl From<X11Family> for Family {
+    fn from(value: X11Family) -> Self {
+        Self(value.into())
+    }
+}
+
+impl From<u16> for Family {
+    fn from(value: u16) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Family> for u16 {
+    fn from(value: Family) -> Self {
+        value.0
+    }
+}
+
+/// A single entry of an `.Xauthority` file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthEntry {
+    /// The protocol family to which the entry applies
+    pub family: Family,
+    /// The address of the peer in a family-specific format
+    pub address: Vec<u8>,
+    /// The display number as a decimal string, or empty to match all displays
+    pub number: Vec<u8>,
+    /// The name of the authentication method to use for the X11 server described by the previous
+    /// fields.
+    pub name: Vec<u8>,
+    /// Extra data for the authentication method.
+    pub data: Vec<u8>,
+}
+
+impl AuthEntry {
+    /// Check if this entry applies to the given address and display.
+    ///
+    /// [`Family::WILD`] matches any address, both in the entry and in the arguments. An empty
+    /// display number in the entry matches any display.
+    pub fn matches(&self, family: Family, address: &[u8], display: u16) -> bool {
+        let address_matches = if family == Family::WILD || self.family == Family::WILD {
+            true
+        } else if family != self.family {
+            false
+        } else {
+            address == &self.address[..]
+        };
+        let display_matches =
+            self.number.is_empty() || self.number == display.to_string().as_bytes();
+        address_matches && display_matches
+    }
+}
+
+mod des;
+mod file;
+mod xdm_authorization;
+
+pub use file::{Xauthority, XauthorityLock};
+
+/// The length of cookies generated by [`generate_cookie`].
+const COOKIE_LENGTH: usize = 16;
+
+/// Generate a random cookie for MIT-MAGIC-COOKIE-1.
+///
+/// The cookie consists of 16 bytes from the random number generator of the operating system. This
+/// is currently only implemented on unix, where `/dev/urandom` is used.
+pub fn generate_cookie() -> Result<Vec<u8>, Error> {
+    #[cfg(unix)]
+    {
+        use std::io::Read;
+
+        let mut cookie = alloc::vec![0; COOKIE_LENGTH];
+        std::fs::File::open("/dev/urandom")?.read_exact(&mut cookie)?;
+        Ok(cookie)
+    }
+    #[cfg(not(unix))]
+    {
+        Err(Error::new(
+            std::io::ErrorKind::Other,
+            "Generating cookies is not supported on this platform",
+        ))
+    }
+}
+
+pub(crate) type AuthInfo = (Vec<u8>, Vec<u8>);
+
+/// Get the authentication information necessary for connecting to the given display.
+///
+/// - `family` is the protocol family that is used for connecting; this describes how to interpret
+///   the `address`.
+/// - `address` is the raw bytes describing the address that is being connected to.
+/// - `display` is the display number.
+///
+/// If successful, this function returns that can be written to the X11 server as authorization
+/// protocol name and data, respectively.
+///
+/// Only MIT-MAGIC-COOKIE-1 is supported by this function. See [`get_auth_for_client`] for
+/// XDM-AUTHORIZATION-1.
+pub fn get_auth(family: Family, address: &[u8], display: u16) -> Result<Option<AuthInfo>, Error> {
+    match file::XAuthorityEntries::new()? {
+        None => Ok(None),
+        Some(entries) => get_auth_impl(entries, family, address, display, |_| None),
+    }
+}
+
+/// Get the authentication information necessary for connecting to the given display from the
+/// given client address.
+///
+/// This works like [`get_auth`], but also supports XDM-AUTHORIZATION-1. Its authenticator contains
+/// the address of the client's end of the connection, which is given as `client_address`. For
+/// Unix domain sockets, `client_address` is `None`.
+///
+/// The supported methods are selected in the following order, which is the same as in libxcb:
+///
+/// 1. XDM-AUTHORIZATION-1
+/// 2. MIT-MAGIC-COOKIE-1
+///
+/// Matching entries for a method that cannot be used (for example, XDM-AUTHORIZATION-1 entries
+/// with data of the wrong length) are skipped.
+pub fn get_auth_for_client(
+    family: Family,
+    address: &[u8],
+    display: u16,
+    client_address: Option<SocketAddr>,
+) -> Result<Option<AuthInfo>, Error> {
+    match file::XAuthorityEntries::new()? {
+        None => Ok(None),
+        Some(entries) => get_auth_impl(entries, family, address, display, |data| {
+            xdm_authorization::authenticator_now(data, client_address)
+        }),
+    }
+}
+
+/// Find the best authentication method for the given display in `entries`.
+///
+/// `xdm_authenticator` computes the authenticator for XDM-AUTHORIZATION-1 from the data of an
+/// entry. If it returns `None`, the entry is skipped.
+fn get_auth_impl(
+    entries: impl Iterator<Item = Result<AuthEntry, Error>>,
+    family: Family,
+    address: &[u8],
+    display: u16,
+    xdm_authenticator: impl Fn(&[u8]) -> Option<Vec<u8>>,
+) -> Result<Option<AuthInfo>, Error> {
+    // The first matching MIT-MAGIC-COOKIE-1 entry, used if no XDM-AUTHORIZATION-1 entry is found
+    let mut mit_magic_cookie = None;
+    for entry in entries {
+        let entry = entry?;
+
+        if !entry.matches(family, address, display) {
+            continue;
+        }
+        if entry.name == XDM_AUTHORIZATION_1 {
+            if let Some(data) = xdm_authenticator(&entry.data) {
+                // This is the most preferred method, so there is no need to look further
+                return Ok(Some((entry.name, data)));
+            }
+        } else if entry.name == MIT_MAGIC_COOKIE_1 && mit_magic_cookie.is_none() {
+            mit_magic_cookie = Some((entry.name, entry.data));
+        }
+    }
+    Ok(mit_magic_cookie)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{get_auth_impl, AuthEntry, Family, MIT_MAGIC_COOKIE_1, XDM_AUTHORIZATION_1};
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    // Call the given function on a matching auth entry. The function can change the entry.
+    // Afterwards, it should still be a match.
+    fn expect_match<F>(f: F)
+    where
+        F: FnOnce(&mut AuthEntry),
+    {
+        let mut entry = AuthEntry {
+            family: Family::LOCAL,
+            address: b"whatever".to_vec(),
+            number: b"42".to_vec(),
+            name: MIT_MAGIC_COOKIE_1.to_vec(),
+            data: b"1234".to_vec(),
+        };
+        f(&mut entry);
+        let entries = vec![Ok(entry)];
+        assert_eq!(
+            get_auth_impl(entries.into_iter(), Family::LOCAL, b"whatever", 42, |_| {
+                None
+            })
+            .unwrap()
+            .unwrap(),
+            (MIT_MAGIC_COOKIE_1.to_vec(), b"1234".to_vec())
+        );
+    }
+
+    // Call the given function on a matching auth entry. The function can change the entry.
+    // Afterwards, it should no longer match.
+    fn expect_mismatch<F>(f: F)
+    where
+        F: FnOnce(&mut AuthEntry),
+    {
+        let mut entry = AuthEntry {
+            family: Family::LOCAL,
+            address: b"whatever".to_vec(),
+            number: b"42".to_vec(),
+            name: MIT_MAGIC_COOKIE_1.to_vec(),
+            data: b"1234".to_vec(),
+        };
+        f(&mut entry);
+        let entries = vec![Ok(entry)];
+        assert_eq!(
+            get_auth_impl(entries.into_iter(), Family::LOCAL, b"whatever", 42, |_| {
+                None
+            })
+            .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn direct_match() {
+        // This checks that an auth entry where all members match, really matches
+        expect_match(|_| {});
+    }
+
+    #[test]
+    fn display_wildcard() {
+        expect_match(|entry| entry.number = vec![]);
+    }
+
+    #[test]
+    fn address_wildcard_match1() {
+        expect_match(|entry| entry.family = Family::WILD);
+    }
+
+    #[test]
+    fn address_wildcard_match2() {
+        let entry = AuthEntry {
+            family: Family::LOCAL,
+            address: b"whatever".to_vec(),
+            number: b"42".to_vec(),
+            name: MIT_MAGIC_COOKIE_1.to_vec(),
+            data: b"1234".to_vec(),
+        };
+        let entries = vec![Ok(entry)];
+        assert_eq!(
+            get_auth_impl(entries.into_iter(), Family::WILD, &[], 42, |_| None)
+                .unwrap()
+                .unwrap(),
+            (MIT_MAGIC_COOKIE_1.to_vec(), b"1234".to_vec())
+        );
+    }
+
+    #[test]
+    fn family_mismatch() {
+        expect_mismatch(|entry| entry.family = Family::KRB5_PRINCIPAL);
+    }
+
+    #[test]
+    fn address_mismatch() {
+        expect_mismatch(|entry| entry.address = b"something else".to_vec());
+    }
+
+    #[test]
+    fn number_mismatch() {
+        expect_mismatch(|entry| entry.number = b"1337".to_vec());
+    }
+
+    #[test]
+    fn protocol_mismatch() {
+        expect_mismatch(|entry| entry.name = b"SUN-DES-1".to_vec());
+    }
+
+    #[test]
+    fn xdm_authorization_unavailable() {
+        // XDM-AUTHORIZATION-1 entries are ignored when no authenticator can be computed
+        expect_mismatch(|entry| entry.name = XDM_AUTHORIZATION_1.to_vec());
+    }
+
+    fn entry(name: &[u8], data: &[u8]) -> AuthEntry {
+        AuthEntry {
+            family: Family::LOCAL,
+            address: b"whatever".to_vec(),
+            number: b"42".to_vec(),
+            name: name.to_vec(),
+            data: data.to_vec(),
+        }
+    }
+
+    fn xdm_authenticator(data: &[u8]) -> Option<Vec<u8>> {
+        if data == b"xdm" {
+            Some(b"authenticator".to_vec())
+        } else {
+            None
+        }
+    }
+
+    #[test]
+    fn xdm_authorization_preferred() {
+        let entries = vec![
+            Ok(entry(MIT_MAGIC_COOKIE_1, b"1234")),
+            Ok(entry(XDM_AUTHORIZATION_1, b"xdm")),
+        ];
+        assert_eq!(
+            get_auth_impl(
+                entries.into_iter(),
+                Family::LOCAL,
+                b"whatever",
+                42,
+                xdm_authenticator
+            )
+            .unwrap()
+            .unwrap(),
+            (XDM_AUTHORIZATION_1.to_vec(), b"authenticator".to_vec())
+        );
+    }
+
+    #[test]
+    fn xdm_authorization_fallback() {
+        // The XDM-AUTHORIZATION-1 entry cannot be used, so the first cookie is used instead
+        let entries = vec![
+            Ok(entry(XDM_AUTHORIZATION_1, b"invalid")),
+            Ok(entry(MIT_MAGIC_COOKIE_1, b"1234")),
+            Ok(entry(MIT_MAGIC_COOKIE_1, b"5678")),
+        ];
+        assert_eq!(
+            get_auth_impl(
+                entries.into_iter(),
+                Family::LOCAL,
+                b"whatever",
+                42,
+                xdm_authenticator
+            )
+            .unwrap()
+            .unwrap(),
+            (MIT_MAGIC_COOKIE_1.to_vec(), b"1234".to_vec())
+        );
+    }
+}
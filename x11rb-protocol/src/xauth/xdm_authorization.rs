@@ -0,0 +1,166 @@
+//! Computing the authenticator of XDM-AUTHORIZATION-1.
+//!
+//! The authorization data of an XDM-AUTHORIZATION-1 entry consists of 8 bytes of
+//! authentication data and a 56 bit DES key ("wrapper"). The client sends the authentication
+//! data together with its own address and the current time, encrypted with the key.
+
+use alloc::vec::Vec;
+use core::convert::TryInto;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use super::des::encrypt_block;
+
+/// The length of the authorization data of an XDM-AUTHORIZATION-1 entry.
+const ENTRY_DATA_LENGTH: usize = 16;
+
+/// The length of the authenticator that is sent to the server.
+const AUTHENTICATOR_LENGTH: usize = 24;
+
+/// Used to generate unique fake addresses for Unix domain sockets.
+static NONCE: AtomicU32 = AtomicU32::new(0);
+
+/// Turn the 56 bit key of an XDM-AUTHORIZATION-1 entry into a DES key.
+///
+/// The key is stored in the last seven bytes of `wrapper`; the first byte is ignored. DES
+/// expects seven bits per byte.
+fn des_key(wrapper: [u8; 8]) -> [u8; 8] {
+    let bits = u64::from_be_bytes(wrapper);
+    let mut key = [0; 8];
+    for (i, byte) in key.iter_mut().enumerate() {
+        // The lowest bit is a parity bit that DES ignores
+        *byte = (((bits >> (49 - 7 * i)) & 0x7f) as u8) << 1;
+    }
+    key
+}
+
+/// Encrypt `data` with DES in CBC mode with a zero initialisation vector.
+///
+/// This is what `XdmcpWrap()` from libXdmcp does. The length of `data` must be a multiple of
+/// eight.
+pub(super) fn wrap(data: &mut [u8], wrapper: [u8; 8]) {
+    let key = des_key(wrapper);
+    let mut previous = [0; 8];
+    for chunk in data.chunks_exact_mut(8) {
+        let mut block = [0; 8];
+        for (block, (data, previous)) in block.iter_mut().zip(chunk.iter().zip(&previous)) {
+            *block = data ^ previous;
+        }
+        previous = encrypt_block(key, block);
+        chunk.copy_from_slice(&previous);
+    }
+}
+
+/// Compute the authenticator for the given entry data, client address and port, and time.
+///
+/// The address and the port are in network byte order. `None` is returned if `entry_data`
+/// does not have the expected length.
+pub(super) fn authenticator(
+    entry_data: &[u8],
+    address: [u8; 4],
+    port: [u8; 2],
+    time: u32,
+) -> Option<Vec<u8>> {
+    if entry_data.len() != ENTRY_DATA_LENGTH {
+        return None;
+    }
+    let mut result = Vec::with_capacity(AUTHENTICATOR_LENGTH);
+    result.extend_from_slice(&entry_data[..8]);
+    result.extend_from_slice(&address);
+    result.extend_from_slice(&port);
+    result.extend_from_slice(&time.to_be_bytes());
+    result.resize(AUTHENTICATOR_LENGTH, 0);
+    wrap(&mut result, entry_data[8..].try_into().unwrap());
+    Some(result)
+}
+
+/// Compute the authenticator for a connection from `client_address` at the current time.
+///
+/// XDM-AUTHORIZATION-1 only knows about IPv4. Like libxcb, a unique fake address is used for
+/// Unix domain sockets (`client_address` is `None`) and an all-zero address is used for IPv6.
+pub(super) fn authenticator_now(
+    entry_data: &[u8],
+    client_address: Option<SocketAddr>,
+) -> Option<Vec<u8>> {
+    let (address, port) = match client_address {
+        Some(SocketAddr::V4(addr)) => (addr.ip().octets(), addr.port()),
+        Some(SocketAddr::V6(addr)) => {
+            let octets = addr.ip().octets();
+            if octets[..10].iter().all(|&b| b == 0) && octets[10..12] == [0xff, 0xff] {
+                // IPv4-mapped address
+                (octets[12..].try_into().unwrap(), addr.port())
+            } else {
+                ([0; 4], 0)
+            }
+        }
+        None => {
+            let nonce = NONCE.fetch_add(1, Ordering::Relaxed).wrapping_add(1);
+            // Only the lower 16 bits of the process ID fit into the port
+            let pid = std::process::id() as u16;
+            ((u32::MAX - nonce).to_be_bytes(), pid)
+        }
+    };
+    let time = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |time| time.as_secs() as u32);
+    authenticator(entry_data, address, port.to_be_bytes(), time)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{authenticator, wrap};
+
+    // The expected values were computed with XdmcpWrap() from libXdmcp
+
+    #[test]
+    fn wrap_zero() {
+        let mut data = [0; 8];
+        wrap(&mut data, [0; 8]);
+        assert_eq!(data, [0x8c, 0xa6, 0x4d, 0xe9, 0xc1, 0xb1, 0x23, 0xa7]);
+    }
+
+    #[test]
+    fn wrap_chained() {
+        let mut data = [0; 24];
+        for (i, byte) in data.iter_mut().enumerate() {
+            *byte = i as u8;
+        }
+        wrap(&mut data, [0xde, 0xad, 0xbe, 0xef, 0x01, 0x23, 0x45, 0x67]);
+        assert_eq!(
+            data,
+            [
+                0xdd, 0x51, 0x0f, 0x58, 0xba, 0x69, 0x3d, 0x8e, 0x84, 0x4e, 0x63, 0x26, 0x10, 0x9c,
+                0x07, 0x0d, 0x10, 0xbf, 0x6b, 0x79, 0xd4, 0x11, 0x6c, 0x17
+            ]
+        );
+    }
+
+    #[test]
+    fn wrap_ignores_first_key_byte() {
+        let (mut data1, mut data2) = ([1; 8], [1; 8]);
+        wrap(&mut data1, [0x00, 1, 2, 3, 4, 5, 6, 7]);
+        wrap(&mut data2, [0xff, 1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!(data1, data2);
+    }
+
+    #[test]
+    fn authenticator_layout() {
+        let entry_data = [
+            1, 2, 3, 4, 5, 6, 7, 8, 0xde, 0xad, 0xbe, 0xef, 0x01, 0x23, 0x45, 0x67,
+        ];
+        let result = authenticator(&entry_data, [192, 168, 1, 2], [0x17, 0x70], 0x1234_5678);
+        assert_eq!(
+            result.unwrap(),
+            [
+                0x38, 0xc5, 0x4d, 0x29, 0x72, 0x05, 0x98, 0x7e, 0x96, 0x5a, 0x91, 0x15, 0x46, 0xb0,
+                0xf0, 0x06, 0x68, 0xf6, 0xb9, 0xcd, 0x42, 0xff, 0x0e, 0x18
+            ]
+        );
+    }
+
+    #[test]
+    fn authenticator_wrong_length() {
+        assert_eq!(authenticator(&[0; 8], [0; 4], [0; 2], 0), None);
+    }
+}
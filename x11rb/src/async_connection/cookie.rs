@@ -0,0 +1,256 @@
+//! Cookies for asynchronous connections.
+//!
+//! These are the asynchronous counterparts of the cookies in [`crate::cookie`]. The methods for
+//! getting replies return futures. Dropping such a future before it completed has the same effect
+//! as dropping the cookie.
+
+use std::marker::PhantomData;
+
+use super::AsyncRequestConnection;
+use crate::connection::{BufWithFds, RequestKind};
+use crate::errors::{ConnectionError, ReplyError};
+use crate::x11_utils::{TryParse, TryParseFd};
+
+use x11rb_protocol::{DiscardMode, SequenceNumber};
+
+/// A handle to a possible error from the X11 server.
+///
+/// This is the asynchronous counterpart of [`crate::cookie::VoidCookie`].
+#[derive(Debug)]
+pub struct AsyncVoidCookie<'a, C>
+where
+    C: AsyncRequestConnection + ?Sized,
+{
+    connection: &'a C,
+    sequence_number: SequenceNumber,
+}
+
+impl<'a, C> AsyncVoidCookie<'a, C>
+where
+    C: AsyncRequestConnection + ?Sized,
+{
+    /// Construct a new cookie.
+    ///
+    /// This function should only be used by implementations of
+    /// `AsyncRequestConnection::send_request_without_reply`.
+    pub fn new(connection: &C, sequence_number: SequenceNumber) -> AsyncVoidCookie<'_, C> {
+        AsyncVoidCookie {
+            connection,
+            sequence_number,
+        }
+    }
+
+    /// Get the sequence number of the request that generated this cookie.
+    pub fn sequence_number(&self) -> SequenceNumber {
+        self.sequence_number
+    }
+
+    fn consume(self) -> (&'a C, SequenceNumber) {
+        let result = (self.connection, self.sequence_number);
+        std::mem::forget(self);
+        result
+    }
+
+    /// Check if the original request caused an X11 error.
+    pub async fn check(self) -> Result<(), ReplyError> {
+        let result = self.connection.check_for_error(self.sequence_number).await;
+        let _ = self.consume();
+        result
+    }
+
+    /// Ignore all errors to this request.
+    ///
+    /// Without calling this method, an error becomes available on the connection as an event after
+    /// this cookie was dropped. This function causes errors to be ignored instead.
+    pub fn ignore_error(self) {
+        let (connection, sequence) = self.consume();
+        connection.discard_reply(
+            sequence,
+            RequestKind::IsVoid,
+            DiscardMode::DiscardReplyAndError,
+        )
+    }
+}
+
+impl<C> Drop for AsyncVoidCookie<'_, C>
+where
+    C: AsyncRequestConnection + ?Sized,
+{
+    fn drop(&mut self) {
+        self.connection.discard_reply(
+            self.sequence_number,
+            RequestKind::IsVoid,
+            DiscardMode::DiscardReply,
+        )
+    }
+}
+
+/// Internal helper for a cookie with an response
+#[derive(Debug)]
+struct RawAsyncCookie<'a, C>
+where
+    C: AsyncRequestConnection + ?Sized,
+{
+    connection: &'a C,
+    sequence_number: SequenceNumber,
+}
+
+impl<C> RawAsyncCookie<'_, C>
+where
+    C: AsyncRequestConnection + ?Sized,
+{
+    /// Consume this instance and get the contained sequence number out.
+    fn into_sequence_number(self) -> SequenceNumber {
+        let number = self.sequence_number;
+        // Prevent drop() from running
+        std::mem::forget(self);
+        number
+    }
+}
+
+impl<C> Drop for RawAsyncCookie<'_, C>
+where
+    C: AsyncRequestConnection + ?Sized,
+{
+    fn drop(&mut self) {
+        self.connection.discard_reply(
+            self.sequence_number,
+            RequestKind::HasResponse,
+            DiscardMode::DiscardReply,
+        );
+    }
+}
+
+/// A handle to a response from the X11 server.
+///
+/// This is the asynchronous counterpart of [`crate::cookie::Cookie`].
+#[derive(Debug)]
+pub struct AsyncCookie<'a, C, R>
+where
+    C: AsyncRequestConnection + ?Sized,
+{
+    raw_cookie: RawAsyncCookie<'a, C>,
+    phantom: PhantomData<R>,
+}
+
+impl<C, R> AsyncCookie<'_, C, R>
+where
+    R: TryParse,
+    C: AsyncRequestConnection + ?Sized,
+{
+    /// Construct a new cookie.
+    ///
+    /// This function should only be used by implementations of
+    /// `AsyncRequestConnection::send_request_with_reply`.
+    pub fn new(connection: &C, sequence_number: SequenceNumber) -> AsyncCookie<'_, C, R> {
+        AsyncCookie {
+            raw_cookie: RawAsyncCookie {
+                connection,
+                sequence_number,
+            },
+            phantom: PhantomData,
+        }
+    }
+
+    /// Get the sequence number of the request that generated this cookie.
+    pub fn sequence_number(&self) -> SequenceNumber {
+        self.raw_cookie.sequence_number
+    }
+
+    /// Get the raw reply that the server sent.
+    pub async fn raw_reply(self) -> Result<C::Buf, ReplyError> {
+        let conn = self.raw_cookie.connection;
+        let result = conn
+            .wait_for_reply_or_error(self.raw_cookie.sequence_number)
+            .await;
+        let _ = self.raw_cookie.into_sequence_number();
+        result
+    }
+
+    /// Get the raw reply that the server sent, but have errors handled as events.
+    pub async fn raw_reply_unchecked(self) -> Result<Option<C::Buf>, ConnectionError> {
+        let conn = self.raw_cookie.connection;
+        let result = conn.wait_for_reply(self.raw_cookie.sequence_number).await;
+        let _ = self.raw_cookie.into_sequence_number();
+        result
+    }
+
+    /// Get the reply that the server sent.
+    pub async fn reply(self) -> Result<R, ReplyError> {
+        Ok(R::try_parse(self.raw_reply().await?.as_ref())?.0)
+    }
+
+    /// Get the reply that the server sent, but have errors handled as events.
+    pub async fn reply_unchecked(self) -> Result<Option<R>, ConnectionError> {
+        self.raw_reply_unchecked()
+            .await?
+            .map(|buf| R::try_parse(buf.as_ref()).map(|r| r.0))
+            .transpose()
+            .map_err(Into::into)
+    }
+
+    /// Discard all responses to the request this cookie represents, even errors.
+    ///
+    /// Without this function, errors are treated as events after the cookie is dropped.
+    pub fn discard_reply_and_errors(self) {
+        let conn = self.raw_cookie.connection;
+        conn.discard_reply(
+            self.raw_cookie.into_sequence_number(),
+            RequestKind::HasResponse,
+            DiscardMode::DiscardReplyAndError,
+        )
+    }
+}
+
+/// A handle to a response containing `RawFd` from the X11 server.
+///
+/// This is the asynchronous counterpart of [`crate::cookie::CookieWithFds`].
+#[derive(Debug)]
+pub struct AsyncCookieWithFds<'a, C, R>
+where
+    C: AsyncRequestConnection + ?Sized,
+{
+    raw_cookie: RawAsyncCookie<'a, C>,
+    phantom: PhantomData<R>,
+}
+
+impl<C, R> AsyncCookieWithFds<'_, C, R>
+where
+    R: TryParseFd,
+    C: AsyncRequestConnection + ?Sized,
+{
+    /// Construct a new cookie.
+    ///
+    /// This function should only be used by implementations of
+    /// `AsyncRequestConnection::send_request_with_reply_with_fds`.
+    pub fn new(connection: &C, sequence_number: SequenceNumber) -> AsyncCookieWithFds<'_, C, R> {
+        AsyncCookieWithFds {
+            raw_cookie: RawAsyncCookie {
+                connection,
+                sequence_number,
+            },
+            phantom: PhantomData,
+        }
+    }
+
+    /// Get the sequence number of the request that generated this cookie.
+    pub fn sequence_number(&self) -> SequenceNumber {
+        self.raw_cookie.sequence_number
+    }
+
+    /// Get the raw reply that the server sent.
+    pub async fn raw_reply(self) -> Result<BufWithFds<C::Buf>, ReplyError> {
+        let conn = self.raw_cookie.connection;
+        let result = conn
+            .wait_for_reply_with_fds(self.raw_cookie.sequence_number)
+            .await;
+        let _ = self.raw_cookie.into_sequence_number();
+        result
+    }
+
+    /// Get the reply that the server sent.
+    pub async fn reply(self) -> Result<R, ReplyError> {
+        let (buffer, mut fds) = self.raw_reply().await?;
+        Ok(R::try_parse_fd(buffer.as_ref(), &mut fds)?.0)
+    }
+}
@@ -0,0 +1,387 @@
+//! Asynchronous connections to an X11 server.
+//!
+//! The [`RequestConnection`](crate::connection::RequestConnection) and
+//! [`Connection`](crate::connection::Connection) traits block the calling thread while waiting
+//! for replies and events. This module contains the asynchronous counterparts
+//! [`AsyncRequestConnection`] and [`AsyncConnection`]. Their methods return futures that can be
+//! awaited on any executor.
+//!
+//! [`AsyncRustConnection`] implements these traits on top of the sans-I/O state machine from
+//! [`x11rb_protocol::connection`]. It communicates with the X11 server through an
+//! [`AsyncStream`], which has to be provided by the user, since this depends on the executor
+//! that is used.
+//!
+//! The generated `ConnectionExt` traits only work with blocking connections. Requests can be sent
+//! on an asynchronous connection by giving the request structures from the protocol modules to
+//! [`AsyncRequestConnection::send_trait_request_with_reply`] and friends:
+//! ```no_run
+//! use x11rb::async_connection::{AsyncConnection, AsyncRequestConnection};
+//! use x11rb::errors::ReplyError;
+//! use x11rb::protocol::xproto::GetInputFocusRequest;
+//!
+//! async fn print_focus(conn: &impl AsyncConnection) -> Result<(), ReplyError> {
+//!     let cookie = conn.send_trait_request_with_reply(GetInputFocusRequest).await?;
+//!     println!("{:?}", cookie.reply().await?);
+//!     Ok(())
+//! }
+//! ```
+
+use std::future::Future;
+use std::io::IoSlice;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use x11rb_protocol::x11_utils::{ReplyFDsRequest, ReplyRequest, VoidRequest};
+
+use crate::connection::{BufWithFds, EventAndSeqNumber, ReplyOrError, RequestKind};
+use crate::errors::{ConnectionError, ParseError, ReplyError, ReplyOrIdError};
+use crate::protocol::xproto::Setup;
+use crate::protocol::Event;
+use crate::utils::RawFdContainer;
+use crate::x11_utils::{ExtensionInformation, TryParse, TryParseFd, X11Error};
+
+pub use x11rb_protocol::{DiscardMode, RawEventAndSeqNumber, SequenceNumber};
+
+mod cookie;
+mod rust_connection;
+mod stream;
+
+pub use cookie::{AsyncCookie, AsyncCookieWithFds, AsyncVoidCookie};
+pub use rust_connection::AsyncRustConnection;
+pub use stream::AsyncStream;
+
+/// A boxed future as returned by the methods of [`AsyncRequestConnection`] and
+/// [`AsyncConnection`].
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// An asynchronous connection to an X11 server for sending requests.
+///
+/// This is the asynchronous counterpart of
+/// [`RequestConnection`](crate::connection::RequestConnection). See there for the documentation
+/// of the individual methods. All methods that might have to wait for the X11 server return a
+/// future instead of blocking.
+pub trait AsyncRequestConnection: Sync {
+    /// Type used as buffer to store raw replies or events before
+    /// they are parsed.
+    type Buf: AsRef<[u8]> + std::fmt::Debug + Send + Sync + 'static;
+
+    /// Send a request with a reply to the server.
+    ///
+    /// See [`RequestConnection::send_request_with_reply`](crate::connection::RequestConnection::send_request_with_reply).
+    fn send_request_with_reply<'this, 'bufs, R>(
+        &'this self,
+        bufs: &'bufs [IoSlice<'bufs>],
+        fds: Vec<RawFdContainer>,
+    ) -> BoxFuture<'bufs, Result<AsyncCookie<'this, Self, R>, ConnectionError>>
+    where
+        'this: 'bufs,
+        R: TryParse + Send;
+
+    /// Send a request with a reply to the server.
+    ///
+    /// This function is a wrapper around [`AsyncRequestConnection::send_request_with_reply`].
+    /// This function gets a [`ReplyRequest`] as its argument to specify the request to send.
+    fn send_trait_request_with_reply<'this, R>(
+        &'this self,
+        request: R,
+    ) -> BoxFuture<'this, Result<AsyncCookie<'this, Self, R::Reply>, ConnectionError>>
+    where
+        R: ReplyRequest + Send + 'this,
+        R::Reply: Send,
+    {
+        Box::pin(async move {
+            let opcode = self.major_opcode(R::EXTENSION_NAME).await?;
+            let (buf, fds) = request.serialize(opcode);
+            self.send_request_with_reply(&[IoSlice::new(&buf)], fds)
+                .await
+        })
+    }
+
+    /// Send a request with a reply containing file descriptors to the server.
+    ///
+    /// See [`RequestConnection::send_request_with_reply_with_fds`](crate::connection::RequestConnection::send_request_with_reply_with_fds).
+    fn send_request_with_reply_with_fds<'this, 'bufs, R>(
+        &'this self,
+        bufs: &'bufs [IoSlice<'bufs>],
+        fds: Vec<RawFdContainer>,
+    ) -> BoxFuture<'bufs, Result<AsyncCookieWithFds<'this, Self, R>, ConnectionError>>
+    where
+        'this: 'bufs,
+        R: TryParseFd + Send;
+
+    /// Send a request with a reply containing file descriptors to the server.
+    ///
+    /// This function is a wrapper around
+    /// [`AsyncRequestConnection::send_request_with_reply_with_fds`]. This function gets a
+    /// [`ReplyFDsRequest`] as its argument to specify the request to send.
+    fn send_trait_request_with_reply_with_fds<'this, R>(
+        &'this self,
+        request: R,
+    ) -> BoxFuture<'this, Result<AsyncCookieWithFds<'this, Self, R::Reply>, ConnectionError>>
+    where
+        R: ReplyFDsRequest + Send + 'this,
+        R::Reply: Send,
+    {
+        Box::pin(async move {
+            let opcode = self.major_opcode(R::EXTENSION_NAME).await?;
+            let (buf, fds) = request.serialize(opcode);
+            self.send_request_with_reply_with_fds(&[IoSlice::new(&buf)], fds)
+                .await
+        })
+    }
+
+    /// Send a request without a reply to the server.
+    ///
+    /// See [`RequestConnection::send_request_without_reply`](crate::connection::RequestConnection::send_request_without_reply).
+    fn send_request_without_reply<'this, 'bufs>(
+        &'this self,
+        bufs: &'bufs [IoSlice<'bufs>],
+        fds: Vec<RawFdContainer>,
+    ) -> BoxFuture<'bufs, Result<AsyncVoidCookie<'this, Self>, ConnectionError>>
+    where
+        'this: 'bufs;
+
+    /// Send a request without a reply to the server.
+    ///
+    /// This function is a wrapper around [`AsyncRequestConnection::send_request_without_reply`].
+    /// This function gets a [`VoidRequest`] as its argument to specify the request to send.
+    fn send_trait_request_without_reply<'this, R>(
+        &'this self,
+        request: R,
+    ) -> BoxFuture<'this, Result<AsyncVoidCookie<'this, Self>, ConnectionError>>
+    where
+        R: VoidRequest + Send + 'this,
+    {
+        Box::pin(async move {
+            let opcode = self.major_opcode(R::EXTENSION_NAME).await?;
+            let (buf, fds) = request.serialize(opcode);
+            self.send_request_without_reply(&[IoSlice::new(&buf)], fds)
+                .await
+        })
+    }
+
+    /// Get the major opcode that should be used for a request of the given extension.
+    ///
+    /// For requests from the core protocol, `None` should be given as `extension_name`.
+    fn major_opcode<'this>(
+        &'this self,
+        extension_name: Option<&'static str>,
+    ) -> BoxFuture<'this, Result<u8, ConnectionError>> {
+        Box::pin(async move {
+            match extension_name {
+                None => Ok(0),
+                Some(extension) => Ok(self
+                    .extension_information(extension)
+                    .await?
+                    .ok_or(ConnectionError::UnsupportedExtension)?
+                    .major_opcode),
+            }
+        })
+    }
+
+    /// A reply to an error should be discarded.
+    ///
+    /// This method is automatically called by the `Drop` implementation of the cookies.
+    fn discard_reply(&self, sequence: SequenceNumber, kind: RequestKind, mode: DiscardMode);
+
+    /// Prefetches information about an extension.
+    ///
+    /// If the information of a extension is not cached yet, this function sends a
+    /// `QueryExtension` request, but it does not wait for the reply.
+    fn prefetch_extension_information(
+        &self,
+        extension_name: &'static str,
+    ) -> Result<(), ConnectionError>;
+
+    /// Get information about an extension.
+    ///
+    /// See [`RequestConnection::extension_information`](crate::connection::RequestConnection::extension_information).
+    fn extension_information(
+        &self,
+        extension_name: &'static str,
+    ) -> BoxFuture<'_, Result<Option<ExtensionInformation>, ConnectionError>>;
+
+    /// Wait for the reply to a request.
+    ///
+    /// The given sequence number identifies the request for which replies are expected. If the X11
+    /// server answered the request with an error, that error is returned as an `Err`.
+    fn wait_for_reply_or_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> BoxFuture<'_, Result<Self::Buf, ReplyError>> {
+        Box::pin(async move {
+            match self.wait_for_reply_or_raw_error(sequence).await? {
+                ReplyOrError::Reply(reply) => Ok(reply),
+                ReplyOrError::Error(error) => {
+                    Err(ReplyError::X11Error(self.parse_error(error.as_ref())?))
+                }
+            }
+        })
+    }
+
+    /// Wait for the reply to a request.
+    ///
+    /// The given sequence number identifies the request for which replies are expected. If the X11
+    /// server answered the request with an error, that error is returned as an `Err`.
+    fn wait_for_reply_or_raw_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> BoxFuture<'_, Result<ReplyOrError<Self::Buf>, ConnectionError>>;
+
+    /// Wait for the reply to a request.
+    ///
+    /// If the X11 server answered the request with an error, this function returns `None` and the
+    /// error is instead returned by `wait_for_event()` or `poll_for_event()`.
+    fn wait_for_reply(
+        &self,
+        sequence: SequenceNumber,
+    ) -> BoxFuture<'_, Result<Option<Self::Buf>, ConnectionError>>;
+
+    /// Wait for the reply to a request that has FDs.
+    fn wait_for_reply_with_fds(
+        &self,
+        sequence: SequenceNumber,
+    ) -> BoxFuture<'_, Result<BufWithFds<Self::Buf>, ReplyError>> {
+        Box::pin(async move {
+            match self.wait_for_reply_with_fds_raw(sequence).await? {
+                ReplyOrError::Reply(reply) => Ok(reply),
+                ReplyOrError::Error(error) => {
+                    Err(ReplyError::X11Error(self.parse_error(error.as_ref())?))
+                }
+            }
+        })
+    }
+
+    /// Wait for the reply to a request that has FDs.
+    #[allow(clippy::type_complexity)]
+    fn wait_for_reply_with_fds_raw(
+        &self,
+        sequence: SequenceNumber,
+    ) -> BoxFuture<'_, Result<ReplyOrError<BufWithFds<Self::Buf>, Self::Buf>, ConnectionError>>;
+
+    /// Check whether a request that does not have a reply caused an X11 error.
+    fn check_for_error(&self, sequence: SequenceNumber) -> BoxFuture<'_, Result<(), ReplyError>> {
+        Box::pin(async move {
+            match self.check_for_raw_error(sequence).await? {
+                Some(err) => Err(self.parse_error(err.as_ref())?.into()),
+                None => Ok(()),
+            }
+        })
+    }
+
+    /// Check whether a request that does not have a reply caused an X11 error.
+    fn check_for_raw_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> BoxFuture<'_, Result<Option<Self::Buf>, ConnectionError>>;
+
+    /// The maximum number of bytes that the X11 server accepts in a request.
+    ///
+    /// The first call of this function uses the BIG-REQUESTS extension to query the maximum
+    /// request length, later calls return the cached value.
+    fn maximum_request_bytes(&self) -> BoxFuture<'_, usize>;
+
+    /// Parse a generic error.
+    fn parse_error(&self, error: &[u8]) -> Result<X11Error, ParseError>;
+
+    /// Parse a generic event.
+    fn parse_event(&self, event: &[u8]) -> Result<Event, ParseError>;
+}
+
+/// An asynchronous connection to an X11 server.
+///
+/// This is the asynchronous counterpart of [`Connection`](crate::connection::Connection).
+pub trait AsyncConnection: AsyncRequestConnection {
+    /// Wait for a new event from the X11 server.
+    fn wait_for_event(&self) -> BoxFuture<'_, Result<Event, ConnectionError>> {
+        Box::pin(async move { Ok(self.wait_for_event_with_sequence().await?.0) })
+    }
+
+    /// Wait for a new raw/unparsed event from the X11 server.
+    fn wait_for_raw_event(&self) -> BoxFuture<'_, Result<Self::Buf, ConnectionError>> {
+        Box::pin(async move { Ok(self.wait_for_raw_event_with_sequence().await?.0) })
+    }
+
+    /// Wait for a new event from the X11 server.
+    fn wait_for_event_with_sequence(
+        &self,
+    ) -> BoxFuture<'_, Result<EventAndSeqNumber, ConnectionError>> {
+        Box::pin(async move {
+            let (event, seq) = self.wait_for_raw_event_with_sequence().await?;
+            let event = self.parse_event(event.as_ref())?;
+            Ok((event, seq))
+        })
+    }
+
+    /// Wait for a new raw/unparsed event from the X11 server.
+    fn wait_for_raw_event_with_sequence(
+        &self,
+    ) -> BoxFuture<'_, Result<RawEventAndSeqNumber<Self::Buf>, ConnectionError>>;
+
+    /// Poll for a new event from the X11 server.
+    ///
+    /// Only events that were already received from the X11 server are returned. This function
+    /// does not do any I/O.
+    fn poll_for_event(&self) -> Result<Option<Event>, ConnectionError> {
+        Ok(self.poll_for_event_with_sequence()?.map(|r| r.0))
+    }
+
+    /// Poll for a new raw/unparsed event from the X11 server.
+    fn poll_for_raw_event(&self) -> Result<Option<Self::Buf>, ConnectionError> {
+        Ok(self.poll_for_raw_event_with_sequence()?.map(|r| r.0))
+    }
+
+    /// Poll for a new event from the X11 server.
+    fn poll_for_event_with_sequence(&self) -> Result<Option<EventAndSeqNumber>, ConnectionError> {
+        Ok(match self.poll_for_raw_event_with_sequence()? {
+            Some((event, seq)) => Some((self.parse_event(event.as_ref())?, seq)),
+            None => None,
+        })
+    }
+
+    /// Poll for a new unparsed/raw event from the X11 server.
+    fn poll_for_raw_event_with_sequence(
+        &self,
+    ) -> Result<Option<RawEventAndSeqNumber<Self::Buf>>, ConnectionError>;
+
+    /// Send all pending requests to the server.
+    ///
+    /// Sending a request only adds it to an internal buffer. You do not have to call this method
+    /// before waiting for a reply, since waiting automatically flushes the buffer.
+    fn flush(&self) -> BoxFuture<'_, Result<(), ConnectionError>>;
+
+    /// Get the setup information sent by the X11 server.
+    fn setup(&self) -> &Setup;
+
+    /// Generate a new X11 identifier.
+    fn generate_id(&self) -> BoxFuture<'_, Result<u32, ReplyOrIdError>>;
+}
+
+/// A future that is implemented by a closure.
+///
+/// This is a replacement for `std::future::poll_fn()`, which is not available in our MSRV.
+struct PollFn<F>(F);
+
+impl<F> std::fmt::Debug for PollFn<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PollFn").finish()
+    }
+}
+
+impl<T, F> Future for PollFn<F>
+where
+    F: FnMut(&mut Context<'_>) -> Poll<T> + Unpin,
+{
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        (self.0)(cx)
+    }
+}
+
+fn poll_fn<T, F>(f: F) -> PollFn<F>
+where
+    F: FnMut(&mut Context<'_>) -> Poll<T> + Unpin,
+{
+    PollFn(f)
+}
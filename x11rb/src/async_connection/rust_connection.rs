@@ -0,0 +1,846 @@
+//! A pure-rust implementation of an asynchronous connection to an X11 server.
+
+use std::borrow::Cow;
+use std::convert::{TryFrom, TryInto};
+use std::fmt;
+use std::future::Future;
+use std::io::{Error, ErrorKind, IoSlice};
+use std::pin::Pin;
+use std::sync::Mutex;
+use std::task::{Context, Poll, Waker};
+
+use super::{
+    poll_fn, AsyncConnection, AsyncCookie, AsyncCookieWithFds, AsyncRequestConnection, AsyncStream,
+    AsyncVoidCookie, BoxFuture,
+};
+use crate::connection::{BufWithFds, ReplyOrError, RequestKind};
+use crate::errors::{ConnectError, ConnectionError, ParseError, ReplyError, ReplyOrIdError};
+use crate::extension_manager::{CheckState, ExtensionManager};
+use crate::protocol::bigreq::{self, EnableReply, EnableRequest};
+use crate::protocol::xc_misc::{self, GetXIDRangeRequest};
+use crate::protocol::xproto::GET_INPUT_FOCUS_REQUEST;
+use crate::protocol::xproto::{QueryExtensionReply, QueryExtensionRequest, Setup};
+use crate::protocol::Event;
+use crate::utils::RawFdContainer;
+use crate::x11_utils::{ExtensionInformation, Request, TryParse, TryParseFd, X11Error};
+use x11rb_protocol::connect::Connect;
+use x11rb_protocol::connection::{Connection as ProtoConnection, PollReply, ReplyFdKind};
+use x11rb_protocol::id_allocator::IdAllocator;
+use x11rb_protocol::packet_reader::PacketReader;
+use x11rb_protocol::{DiscardMode, RawEventAndSeqNumber, SequenceNumber};
+
+/// If more than this many bytes are buffered after sending a request, the buffer is flushed.
+const FLUSH_THRESHOLD: usize = 16384;
+
+type ReplyOrErrorWithFds = ReplyOrError<BufWithFds<Vec<u8>>, Vec<u8>>;
+
+#[derive(Debug)]
+enum MaxRequestBytes {
+    Unknown,
+    Requested(Option<SequenceNumber>),
+    Known(usize),
+}
+
+struct ConnectionInner {
+    inner: ProtoConnection,
+    write_buffer: Vec<u8>,
+    write_fds: Vec<RawFdContainer>,
+    read_buffer: Box<[u8]>,
+    packet_reader: PacketReader,
+    wakers: Vec<Waker>,
+}
+
+impl fmt::Debug for ConnectionInner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConnectionInner")
+            .field("inner", &self.inner)
+            .field(
+                "write_buffer",
+                &format_args!("[{} buffered bytes]", self.write_buffer.len()),
+            )
+            .field("write_fds", &self.write_fds)
+            .field("packet_reader", &self.packet_reader)
+            .field("wakers", &self.wakers.len())
+            .finish()
+    }
+}
+
+impl ConnectionInner {
+    /// Add a request to the write buffer.
+    ///
+    /// `big_length` must be set if the request is too large for the normal length field. In
+    /// this case, the request is rewritten to use the BIG-REQUESTS encoding.
+    fn buffer_request(
+        &mut self,
+        bufs: &[IoSlice<'_>],
+        fds: Vec<RawFdContainer>,
+        kind: ReplyFdKind,
+        big_length: Option<u32>,
+    ) -> SequenceNumber {
+        loop {
+            match self.inner.send_request(kind) {
+                Some(seqno) => {
+                    match big_length {
+                        None => bufs
+                            .iter()
+                            .for_each(|buf| self.write_buffer.extend_from_slice(buf)),
+                        Some(length) => {
+                            let first_buf = &bufs[0];
+                            // length field zero indicates big requests
+                            self.write_buffer.extend_from_slice(&[
+                                first_buf[0],
+                                first_buf[1],
+                                0,
+                                0,
+                            ]);
+                            self.write_buffer.extend_from_slice(&length.to_ne_bytes());
+                            self.write_buffer.extend_from_slice(&first_buf[4..]);
+                            bufs[1..]
+                                .iter()
+                                .for_each(|buf| self.write_buffer.extend_from_slice(buf));
+                        }
+                    }
+                    self.write_fds.extend(fds);
+                    return seqno;
+                }
+                None => self.buffer_sync(),
+            }
+        }
+    }
+
+    /// Add a synchronisation packet to the write buffer.
+    ///
+    /// This adds a `GetInputFocus` request and arranges for its reply to be ignored.
+    fn buffer_sync(&mut self) {
+        let length = 1u16.to_ne_bytes();
+        let request = [
+            GET_INPUT_FOCUS_REQUEST,
+            0, /* pad */
+            length[0],
+            length[1],
+        ];
+
+        let seqno = self
+            .inner
+            .send_request(ReplyFdKind::ReplyWithoutFDs)
+            .expect("Sending a HasResponse request should not be blocked by syncs");
+        self.inner
+            .discard_reply(seqno, DiscardMode::DiscardReplyAndError);
+        self.write_buffer.extend_from_slice(&request);
+    }
+
+    /// Remember that the current task wants to be woken up once new packets were read.
+    fn register_waker(&mut self, waker: &Waker) {
+        if !self.wakers.iter().any(|w| w.will_wake(waker)) {
+            self.wakers.push(waker.clone());
+        }
+    }
+
+    /// Wake up all tasks that wait for new packets.
+    fn wake_all(&mut self) {
+        self.wakers.drain(..).for_each(Waker::wake);
+    }
+}
+
+/// A future that waits for packets from an [`AsyncRustConnection`].
+///
+/// An [`AsyncStream`] only has to remember the waker of the task that polled it last. If that
+/// task stops waiting before it was woken up, nobody would be woken up once data arrives. Thus,
+/// dropping a `Waiter` that returned `Poll::Pending` wakes up all other waiting tasks, so that one
+/// of them registers itself with the stream again.
+struct Waiter<'a, S, F> {
+    conn: &'a AsyncRustConnection<S>,
+    poll: F,
+    pending: bool,
+}
+
+impl<S, F> fmt::Debug for Waiter<'_, S, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Waiter")
+            .field("pending", &self.pending)
+            .finish()
+    }
+}
+
+impl<T, S, F> Future for Waiter<'_, S, F>
+where
+    F: FnMut(&mut Context<'_>) -> Poll<T> + Unpin,
+{
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        let result = (self.poll)(cx);
+        self.pending = result.is_pending();
+        result
+    }
+}
+
+impl<S, F> Drop for Waiter<'_, S, F> {
+    fn drop(&mut self) {
+        if self.pending {
+            self.conn.inner.lock().unwrap().wake_all();
+        }
+    }
+}
+
+/// An asynchronous connection to an X11 server implemented in pure rust
+///
+/// This is the asynchronous counterpart of
+/// [`RustConnection`](crate::rust_connection::RustConnection). It is generic over `S`, which
+/// has to implement [`AsyncStream`] and is used to communicate with the server.
+///
+/// Sending a request only adds it to an internal buffer. Waiting for a reply or an event
+/// automatically flushes this buffer. Any task that waits for a reply might read packets that
+/// are meant for another task. In this case, all waiting tasks are woken up.
+#[derive(Debug)]
+pub struct AsyncRustConnection<S> {
+    inner: Mutex<ConnectionInner>,
+    stream: S,
+    setup: Setup,
+    extension_manager: Mutex<ExtensionManager>,
+    maximum_request_bytes: Mutex<MaxRequestBytes>,
+    id_allocator: Mutex<IdAllocator>,
+}
+
+// Locking rules
+// =============
+//
+// The same ordering as for `RustConnection` applies: `maximum_request_bytes`, `extension_manager`
+// and `id_allocator` may be locked when no other mutex is held. `inner` has to be locked last.
+// No mutex is ever held across an `.await`. Instead, the mutexes are only locked inside of
+// `poll`-style functions.
+
+impl<S: AsyncStream> AsyncRustConnection<S> {
+    /// Establish a new connection to the given stream.
+    ///
+    /// `screen` is the number of the screen that should be used. This function checks that a
+    /// screen with that number exists.
+    pub async fn connect_to_stream(stream: S, screen: usize) -> Result<Self, ConnectError> {
+        Self::connect_to_stream_with_auth_info(stream, screen, Vec::new(), Vec::new()).await
+    }
+
+    /// Establish a new connection to the given stream.
+    ///
+    /// `screen` is the number of the screen that should be used. This function checks that a
+    /// screen with that number exists.
+    ///
+    /// The parameters `auth_name` and `auth_data` are used for the members
+    /// `authorization_protocol_name` and `authorization_protocol_data` of the `SetupRequest` that
+    /// is sent to the X11 server.
+    pub async fn connect_to_stream_with_auth_info(
+        stream: S,
+        screen: usize,
+        auth_name: Vec<u8>,
+        auth_data: Vec<u8>,
+    ) -> Result<Self, ConnectError> {
+        let (mut connect, setup_request) = Connect::with_authorization(auth_name, auth_data);
+
+        // write the connect() setup request
+        let mut nwritten = 0;
+        let mut fds = vec![];
+        while nwritten != setup_request.len() {
+            let written =
+                poll_fn(|cx| stream.poll_write(cx, &setup_request[nwritten..], &mut fds)).await?;
+            if written == 0 {
+                return Err(
+                    Error::new(ErrorKind::WriteZero, "failed to write whole buffer").into(),
+                );
+            }
+            nwritten += written;
+        }
+
+        // read in the setup
+        loop {
+            let adv = poll_fn(|cx| stream.poll_read(cx, connect.buffer(), &mut fds)).await?;
+            if adv == 0 {
+                return Err(
+                    Error::new(ErrorKind::UnexpectedEof, "failed to read whole buffer").into(),
+                );
+            }
+
+            // advance the internal buffer
+            if connect.advance(adv) {
+                break;
+            }
+        }
+
+        // resolve the setup
+        let setup = connect.into_setup()?;
+
+        // Check that we got a valid screen number
+        if screen >= setup.roots.len() {
+            return Err(ConnectError::InvalidScreen);
+        }
+
+        // Success! Set up our state
+        Self::for_connected_stream(stream, setup)
+    }
+
+    /// Establish a new connection for an already connected stream.
+    ///
+    /// The given `stream` is used for communicating with the X11 server.
+    /// It is assumed that `setup` was just received from the server. Thus, the first reply to a
+    /// request that is sent will have sequence number one.
+    pub fn for_connected_stream(stream: S, setup: Setup) -> Result<Self, ConnectError> {
+        let id_allocator = IdAllocator::new(setup.resource_id_base, setup.resource_id_mask)?;
+
+        Ok(AsyncRustConnection {
+            inner: Mutex::new(ConnectionInner {
+                inner: ProtoConnection::new(),
+                write_buffer: Vec::new(),
+                write_fds: Vec::new(),
+                // Buffer size chosen by checking what libxcb does
+                read_buffer: vec![0; 4096].into_boxed_slice(),
+                packet_reader: PacketReader::new(),
+                wakers: Vec::new(),
+            }),
+            stream,
+            setup,
+            extension_manager: Default::default(),
+            maximum_request_bytes: Mutex::new(MaxRequestBytes::Unknown),
+            id_allocator: Mutex::new(id_allocator),
+        })
+    }
+
+    /// Returns a reference to the contained stream.
+    pub fn stream(&self) -> &S {
+        &self.stream
+    }
+
+    /// Create a future that waits for packets by calling `poll` until it is ready.
+    fn wait<T, F>(&self, poll: F) -> Waiter<'_, S, F>
+    where
+        F: FnMut(&mut Context<'_>) -> Poll<T> + Unpin,
+    {
+        Waiter {
+            conn: self,
+            poll,
+            pending: false,
+        }
+    }
+
+    /// Read as many packets as possible from the stream and enqueue them.
+    ///
+    /// Returns `Poll::Ready(Ok(()))` if anything was read. In this case, all tasks that are
+    /// waiting for packets are woken up. Otherwise, the current task is remembered as waiting.
+    fn poll_read_packets(
+        &self,
+        inner: &mut ConnectionInner,
+        cx: &mut Context<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        let mut progress = false;
+        loop {
+            let mut fds = Vec::new();
+            // if the necessary packet size is larger than our buffer, just fill straight
+            // into the buffer
+            let direct = inner.packet_reader.remaining_capacity() >= inner.read_buffer.len();
+            let result = if direct {
+                self.stream
+                    .poll_read(cx, inner.packet_reader.buffer(), &mut fds)
+            } else {
+                self.stream.poll_read(cx, &mut inner.read_buffer, &mut fds)
+            };
+            inner.inner.enqueue_fds(fds);
+            let nread = match result {
+                Poll::Pending => break,
+                Poll::Ready(Ok(0)) => {
+                    return Poll::Ready(Err(Error::new(
+                        ErrorKind::UnexpectedEof,
+                        "The X11 server closed the connection",
+                    )));
+                }
+                Poll::Ready(Ok(n)) => n,
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+            };
+            progress = true;
+
+            if direct {
+                if let Some(packet) = inner.packet_reader.advance(nread) {
+                    inner.inner.enqueue_packet(packet);
+                }
+            } else {
+                let mut src = &inner.read_buffer[..nread];
+                while !src.is_empty() {
+                    let dest = inner.packet_reader.buffer();
+                    let amt_to_read = std::cmp::min(src.len(), dest.len());
+                    dest[..amt_to_read].copy_from_slice(&src[..amt_to_read]);
+                    src = &src[amt_to_read..];
+                    if let Some(packet) = inner.packet_reader.advance(amt_to_read) {
+                        inner.inner.enqueue_packet(packet);
+                    }
+                }
+            }
+        }
+        if progress {
+            inner.wake_all();
+            Poll::Ready(Ok(()))
+        } else {
+            inner.register_waker(cx.waker());
+            Poll::Pending
+        }
+    }
+
+    /// Write the content of the write buffer to the stream.
+    fn poll_flush_inner(
+        &self,
+        inner: &mut ConnectionInner,
+        cx: &mut Context<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        while !inner.write_buffer.is_empty() {
+            match self
+                .stream
+                .poll_write(cx, &inner.write_buffer, &mut inner.write_fds)
+            {
+                Poll::Ready(Ok(0)) => {
+                    return Poll::Ready(Err(Error::new(
+                        ErrorKind::WriteZero,
+                        "failed to write anything",
+                    )));
+                }
+                Poll::Ready(Ok(n)) => {
+                    let _ = inner.write_buffer.drain(..n);
+                }
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                Poll::Pending => {
+                    // Writing would block, try to read instead because the
+                    // server might not accept new requests after its
+                    // buffered replies have been read.
+                    match self.poll_read_packets(inner, cx) {
+                        Poll::Ready(Ok(())) => {}
+                        Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                        Poll::Pending => return Poll::Pending,
+                    }
+                }
+            }
+        }
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_flush(&self, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        let mut inner = self.inner.lock().unwrap();
+        self.poll_flush_inner(&mut inner, cx)
+    }
+
+    /// Flush the write buffer and then read packets until `check` returns `Some`.
+    fn poll_wait<T>(
+        &self,
+        inner: &mut ConnectionInner,
+        cx: &mut Context<'_>,
+        mut check: impl FnMut(&mut ProtoConnection) -> Option<T>,
+    ) -> Poll<Result<T, ConnectionError>> {
+        loop {
+            let flushed = match self.poll_flush_inner(inner, cx) {
+                Poll::Ready(Ok(())) => true,
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e.into())),
+                Poll::Pending => false,
+            };
+            if let Some(result) = check(&mut inner.inner) {
+                return Poll::Ready(Ok(result));
+            }
+            if !flushed {
+                return Poll::Pending;
+            }
+            match self.poll_read_packets(inner, cx) {
+                Poll::Ready(Ok(())) => {}
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e.into())),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+
+    fn poll_wait_for_reply_with_fds(
+        &self,
+        cx: &mut Context<'_>,
+        sequence: SequenceNumber,
+    ) -> Poll<Result<ReplyOrErrorWithFds, ConnectionError>> {
+        let mut inner = self.inner.lock().unwrap();
+        self.poll_wait(&mut inner, cx, |conn| {
+            conn.poll_for_reply_or_error(sequence)
+        })
+        .map_ok(|reply| {
+            if reply.0[0] == 0 {
+                ReplyOrError::Error(reply.0)
+            } else {
+                ReplyOrError::Reply(reply)
+            }
+        })
+    }
+
+    /// Poll for the result of a `QueryExtension` request that was already sent.
+    fn poll_extension_information(
+        &self,
+        cx: &mut Context<'_>,
+        extension_name: &'static str,
+    ) -> Poll<Result<Option<ExtensionInformation>, ConnectionError>> {
+        let mut ext_mgr = self.extension_manager.lock().unwrap();
+        let sequence = match ext_mgr.state_mut(extension_name) {
+            Some(CheckState::Prefetched(sequence)) => *sequence,
+            Some(CheckState::Present(info)) => return Poll::Ready(Ok(Some(*info))),
+            Some(CheckState::Missing) => return Poll::Ready(Ok(None)),
+            Some(CheckState::Error) | None => {
+                return Poll::Ready(Err(ConnectionError::UnknownError))
+            }
+        };
+        let reply = {
+            let mut inner = self.inner.lock().unwrap();
+            match self.poll_wait(&mut inner, cx, |conn| {
+                conn.poll_for_reply_or_error(sequence)
+            }) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(Err(e)) => Err(e.into()),
+                Poll::Ready(Ok((buffer, _fds))) => {
+                    if buffer[0] == 0 {
+                        X11Error::try_parse(&buffer, &*ext_mgr)
+                            .map_err(ReplyError::from)
+                            .and_then(|error| Err(ReplyError::X11Error(error)))
+                    } else {
+                        QueryExtensionReply::try_parse(&buffer)
+                            .map(|reply| reply.0)
+                            .map_err(ReplyError::from)
+                    }
+                }
+            }
+        };
+        let state = ext_mgr
+            .state_mut(extension_name)
+            .expect("The state was there a moment ago");
+        Poll::Ready(state.handle_reply(reply))
+    }
+
+    /// Poll for the result of the `BigRequests::Enable` request that was already sent.
+    fn poll_maximum_request_bytes(&self, cx: &mut Context<'_>) -> Poll<usize> {
+        let mut max_bytes = self.maximum_request_bytes.lock().unwrap();
+        let length = match *max_bytes {
+            MaxRequestBytes::Unknown => unreachable!("The request was sent before"),
+            MaxRequestBytes::Known(length) => return Poll::Ready(length),
+            MaxRequestBytes::Requested(None) => None,
+            MaxRequestBytes::Requested(Some(sequence)) => {
+                let mut inner = self.inner.lock().unwrap();
+                match self.poll_wait(&mut inner, cx, |conn| {
+                    conn.poll_for_reply_or_error(sequence)
+                }) {
+                    Poll::Pending => return Poll::Pending,
+                    Poll::Ready(Err(_)) => None,
+                    Poll::Ready(Ok((buffer, _fds))) => EnableReply::try_parse(&buffer)
+                        .ok()
+                        .filter(|_| buffer[0] != 0)
+                        .map(|reply| reply.0.maximum_request_length),
+                }
+            }
+        };
+        let length = length
+            // If anything failed (sending the request, getting the reply), use Setup
+            .unwrap_or_else(|| self.setup.maximum_request_length.into())
+            // Turn the u32 into usize, using the max value in case of overflow
+            .try_into()
+            .unwrap_or(usize::MAX);
+        let length = length * 4;
+        *max_bytes = MaxRequestBytes::Known(length);
+        Poll::Ready(length)
+    }
+}
+
+impl<S: AsyncStream + Send + Sync> AsyncRustConnection<S> {
+    /// Internal function for actually sending a request.
+    ///
+    /// This function "does the actual work" for `send_request_with_reply()` and
+    /// `send_request_without_reply()`.
+    async fn send_request(
+        &self,
+        bufs: &[IoSlice<'_>],
+        fds: Vec<RawFdContainer>,
+        kind: ReplyFdKind,
+    ) -> Result<SequenceNumber, ConnectionError> {
+        // Compute the total length of the request
+        let length: usize = bufs.iter().map(|buf| buf.len()).sum();
+        assert_eq!(
+            length % 4,
+            0,
+            "The length of X11 requests must be a multiple of 4, got {}",
+            length
+        );
+        let wire_length = length / 4;
+
+        // If the length does not fit into an u16, we need to use big requests
+        let big_length = if let Ok(wire_length) = u16::try_from(wire_length) {
+            // Check that the request contains the correct length field
+            let length_field = u16::from_ne_bytes([bufs[0][2], bufs[0][3]]);
+            assert_eq!(
+                wire_length, length_field,
+                "Length field contains incorrect value"
+            );
+            None
+        } else {
+            // Check that the total length is not too large
+            if length > self.maximum_request_bytes().await {
+                return Err(ConnectionError::MaximumRequestLengthExceeded);
+            }
+            // Big requests need four extra bytes, thus "+1"
+            let wire_length: u32 = wire_length
+                .checked_add(1)
+                .ok_or(ConnectionError::MaximumRequestLengthExceeded)?
+                .try_into()
+                .expect("X11 request larger than 2^34 bytes?!?");
+            Some(wire_length)
+        };
+
+        let (seqno, needs_flush) = {
+            let mut inner = self.inner.lock().unwrap();
+            let seqno = inner.buffer_request(bufs, fds, kind, big_length);
+            (seqno, inner.write_buffer.len() >= FLUSH_THRESHOLD)
+        };
+        if needs_flush {
+            self.wait(|cx| self.poll_flush(cx)).await?;
+        }
+        Ok(seqno)
+    }
+}
+
+impl<S: AsyncStream + Send + Sync> AsyncRequestConnection for AsyncRustConnection<S> {
+    type Buf = Vec<u8>;
+
+    fn send_request_with_reply<'this, 'bufs, R>(
+        &'this self,
+        bufs: &'bufs [IoSlice<'bufs>],
+        fds: Vec<RawFdContainer>,
+    ) -> BoxFuture<'bufs, Result<AsyncCookie<'this, Self, R>, ConnectionError>>
+    where
+        'this: 'bufs,
+        R: TryParse + Send,
+    {
+        Box::pin(async move {
+            let seqno = self
+                .send_request(bufs, fds, ReplyFdKind::ReplyWithoutFDs)
+                .await?;
+            Ok(AsyncCookie::new(self, seqno))
+        })
+    }
+
+    fn send_request_with_reply_with_fds<'this, 'bufs, R>(
+        &'this self,
+        bufs: &'bufs [IoSlice<'bufs>],
+        fds: Vec<RawFdContainer>,
+    ) -> BoxFuture<'bufs, Result<AsyncCookieWithFds<'this, Self, R>, ConnectionError>>
+    where
+        'this: 'bufs,
+        R: TryParseFd + Send,
+    {
+        Box::pin(async move {
+            let seqno = self
+                .send_request(bufs, fds, ReplyFdKind::ReplyWithFDs)
+                .await?;
+            Ok(AsyncCookieWithFds::new(self, seqno))
+        })
+    }
+
+    fn send_request_without_reply<'this, 'bufs>(
+        &'this self,
+        bufs: &'bufs [IoSlice<'bufs>],
+        fds: Vec<RawFdContainer>,
+    ) -> BoxFuture<'bufs, Result<AsyncVoidCookie<'this, Self>, ConnectionError>>
+    where
+        'this: 'bufs,
+    {
+        Box::pin(async move {
+            let seqno = self.send_request(bufs, fds, ReplyFdKind::NoReply).await?;
+            Ok(AsyncVoidCookie::new(self, seqno))
+        })
+    }
+
+    fn discard_reply(&self, sequence: SequenceNumber, _kind: RequestKind, mode: DiscardMode) {
+        self.inner
+            .lock()
+            .unwrap()
+            .inner
+            .discard_reply(sequence, mode);
+    }
+
+    fn prefetch_extension_information(
+        &self,
+        extension_name: &'static str,
+    ) -> Result<(), ConnectionError> {
+        let mut ext_mgr = self.extension_manager.lock().unwrap();
+        if ext_mgr.state_mut(extension_name).is_none() {
+            let request = QueryExtensionRequest {
+                name: Cow::Borrowed(extension_name.as_bytes()),
+            };
+            let (buf, fds) = Request::serialize(request, 0);
+            let sequence = self.inner.lock().unwrap().buffer_request(
+                &[IoSlice::new(&buf)],
+                fds,
+                ReplyFdKind::ReplyWithoutFDs,
+                None,
+            );
+            ext_mgr.insert_prefetched(extension_name, sequence);
+        }
+        Ok(())
+    }
+
+    fn extension_information(
+        &self,
+        extension_name: &'static str,
+    ) -> BoxFuture<'_, Result<Option<ExtensionInformation>, ConnectionError>> {
+        Box::pin(async move {
+            self.prefetch_extension_information(extension_name)?;
+            self.wait(|cx| self.poll_extension_information(cx, extension_name))
+                .await
+        })
+    }
+
+    fn wait_for_reply_or_raw_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> BoxFuture<'_, Result<ReplyOrError<Vec<u8>>, ConnectionError>> {
+        Box::pin(async move {
+            match self.wait_for_reply_with_fds_raw(sequence).await? {
+                ReplyOrError::Reply((reply, _fds)) => Ok(ReplyOrError::Reply(reply)),
+                ReplyOrError::Error(e) => Ok(ReplyOrError::Error(e)),
+            }
+        })
+    }
+
+    fn wait_for_reply(
+        &self,
+        sequence: SequenceNumber,
+    ) -> BoxFuture<'_, Result<Option<Vec<u8>>, ConnectionError>> {
+        Box::pin(self.wait(move |cx| {
+            let mut inner = self.inner.lock().unwrap();
+            self.poll_wait(&mut inner, cx, |conn| match conn.poll_for_reply(sequence) {
+                PollReply::TryAgain => None,
+                PollReply::NoReply => Some(None),
+                PollReply::Reply(buffer) => Some(Some(buffer)),
+            })
+        }))
+    }
+
+    fn wait_for_reply_with_fds_raw(
+        &self,
+        sequence: SequenceNumber,
+    ) -> BoxFuture<'_, Result<ReplyOrErrorWithFds, ConnectionError>> {
+        Box::pin(self.wait(move |cx| self.poll_wait_for_reply_with_fds(cx, sequence)))
+    }
+
+    fn check_for_raw_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> BoxFuture<'_, Result<Option<Vec<u8>>, ConnectionError>> {
+        {
+            let mut inner = self.inner.lock().unwrap();
+            if inner.inner.prepare_check_for_reply_or_error(sequence) {
+                inner.buffer_sync();
+                assert!(!inner.inner.prepare_check_for_reply_or_error(sequence));
+            }
+        }
+        Box::pin(self.wait(move |cx| {
+            let mut inner = self.inner.lock().unwrap();
+            self.poll_wait(&mut inner, cx, |conn| {
+                match conn.poll_check_for_reply_or_error(sequence) {
+                    PollReply::TryAgain => None,
+                    PollReply::NoReply => Some(None),
+                    PollReply::Reply(buffer) => Some(Some(buffer)),
+                }
+            })
+        }))
+    }
+
+    fn maximum_request_bytes(&self) -> BoxFuture<'_, usize> {
+        Box::pin(async move {
+            let unknown = matches!(
+                *self.maximum_request_bytes.lock().unwrap(),
+                MaxRequestBytes::Unknown
+            );
+            if unknown {
+                let info = self
+                    .extension_information(bigreq::X11_EXTENSION_NAME)
+                    .await
+                    .ok()
+                    .flatten();
+                let mut max_bytes = self.maximum_request_bytes.lock().unwrap();
+                if let MaxRequestBytes::Unknown = *max_bytes {
+                    let sequence = info.map(|info| {
+                        let (buf, fds) = Request::serialize(EnableRequest, info.major_opcode);
+                        self.inner.lock().unwrap().buffer_request(
+                            &[IoSlice::new(&buf)],
+                            fds,
+                            ReplyFdKind::ReplyWithoutFDs,
+                            None,
+                        )
+                    });
+                    *max_bytes = MaxRequestBytes::Requested(sequence);
+                }
+            }
+            self.wait(|cx| self.poll_maximum_request_bytes(cx)).await
+        })
+    }
+
+    fn parse_error(&self, error: &[u8]) -> Result<X11Error, ParseError> {
+        let ext_mgr = self.extension_manager.lock().unwrap();
+        X11Error::try_parse(error, &*ext_mgr)
+    }
+
+    fn parse_event(&self, event: &[u8]) -> Result<Event, ParseError> {
+        let ext_mgr = self.extension_manager.lock().unwrap();
+        Event::parse(event, &*ext_mgr)
+    }
+}
+
+impl<S: AsyncStream + Send + Sync> AsyncConnection for AsyncRustConnection<S> {
+    fn wait_for_raw_event_with_sequence(
+        &self,
+    ) -> BoxFuture<'_, Result<RawEventAndSeqNumber<Vec<u8>>, ConnectionError>> {
+        Box::pin(self.wait(move |cx| {
+            let mut inner = self.inner.lock().unwrap();
+            self.poll_wait(
+                &mut inner,
+                cx,
+                ProtoConnection::poll_for_event_with_sequence,
+            )
+        }))
+    }
+
+    fn poll_for_raw_event_with_sequence(
+        &self,
+    ) -> Result<Option<RawEventAndSeqNumber<Vec<u8>>>, ConnectionError> {
+        Ok(self
+            .inner
+            .lock()
+            .unwrap()
+            .inner
+            .poll_for_event_with_sequence())
+    }
+
+    fn flush(&self) -> BoxFuture<'_, Result<(), ConnectionError>> {
+        Box::pin(self.wait(move |cx| self.poll_flush(cx).map_err(Into::into)))
+    }
+
+    fn setup(&self) -> &Setup {
+        &self.setup
+    }
+
+    fn generate_id(&self) -> BoxFuture<'_, Result<u32, ReplyOrIdError>> {
+        Box::pin(async move {
+            let id = self.id_allocator.lock().unwrap().generate_id();
+            if let Some(id) = id {
+                return Ok(id);
+            }
+            if self
+                .extension_information(xc_misc::X11_EXTENSION_NAME)
+                .await?
+                .is_none()
+            {
+                // IDs are exhausted and XC-MISC is not available
+                return Err(ReplyOrIdError::IdsExhausted);
+            }
+            let range = self
+                .send_trait_request_with_reply(GetXIDRangeRequest)
+                .await?
+                .reply()
+                .await?;
+            let mut id_allocator = self.id_allocator.lock().unwrap();
+            id_allocator.update_xid_range(&range)?;
+            id_allocator
+                .generate_id()
+                .ok_or(ReplyOrIdError::IdsExhausted)
+        })
+    }
+}
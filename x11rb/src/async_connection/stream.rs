@@ -0,0 +1,78 @@
+use std::io::{IoSlice, Result};
+use std::task::{Context, Poll};
+
+use crate::utils::RawFdContainer;
+
+/// A trait used to implement the raw asynchronous communication with the X11 server.
+///
+/// This is the asynchronous counterpart of [`crate::rust_connection::Stream`]. Instead of
+/// offering a blocking `poll()` method, reading and writing return [`Poll::Pending`] when they
+/// cannot make progress. In this case, the waker from the given [`Context`] has to be woken once
+/// the operation can be retried.
+///
+/// This trait is independent of any specific executor. For example, with `smol` it can be
+/// implemented for a wrapper around `Async<UnixStream>` by forwarding to `poll_readable()` and
+/// `poll_writable()` and then doing the actual I/O with `DefaultStream`.
+///
+/// It is enough for a stream to remember the waker of the task that polled it last.
+/// [`AsyncRustConnection`](super::AsyncRustConnection) remembers all tasks that wait for data
+/// and wakes them up when any of them read something. When a waiting task is cancelled, the
+/// other waiting tasks are woken up so that one of them polls the stream again.
+///
+/// None of the functions of this trait shall return [`std::io::ErrorKind::Interrupted`] or
+/// [`std::io::ErrorKind::WouldBlock`]. The first should be retried by the implementation and the
+/// second should be turned into `Poll::Pending`.
+pub trait AsyncStream {
+    /// Try to read some bytes and FDs from this stream.
+    ///
+    /// This function works like [`crate::rust_connection::Stream::read`], but returns
+    /// `Poll::Pending` instead of blocking. Any received file descriptors are appended to the given
+    /// `fd_storage`.
+    ///
+    /// # Multithreading
+    ///
+    /// If `Self` is `Send + Sync` and this is used concurrently from more than one thread, the
+    /// same rules as for [`crate::rust_connection::Stream::read`] apply.
+    fn poll_read(
+        &self,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+        fd_storage: &mut Vec<RawFdContainer>,
+    ) -> Poll<Result<usize>>;
+
+    /// Try to write a buffer and some FDs into this stream.
+    ///
+    /// This function works like [`crate::rust_connection::Stream::write`], but returns
+    /// `Poll::Pending` instead of blocking. Any file descriptors that were sent are removed from
+    /// the beginning of the given `Vec`.
+    ///
+    /// # Multithreading
+    ///
+    /// If `Self` is `Send + Sync` and this is used concurrently from more than one thread, the
+    /// same rules as for [`crate::rust_connection::Stream::write`] apply.
+    fn poll_write(
+        &self,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+        fds: &mut Vec<RawFdContainer>,
+    ) -> Poll<Result<usize>>;
+
+    /// Like `poll_write`, except that it writes from a slice of buffers.
+    ///
+    /// This method must behave as a call to `poll_write` with the buffers concatenated would.
+    ///
+    /// The default implementation calls `poll_write` with the first nonempty buffer provided.
+    fn poll_write_vectored(
+        &self,
+        cx: &mut Context<'_>,
+        bufs: &[IoSlice<'_>],
+        fds: &mut Vec<RawFdContainer>,
+    ) -> Poll<Result<usize>> {
+        for buf in bufs {
+            if !buf.is_empty() {
+                return self.poll_write(cx, buf, fds);
+            }
+        }
+        Poll::Ready(Ok(0))
+    }
+}
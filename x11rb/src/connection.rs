@@ -6,6 +6,7 @@
 use std::borrow::Cow;
 use std::convert::{TryFrom, TryInto};
 use std::io::IoSlice;
+use std::time::Duration;
 
 use x11rb_protocol::x11_utils::{ReplyFDsRequest, ReplyRequest, VoidRequest};
 
@@ -13,6 +14,7 @@ use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
 use crate::errors::{ConnectionError, ParseError, ReplyError, ReplyOrIdError};
 use crate::protocol::xproto::Setup;
 use crate::protocol::Event;
+use crate::statistics::ConnectionStatistics;
 use crate::utils::RawFdContainer;
 use crate::x11_utils::{ExtensionInformation, TryParse, TryParseFd, X11Error};
 
@@ -69,6 +71,7 @@ pub trait RequestConnection {
     /// automatically uses the BIG-REQUESTS extension for such large requests.
     ///
     /// In any case, the request may not be larger than the server's maximum request length.
+    #[track_caller]
     fn send_request_with_reply<R>(
         &self,
         bufs: &[IoSlice<'_>],
@@ -81,6 +84,7 @@ pub trait RequestConnection {
     ///
     /// This function is a wrapper around [`RequestConnection::send_request_with_reply`]. This
     /// function gets a [`ReplyRequest`] as its argument to specify the request to send.
+    #[track_caller]
     fn send_trait_request_with_reply<R>(
         &self,
         request: R,
@@ -119,6 +123,7 @@ pub trait RequestConnection {
     /// automatically uses the BIG-REQUESTS extension for such large requests.
     ///
     /// In any case, the request may not be larger than the server's maximum request length.
+    #[track_caller]
     fn send_request_with_reply_with_fds<R>(
         &self,
         bufs: &[IoSlice<'_>],
@@ -131,6 +136,7 @@ pub trait RequestConnection {
     ///
     /// This function is a wrapper around [`RequestConnection::send_request_with_reply_with_fds`].
     /// This function gets a [`ReplyFDsRequest`] as its argument to specify the request to send.
+    #[track_caller]
     fn send_trait_request_with_reply_with_fds<R>(
         &self,
         request: R,
@@ -169,6 +175,7 @@ pub trait RequestConnection {
     /// automatically uses the BIG-REQUESTS extension for such large requests.
     ///
     /// In any case, the request may not be larger than the server's maximum request length.
+    #[track_caller]
     fn send_request_without_reply(
         &self,
         bufs: &[IoSlice<'_>],
@@ -179,6 +186,7 @@ pub trait RequestConnection {
     ///
     /// This function is a wrapper around [`RequestConnection::send_request_without_reply`]. This
     /// function gets a [`VoidRequest`] as its argument to specify the request to send.
+    #[track_caller]
     fn send_trait_request_without_reply<R>(
         &self,
         request: R,
@@ -233,6 +241,44 @@ pub trait RequestConnection {
         extension_name: &'static str,
     ) -> Result<Option<ExtensionInformation>, ConnectionError>;
 
+    /// Get the version of an extension that was negotiated with the X11 server.
+    ///
+    /// This performs the version handshake of the extension (e.g. `QueryVersion`) with the newest
+    /// version that x11rb supports. `None` is returned if the server does not support the
+    /// extension. See [`crate::extension_version`] for details.
+    ///
+    /// The default implementation negotiates the version on every call. Implementations should
+    /// remember the result, for example via
+    /// [`ExtensionVersions`](crate::extension_version::ExtensionVersions).
+    fn extension_version(
+        &self,
+        extension_name: &'static str,
+    ) -> Result<Option<(u32, u32)>, ReplyError> {
+        crate::extension_version::negotiate_version(self, extension_name)
+    }
+
+    /// Check that a request can be sent with the negotiated version of its extension.
+    ///
+    /// The request functions in [`crate::protocol`] call this before sending requests that were
+    /// added in a later version of their extension. An error is returned if
+    /// [`extension_version()`](Self::extension_version) negotiated an older version than
+    /// `required`. If no version was negotiated, no error is returned.
+    ///
+    /// The XML protocol descriptions do not say which version of an extension added a request.
+    /// Thus, only the requests of the Composite, RandR, and XFIXES extensions are checked, for
+    /// which this information is maintained by hand. The requests of all other extensions are sent
+    /// without calling this function.
+    ///
+    /// The default implementation does not remember negotiated versions and never fails.
+    fn check_extension_version(
+        &self,
+        extension_name: &'static str,
+        required: (u32, u32),
+    ) -> Result<(), ConnectionError> {
+        let _ = (extension_name, required);
+        Ok(())
+    }
+
     /// Wait for the reply to a request.
     ///
     /// The given sequence number identifies the request for which replies are expected. If the X11
@@ -259,6 +305,70 @@ pub trait RequestConnection {
         sequence: SequenceNumber,
     ) -> Result<ReplyOrError<Self::Buf>, ConnectionError>;
 
+    /// Wait for the replies to many requests.
+    ///
+    /// This works like calling `wait_for_reply_or_raw_error()` for each of the given sequence
+    /// numbers, but connections can implement it more efficiently: All replies that arrive while
+    /// waiting are collected at once instead of waking up once per request. The results are
+    /// returned in the order of `sequences`.
+    ///
+    /// The default implementation just calls `wait_for_reply_or_raw_error()` in a loop. See
+    /// [`crate::cookie::wait_for_replies`] for a typed interface.
+    ///
+    /// The requests must not have replies that carry file descriptors, because there is no place
+    /// for them in the results. Connections may fail with [`ConnectionError::FdPassingFailed`]
+    /// when such a reply is received.
+    ///
+    /// Users of this library will most likely not want to use this function directly.
+    fn wait_for_replies_or_raw_errors(
+        &self,
+        sequences: &[SequenceNumber],
+    ) -> Result<Vec<ReplyOrError<Self::Buf>>, ConnectionError> {
+        sequences
+            .iter()
+            .map(|&sequence| self.wait_for_reply_or_raw_error(sequence))
+            .collect()
+    }
+
+    /// Wait for the reply to a request, but at most for the given timeout.
+    ///
+    /// This works like `wait_for_reply_or_error()`, but returns
+    /// [`ConnectionError::Timeout`] if no reply or error was received in time.
+    ///
+    /// Users of this library will most likely not want to use this function directly.
+    fn wait_for_reply_or_error_timeout(
+        &self,
+        sequence: SequenceNumber,
+        timeout: Duration,
+    ) -> Result<Self::Buf, ReplyError> {
+        match self.wait_for_reply_or_raw_error_timeout(sequence, timeout)? {
+            ReplyOrError::Reply(reply) => Ok(reply),
+            ReplyOrError::Error(error) => {
+                Err(ReplyError::X11Error(self.parse_error(error.as_ref())?))
+            }
+        }
+    }
+
+    /// Wait for the reply to a request, but at most for the given timeout.
+    ///
+    /// This works like `wait_for_reply_or_raw_error()`, but returns
+    /// [`ConnectionError::Timeout`] if no reply or error was received in time. In this case, the
+    /// state of the connection does not change and the reply can still be received later.
+    ///
+    /// The default implementation returns [`ConnectionError::UnsupportedTimeout`] without waiting.
+    /// This is the case for `XCBConnection`, since libxcb does not offer a way to wait with a
+    /// timeout.
+    ///
+    /// Users of this library will most likely not want to use this function directly.
+    fn wait_for_reply_or_raw_error_timeout(
+        &self,
+        sequence: SequenceNumber,
+        timeout: Duration,
+    ) -> Result<ReplyOrError<Self::Buf>, ConnectionError> {
+        let _ = (sequence, timeout);
+        Err(ConnectionError::UnsupportedTimeout)
+    }
+
     /// Wait for the reply to a request.
     ///
     /// The given sequence number identifies the request for which replies are expected. If the X11
@@ -320,6 +430,42 @@ pub trait RequestConnection {
         sequence: SequenceNumber,
     ) -> Result<Option<Self::Buf>, ConnectionError>;
 
+    /// Check whether a request that does not have a reply caused an X11 error, but wait at most
+    /// for the given timeout.
+    ///
+    /// This works like `check_for_error()`, but returns [`ConnectionError::Timeout`] if the
+    /// answer was not received in time.
+    ///
+    /// Users of this library will most likely not want to use this function directly.
+    fn check_for_error_timeout(
+        &self,
+        sequence: SequenceNumber,
+        timeout: Duration,
+    ) -> Result<(), ReplyError> {
+        match self.check_for_raw_error_timeout(sequence, timeout)? {
+            Some(err) => Err(self.parse_error(err.as_ref())?.into()),
+            None => Ok(()),
+        }
+    }
+
+    /// Check whether a request that does not have a reply caused an X11 error, but wait at most
+    /// for the given timeout.
+    ///
+    /// This works like `check_for_raw_error()`, but returns [`ConnectionError::Timeout`] if the
+    /// answer was not received in time. In this case, the check can be repeated later.
+    ///
+    /// The default implementation returns [`ConnectionError::UnsupportedTimeout`] without waiting.
+    ///
+    /// Users of this library will most likely not want to use this function directly.
+    fn check_for_raw_error_timeout(
+        &self,
+        sequence: SequenceNumber,
+        timeout: Duration,
+    ) -> Result<Option<Self::Buf>, ConnectionError> {
+        let _ = (sequence, timeout);
+        Err(ConnectionError::UnsupportedTimeout)
+    }
+
     /// Prefetches the maximum request length.
     ///
     /// If the maximum request length is not cached yet, this function sends a `BigRequests::Enable`
@@ -355,6 +501,14 @@ pub trait RequestConnection {
 
     /// Parse a generic event.
     fn parse_event(&self, event: &[u8]) -> Result<Event, ParseError>;
+
+    /// Get a snapshot of the traffic counters of this connection.
+    ///
+    /// Returns `None` if counting is disabled or not supported by this connection. See the
+    /// [`statistics`](crate::statistics) module for details.
+    fn statistics(&self) -> Option<ConnectionStatistics> {
+        None
+    }
 }
 
 /// A connection to an X11 server.
@@ -381,6 +535,36 @@ pub trait Connection: RequestConnection {
         &self,
     ) -> Result<RawEventAndSeqNumber<Self::Buf>, ConnectionError>;
 
+    /// Wait for a new event from the X11 server, but at most for the given timeout.
+    ///
+    /// If no event was received in time, [`ConnectionError::Timeout`] is returned.
+    fn wait_for_event_timeout(&self, timeout: Duration) -> Result<Event, ConnectionError> {
+        let (event, _seq) = self.wait_for_raw_event_with_sequence_timeout(timeout)?;
+        Ok(self.parse_event(event.as_ref())?)
+    }
+
+    /// Wait for a new raw/unparsed event from the X11 server, but at most for the given timeout.
+    ///
+    /// If no event was received in time, [`ConnectionError::Timeout`] is returned.
+    fn wait_for_raw_event_timeout(&self, timeout: Duration) -> Result<Self::Buf, ConnectionError> {
+        Ok(self.wait_for_raw_event_with_sequence_timeout(timeout)?.0)
+    }
+
+    /// Wait for a new raw/unparsed event from the X11 server, but at most for the given timeout.
+    ///
+    /// If no event was received in time, [`ConnectionError::Timeout`] is returned.
+    ///
+    /// The default implementation returns [`ConnectionError::UnsupportedTimeout`] without waiting.
+    /// This is the case for `XCBConnection`, since libxcb does not offer a way to wait with a
+    /// timeout.
+    fn wait_for_raw_event_with_sequence_timeout(
+        &self,
+        timeout: Duration,
+    ) -> Result<RawEventAndSeqNumber<Self::Buf>, ConnectionError> {
+        let _ = timeout;
+        Err(ConnectionError::UnsupportedTimeout)
+    }
+
     /// Poll for a new event from the X11 server.
     fn poll_for_event(&self) -> Result<Option<Event>, ConnectionError> {
         Ok(self.poll_for_event_with_sequence()?.map(|r| r.0))
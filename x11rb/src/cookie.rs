@@ -43,15 +43,45 @@
 //! | ------ | ---------------------------------- | ------------------------- |
 //! | Get    | `Cookie::reply`                    | `Cookie::reply_unchecked` |
 //! | Ignore | `Cookie::discard_reply_and_errors` | Just drop the cookie      |
+//!
+//! # Waiting for many replies
+//!
+//! Calling [`Cookie::reply`] in a loop waits for each reply separately. When many requests are
+//! pipelined, [`wait_for_replies`] can wait for all of them at once. It accepts everything that
+//! implements [`ReplyBatch`]: single cookies, `Vec`s of batches, and tuples of batches. Thus,
+//! cookies for different kinds of replies can be mixed:
+//!
+//! ```no_run
+//! use x11rb::connection::Connection;
+//! use x11rb::cookie::wait_for_replies;
+//! use x11rb::errors::ReplyError;
+//! use x11rb::protocol::xproto::{ConnectionExt, Window};
+//! # fn do_it(conn: &impl Connection, windows: &[Window]) -> Result<(), ReplyError> {
+//! let attributes = windows
+//!     .iter()
+//!     .map(|&window| conn.get_window_attributes(window))
+//!     .collect::<Result<Vec<_>, _>>()?;
+//! let focus = conn.get_input_focus()?;
+//! let (attributes, focus) = wait_for_replies(conn, (attributes, focus))?;
+//! for (window, attributes) in windows.iter().zip(attributes) {
+//!     // Each cookie has its own result
+//!     println!("{}: {:?}", window, attributes?.map_state);
+//! }
+//! println!("focus is at {}", focus?.focus);
+//! # Ok(())
+//! # }
+//! ```
 
 use std::marker::PhantomData;
+use std::time::Duration;
+use std::vec::IntoIter;
 
-use crate::connection::{BufWithFds, RequestConnection, RequestKind};
-use crate::errors::{ConnectionError, ReplyError};
+use crate::connection::{BufWithFds, ReplyOrError, RequestConnection, RequestKind};
+use crate::errors::{ConnectionError, ReplyError, TimeoutError};
 #[cfg(feature = "record")]
 use crate::protocol::record::EnableContextReply;
 use crate::protocol::xproto::ListFontsWithInfoReply;
-use crate::x11_utils::{TryParse, TryParseFd};
+use crate::x11_utils::{BorrowedReply, TryParse, TryParseFd};
 
 use x11rb_protocol::{DiscardMode, SequenceNumber};
 
@@ -97,12 +127,36 @@ where
         result
     }
 
+    /// Consume this instance and get the contained sequence number out.
+    pub(crate) fn into_sequence_number(self) -> SequenceNumber {
+        self.consume().1
+    }
+
     /// Check if the original request caused an X11 error.
     pub fn check(self) -> Result<(), ReplyError> {
         let (connection, sequence) = self.consume();
         connection.check_for_error(sequence)
     }
 
+    /// Check if the original request caused an X11 error, but wait at most for the given timeout.
+    ///
+    /// If the timeout expires, this cookie is returned in [`TimeoutError::TimedOut`]. It can then
+    /// be checked again or dropped.
+    pub fn check_timeout(self, timeout: Duration) -> Result<(), TimeoutError<Self>> {
+        match self
+            .connection
+            .check_for_error_timeout(self.sequence_number, timeout)
+        {
+            Err(ReplyError::ConnectionError(ConnectionError::Timeout)) => {
+                Err(TimeoutError::TimedOut(self))
+            }
+            result => {
+                let _ = self.consume();
+                Ok(result?)
+            }
+        }
+    }
+
     /// Ignore all errors to this request.
     ///
     /// Without calling this method, an error becomes available on the connection as an event after
@@ -226,11 +280,35 @@ where
         conn.wait_for_reply(self.raw_cookie.into_sequence_number())
     }
 
+    /// Get the raw reply that the server sent, but wait at most for the given timeout.
+    ///
+    /// If the timeout expires, this cookie is returned in [`TimeoutError::TimedOut`]. It can then
+    /// be used to wait again, or the reply can be discarded by dropping it.
+    pub fn raw_reply_timeout(self, timeout: Duration) -> Result<C::Buf, TimeoutError<Self>> {
+        let conn = self.raw_cookie.connection;
+        match conn.wait_for_reply_or_error_timeout(self.raw_cookie.sequence_number, timeout) {
+            Err(ReplyError::ConnectionError(ConnectionError::Timeout)) => {
+                Err(TimeoutError::TimedOut(self))
+            }
+            result => {
+                let _ = self.raw_cookie.into_sequence_number();
+                Ok(result?)
+            }
+        }
+    }
+
     /// Get the reply that the server sent.
     pub fn reply(self) -> Result<R, ReplyError> {
         Ok(R::try_parse(self.raw_reply()?.as_ref())?.0)
     }
 
+    /// Get the reply that the server sent, but wait at most for the given timeout.
+    ///
+    /// See [`Cookie::raw_reply_timeout`] for what happens when the timeout expires.
+    pub fn reply_timeout(self, timeout: Duration) -> Result<R, TimeoutError<Self>> {
+        Ok(R::try_parse(self.raw_reply_timeout(timeout)?.as_ref())?.0)
+    }
+
     /// Get the reply that the server sent, but have errors handled as events.
     pub fn reply_unchecked(self) -> Result<Option<R>, ConnectionError> {
         self.raw_reply_unchecked()?
@@ -257,6 +335,174 @@ where
     }
 }
 
+impl<C, R> Cookie<'_, C, R>
+where
+    R: TryParse + for<'r> BorrowedReply<'r>,
+    C: RequestConnection + ?Sized,
+{
+    /// Get the reply that the server sent and give its borrowed version to `f`.
+    ///
+    /// In contrast to [`Cookie::reply`], the lists in the reply are not copied. Instead, they
+    /// refer to the buffer that the reply was received in. This avoids copies of large replies,
+    /// for example of `GetImage`.
+    ///
+    /// ```no_run
+    /// # use x11rb::connection::Connection;
+    /// # use x11rb::errors::ReplyError;
+    /// # use x11rb::protocol::xproto::{ConnectionExt, ImageFormat, Window};
+    /// # fn do_it(conn: &impl Connection, window: Window) -> Result<(), ReplyError> {
+    /// let cookie = conn.get_image(ImageFormat::Z_PIXMAP, window, 0, 0, 100, 100, !0)?;
+    /// let black_pixels = cookie.with_reply(|reply| reply.data.iter().filter(|&&b| b == 0).count())?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_reply<T, F>(self, f: F) -> Result<T, ReplyError>
+    where
+        F: for<'r> FnOnce(<R as BorrowedReply<'r>>::Ref) -> T,
+    {
+        let buf = self.raw_reply()?;
+        let (reply, _) = R::try_parse_ref(buf.as_ref())?;
+        Ok(f(reply))
+    }
+}
+
+/// A set of cookies whose replies can be waited for at once with [`wait_for_replies`].
+///
+/// This trait is implemented for [`Cookie`], for `Vec`s and `Box`es of batches, and for tuples of
+/// up to eight batches. To put cookies for different kinds of replies into a single `Vec`, an enum
+/// of cookies can implement this trait by forwarding to the contained cookie.
+pub trait ReplyBatch<C>
+where
+    C: RequestConnection + ?Sized,
+{
+    /// The results of all cookies in this batch.
+    type Output;
+
+    /// Append the sequence numbers of all cookies in this batch to `sequences`.
+    fn sequence_numbers(&self, sequences: &mut Vec<SequenceNumber>);
+
+    /// Turn the responses to the requests of this batch into the results.
+    ///
+    /// `responses` contains the responses in the order in which `sequence_numbers()` provided the
+    /// sequence numbers. This function has to take exactly one response per sequence number.
+    fn resolve(self, responses: &mut IntoIter<ReplyOrError<C::Buf>>) -> Self::Output;
+}
+
+/// Wait for the replies to all cookies in `batch`.
+///
+/// Instead of waiting for each reply separately, this waits until the replies to all requests were
+/// received. The connection can then collect all replies that arrive while waiting at once. See
+/// [`RequestConnection::wait_for_replies_or_raw_errors`].
+///
+/// X11 errors are reported for each cookie separately. Only problems with the connection itself
+/// cause the whole batch to fail.
+pub fn wait_for_replies<C, B>(connection: &C, batch: B) -> Result<B::Output, ConnectionError>
+where
+    C: RequestConnection + ?Sized,
+    B: ReplyBatch<C>,
+{
+    let mut sequences = Vec::new();
+    batch.sequence_numbers(&mut sequences);
+    let responses = connection.wait_for_replies_or_raw_errors(&sequences)?;
+    assert_eq!(responses.len(), sequences.len());
+    let mut responses = responses.into_iter();
+    let result = batch.resolve(&mut responses);
+    assert!(responses.next().is_none());
+    Ok(result)
+}
+
+impl<C, R> ReplyBatch<C> for Cookie<'_, C, R>
+where
+    R: TryParse,
+    C: RequestConnection + ?Sized,
+{
+    type Output = Result<R, ReplyError>;
+
+    fn sequence_numbers(&self, sequences: &mut Vec<SequenceNumber>) {
+        sequences.push(self.sequence_number());
+    }
+
+    fn resolve(self, responses: &mut IntoIter<ReplyOrError<C::Buf>>) -> Self::Output {
+        let conn = self.raw_cookie.connection;
+        // The response is handled here, so nothing has to be discarded
+        let _ = self.into_sequence_number();
+        match responses.next().expect("Missing response for a cookie") {
+            ReplyOrError::Reply(reply) => Ok(R::try_parse(reply.as_ref())?.0),
+            ReplyOrError::Error(error) => {
+                Err(ReplyError::X11Error(conn.parse_error(error.as_ref())?))
+            }
+        }
+    }
+}
+
+impl<C, B> ReplyBatch<C> for Vec<B>
+where
+    C: RequestConnection + ?Sized,
+    B: ReplyBatch<C>,
+{
+    type Output = Vec<B::Output>;
+
+    fn sequence_numbers(&self, sequences: &mut Vec<SequenceNumber>) {
+        self.iter()
+            .for_each(|batch| batch.sequence_numbers(sequences));
+    }
+
+    fn resolve(self, responses: &mut IntoIter<ReplyOrError<C::Buf>>) -> Self::Output {
+        self.into_iter()
+            .map(|batch| batch.resolve(responses))
+            .collect()
+    }
+}
+
+impl<C, B> ReplyBatch<C> for Box<B>
+where
+    C: RequestConnection + ?Sized,
+    B: ReplyBatch<C>,
+{
+    type Output = B::Output;
+
+    fn sequence_numbers(&self, sequences: &mut Vec<SequenceNumber>) {
+        (**self).sequence_numbers(sequences)
+    }
+
+    fn resolve(self, responses: &mut IntoIter<ReplyOrError<C::Buf>>) -> Self::Output {
+        (*self).resolve(responses)
+    }
+}
+
+macro_rules! reply_batch_tuple {
+    ($($name:ident),*) => {
+        impl<C, $($name),*> ReplyBatch<C> for ($($name,)*)
+        where
+            C: RequestConnection + ?Sized,
+            $($name: ReplyBatch<C>,)*
+        {
+            type Output = ($($name::Output,)*);
+
+            #[allow(non_snake_case)]
+            fn sequence_numbers(&self, sequences: &mut Vec<SequenceNumber>) {
+                let ($($name,)*) = self;
+                $($name.sequence_numbers(sequences);)*
+            }
+
+            #[allow(non_snake_case)]
+            fn resolve(self, responses: &mut IntoIter<ReplyOrError<C::Buf>>) -> Self::Output {
+                let ($($name,)*) = self;
+                ($($name.resolve(responses),)*)
+            }
+        }
+    };
+}
+
+reply_batch_tuple!(T1);
+reply_batch_tuple!(T1, T2);
+reply_batch_tuple!(T1, T2, T3);
+reply_batch_tuple!(T1, T2, T3, T4);
+reply_batch_tuple!(T1, T2, T3, T4, T5);
+reply_batch_tuple!(T1, T2, T3, T4, T5, T6);
+reply_batch_tuple!(T1, T2, T3, T4, T5, T6, T7);
+reply_batch_tuple!(T1, T2, T3, T4, T5, T6, T7, T8);
+
 /// A handle to a response containing `RawFd` from the X11 server.
 ///
 /// When sending a request to the X11 server, this library returns a `Cookie`. This `Cookie` can
@@ -307,6 +553,11 @@ where
         let (buffer, mut fds) = self.raw_reply()?;
         Ok(R::try_parse_fd(buffer.as_ref(), &mut fds)?.0)
     }
+
+    /// Consume this instance and get the contained sequence number out.
+    pub(crate) fn into_sequence_number(self) -> SequenceNumber {
+        self.raw_cookie.into_sequence_number()
+    }
 }
 
 macro_rules! multiple_reply_cookie {
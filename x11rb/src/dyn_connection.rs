@@ -0,0 +1,594 @@
+//! An object-safe variant of the [`Connection`] trait.
+//!
+//! [`RequestConnection`] has generic methods and an associated buffer type, so it cannot be used
+//! as a trait object. [`DynConnection`] offers the same functionality without these obstacles and
+//! is implemented for every [`Connection`]. In turn, `dyn DynConnection` implements
+//! [`Connection`], so that the request functions in [`crate::protocol`], the `ConnectionExt`
+//! traits, and the cookies work with `&dyn DynConnection`.
+//!
+//! This allows to choose the kind of connection at runtime or to wrap a connection in another
+//! one without making all code generic over the connection type:
+//!
+//! ```no_run
+//! use x11rb::connection::Connection;
+//! use x11rb::dyn_connection::DynConnection;
+//! use x11rb::protocol::xproto::ConnectionExt;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let conn: Box<dyn DynConnection> = Box::new(x11rb::connect(None)?.0);
+//! let focus = conn.get_input_focus()?.reply()?.focus;
+//! println!("The input focus is on window {:#x}", focus);
+//! conn.flush()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Raw replies and events are returned as `Vec<u8>` by `dyn DynConnection`. For connections whose
+//! buffer type is `Vec<u8>`, like [`RustConnection`](crate::rust_connection::RustConnection),
+//! this does not copy anything.
+
+use std::any::Any;
+use std::io::IoSlice;
+use std::time::Duration;
+
+use crate::connection::{
+    BufWithFds, Connection, RawEventAndSeqNumber, ReplyOrError, RequestConnection, RequestKind,
+};
+use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
+use crate::errors::{ConnectionError, ParseError, ReplyError, ReplyOrIdError};
+use crate::protocol::xproto::Setup;
+use crate::protocol::Event;
+use crate::statistics::ConnectionStatistics;
+use crate::utils::RawFdContainer;
+use crate::x11_utils::{ExtensionInformation, TryParse, X11Error};
+
+use x11rb_protocol::{DiscardMode, SequenceNumber};
+
+/// An object-safe version of [`Connection`].
+///
+/// This trait is implemented for all types that implement [`Connection`]. Its methods correspond
+/// to the methods of [`RequestConnection`] and [`Connection`] with the same name, minus the `dyn_`
+/// prefix. The prefix avoids ambiguities when both traits are in scope.
+///
+/// Usually, there is no need to call these methods directly. Instead, use `dyn DynConnection`
+/// like any other [`Connection`].
+pub trait DynConnection {
+    /// See [`RequestConnection::send_request_with_reply`].
+    #[track_caller]
+    fn dyn_send_request_with_reply(
+        &self,
+        bufs: &[IoSlice<'_>],
+        fds: Vec<RawFdContainer>,
+    ) -> Result<SequenceNumber, ConnectionError>;
+
+    /// See [`RequestConnection::send_request_with_reply_with_fds`].
+    #[track_caller]
+    fn dyn_send_request_with_reply_with_fds(
+        &self,
+        bufs: &[IoSlice<'_>],
+        fds: Vec<RawFdContainer>,
+    ) -> Result<SequenceNumber, ConnectionError>;
+
+    /// See [`RequestConnection::send_request_without_reply`].
+    #[track_caller]
+    fn dyn_send_request_without_reply(
+        &self,
+        bufs: &[IoSlice<'_>],
+        fds: Vec<RawFdContainer>,
+    ) -> Result<SequenceNumber, ConnectionError>;
+
+    /// See [`RequestConnection::discard_reply`].
+    fn dyn_discard_reply(&self, sequence: SequenceNumber, kind: RequestKind, mode: DiscardMode);
+
+    /// See [`RequestConnection::prefetch_extension_information`].
+    fn dyn_prefetch_extension_information(
+        &self,
+        extension_name: &'static str,
+    ) -> Result<(), ConnectionError>;
+
+    /// See [`RequestConnection::extension_information`].
+    fn dyn_extension_information(
+        &self,
+        extension_name: &'static str,
+    ) -> Result<Option<ExtensionInformation>, ConnectionError>;
+
+    /// See [`RequestConnection::extension_version`].
+    fn dyn_extension_version(
+        &self,
+        extension_name: &'static str,
+    ) -> Result<Option<(u32, u32)>, ReplyError>;
+
+    /// See [`RequestConnection::check_extension_version`].
+    fn dyn_check_extension_version(
+        &self,
+        extension_name: &'static str,
+        required: (u32, u32),
+    ) -> Result<(), ConnectionError>;
+
+    /// See [`RequestConnection::wait_for_reply_or_raw_error`].
+    fn dyn_wait_for_reply_or_raw_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<ReplyOrError<Vec<u8>>, ConnectionError>;
+
+    /// See [`RequestConnection::wait_for_replies_or_raw_errors`].
+    fn dyn_wait_for_replies_or_raw_errors(
+        &self,
+        sequences: &[SequenceNumber],
+    ) -> Result<Vec<ReplyOrError<Vec<u8>>>, ConnectionError>;
+
+    /// See [`RequestConnection::wait_for_reply_or_raw_error_timeout`].
+    fn dyn_wait_for_reply_or_raw_error_timeout(
+        &self,
+        sequence: SequenceNumber,
+        timeout: Duration,
+    ) -> Result<ReplyOrError<Vec<u8>>, ConnectionError>;
+
+    /// See [`RequestConnection::wait_for_reply`].
+    fn dyn_wait_for_reply(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<Option<Vec<u8>>, ConnectionError>;
+
+    /// See [`RequestConnection::wait_for_reply_with_fds_raw`].
+    fn dyn_wait_for_reply_with_fds_raw(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<ReplyOrError<BufWithFds<Vec<u8>>, Vec<u8>>, ConnectionError>;
+
+    /// See [`RequestConnection::check_for_raw_error`].
+    fn dyn_check_for_raw_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<Option<Vec<u8>>, ConnectionError>;
+
+    /// See [`RequestConnection::check_for_raw_error_timeout`].
+    fn dyn_check_for_raw_error_timeout(
+        &self,
+        sequence: SequenceNumber,
+        timeout: Duration,
+    ) -> Result<Option<Vec<u8>>, ConnectionError>;
+
+    /// See [`RequestConnection::prefetch_maximum_request_bytes`].
+    fn dyn_prefetch_maximum_request_bytes(&self);
+
+    /// See [`RequestConnection::maximum_request_bytes`].
+    fn dyn_maximum_request_bytes(&self) -> usize;
+
+    /// See [`RequestConnection::parse_error`].
+    fn dyn_parse_error(&self, error: &[u8]) -> Result<X11Error, ParseError>;
+
+    /// See [`RequestConnection::parse_event`].
+    fn dyn_parse_event(&self, event: &[u8]) -> Result<Event, ParseError>;
+
+    /// See [`RequestConnection::statistics`].
+    fn dyn_statistics(&self) -> Option<ConnectionStatistics>;
+
+    /// See [`Connection::wait_for_raw_event_with_sequence`].
+    fn dyn_wait_for_raw_event_with_sequence(
+        &self,
+    ) -> Result<RawEventAndSeqNumber<Vec<u8>>, ConnectionError>;
+
+    /// See [`Connection::wait_for_raw_event_with_sequence_timeout`].
+    fn dyn_wait_for_raw_event_with_sequence_timeout(
+        &self,
+        timeout: Duration,
+    ) -> Result<RawEventAndSeqNumber<Vec<u8>>, ConnectionError>;
+
+    /// See [`Connection::poll_for_raw_event_with_sequence`].
+    fn dyn_poll_for_raw_event_with_sequence(
+        &self,
+    ) -> Result<Option<RawEventAndSeqNumber<Vec<u8>>>, ConnectionError>;
+
+    /// See [`Connection::flush`].
+    fn dyn_flush(&self) -> Result<(), ConnectionError>;
+
+    /// See [`Connection::setup`].
+    fn dyn_setup(&self) -> &Setup;
+
+    /// See [`Connection::generate_id`].
+    fn dyn_generate_id(&self) -> Result<u32, ReplyOrIdError>;
+}
+
+/// Placeholder for the reply type of cookies whose sequence number is taken out immediately.
+struct AnyReply;
+
+impl TryParse for AnyReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Ok((AnyReply, value))
+    }
+}
+
+/// Turn a buffer into a `Vec<u8>`, copying it only if it is not already a `Vec<u8>`.
+fn into_vec<B: AsRef<[u8]> + 'static>(buf: B) -> Vec<u8> {
+    let mut buf = Some(buf);
+    let any: &mut dyn Any = &mut buf;
+    match any.downcast_mut::<Option<Vec<u8>>>() {
+        Some(vec) => vec.take().unwrap(),
+        None => buf.unwrap().as_ref().to_vec(),
+    }
+}
+
+fn reply_or_error_into_vec<B: AsRef<[u8]> + std::fmt::Debug + 'static>(
+    reply: ReplyOrError<B>,
+) -> ReplyOrError<Vec<u8>> {
+    match reply {
+        ReplyOrError::Reply(reply) => ReplyOrError::Reply(into_vec(reply)),
+        ReplyOrError::Error(error) => ReplyOrError::Error(into_vec(error)),
+    }
+}
+
+impl<C: Connection> DynConnection for C {
+    #[track_caller]
+    fn dyn_send_request_with_reply(
+        &self,
+        bufs: &[IoSlice<'_>],
+        fds: Vec<RawFdContainer>,
+    ) -> Result<SequenceNumber, ConnectionError> {
+        let cookie: Cookie<'_, C, AnyReply> = self.send_request_with_reply(bufs, fds)?;
+        Ok(cookie.into_sequence_number())
+    }
+
+    #[track_caller]
+    fn dyn_send_request_with_reply_with_fds(
+        &self,
+        bufs: &[IoSlice<'_>],
+        fds: Vec<RawFdContainer>,
+    ) -> Result<SequenceNumber, ConnectionError> {
+        let cookie: CookieWithFds<'_, C, AnyReply> =
+            self.send_request_with_reply_with_fds(bufs, fds)?;
+        Ok(cookie.into_sequence_number())
+    }
+
+    #[track_caller]
+    fn dyn_send_request_without_reply(
+        &self,
+        bufs: &[IoSlice<'_>],
+        fds: Vec<RawFdContainer>,
+    ) -> Result<SequenceNumber, ConnectionError> {
+        Ok(self
+            .send_request_without_reply(bufs, fds)?
+            .into_sequence_number())
+    }
+
+    fn dyn_discard_reply(&self, sequence: SequenceNumber, kind: RequestKind, mode: DiscardMode) {
+        self.discard_reply(sequence, kind, mode)
+    }
+
+    fn dyn_prefetch_extension_information(
+        &self,
+        extension_name: &'static str,
+    ) -> Result<(), ConnectionError> {
+        self.prefetch_extension_information(extension_name)
+    }
+
+    fn dyn_extension_information(
+        &self,
+        extension_name: &'static str,
+    ) -> Result<Option<ExtensionInformation>, ConnectionError> {
+        self.extension_information(extension_name)
+    }
+
+    fn dyn_extension_version(
+        &self,
+        extension_name: &'static str,
+    ) -> Result<Option<(u32, u32)>, ReplyError> {
+        self.extension_version(extension_name)
+    }
+
+    fn dyn_check_extension_version(
+        &self,
+        extension_name: &'static str,
+        required: (u32, u32),
+    ) -> Result<(), ConnectionError> {
+        self.check_extension_version(extension_name, required)
+    }
+
+    fn dyn_wait_for_reply_or_raw_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<ReplyOrError<Vec<u8>>, ConnectionError> {
+        Ok(reply_or_error_into_vec(
+            self.wait_for_reply_or_raw_error(sequence)?,
+        ))
+    }
+
+    fn dyn_wait_for_replies_or_raw_errors(
+        &self,
+        sequences: &[SequenceNumber],
+    ) -> Result<Vec<ReplyOrError<Vec<u8>>>, ConnectionError> {
+        Ok(self
+            .wait_for_replies_or_raw_errors(sequences)?
+            .into_iter()
+            .map(reply_or_error_into_vec)
+            .collect())
+    }
+
+    fn dyn_wait_for_reply_or_raw_error_timeout(
+        &self,
+        sequence: SequenceNumber,
+        timeout: Duration,
+    ) -> Result<ReplyOrError<Vec<u8>>, ConnectionError> {
+        Ok(reply_or_error_into_vec(
+            self.wait_for_reply_or_raw_error_timeout(sequence, timeout)?,
+        ))
+    }
+
+    fn dyn_wait_for_reply(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<Option<Vec<u8>>, ConnectionError> {
+        Ok(self.wait_for_reply(sequence)?.map(into_vec))
+    }
+
+    fn dyn_wait_for_reply_with_fds_raw(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<ReplyOrError<BufWithFds<Vec<u8>>, Vec<u8>>, ConnectionError> {
+        Ok(match self.wait_for_reply_with_fds_raw(sequence)? {
+            ReplyOrError::Reply((reply, fds)) => ReplyOrError::Reply((into_vec(reply), fds)),
+            ReplyOrError::Error(error) => ReplyOrError::Error(into_vec(error)),
+        })
+    }
+
+    fn dyn_check_for_raw_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<Option<Vec<u8>>, ConnectionError> {
+        Ok(self.check_for_raw_error(sequence)?.map(into_vec))
+    }
+
+    fn dyn_check_for_raw_error_timeout(
+        &self,
+        sequence: SequenceNumber,
+        timeout: Duration,
+    ) -> Result<Option<Vec<u8>>, ConnectionError> {
+        Ok(self
+            .check_for_raw_error_timeout(sequence, timeout)?
+            .map(into_vec))
+    }
+
+    fn dyn_prefetch_maximum_request_bytes(&self) {
+        self.prefetch_maximum_request_bytes()
+    }
+
+    fn dyn_maximum_request_bytes(&self) -> usize {
+        self.maximum_request_bytes()
+    }
+
+    fn dyn_parse_error(&self, error: &[u8]) -> Result<X11Error, ParseError> {
+        self.parse_error(error)
+    }
+
+    fn dyn_parse_event(&self, event: &[u8]) -> Result<Event, ParseError> {
+        self.parse_event(event)
+    }
+
+    fn dyn_statistics(&self) -> Option<ConnectionStatistics> {
+        self.statistics()
+    }
+
+    fn dyn_wait_for_raw_event_with_sequence(
+        &self,
+    ) -> Result<RawEventAndSeqNumber<Vec<u8>>, ConnectionError> {
+        let (event, seq) = self.wait_for_raw_event_with_sequence()?;
+        Ok((into_vec(event), seq))
+    }
+
+    fn dyn_wait_for_raw_event_with_sequence_timeout(
+        &self,
+        timeout: Duration,
+    ) -> Result<RawEventAndSeqNumber<Vec<u8>>, ConnectionError> {
+        let (event, seq) = self.wait_for_raw_event_with_sequence_timeout(timeout)?;
+        Ok((into_vec(event), seq))
+    }
+
+    fn dyn_poll_for_raw_event_with_sequence(
+        &self,
+    ) -> Result<Option<RawEventAndSeqNumber<Vec<u8>>>, ConnectionError> {
+        Ok(self
+            .poll_for_raw_event_with_sequence()?
+            .map(|(event, seq)| (into_vec(event), seq)))
+    }
+
+    fn dyn_flush(&self) -> Result<(), ConnectionError> {
+        self.flush()
+    }
+
+    fn dyn_setup(&self) -> &Setup {
+        self.setup()
+    }
+
+    fn dyn_generate_id(&self) -> Result<u32, ReplyOrIdError> {
+        self.generate_id()
+    }
+}
+
+/// Implement `RequestConnection` and `Connection` for a trait object type of `DynConnection`.
+macro_rules! impl_connection_for_dyn {
+    ($type:ty) => {
+        impl RequestConnection for $type {
+            type Buf = Vec<u8>;
+
+            #[track_caller]
+            fn send_request_with_reply<R>(
+                &self,
+                bufs: &[IoSlice<'_>],
+                fds: Vec<RawFdContainer>,
+            ) -> Result<Cookie<'_, Self, R>, ConnectionError>
+            where
+                R: TryParse,
+            {
+                let sequence = self.dyn_send_request_with_reply(bufs, fds)?;
+                Ok(Cookie::new(self, sequence))
+            }
+
+            #[track_caller]
+            fn send_request_with_reply_with_fds<R>(
+                &self,
+                bufs: &[IoSlice<'_>],
+                fds: Vec<RawFdContainer>,
+            ) -> Result<CookieWithFds<'_, Self, R>, ConnectionError>
+            where
+                R: crate::x11_utils::TryParseFd,
+            {
+                let sequence = self.dyn_send_request_with_reply_with_fds(bufs, fds)?;
+                Ok(CookieWithFds::new(self, sequence))
+            }
+
+            #[track_caller]
+            fn send_request_without_reply(
+                &self,
+                bufs: &[IoSlice<'_>],
+                fds: Vec<RawFdContainer>,
+            ) -> Result<VoidCookie<'_, Self>, ConnectionError> {
+                let sequence = self.dyn_send_request_without_reply(bufs, fds)?;
+                Ok(VoidCookie::new(self, sequence))
+            }
+
+            fn discard_reply(
+                &self,
+                sequence: SequenceNumber,
+                kind: RequestKind,
+                mode: DiscardMode,
+            ) {
+                self.dyn_discard_reply(sequence, kind, mode)
+            }
+
+            fn prefetch_extension_information(
+                &self,
+                extension_name: &'static str,
+            ) -> Result<(), ConnectionError> {
+                self.dyn_prefetch_extension_information(extension_name)
+            }
+
+            fn extension_information(
+                &self,
+                extension_name: &'static str,
+            ) -> Result<Option<ExtensionInformation>, ConnectionError> {
+                self.dyn_extension_information(extension_name)
+            }
+
+            fn extension_version(
+                &self,
+                extension_name: &'static str,
+            ) -> Result<Option<(u32, u32)>, ReplyError> {
+                self.dyn_extension_version(extension_name)
+            }
+
+            fn check_extension_version(
+                &self,
+                extension_name: &'static str,
+                required: (u32, u32),
+            ) -> Result<(), ConnectionError> {
+                self.dyn_check_extension_version(extension_name, required)
+            }
+
+            fn wait_for_reply_or_raw_error(
+                &self,
+                sequence: SequenceNumber,
+            ) -> Result<ReplyOrError<Vec<u8>>, ConnectionError> {
+                self.dyn_wait_for_reply_or_raw_error(sequence)
+            }
+
+            fn wait_for_replies_or_raw_errors(
+                &self,
+                sequences: &[SequenceNumber],
+            ) -> Result<Vec<ReplyOrError<Vec<u8>>>, ConnectionError> {
+                self.dyn_wait_for_replies_or_raw_errors(sequences)
+            }
+
+            fn wait_for_reply_or_raw_error_timeout(
+                &self,
+                sequence: SequenceNumber,
+                timeout: Duration,
+            ) -> Result<ReplyOrError<Vec<u8>>, ConnectionError> {
+                self.dyn_wait_for_reply_or_raw_error_timeout(sequence, timeout)
+            }
+
+            fn wait_for_reply(
+                &self,
+                sequence: SequenceNumber,
+            ) -> Result<Option<Vec<u8>>, ConnectionError> {
+                self.dyn_wait_for_reply(sequence)
+            }
+
+            fn wait_for_reply_with_fds_raw(
+                &self,
+                sequence: SequenceNumber,
+            ) -> Result<ReplyOrError<BufWithFds<Vec<u8>>, Vec<u8>>, ConnectionError> {
+                self.dyn_wait_for_reply_with_fds_raw(sequence)
+            }
+
+            fn check_for_raw_error(
+                &self,
+                sequence: SequenceNumber,
+            ) -> Result<Option<Vec<u8>>, ConnectionError> {
+                self.dyn_check_for_raw_error(sequence)
+            }
+
+            fn check_for_raw_error_timeout(
+                &self,
+                sequence: SequenceNumber,
+                timeout: Duration,
+            ) -> Result<Option<Vec<u8>>, ConnectionError> {
+                self.dyn_check_for_raw_error_timeout(sequence, timeout)
+            }
+
+            fn prefetch_maximum_request_bytes(&self) {
+                self.dyn_prefetch_maximum_request_bytes()
+            }
+
+            fn maximum_request_bytes(&self) -> usize {
+                self.dyn_maximum_request_bytes()
+            }
+
+            fn parse_error(&self, error: &[u8]) -> Result<X11Error, ParseError> {
+                self.dyn_parse_error(error)
+            }
+
+            fn parse_event(&self, event: &[u8]) -> Result<Event, ParseError> {
+                self.dyn_parse_event(event)
+            }
+
+            fn statistics(&self) -> Option<ConnectionStatistics> {
+                self.dyn_statistics()
+            }
+        }
+
+        impl Connection for $type {
+            fn wait_for_raw_event_with_sequence(
+                &self,
+            ) -> Result<RawEventAndSeqNumber<Vec<u8>>, ConnectionError> {
+                self.dyn_wait_for_raw_event_with_sequence()
+            }
+
+            fn wait_for_raw_event_with_sequence_timeout(
+                &self,
+                timeout: Duration,
+            ) -> Result<RawEventAndSeqNumber<Vec<u8>>, ConnectionError> {
+                self.dyn_wait_for_raw_event_with_sequence_timeout(timeout)
+            }
+
+            fn poll_for_raw_event_with_sequence(
+                &self,
+            ) -> Result<Option<RawEventAndSeqNumber<Vec<u8>>>, ConnectionError> {
+                self.dyn_poll_for_raw_event_with_sequence()
+            }
+
+            fn flush(&self) -> Result<(), ConnectionError> {
+                self.dyn_flush()
+            }
+
+            fn setup(&self) -> &Setup {
+                self.dyn_setup()
+            }
+
+            fn generate_id(&self) -> Result<u32, ReplyOrIdError> {
+                self.dyn_generate_id()
+            }
+        }
+    };
+}
+
+impl_connection_for_dyn!(dyn DynConnection + '_);
+impl_connection_for_dyn!(dyn DynConnection + Send + Sync + '_);
@@ -0,0 +1,257 @@
+//! Handling of X11 errors that no one waits for.
+//!
+//! Errors for requests without a reply are only reported via a cookie when the cookie is checked
+//! with [`VoidCookie::check`](crate::cookie::VoidCookie::check). Otherwise, the error is returned
+//! as [`Event::Error`](crate::protocol::Event::Error) from the event queue, where it is easily
+//! overlooked. An [`ErrorHandler`] that is installed on a connection receives such errors instead.
+//!
+//! Finding the request that caused an error is easier when the connection records where each
+//! request was sent from. This is disabled by default, because it costs some time and memory for
+//! each request. Once it is enabled, the error handler receives a [`RequestOrigin`] for errors.
+//!
+//! ```no_run
+//! use std::sync::Arc;
+//! use x11rb::connection::Connection;
+//! use x11rb::protocol::xproto::ConnectionExt;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let (conn, _) = x11rb::rust_connection::RustConnection::connect(None)?;
+//! conn.set_request_origin_tracking(true);
+//! conn.set_error_handler(Some(Arc::new(|error, origin| match origin {
+//!     Some(origin) => eprintln!("{:?} caused by the request sent at {}", error, origin),
+//!     None => eprintln!("{:?}", error),
+//! })));
+//! conn.destroy_window(0)?; // This error goes to the error handler
+//! conn.flush()?;
+//! let event = conn.wait_for_event()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::panic::Location;
+use std::sync::Arc;
+
+use crate::x11_utils::X11Error;
+use x11rb_protocol::SequenceNumber;
+
+/// A function that is called with X11 errors that would otherwise end up in the event queue.
+///
+/// The second argument describes where the failing request was sent from. It is only available
+/// when request origin tracking was enabled on the connection before the request was sent.
+///
+/// The handler is called by the thread that takes the error from the event queue, for example
+/// in [`Connection::poll_for_event`](crate::connection::Connection::poll_for_event). No internal
+/// locks of the connection are held while the handler runs.
+pub type ErrorHandler = Arc<dyn Fn(&X11Error, Option<&RequestOrigin>) + Send + Sync>;
+
+/// Describes where a request was sent from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RequestOrigin {
+    location: &'static Location<'static>,
+}
+
+impl RequestOrigin {
+    /// Get the location in the source code that sent the request.
+    ///
+    /// For requests that are sent via the functions in [`crate::protocol`], this is the place
+    /// where that function was called.
+    pub fn location(&self) -> &'static Location<'static> {
+        self.location
+    }
+}
+
+impl fmt::Display for RequestOrigin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.location, f)
+    }
+}
+
+/// The error handler of a connection and the origins of its requests.
+#[derive(Default)]
+pub(crate) struct ErrorHandling {
+    pub(crate) handler: Option<ErrorHandler>,
+    pub(crate) origins: Option<RequestOrigins>,
+}
+
+impl fmt::Debug for ErrorHandling {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ErrorHandling")
+            .field("handler", &self.handler.as_ref().map(|_| "<handler>"))
+            .field("origins", &self.origins)
+            .finish()
+    }
+}
+
+impl ErrorHandling {
+    /// Enable or disable recording the origins of requests.
+    pub(crate) fn set_origin_tracking(&mut self, enabled: bool) {
+        if enabled != self.origins.is_some() {
+            self.origins = if enabled {
+                Some(Default::default())
+            } else {
+                None
+            };
+        }
+    }
+
+    /// Remember where the request with the given sequence number was sent from, if enabled.
+    pub(crate) fn request_sent(
+        &mut self,
+        sequence: SequenceNumber,
+        location: &'static Location<'static>,
+    ) {
+        if let Some(origins) = &mut self.origins {
+            origins.request_sent(sequence, location);
+        }
+    }
+
+    /// The reply or error for the given sequence number was consumed or discarded.
+    ///
+    /// An error that does not go to the event queue will never be taken from there, so its origin
+    /// is no longer needed.
+    pub(crate) fn response_consumed(&mut self, sequence: SequenceNumber) {
+        if let Some(origins) = &mut self.origins {
+            origins.response_consumed(sequence);
+        }
+    }
+
+    /// Get the handler for an event from the event queue and the origin of its request.
+    ///
+    /// Returns `None` if the event is not an error or no handler is installed.
+    pub(crate) fn event_taken(
+        &mut self,
+        event: &[u8],
+        sequence: SequenceNumber,
+    ) -> Option<(ErrorHandler, Option<RequestOrigin>)> {
+        let origin = self
+            .origins
+            .as_mut()
+            .and_then(|origins| origins.event_taken(sequence));
+        match &self.handler {
+            Some(handler) if event[0] == 0 => Some((handler.clone(), origin)),
+            _ => None,
+        }
+    }
+}
+
+/// Keeps the origins of requests until it is clear that they did not cause an error.
+#[derive(Debug, Default)]
+pub(crate) struct RequestOrigins {
+    // Origins of requests that no response was received for yet, sorted by sequence number
+    requests: VecDeque<(SequenceNumber, RequestOrigin)>,
+    // Origins of requests that caused an error that was not returned yet
+    errors: HashMap<SequenceNumber, RequestOrigin>,
+}
+
+impl RequestOrigins {
+    /// Remember where the request with the given sequence number was sent from.
+    pub(crate) fn request_sent(
+        &mut self,
+        sequence: SequenceNumber,
+        location: &'static Location<'static>,
+    ) {
+        self.requests
+            .push_back((sequence, RequestOrigin { location }));
+    }
+
+    /// A packet with the given sequence number was received.
+    ///
+    /// Responses arrive in order, so requests with a smaller sequence number can no longer
+    /// cause an error.
+    pub(crate) fn packet_received(&mut self, sequence: SequenceNumber, is_error: bool) {
+        while let Some(&(request, origin)) = self.requests.front() {
+            if request > sequence {
+                break;
+            }
+            let _ = self.requests.pop_front();
+            if request == sequence && is_error {
+                let _ = self.errors.insert(request, origin);
+            }
+        }
+    }
+
+    /// The reply or error for the given sequence number was consumed without the event queue.
+    ///
+    /// This also covers requests whose response will be discarded once it arrives.
+    pub(crate) fn response_consumed(&mut self, sequence: SequenceNumber) {
+        let _ = self.errors.remove(&sequence);
+        let index = self
+            .requests
+            .iter()
+            .take_while(|&&(request, _)| request <= sequence)
+            .position(|&(request, _)| request == sequence);
+        if let Some(index) = index {
+            let _ = self.requests.remove(index);
+        }
+    }
+
+    /// An event or error with the given sequence number is taken from the event queue.
+    ///
+    /// Returns the origin of the request with that sequence number. Since the event queue is in
+    /// order, older requests are no longer needed afterwards.
+    pub(crate) fn event_taken(&mut self, sequence: SequenceNumber) -> Option<RequestOrigin> {
+        self.errors.retain(|&request, _| request >= sequence);
+        while let Some(&(request, _)) = self.requests.front() {
+            if request >= sequence {
+                break;
+            }
+            let _ = self.requests.pop_front();
+        }
+        // Connections that do not report received packets keep the origins of errors here
+        let pending = self
+            .requests
+            .front()
+            .filter(|&&(request, _)| request == sequence);
+        self.errors
+            .remove(&sequence)
+            .or_else(|| pending.map(|&(_, origin)| origin))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::RequestOrigins;
+    use std::panic::Location;
+
+    #[test]
+    fn keeps_origins_of_errors() {
+        let location = Location::caller();
+        let mut origins = RequestOrigins::default();
+        for sequence in 1..=4 {
+            origins.request_sent(sequence, location);
+        }
+        origins.packet_received(2, true);
+        origins.packet_received(3, false);
+        assert_eq!(origins.requests.len(), 1);
+
+        assert_eq!(origins.event_taken(1), None);
+        assert_eq!(origins.event_taken(2).unwrap().location(), location);
+        assert_eq!(origins.event_taken(2), None);
+        // No response was received yet
+        assert_eq!(origins.event_taken(4).unwrap().location(), location);
+        assert_eq!(origins.event_taken(5), None);
+        assert!(origins.requests.is_empty());
+    }
+
+    #[test]
+    fn forgets_origins_of_consumed_responses() {
+        let location = Location::caller();
+        let mut origins = RequestOrigins::default();
+        for sequence in 1..=1000 {
+            origins.request_sent(sequence, location);
+            origins.packet_received(sequence, true);
+            origins.response_consumed(sequence);
+        }
+        assert!(origins.errors.is_empty());
+        assert!(origins.requests.is_empty());
+
+        // The error of a discarded request arrives after the request was forgotten
+        origins.request_sent(1001, location);
+        origins.request_sent(1002, location);
+        origins.response_consumed(1001);
+        origins.packet_received(1001, true);
+        assert!(origins.errors.is_empty());
+        assert_eq!(origins.requests.len(), 1);
+    }
+}
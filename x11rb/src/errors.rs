@@ -2,7 +2,7 @@
 
 use crate::x11_utils::X11Error;
 
-pub use x11rb_protocol::errors::{ConnectError, IdsExhausted, ParseError};
+pub use x11rb_protocol::errors::{ConnectError, IdsExhausted, ParseError, SerializeError};
 
 /// An error occurred  while dynamically loading libxcb.
 #[cfg(feature = "dl-libxcb")]
@@ -56,6 +56,18 @@ pub enum ConnectionError {
     /// This corresponds to `XCB_CONN_CLOSED_EXT_NOTSUPPORTED`.
     UnsupportedExtension,
 
+    /// A request needs a newer version of an X11 extension than was negotiated with the server.
+    ///
+    /// See [`crate::extension_version`] for how versions are negotiated.
+    ExtensionVersionTooOld {
+        /// The name of the extension.
+        extension: &'static str,
+        /// The version of the extension that the request needs.
+        required: (u32, u32),
+        /// The version of the extension that was negotiated with the server.
+        negotiated: (u32, u32),
+    },
+
     /// A request larger than the maximum request length was sent.
     ///
     /// This corresponds to `XCB_CONN_CLOSED_REQ_LEN_EXCEED`.
@@ -76,6 +88,24 @@ pub enum ConnectionError {
 
     /// An I/O error occurred on the connection.
     IoError(std::io::Error),
+
+    /// Waiting for a reply or an event took longer than the given timeout.
+    ///
+    /// The connection is still usable after this error. The reply that was waited for can still
+    /// be received later.
+    Timeout,
+
+    /// A property was replaced too often while it was read in several parts.
+    ///
+    /// See [`crate::wrapper::ConnectionExt::get_property_full`].
+    PropertyKeptChanging,
+
+    /// Waiting with a timeout was requested, but the connection does not support timeouts.
+    ///
+    /// This is the case for `XCBConnection`, since libxcb does not offer a way to wait with a
+    /// timeout, and for `RustConnection`s whose [`Stream`](crate::rust_connection::Stream) does
+    /// not implement `poll_timeout`.
+    UnsupportedTimeout,
 }
 
 impl std::error::Error for ConnectionError {}
@@ -85,6 +115,15 @@ impl std::fmt::Display for ConnectionError {
         match self {
             ConnectionError::UnknownError => write!(f, "Unknown connection error"),
             ConnectionError::UnsupportedExtension => write!(f, "Unsupported extension"),
+            ConnectionError::ExtensionVersionTooOld {
+                extension,
+                required,
+                negotiated,
+            } => write!(
+                f,
+                "The request needs version {}.{} of the {} extension, but only {}.{} was negotiated",
+                required.0, required.1, extension, negotiated.0, negotiated.1
+            ),
             ConnectionError::InsufficientMemory => write!(f, "Insufficient memory"),
             ConnectionError::MaximumRequestLengthExceeded => {
                 write!(f, "Maximum request length exceeded")
@@ -92,6 +131,25 @@ impl std::fmt::Display for ConnectionError {
             ConnectionError::FdPassingFailed => write!(f, "FD passing failed"),
             ConnectionError::ParseError(err) => err.fmt(f),
             ConnectionError::IoError(err) => err.fmt(f),
+            ConnectionError::Timeout => write!(f, "Timed out waiting for the X11 server"),
+            ConnectionError::PropertyKeptChanging => {
+                write!(f, "The property kept changing while it was read")
+            }
+            ConnectionError::UnsupportedTimeout => {
+                write!(f, "The connection does not support timeouts")
+            }
+        }
+    }
+}
+
+impl ConnectionError {
+    /// Check whether this error means that the connection to the X11 server was lost.
+    ///
+    /// Returns `None` for errors that do not affect the connection itself.
+    pub fn disconnect_reason(&self) -> Option<DisconnectReason> {
+        match self {
+            ConnectionError::IoError(err) => DisconnectReason::from_io_error(err),
+            _ => None,
         }
     }
 }
@@ -104,7 +162,84 @@ impl From<ParseError> for ConnectionError {
 
 impl From<std::io::Error> for ConnectionError {
     fn from(err: std::io::Error) -> Self {
-        ConnectionError::IoError(err)
+        if UnsupportedTimeoutError::is_cause_of(&err) {
+            ConnectionError::UnsupportedTimeout
+        } else {
+            ConnectionError::IoError(err)
+        }
+    }
+}
+
+/// The payload of the I/O error that the default implementation of `Stream::poll_timeout`
+/// returns.
+///
+/// This allows to turn that I/O error into [`ConnectionError::UnsupportedTimeout`].
+#[derive(Debug)]
+pub(crate) struct UnsupportedTimeoutError;
+
+impl UnsupportedTimeoutError {
+    /// Wrap this payload in an I/O error.
+    pub(crate) fn into_io_error(self) -> std::io::Error {
+        std::io::Error::new(std::io::ErrorKind::Other, self)
+    }
+
+    /// Check whether the given I/O error was created by `into_io_error`.
+    fn is_cause_of(err: &std::io::Error) -> bool {
+        err.get_ref().map_or(false, |inner| inner.is::<Self>())
+    }
+}
+
+impl std::fmt::Display for UnsupportedTimeoutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "The stream does not support timeouts")
+    }
+}
+
+impl std::error::Error for UnsupportedTimeoutError {}
+
+/// The reason why the connection to the X11 server was lost.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DisconnectReason {
+    /// The X11 server closed the connection.
+    ///
+    /// This happens when the X11 server shuts down or is restarted, but also when it killed this
+    /// client, for example via `KillClient`.
+    ServerShutdown,
+
+    /// An I/O error of the given kind occurred on the connection.
+    IoError(std::io::ErrorKind),
+}
+
+impl DisconnectReason {
+    /// Get the reason for a lost connection from the I/O error that was caused by it.
+    ///
+    /// Returns `None` for errors that do not indicate a lost connection.
+    pub(crate) fn from_io_error(err: &std::io::Error) -> Option<Self> {
+        use std::io::ErrorKind;
+        if UnsupportedTimeoutError::is_cause_of(err) {
+            return None;
+        }
+        match err.kind() {
+            ErrorKind::WouldBlock | ErrorKind::Interrupted => None,
+            // The server closed the connection, either while we were reading or while we were
+            // writing. A reset happens if the server closed it with unread requests pending.
+            ErrorKind::UnexpectedEof | ErrorKind::BrokenPipe | ErrorKind::ConnectionReset => {
+                Some(DisconnectReason::ServerShutdown)
+            }
+            kind => Some(DisconnectReason::IoError(kind)),
+        }
+    }
+}
+
+impl std::fmt::Display for DisconnectReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DisconnectReason::ServerShutdown => write!(f, "The X11 server closed the connection"),
+            DisconnectReason::IoError(kind) => {
+                write!(f, "The connection to the X11 server failed: {:?}", kind)
+            }
+        }
     }
 }
 
@@ -128,6 +263,18 @@ impl std::fmt::Display for ReplyError {
     }
 }
 
+impl ReplyError {
+    /// Check whether this error means that the connection to the X11 server was lost.
+    ///
+    /// See [`ConnectionError::disconnect_reason`].
+    pub fn disconnect_reason(&self) -> Option<DisconnectReason> {
+        match self {
+            ReplyError::ConnectionError(err) => err.disconnect_reason(),
+            ReplyError::X11Error(_) => None,
+        }
+    }
+}
+
 impl From<ParseError> for ReplyError {
     fn from(err: ParseError) -> Self {
         Self::from(ConnectionError::from(err))
@@ -152,6 +299,61 @@ impl From<X11Error> for ReplyError {
     }
 }
 
+/// An error that occurred while waiting for the response to a request with a timeout.
+///
+/// When the timeout expires, the cookie that was waited on is returned in
+/// [`TimeoutError::TimedOut`]. It can be used to wait again, to wait without a timeout, or to
+/// discard the response.
+#[derive(Debug)]
+pub enum TimeoutError<T> {
+    /// The timeout expired before the response arrived.
+    TimedOut(T),
+    /// Some other error occurred, see [`ReplyError`].
+    ReplyError(ReplyError),
+}
+
+impl<T> TimeoutError<T> {
+    /// Get the cookie back if the timeout expired.
+    pub fn into_timed_out(self) -> Option<T> {
+        match self {
+            TimeoutError::TimedOut(cookie) => Some(cookie),
+            TimeoutError::ReplyError(_) => None,
+        }
+    }
+}
+
+impl<T: std::fmt::Debug> std::error::Error for TimeoutError<T> {}
+
+impl<T> std::fmt::Display for TimeoutError<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimeoutError::TimedOut(_) => ConnectionError::Timeout.fmt(f),
+            TimeoutError::ReplyError(e) => e.fmt(f),
+        }
+    }
+}
+
+impl<T> From<ReplyError> for TimeoutError<T> {
+    fn from(err: ReplyError) -> Self {
+        TimeoutError::ReplyError(err)
+    }
+}
+
+impl<T> From<ParseError> for TimeoutError<T> {
+    fn from(err: ParseError) -> Self {
+        ReplyError::from(err).into()
+    }
+}
+
+impl<T> From<TimeoutError<T>> for ReplyError {
+    fn from(err: TimeoutError<T>) -> Self {
+        match err {
+            TimeoutError::TimedOut(_) => ConnectionError::Timeout.into(),
+            TimeoutError::ReplyError(err) => err,
+        }
+    }
+}
+
 /// An error caused by some request or by the exhaustion of IDs.
 #[derive(Debug)]
 pub enum ReplyOrIdError {
@@ -175,6 +377,18 @@ impl std::fmt::Display for ReplyOrIdError {
 
 impl std::error::Error for ReplyOrIdError {}
 
+impl ReplyOrIdError {
+    /// Check whether this error means that the connection to the X11 server was lost.
+    ///
+    /// See [`ConnectionError::disconnect_reason`].
+    pub fn disconnect_reason(&self) -> Option<DisconnectReason> {
+        match self {
+            ReplyOrIdError::ConnectionError(err) => err.disconnect_reason(),
+            ReplyOrIdError::IdsExhausted | ReplyOrIdError::X11Error(_) => None,
+        }
+    }
+}
+
 impl From<ParseError> for ReplyOrIdError {
     fn from(err: ParseError) -> Self {
         ConnectionError::from(err).into()
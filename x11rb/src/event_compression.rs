@@ -0,0 +1,380 @@
+//! Merging of redundant events.
+//!
+//! Resizing or dragging a window causes the X11 server to send a flood of `ConfigureNotify`,
+//! `Expose`, and `MotionNotify` events. Most programs only care about the latest position or
+//! size and about the total area that needs to be redrawn. An [`EventCompressor`] sits between
+//! the connection and the event loop and merges such events:
+//!
+//! - Consecutive `MotionNotify` events for the same window are merged into the last one.
+//! - A series of `Expose` events for the same window is merged into one event whose rectangle is
+//!   the bounding box of the merged events and whose `count` is that of the last merged event.
+//!   This is zero if the whole series was received. Otherwise, the `count` says how many events
+//!   of the series still follow.
+//! - A `ConfigureNotify` event is dropped if it is followed by another `ConfigureNotify` event
+//!   for the same window. Only `ConfigureNotify` events for other windows may come in between,
+//!   so that e.g. a `MapNotify` is never moved before the configuration that preceded it.
+//!
+//! Only events that the connection already received are considered, so events are never held
+//! back while waiting for the server. All other events, and the events that are kept, are returned
+//! in the order in which they were received.
+//!
+//! ```no_run
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ConnectionError;
+//! use x11rb::event_compression::EventCompressor;
+//! # fn do_it(conn: &impl Connection) -> Result<(), ConnectionError> {
+//! let mut compressor = EventCompressor::new();
+//! // Handle every single MotionNotify, e.g. for a drawing program
+//! compressor.set_compress_motion(false);
+//! loop {
+//!     let event = compressor.wait_for_event(conn)?;
+//!     println!("{:?}", event);
+//! }
+//! # }
+//! ```
+
+use std::collections::VecDeque;
+
+use crate::connection::Connection;
+use crate::errors::ConnectionError;
+use crate::protocol::xproto::ExposeEvent;
+use crate::protocol::Event;
+
+/// Merges redundant events that are received from a connection.
+///
+/// See the [module documentation](self) for which events are merged. Each kind of merging is
+/// enabled by default and can be disabled separately.
+///
+/// The events are read from the connection with [`Connection::poll_for_event`] and
+/// [`Connection::wait_for_event`]. The compressor keeps events that were read, but not returned
+/// yet. Thus, all events have to be retrieved through the compressor once it is used.
+#[derive(Debug, Clone)]
+pub struct EventCompressor {
+    pending: VecDeque<Event>,
+    compress_motion: bool,
+    compress_expose: bool,
+    compress_configure: bool,
+}
+
+impl Default for EventCompressor {
+    fn default() -> Self {
+        Self {
+            pending: VecDeque::new(),
+            compress_motion: true,
+            compress_expose: true,
+            compress_configure: true,
+        }
+    }
+}
+
+impl EventCompressor {
+    /// Create a new compressor that merges all supported kinds of events.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Enable or disable merging consecutive `MotionNotify` events for the same window.
+    pub fn set_compress_motion(&mut self, enabled: bool) {
+        self.compress_motion = enabled;
+    }
+
+    /// Enable or disable merging series of `Expose` events for the same window.
+    pub fn set_compress_expose(&mut self, enabled: bool) {
+        self.compress_expose = enabled;
+    }
+
+    /// Enable or disable dropping `ConfigureNotify` events that are directly superseded by a later
+    /// one.
+    pub fn set_compress_configure(&mut self, enabled: bool) {
+        self.compress_configure = enabled;
+    }
+
+    /// Get the next event without blocking.
+    ///
+    /// This function returns `None` if no event is available.
+    pub fn poll_for_event<C: Connection + ?Sized>(
+        &mut self,
+        conn: &C,
+    ) -> Result<Option<Event>, ConnectionError> {
+        self.read_available(conn)?;
+        Ok(self.next_event())
+    }
+
+    /// Wait for the next event.
+    pub fn wait_for_event<C: Connection + ?Sized>(
+        &mut self,
+        conn: &C,
+    ) -> Result<Event, ConnectionError> {
+        if self.pending.is_empty() {
+            self.pending.push_back(conn.wait_for_event()?);
+        }
+        self.read_available(conn)?;
+        Ok(self
+            .next_event()
+            .expect("there is at least one pending event"))
+    }
+
+    /// Move all events that are available on the connection into the pending queue.
+    fn read_available<C: Connection + ?Sized>(&mut self, conn: &C) -> Result<(), ConnectionError> {
+        while let Some(event) = conn.poll_for_event()? {
+            self.pending.push_back(event);
+        }
+        Ok(())
+    }
+
+    /// Take the next event from the pending queue and merge it with the events that follow.
+    fn next_event(&mut self) -> Option<Event> {
+        loop {
+            let event = self.pending.pop_front()?;
+            match event {
+                Event::MotionNotify(mut motion) if self.compress_motion => {
+                    while let Some(Event::MotionNotify(next)) = self.pending.front() {
+                        if next.event != motion.event {
+                            break;
+                        }
+                        if let Some(Event::MotionNotify(next)) = self.pending.pop_front() {
+                            motion = next;
+                        }
+                    }
+                    return Some(Event::MotionNotify(motion));
+                }
+                Event::Expose(mut expose) if self.compress_expose => {
+                    while expose.count != 0 {
+                        match self.pending.front() {
+                            Some(Event::Expose(next)) if next.window == expose.window => {}
+                            _ => break,
+                        }
+                        if let Some(Event::Expose(next)) = self.pending.pop_front() {
+                            expose = union_expose(&expose, &next);
+                        }
+                    }
+                    return Some(Event::Expose(expose));
+                }
+                Event::ConfigureNotify(configure) if self.compress_configure => {
+                    // Only other windows' ConfigureNotify events may come in between
+                    let mut superseded = false;
+                    for later in self.pending.iter() {
+                        match later {
+                            Event::ConfigureNotify(later)
+                                if later.event == configure.event
+                                    && later.window == configure.window =>
+                            {
+                                superseded = true;
+                                break;
+                            }
+                            Event::ConfigureNotify(_) => {}
+                            _ => break,
+                        }
+                    }
+                    if !superseded {
+                        return Some(Event::ConfigureNotify(configure));
+                    }
+                }
+                event => return Some(event),
+            }
+        }
+    }
+}
+
+/// Merge an `Expose` event with the next event of its series.
+///
+/// The result covers the bounding box of both rectangles and has the `count` of `next`.
+fn union_expose(first: &ExposeEvent, next: &ExposeEvent) -> ExposeEvent {
+    let x1 = first.x.min(next.x);
+    let y1 = first.y.min(next.y);
+    let x2 = (u32::from(first.x) + u32::from(first.width))
+        .max(u32::from(next.x) + u32::from(next.width));
+    let y2 = (u32::from(first.y) + u32::from(first.height))
+        .max(u32::from(next.y) + u32::from(next.height));
+    ExposeEvent {
+        x: x1,
+        y: y1,
+        width: (x2 - u32::from(x1)).min(u32::from(u16::MAX)) as u16,
+        height: (y2 - u32::from(y1)).min(u32::from(u16::MAX)) as u16,
+        ..*next
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::EventCompressor;
+    use crate::protocol::xproto::{
+        ConfigureNotifyEvent, ExposeEvent, MapNotifyEvent, MotionNotifyEvent,
+        CONFIGURE_NOTIFY_EVENT, EXPOSE_EVENT, MAP_NOTIFY_EVENT, MOTION_NOTIFY_EVENT,
+    };
+    use crate::protocol::Event;
+
+    fn motion(window: u32, x: i16) -> Event {
+        Event::MotionNotify(MotionNotifyEvent {
+            response_type: MOTION_NOTIFY_EVENT,
+            event: window,
+            event_x: x,
+            ..Default::default()
+        })
+    }
+
+    fn expose(window: u32, x: u16, y: u16, width: u16, height: u16, count: u16) -> Event {
+        Event::Expose(ExposeEvent {
+            response_type: EXPOSE_EVENT,
+            window,
+            x,
+            y,
+            width,
+            height,
+            count,
+            ..Default::default()
+        })
+    }
+
+    fn configure(window: u32, width: u16) -> Event {
+        Event::ConfigureNotify(ConfigureNotifyEvent {
+            response_type: CONFIGURE_NOTIFY_EVENT,
+            event: window,
+            window,
+            width,
+            ..Default::default()
+        })
+    }
+
+    fn map(window: u32) -> Event {
+        Event::MapNotify(MapNotifyEvent {
+            response_type: MAP_NOTIFY_EVENT,
+            event: window,
+            window,
+            ..Default::default()
+        })
+    }
+
+    // `Event` does not implement `PartialEq`, so compare the debug output
+    fn compress(compressor: &mut EventCompressor, events: Vec<Event>) -> String {
+        compressor.pending.extend(events);
+        let events = std::iter::from_fn(|| compressor.next_event()).collect::<Vec<_>>();
+        format!("{:?}", events)
+    }
+
+    #[test]
+    fn merges_consecutive_motion() {
+        let events = vec![
+            motion(1, 1),
+            motion(1, 2),
+            motion(2, 3),
+            motion(1, 4),
+            map(1),
+            motion(1, 5),
+            motion(1, 6),
+        ];
+        let expected = vec![
+            motion(1, 2),
+            motion(2, 3),
+            motion(1, 4),
+            map(1),
+            motion(1, 6),
+        ];
+        assert_eq!(
+            compress(&mut EventCompressor::new(), events),
+            format!("{:?}", expected)
+        );
+    }
+
+    #[test]
+    fn unions_expose_series() {
+        let events = vec![
+            expose(1, 10, 10, 5, 5, 2),
+            expose(1, 0, 20, 5, 5, 1),
+            expose(1, 30, 0, 5, 5, 0),
+            expose(1, 0, 0, 1, 1, 0),
+            expose(2, 0, 0, 1, 1, 1),
+            map(2),
+            expose(2, 5, 5, 1, 1, 0),
+        ];
+        let expected = vec![
+            expose(1, 0, 0, 35, 25, 0),
+            expose(1, 0, 0, 1, 1, 0),
+            expose(2, 0, 0, 1, 1, 1),
+            map(2),
+            expose(2, 5, 5, 1, 1, 0),
+        ];
+        assert_eq!(
+            compress(&mut EventCompressor::new(), events),
+            format!("{:?}", expected)
+        );
+    }
+
+    #[test]
+    fn unions_partial_expose_series() {
+        let events = vec![
+            expose(1, 10, 10, 5, 5, 3),
+            expose(1, 0, 20, 5, 5, 2),
+            map(1),
+            expose(1, 30, 0, 5, 5, 1),
+        ];
+        let expected = vec![
+            expose(1, 0, 10, 15, 15, 2),
+            map(1),
+            expose(1, 30, 0, 5, 5, 1),
+        ];
+        assert_eq!(
+            compress(&mut EventCompressor::new(), events),
+            format!("{:?}", expected)
+        );
+    }
+
+    #[test]
+    fn keeps_last_configure() {
+        let events = vec![
+            configure(1, 10),
+            configure(2, 10),
+            map(1),
+            configure(1, 20),
+            configure(1, 30),
+        ];
+        let expected = vec![configure(1, 10), configure(2, 10), map(1), configure(1, 30)];
+        assert_eq!(
+            compress(&mut EventCompressor::new(), events),
+            format!("{:?}", expected)
+        );
+    }
+
+    #[test]
+    fn configure_not_merged_across_other_events() {
+        let events = vec![
+            configure(1, 10),
+            configure(2, 10),
+            configure(1, 20),
+            motion(1, 1),
+            configure(1, 30),
+            map(2),
+            configure(2, 20),
+        ];
+        let expected = vec![
+            configure(2, 10),
+            configure(1, 20),
+            motion(1, 1),
+            configure(1, 30),
+            map(2),
+            configure(2, 20),
+        ];
+        assert_eq!(
+            compress(&mut EventCompressor::new(), events),
+            format!("{:?}", expected)
+        );
+    }
+
+    #[test]
+    fn can_be_disabled() {
+        let events = vec![
+            motion(1, 1),
+            motion(1, 2),
+            expose(1, 0, 0, 1, 1, 1),
+            expose(1, 1, 1, 1, 1, 0),
+            configure(1, 10),
+            configure(1, 20),
+        ];
+        let mut compressor = EventCompressor::new();
+        compressor.set_compress_motion(false);
+        compressor.set_compress_expose(false);
+        compressor.set_compress_configure(false);
+        let expected = format!("{:?}", events);
+        assert_eq!(compress(&mut compressor, events), expected);
+    }
+}
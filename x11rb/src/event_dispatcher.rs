@@ -0,0 +1,247 @@
+//! Routing of events to handlers for individual windows.
+//!
+//! Instead of a large `match` on [`Event`] that then looks at the window of each event, an
+//! [`EventDispatcher`] allows to register handlers per window and optionally per event type. The
+//! window of an event is determined with [`Event::window`], which knows for each kind of event
+//! whether the `event`, `parent`, or `window` field is the relevant one.
+//!
+//! ```no_run
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ConnectionError;
+//! use x11rb::event_dispatcher::EventDispatcher;
+//! use x11rb::protocol::xproto::{Window, EXPOSE_EVENT};
+//! use x11rb::protocol::Event;
+//! # fn do_it(conn: &impl Connection, window: Window) -> Result<(), ConnectionError> {
+//! let mut dispatcher = EventDispatcher::new();
+//! dispatcher.add_handler(window, EXPOSE_EVENT, |event| {
+//!     if let Event::Expose(event) = event {
+//!         println!("Need to redraw {}x{} at {},{}", event.width, event.height, event.x, event.y);
+//!     }
+//! });
+//! dispatcher.add_window_handler(window, |event| println!("{:?}", event));
+//! dispatcher.set_fallback(|event| println!("Unrouted event {:?}", event));
+//! loop {
+//!     dispatcher.wait_and_dispatch(conn)?;
+//! }
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::connection::Connection;
+use crate::errors::ConnectionError;
+use crate::protocol::xproto::Window;
+use crate::protocol::Event;
+
+/// A function that handles events.
+type Handler<'a> = Box<dyn FnMut(&Event) + 'a>;
+
+/// Routes events to the handlers of their windows.
+///
+/// When an event is dispatched, the first handler that matches is called:
+///
+/// 1. The handler for the event's window and the event's response type, added with
+///    [`add_handler`](Self::add_handler).
+/// 2. The handler for all events of the window, added with
+///    [`add_window_handler`](Self::add_window_handler).
+/// 3. The fallback handler, set with [`set_fallback`](Self::set_fallback).
+///
+/// Events without a window, for example X11 errors, only go to the fallback.
+#[derive(Default)]
+pub struct EventDispatcher<'a> {
+    handlers: HashMap<(Window, Option<u8>), Handler<'a>>,
+    fallback: Option<Handler<'a>>,
+}
+
+impl fmt::Debug for EventDispatcher<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventDispatcher")
+            .field("handlers", &self.handlers.keys().collect::<Vec<_>>())
+            .field("fallback", &self.fallback.is_some())
+            .finish()
+    }
+}
+
+impl<'a> EventDispatcher<'a> {
+    /// Create a new dispatcher without any handlers.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Handle events of the given response type for `window`.
+    ///
+    /// `response_type` is compared with [`Event::response_type`]. For core events, this is one of
+    /// the constants like [`EXPOSE_EVENT`](crate::protocol::xproto::EXPOSE_EVENT). Events of
+    /// extensions have to be offset by the extension's `first_event`, and all events of the
+    /// Generic Event Extension share the response type
+    /// [`GE_GENERIC_EVENT`](crate::protocol::xproto::GE_GENERIC_EVENT).
+    ///
+    /// A previous handler for the same window and response type is replaced.
+    pub fn add_handler(
+        &mut self,
+        window: Window,
+        response_type: u8,
+        handler: impl FnMut(&Event) + 'a,
+    ) {
+        let _ = self
+            .handlers
+            .insert((window, Some(response_type)), Box::new(handler));
+    }
+
+    /// Handle all events for `window` that no handler for their response type exists for.
+    ///
+    /// A previous handler for the same window is replaced.
+    pub fn add_window_handler(&mut self, window: Window, handler: impl FnMut(&Event) + 'a) {
+        let _ = self.handlers.insert((window, None), Box::new(handler));
+    }
+
+    /// Remove all handlers for `window`.
+    ///
+    /// This is useful once the window was destroyed.
+    pub fn remove_window(&mut self, window: Window) {
+        self.handlers
+            .retain(|&(handler_window, _), _| handler_window != window);
+    }
+
+    /// Set the handler for events that no other handler exists for.
+    pub fn set_fallback(&mut self, handler: impl FnMut(&Event) + 'a) {
+        self.fallback = Some(Box::new(handler));
+    }
+
+    /// Remove the fallback handler.
+    ///
+    /// Events that no other handler exists for are then silently dropped.
+    pub fn remove_fallback(&mut self) {
+        self.fallback = None;
+    }
+
+    /// Call the handler for the given event.
+    ///
+    /// Returns `false` if no handler, not even a fallback, was found.
+    pub fn dispatch(&mut self, event: &Event) -> bool {
+        let key = event.window().map(|window| {
+            let typed_key = (window, Some(event.response_type()));
+            if self.handlers.contains_key(&typed_key) {
+                typed_key
+            } else {
+                (window, None)
+            }
+        });
+        let handler = match key.and_then(|key| self.handlers.get_mut(&key)) {
+            Some(handler) => Some(handler),
+            None => self.fallback.as_mut(),
+        };
+        match handler {
+            Some(handler) => {
+                handler(event);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Dispatch all events that are already available on the connection without blocking.
+    ///
+    /// Returns the number of events that were dispatched.
+    pub fn dispatch_pending<C: Connection + ?Sized>(
+        &mut self,
+        conn: &C,
+    ) -> Result<usize, ConnectionError> {
+        let mut count = 0;
+        while let Some(event) = conn.poll_for_event()? {
+            let _ = self.dispatch(&event);
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// Wait for an event, dispatch it, and then dispatch all other available events.
+    ///
+    /// Returns the number of events that were dispatched.
+    pub fn wait_and_dispatch<C: Connection + ?Sized>(
+        &mut self,
+        conn: &C,
+    ) -> Result<usize, ConnectionError> {
+        let event = conn.wait_for_event()?;
+        let _ = self.dispatch(&event);
+        Ok(1 + self.dispatch_pending(conn)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::cell::RefCell;
+
+    use super::EventDispatcher;
+    use crate::protocol::xproto::{
+        ConfigureNotifyEvent, ExposeEvent, CONFIGURE_NOTIFY_EVENT, EXPOSE_EVENT,
+    };
+    use crate::protocol::Event;
+
+    fn expose(window: u32) -> Event {
+        Event::Expose(ExposeEvent {
+            response_type: EXPOSE_EVENT,
+            window,
+            ..Default::default()
+        })
+    }
+
+    fn configure_notify(event: u32, window: u32) -> Event {
+        Event::ConfigureNotify(ConfigureNotifyEvent {
+            response_type: CONFIGURE_NOTIFY_EVENT,
+            event,
+            window,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn routes_by_window_and_type() {
+        let calls = RefCell::new(Vec::new());
+        let mut dispatcher = EventDispatcher::new();
+        dispatcher.add_handler(1, EXPOSE_EVENT, |_| calls.borrow_mut().push("expose 1"));
+        dispatcher.add_window_handler(1, |_| calls.borrow_mut().push("window 1"));
+        dispatcher.add_window_handler(2, |_| calls.borrow_mut().push("window 2"));
+        dispatcher.set_fallback(|_| calls.borrow_mut().push("fallback"));
+
+        assert!(dispatcher.dispatch(&expose(1)));
+        assert!(dispatcher.dispatch(&configure_notify(1, 5)));
+        assert!(dispatcher.dispatch(&expose(2)));
+        assert!(dispatcher.dispatch(&expose(3)));
+        assert!(dispatcher.dispatch(&Event::Unknown(vec![100; 32])));
+        drop(dispatcher);
+
+        assert_eq!(
+            calls.into_inner(),
+            ["expose 1", "window 1", "window 2", "fallback", "fallback"]
+        );
+    }
+
+    #[test]
+    fn uses_event_field() {
+        // A ConfigureNotify for window 5 that was selected on its parent 1
+        let event = configure_notify(1, 5);
+        assert_eq!(event.window(), Some(1));
+
+        let mut seen = None;
+        let mut dispatcher = EventDispatcher::new();
+        dispatcher.add_window_handler(1, |event| seen = event.window());
+        assert!(dispatcher.dispatch(&event));
+        drop(dispatcher);
+        assert_eq!(seen, Some(1));
+    }
+
+    #[test]
+    fn remove_handlers() {
+        let mut dispatcher = EventDispatcher::new();
+        dispatcher.add_handler(1, EXPOSE_EVENT, |_| {});
+        dispatcher.add_window_handler(1, |_| {});
+        dispatcher.set_fallback(|_| {});
+
+        dispatcher.remove_window(1);
+        assert!(dispatcher.dispatch(&expose(1)));
+        dispatcher.remove_fallback();
+        assert!(!dispatcher.dispatch(&expose(1)));
+    }
+}
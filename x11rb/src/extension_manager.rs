@@ -18,13 +18,50 @@ use x11rb_protocol::SequenceNumber;
 pub struct ExtensionManager(HashMap<&'static str, CheckState>);
 
 #[derive(Debug)]
-enum CheckState {
+pub(crate) enum CheckState {
     Prefetched(SequenceNumber),
     Present(ExtensionInformation),
     Missing,
     Error,
 }
 
+impl CheckState {
+    /// Update this state with the result of the `QueryExtension` request.
+    ///
+    /// This is used when the reply to a prefetched request was received. The new state is
+    /// returned in the format of `RequestConnection::extension_information()`.
+    pub(crate) fn handle_reply(
+        &mut self,
+        reply: Result<QueryExtensionReply, ReplyError>,
+    ) -> Result<Option<ExtensionInformation>, ConnectionError> {
+        match reply {
+            Err(err) => {
+                *self = CheckState::Error;
+                match err {
+                    ReplyError::ConnectionError(e) => Err(e),
+                    // The X11 protocol specification does not specify any error
+                    // for the QueryExtension request, so this should not happen.
+                    ReplyError::X11Error(_) => Err(ConnectionError::UnknownError),
+                }
+            }
+            Ok(info) => {
+                if info.present {
+                    let info = ExtensionInformation {
+                        major_opcode: info.major_opcode,
+                        first_event: info.first_event,
+                        first_error: info.first_error,
+                    };
+                    *self = CheckState::Present(info);
+                    Ok(Some(info))
+                } else {
+                    *self = CheckState::Missing;
+                    Ok(None)
+                }
+            }
+        }
+    }
+}
+
 impl ExtensionManager {
     /// If the extension has not prefetched yet, sends a `QueryExtension`
     /// requests, adds a field to the hash map and returns a reference to it.
@@ -66,37 +103,33 @@ impl ExtensionManager {
         let entry = self.prefetch_extension_information_aux(conn, extension_name)?;
         match entry {
             CheckState::Prefetched(sequence_number) => {
-                match Cookie::<C, QueryExtensionReply>::new(conn, *sequence_number).reply() {
-                    Err(err) => {
-                        *entry = CheckState::Error;
-                        match err {
-                            ReplyError::ConnectionError(e) => Err(e),
-                            // The X11 protocol specification does not specify any error
-                            // for the QueryExtension request, so this should not happen.
-                            ReplyError::X11Error(_) => Err(ConnectionError::UnknownError),
-                        }
-                    }
-                    Ok(info) => {
-                        if info.present {
-                            let info = ExtensionInformation {
-                                major_opcode: info.major_opcode,
-                                first_event: info.first_event,
-                                first_error: info.first_error,
-                            };
-                            *entry = CheckState::Present(info);
-                            Ok(Some(info))
-                        } else {
-                            *entry = CheckState::Missing;
-                            Ok(None)
-                        }
-                    }
-                }
+                let reply = Cookie::<C, QueryExtensionReply>::new(conn, *sequence_number).reply();
+                entry.handle_reply(reply)
             }
             CheckState::Present(info) => Ok(Some(*info)),
             CheckState::Missing => Ok(None),
             CheckState::Error => Err(ConnectionError::UnknownError),
         }
     }
+
+    /// Get the state of an extension, if it is known.
+    ///
+    /// This is used by connections that cannot block while waiting for the reply to a
+    /// `QueryExtension` request.
+    pub(crate) fn state_mut(&mut self, extension_name: &'static str) -> Option<&mut CheckState> {
+        self.0.get_mut(extension_name)
+    }
+
+    /// Remember that a `QueryExtension` request for the given extension was sent.
+    pub(crate) fn insert_prefetched(
+        &mut self,
+        extension_name: &'static str,
+        sequence_number: SequenceNumber,
+    ) {
+        let _ = self
+            .0
+            .insert(extension_name, CheckState::Prefetched(sequence_number));
+    }
 }
 
 impl ExtInfoProvider for ExtensionManager {
@@ -146,6 +179,13 @@ impl ExtInfoProvider for ExtensionManager {
             })
             .max_by_key(|(_, info)| info.first_error)
     }
+
+    fn get_from_name(&self, extension_name: &str) -> Option<ExtensionInformation> {
+        match self.0.get(extension_name) {
+            Some(CheckState::Present(info)) => Some(*info),
+            _ => None,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,244 @@
+//! Negotiation of the versions of X11 extensions.
+//!
+//! Many extensions expect a `QueryVersion` request (sometimes called differently) before any
+//! other request of the extension is sent. The client announces the newest version that it
+//! supports and the server answers with the version that it will use for this client. Both sides
+//! then behave according to this version.
+//!
+//! [`RequestConnection::extension_version`] performs this handshake. The client version is the
+//! version of the protocol description that x11rb was generated from. [`RustConnection`] and
+//! `XCBConnection` negotiate each extension only once and remember the result.
+//!
+//! After a version was negotiated, the request functions in [`crate::protocol`] check that the
+//! negotiated version is new enough for the request. If it is not, they fail with
+//! [`ConnectionError::ExtensionVersionTooOld`] instead of sending a request that the server would
+//! reject. No check happens for extensions whose version was not negotiated via
+//! [`RequestConnection::extension_version`]. Currently, only the requests of the Composite,
+//! RandR, and XFIXES extensions are checked, see [`RequestConnection::check_extension_version`].
+//!
+//! [`RustConnection`]: crate::rust_connection::RustConnection
+//!
+//! ```no_run
+//! use x11rb::connection::RequestConnection;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let (conn, _) = x11rb::connect(None)?;
+//! match conn.extension_version("RANDR")? {
+//!     Some((major, minor)) => println!("Using RandR {}.{}", major, minor),
+//!     None => println!("RandR is not supported by the X11 server"),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use crate::connection::RequestConnection;
+use crate::errors::{ConnectionError, ReplyError};
+
+/// Send the version request of an extension and get `Some` version from its reply.
+#[allow(unused_macros)]
+macro_rules! query_version {
+    ($conn:expr, $module:ident::$function:ident(u32), $major:ident, $minor:ident) => {{
+        let (major, minor) = crate::protocol::$module::X11_XML_VERSION;
+        let reply = crate::protocol::$module::$function($conn, major, minor)?.reply()?;
+        Some((reply.$major, reply.$minor))
+    }};
+    ($conn:expr, $module:ident::$function:ident($arg:ty), $major:ident, $minor:ident) => {{
+        let (major, minor) = crate::protocol::$module::X11_XML_VERSION;
+        let reply =
+            crate::protocol::$module::$function($conn, major as $arg, minor as $arg)?.reply()?;
+        Some((u32::from(reply.$major), u32::from(reply.$minor)))
+    }};
+    ($conn:expr, $module:ident::$function:ident(), $major:ident, $minor:ident) => {{
+        let reply = crate::protocol::$module::$function($conn)?.reply()?;
+        Some((u32::from(reply.$major), u32::from(reply.$minor)))
+    }};
+}
+
+/// Negotiate the version of an extension with the X11 server.
+///
+/// This sends the version request of the extension with the newest version that x11rb supports
+/// and waits for the reply. Returns `None` if the server does not support the extension.
+/// Extensions that do not have a version request, or that are not enabled via their feature, cause
+/// [`ConnectionError::UnsupportedExtension`].
+///
+/// This function does not cache anything. Use [`RequestConnection::extension_version`] instead.
+pub fn negotiate_version<C: RequestConnection + ?Sized>(
+    conn: &C,
+    extension_name: &'static str,
+) -> Result<Option<(u32, u32)>, ReplyError> {
+    if conn.extension_information(extension_name)?.is_none() {
+        return Ok(None);
+    }
+    let version = match extension_name {
+        #[cfg(feature = "composite")]
+        crate::protocol::composite::X11_EXTENSION_NAME => {
+            query_version!(
+                conn,
+                composite::query_version(u32),
+                major_version,
+                minor_version
+            )
+        }
+        #[cfg(feature = "damage")]
+        crate::protocol::damage::X11_EXTENSION_NAME => {
+            query_version!(
+                conn,
+                damage::query_version(u32),
+                major_version,
+                minor_version
+            )
+        }
+        #[cfg(feature = "dpms")]
+        crate::protocol::dpms::X11_EXTENSION_NAME => query_version!(
+            conn,
+            dpms::get_version(u16),
+            server_major_version,
+            server_minor_version
+        ),
+        #[cfg(feature = "dri2")]
+        crate::protocol::dri2::X11_EXTENSION_NAME => {
+            query_version!(conn, dri2::query_version(u32), major_version, minor_version)
+        }
+        #[cfg(feature = "dri3")]
+        crate::protocol::dri3::X11_EXTENSION_NAME => {
+            query_version!(conn, dri3::query_version(u32), major_version, minor_version)
+        }
+        #[cfg(feature = "glx")]
+        crate::protocol::glx::X11_EXTENSION_NAME => {
+            query_version!(conn, glx::query_version(u32), major_version, minor_version)
+        }
+        #[cfg(feature = "present")]
+        crate::protocol::present::X11_EXTENSION_NAME => {
+            query_version!(
+                conn,
+                present::query_version(u32),
+                major_version,
+                minor_version
+            )
+        }
+        #[cfg(feature = "randr")]
+        crate::protocol::randr::X11_EXTENSION_NAME => {
+            query_version!(
+                conn,
+                randr::query_version(u32),
+                major_version,
+                minor_version
+            )
+        }
+        #[cfg(feature = "record")]
+        crate::protocol::record::X11_EXTENSION_NAME => {
+            query_version!(
+                conn,
+                record::query_version(u16),
+                major_version,
+                minor_version
+            )
+        }
+        #[cfg(feature = "render")]
+        crate::protocol::render::X11_EXTENSION_NAME => {
+            query_version!(
+                conn,
+                render::query_version(u32),
+                major_version,
+                minor_version
+            )
+        }
+        #[cfg(feature = "res")]
+        crate::protocol::res::X11_EXTENSION_NAME => {
+            query_version!(conn, res::query_version(u8), server_major, server_minor)
+        }
+        #[cfg(feature = "screensaver")]
+        crate::protocol::screensaver::X11_EXTENSION_NAME => query_version!(
+            conn,
+            screensaver::query_version(u8),
+            server_major_version,
+            server_minor_version
+        ),
+        #[cfg(feature = "shape")]
+        crate::protocol::shape::X11_EXTENSION_NAME => {
+            query_version!(conn, shape::query_version(), major_version, minor_version)
+        }
+        #[cfg(feature = "shm")]
+        crate::protocol::shm::X11_EXTENSION_NAME => {
+            query_version!(conn, shm::query_version(), major_version, minor_version)
+        }
+        #[cfg(feature = "sync")]
+        crate::protocol::sync::X11_EXTENSION_NAME => {
+            query_version!(conn, sync::initialize(u8), major_version, minor_version)
+        }
+        #[cfg(feature = "xfixes")]
+        crate::protocol::xfixes::X11_EXTENSION_NAME => {
+            query_version!(
+                conn,
+                xfixes::query_version(u32),
+                major_version,
+                minor_version
+            )
+        }
+        #[cfg(feature = "xinerama")]
+        crate::protocol::xinerama::X11_EXTENSION_NAME => {
+            query_version!(conn, xinerama::query_version(u8), major, minor)
+        }
+        #[cfg(feature = "xinput")]
+        crate::protocol::xinput::X11_EXTENSION_NAME => {
+            query_version!(
+                conn,
+                xinput::xi_query_version(u16),
+                major_version,
+                minor_version
+            )
+        }
+        _ => None,
+    };
+    match version {
+        Some(version) => Ok(Some(version)),
+        None => Err(ConnectionError::UnsupportedExtension.into()),
+    }
+}
+
+/// Helper for implementing `RequestConnection::extension_version()`.
+///
+/// This remembers the negotiated version of each extension. Most likely, you do not need this in
+/// your own code, unless you really want to implement your own X11 connection.
+#[derive(Debug, Default)]
+pub struct ExtensionVersions(Mutex<HashMap<&'static str, Option<(u32, u32)>>>);
+
+impl ExtensionVersions {
+    /// Get the negotiated version of an extension, negotiating it if necessary.
+    pub fn extension_version<C: RequestConnection + ?Sized>(
+        &self,
+        conn: &C,
+        extension_name: &'static str,
+    ) -> Result<Option<(u32, u32)>, ReplyError> {
+        if let Some(&version) = self.0.lock().unwrap().get(extension_name) {
+            return Ok(version);
+        }
+        // The lock is not held while negotiating, since sending requests might need it again.
+        // If two threads negotiate at the same time, the server answers both the same way.
+        let version = negotiate_version(conn, extension_name)?;
+        let _ = self.0.lock().unwrap().insert(extension_name, version);
+        Ok(version)
+    }
+
+    /// Check that the negotiated version of an extension is at least `required`.
+    ///
+    /// Nothing is checked if the version of the extension was not negotiated yet.
+    pub fn check_extension_version(
+        &self,
+        extension_name: &'static str,
+        required: (u32, u32),
+    ) -> Result<(), ConnectionError> {
+        match self.0.lock().unwrap().get(extension_name) {
+            Some(&Some(negotiated)) if negotiated < required => {
+                Err(ConnectionError::ExtensionVersionTooOld {
+                    extension: extension_name,
+                    required,
+                    negotiated,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,196 @@
+//! An in-process fake X11 server for tests.
+//!
+//! [`FakeServer`] implements a small subset of the core X11 protocol with real state, so that
+//! code using x11rb can be tested without running an X11 server like Xvfb. Each call to
+//! [`FakeServer::connect`] creates a new client of the same server. Clients can see each other's
+//! windows, properties, and selections.
+//!
+//! The following requests are supported and generate the usual events:
+//!
+//! * `CreateWindow`, `DestroyWindow`, `MapWindow`, `UnmapWindow`, `ConfigureWindow`,
+//!   `ChangeWindowAttributes` (only the event mask, override-redirect and do-not-propagate mask
+//!   have an effect), `GetWindowAttributes`, `GetGeometry`, and `QueryTree`
+//! * `InternAtom` and `GetAtomName`
+//! * `ChangeProperty`, `DeleteProperty`, `GetProperty`, and `ListProperties`
+//! * `SetSelectionOwner`, `GetSelectionOwner`, and `ConvertSelection`
+//! * `SendEvent`
+//! * `GetInputFocus`, `QueryExtension` (no extensions are present), and `NoOperation`
+//!
+//! `MapWindow` and `ConfigureWindow` are redirected to a client that selected
+//! `SubstructureRedirect` on the parent window, which allows testing window manager logic.
+//!
+//! All other requests fail with an `Implementation` error. The server has a single screen with a
+//! 24 bit TrueColor visual. It only accepts clients using the native byte order.
+//!
+//! The code in this module is only available when the `fake_server` feature of the library is
+//! enabled.
+//!
+//! # Example
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::fake_server::FakeServer;
+//! use x11rb::protocol::xproto::{ConnectionExt, CreateWindowAux, WindowClass};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let server = FakeServer::new();
+//! let conn = server.connect()?;
+//! let root = conn.setup().roots[0].root;
+//! let window = conn.generate_id()?;
+//! conn.create_window(0, window, root, 0, 0, 100, 100, 0, WindowClass::INPUT_OUTPUT, 0,
+//!                    &CreateWindowAux::new())?;
+//! let tree = conn.query_tree(root)?.reply()?;
+//! assert_eq!(tree.children, [window]);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::io::{Error, ErrorKind, Result};
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::errors::ConnectError;
+use crate::rust_connection::{PollMode, RustConnection, Stream};
+use crate::utils::RawFdContainer;
+
+mod state;
+
+use state::{ClientId, ServerState};
+
+#[derive(Debug)]
+struct Shared {
+    state: Mutex<ServerState>,
+    /// Notified when new data is available for any client.
+    condition: Condvar,
+}
+
+/// An in-process fake X11 server.
+///
+/// Cloning a `FakeServer` produces another handle to the same server.
+#[derive(Debug, Clone)]
+pub struct FakeServer {
+    shared: Arc<Shared>,
+}
+
+impl Default for FakeServer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FakeServer {
+    /// Create a new fake server with a screen of size 1024x768.
+    pub fn new() -> Self {
+        Self::with_screen_size(1024, 768)
+    }
+
+    /// Create a new fake server with a screen of the given size.
+    pub fn with_screen_size(width: u16, height: u16) -> Self {
+        Self {
+            shared: Arc::new(Shared {
+                state: Mutex::new(ServerState::new(width, height)),
+                condition: Condvar::new(),
+            }),
+        }
+    }
+
+    /// Create a new client of this server.
+    ///
+    /// The returned stream still has to go through the setup, for example with
+    /// [`RustConnection::connect_to_stream`].
+    pub fn stream(&self) -> FakeStream {
+        let client = self.shared.state.lock().unwrap().add_client();
+        FakeStream {
+            shared: Arc::clone(&self.shared),
+            client,
+        }
+    }
+
+    /// Create a new client of this server and connect to it.
+    pub fn connect(&self) -> std::result::Result<RustConnection<FakeStream>, ConnectError> {
+        RustConnection::connect_to_stream(self.stream(), 0)
+    }
+}
+
+/// A [`Stream`] connected to a [`FakeServer`].
+///
+/// Requests are handled synchronously while they are written. When the stream is dropped, the
+/// client disconnects and all of its windows are destroyed.
+///
+/// Waiting for data with [`Stream::poll`] blocks until another client causes an event for this
+/// client. Thus, a test that waits for an event that is never generated hangs, just like with a
+/// real X11 server.
+#[derive(Debug)]
+pub struct FakeStream {
+    shared: Arc<Shared>,
+    client: ClientId,
+}
+
+impl FakeStream {
+    fn wait_readable(&self, deadline: Option<Instant>) -> Result<()> {
+        let mut state = self.shared.state.lock().unwrap();
+        while !state.has_output(self.client) {
+            match deadline {
+                None => state = self.shared.condition.wait(state).unwrap(),
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        break;
+                    }
+                    state = self
+                        .shared
+                        .condition
+                        .wait_timeout(state, deadline - now)
+                        .unwrap()
+                        .0;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Stream for FakeStream {
+    fn poll(&self, mode: PollMode) -> Result<()> {
+        if mode.writable() {
+            Ok(())
+        } else {
+            self.wait_readable(None)
+        }
+    }
+
+    fn poll_timeout(&self, mode: PollMode, timeout: Duration) -> Result<()> {
+        if mode.writable() {
+            Ok(())
+        } else {
+            self.wait_readable(Some(Instant::now() + timeout))
+        }
+    }
+
+    fn read(&self, buf: &mut [u8], _fd_storage: &mut Vec<RawFdContainer>) -> Result<usize> {
+        let mut state = self.shared.state.lock().unwrap();
+        match state.read(self.client, buf) {
+            0 => Err(Error::new(ErrorKind::WouldBlock, "no data available")),
+            n => Ok(n),
+        }
+    }
+
+    fn write(&self, buf: &[u8], fds: &mut Vec<RawFdContainer>) -> Result<usize> {
+        if !fds.is_empty() {
+            return Err(Error::new(
+                ErrorKind::Other,
+                "the fake server does not support FD passing",
+            ));
+        }
+        self.shared.state.lock().unwrap().write(self.client, buf);
+        self.shared.condition.notify_all();
+        Ok(buf.len())
+    }
+}
+
+impl Drop for FakeStream {
+    fn drop(&mut self) {
+        self.shared.state.lock().unwrap().remove_client(self.client);
+        self.shared.condition.notify_all();
+    }
+}
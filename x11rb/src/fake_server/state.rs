@@ -0,0 +1,1357 @@
+//! The state of the fake X11 server and the implementation of the supported requests.
+
+use std::collections::{HashMap, VecDeque};
+use std::convert::TryFrom;
+
+use crate::protocol::xproto::{
+    Atom, BackingStore, ChangePropertyRequest, ChangeWindowAttributesAux, ConfigWindow,
+    ConfigureNotifyEvent, ConfigureRequestEvent, ConfigureWindowAux, ConvertSelectionRequest,
+    CreateNotifyEvent, CreateWindowRequest, Depth, DestroyNotifyEvent, EventMask, ExposeEvent,
+    Format, GetAtomNameReply, GetGeometryReply, GetInputFocusReply, GetPropertyReply,
+    GetPropertyRequest, GetSelectionOwnerReply, GetWindowAttributesReply, Gravity, ImageOrder,
+    InputFocus, InternAtomReply, ListPropertiesReply, MapNotifyEvent, MapRequestEvent, MapState,
+    PropMode, Property, PropertyNotifyEvent, QueryExtensionReply, QueryTreeReply,
+    SelectionClearEvent, SelectionNotifyEvent, SelectionRequestEvent, SendEventRequest,
+    SetSelectionOwnerRequest, Setup, SetupFailed, StackMode, Timestamp, UnmapNotifyEvent,
+    VisualClass, Visualtype, Window, WindowClass, ACCESS_ERROR, ATOM_ERROR, CONFIGURE_NOTIFY_EVENT,
+    CONFIGURE_REQUEST_EVENT, CREATE_NOTIFY_EVENT, DESTROY_NOTIFY_EVENT, DRAWABLE_ERROR,
+    EXPOSE_EVENT, ID_CHOICE_ERROR, IMPLEMENTATION_ERROR, LENGTH_ERROR, MAP_NOTIFY_EVENT,
+    MAP_REQUEST_EVENT, MATCH_ERROR, PROPERTY_NOTIFY_EVENT, SELECTION_CLEAR_EVENT,
+    SELECTION_NOTIFY_EVENT, SELECTION_REQUEST_EVENT, UNMAP_NOTIFY_EVENT, VALUE_ERROR, WINDOW_ERROR,
+};
+use crate::protocol::Request;
+use crate::x11_utils::{
+    parse_request_header, BigRequests, ExtInfoProvider, ExtensionInformation, Serialize,
+};
+
+/// Identifier of a client of the fake server.
+pub(super) type ClientId = usize;
+
+/// The root window of the only screen.
+const ROOT: Window = 0x100;
+/// The default colormap of the only screen.
+const COLORMAP: u32 = 0x101;
+/// The only visual of the only screen.
+const VISUAL: u32 = 0x102;
+/// The depth of the root window.
+const DEPTH: u8 = 24;
+/// The part of a resource ID that a client can choose freely.
+const RESOURCE_ID_MASK: u32 = 0x001f_ffff;
+
+/// The names of the predefined atoms, starting with atom 1.
+const PREDEFINED_ATOMS: [&str; 68] = [
+    "PRIMARY",
+    "SECONDARY",
+    "ARC",
+    "ATOM",
+    "BITMAP",
+    "CARDINAL",
+    "COLORMAP",
+    "CURSOR",
+    "CUT_BUFFER0",
+    "CUT_BUFFER1",
+    "CUT_BUFFER2",
+    "CUT_BUFFER3",
+    "CUT_BUFFER4",
+    "CUT_BUFFER5",
+    "CUT_BUFFER6",
+    "CUT_BUFFER7",
+    "DRAWABLE",
+    "FONT",
+    "INTEGER",
+    "PIXMAP",
+    "POINT",
+    "RECTANGLE",
+    "RESOURCE_MANAGER",
+    "RGB_COLOR_MAP",
+    "RGB_BEST_MAP",
+    "RGB_BLUE_MAP",
+    "RGB_DEFAULT_MAP",
+    "RGB_GRAY_MAP",
+    "RGB_GREEN_MAP",
+    "RGB_RED_MAP",
+    "STRING",
+    "VISUALID",
+    "WINDOW",
+    "WM_COMMAND",
+    "WM_HINTS",
+    "WM_CLIENT_MACHINE",
+    "WM_ICON_NAME",
+    "WM_ICON_SIZE",
+    "WM_NAME",
+    "WM_NORMAL_HINTS",
+    "WM_SIZE_HINTS",
+    "WM_ZOOM_HINTS",
+    "MIN_SPACE",
+    "NORM_SPACE",
+    "MAX_SPACE",
+    "END_SPACE",
+    "SUPERSCRIPT_X",
+    "SUPERSCRIPT_Y",
+    "SUBSCRIPT_X",
+    "SUBSCRIPT_Y",
+    "UNDERLINE_POSITION",
+    "UNDERLINE_THICKNESS",
+    "STRIKEOUT_ASCENT",
+    "STRIKEOUT_DESCENT",
+    "ITALIC_ANGLE",
+    "X_HEIGHT",
+    "QUAD_WIDTH",
+    "WEIGHT",
+    "POINT_SIZE",
+    "RESOLUTION",
+    "COPYRIGHT",
+    "NOTICE",
+    "FONT_NAME",
+    "FAMILY_NAME",
+    "FULL_NAME",
+    "CAP_HEIGHT",
+    "WM_CLASS",
+    "WM_TRANSIENT_FOR",
+];
+
+/// An X11 error that a request caused.
+#[derive(Debug, Clone, Copy)]
+struct RequestError {
+    error_code: u8,
+    bad_value: u32,
+}
+
+impl RequestError {
+    fn new(error_code: u8, bad_value: u32) -> Self {
+        Self {
+            error_code,
+            bad_value,
+        }
+    }
+}
+
+type RequestResult = Result<(), RequestError>;
+
+/// An `ExtInfoProvider` for a server without any extensions.
+struct NoExtensions;
+
+impl ExtInfoProvider for NoExtensions {
+    fn get_from_major_opcode(&self, _major_opcode: u8) -> Option<(&str, ExtensionInformation)> {
+        None
+    }
+
+    fn get_from_event_code(&self, _event_code: u8) -> Option<(&str, ExtensionInformation)> {
+        None
+    }
+
+    fn get_from_error_code(&self, _error_code: u8) -> Option<(&str, ExtensionInformation)> {
+        None
+    }
+}
+
+#[derive(Debug, Default)]
+struct Client {
+    /// Bytes that were written by the client, but not yet handled.
+    input: Vec<u8>,
+    /// Bytes that the client can read.
+    output: VecDeque<u8>,
+    /// Was the setup already done?
+    setup_done: bool,
+    /// The sequence number of the last request of this client.
+    sequence: u16,
+}
+
+#[derive(Debug)]
+struct PropertyValue {
+    type_: Atom,
+    format: u8,
+    data: Vec<u8>,
+}
+
+#[derive(Debug)]
+struct WindowState {
+    /// The client that created this window, or `None` for the root window.
+    creator: Option<ClientId>,
+    /// The parent window, or `0` for the root window.
+    parent: Window,
+    /// All children of this window in stacking order from bottom to top.
+    children: Vec<Window>,
+    x: i16,
+    y: i16,
+    width: u16,
+    height: u16,
+    border_width: u16,
+    class: WindowClass,
+    mapped: bool,
+    override_redirect: bool,
+    /// The event masks that the clients selected on this window.
+    event_masks: HashMap<ClientId, u32>,
+    do_not_propagate_mask: u32,
+    /// The properties of this window in the order in which they were created.
+    properties: Vec<(Atom, PropertyValue)>,
+}
+
+impl WindowState {
+    fn property(&self, atom: Atom) -> Option<&PropertyValue> {
+        self.properties
+            .iter()
+            .find(|(name, _)| *name == atom)
+            .map(|(_, value)| value)
+    }
+
+    fn all_event_masks(&self) -> u32 {
+        self.event_masks.values().fold(0, |acc, mask| acc | mask)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct SelectionOwner {
+    window: Window,
+    client: ClientId,
+    time: Timestamp,
+}
+
+/// The complete state of the fake X11 server.
+#[derive(Debug)]
+pub(super) struct ServerState {
+    width: u16,
+    height: u16,
+    clients: HashMap<ClientId, Client>,
+    next_client: ClientId,
+    /// The names of all atoms, starting with atom 1.
+    atoms: Vec<Vec<u8>>,
+    windows: HashMap<Window, WindowState>,
+    selections: HashMap<Atom, SelectionOwner>,
+    /// The current server time, which increases with every request.
+    time: Timestamp,
+}
+
+impl ServerState {
+    /// Create a new server with a single screen of the given size.
+    pub(super) fn new(width: u16, height: u16) -> Self {
+        let root = WindowState {
+            creator: None,
+            parent: 0,
+            children: Vec::new(),
+            x: 0,
+            y: 0,
+            width,
+            height,
+            border_width: 0,
+            class: WindowClass::INPUT_OUTPUT,
+            mapped: true,
+            override_redirect: false,
+            event_masks: HashMap::new(),
+            do_not_propagate_mask: 0,
+            properties: Vec::new(),
+        };
+        let mut windows = HashMap::new();
+        let _ = windows.insert(ROOT, root);
+        Self {
+            width,
+            height,
+            clients: HashMap::new(),
+            next_client: 0,
+            atoms: PREDEFINED_ATOMS
+                .iter()
+                .map(|name| name.as_bytes().to_vec())
+                .collect(),
+            windows,
+            selections: HashMap::new(),
+            time: 1,
+        }
+    }
+
+    /// Add a new client to the server.
+    pub(super) fn add_client(&mut self) -> ClientId {
+        let id = self.next_client;
+        self.next_client += 1;
+        let _ = self.clients.insert(id, Client::default());
+        id
+    }
+
+    /// Remove a client and all of its resources from the server.
+    pub(super) fn remove_client(&mut self, client: ClientId) {
+        let top_level = self
+            .windows
+            .iter()
+            .filter(|(_, window)| window.creator == Some(client))
+            .filter(|(_, window)| {
+                self.windows.get(&window.parent).and_then(|p| p.creator) != Some(client)
+            })
+            .map(|(id, _)| *id)
+            .collect::<Vec<_>>();
+        for window in top_level {
+            // The window might already be gone as the inferior of another window
+            if self.windows.contains_key(&window) {
+                self.destroy_window(window);
+            }
+        }
+        for window in self.windows.values_mut() {
+            let _ = window.event_masks.remove(&client);
+        }
+        self.selections.retain(|_, owner| owner.client != client);
+        let _ = self.clients.remove(&client);
+    }
+
+    /// Does the given client have data to read?
+    pub(super) fn has_output(&self, client: ClientId) -> bool {
+        self.clients
+            .get(&client)
+            .map_or(false, |client| !client.output.is_empty())
+    }
+
+    /// Read data that the server sent to the given client.
+    pub(super) fn read(&mut self, client: ClientId, buf: &mut [u8]) -> usize {
+        let output = &mut self.clients.get_mut(&client).unwrap().output;
+        let len = output.len().min(buf.len());
+        for (target, source) in buf.iter_mut().zip(output.drain(..len)) {
+            *target = source;
+        }
+        len
+    }
+
+    /// Handle data that the given client sent to the server.
+    pub(super) fn write(&mut self, client: ClientId, buf: &[u8]) {
+        self.clients
+            .get_mut(&client)
+            .unwrap()
+            .input
+            .extend_from_slice(buf);
+        while self.handle_next_packet(client) {}
+    }
+
+    /// Handle the next complete packet that the client sent, if there is one.
+    fn handle_next_packet(&mut self, client_id: ClientId) -> bool {
+        let client = self.clients.get_mut(&client_id).unwrap();
+        if !client.setup_done {
+            if client.input.len() < 12 {
+                return false;
+            }
+            let name_len = usize::from(u16::from_ne_bytes([client.input[6], client.input[7]]));
+            let data_len = usize::from(u16::from_ne_bytes([client.input[8], client.input[9]]));
+            let length = 12 + pad(name_len) + pad(data_len);
+            if client.input.len() < length {
+                return false;
+            }
+            let setup_request = client.input.drain(..length).collect::<Vec<_>>();
+            client.setup_done = true;
+            let native_byte_order = if cfg!(target_endian = "little") {
+                b'l'
+            } else {
+                b'B'
+            };
+            if setup_request[0] == native_byte_order {
+                let setup = self.setup(client_id);
+                let client = self.clients.get_mut(&client_id).unwrap();
+                client.output.extend(setup.serialize());
+            } else {
+                let reason = b"The fake server only supports the native byte order".to_vec();
+                let failed = SetupFailed {
+                    status: 0,
+                    protocol_major_version: 11,
+                    protocol_minor_version: 0,
+                    length: u16::try_from(pad(reason.len()) / 4).unwrap(),
+                    reason,
+                };
+                let mut bytes = failed.serialize();
+                bytes.resize(8 + pad(bytes.len() - 8), 0);
+                client.output.extend(bytes);
+            }
+            return true;
+        }
+
+        if client.input.len() < 4 {
+            return false;
+        }
+        let length = usize::from(u16::from_ne_bytes([client.input[2], client.input[3]])) * 4;
+        client.sequence = client.sequence.wrapping_add(1);
+        self.time += 1;
+        if length == 0 {
+            // BIG-REQUESTS is not supported, so the length of this request is unknown. Just
+            // skip the header.
+            let header = client.input.drain(..4).collect::<Vec<_>>();
+            self.send_error(client_id, &header, RequestError::new(LENGTH_ERROR, 0));
+            return true;
+        }
+        if client.input.len() < length {
+            // Undo the above, the request is not yet complete
+            client.sequence = client.sequence.wrapping_sub(1);
+            self.time -= 1;
+            return false;
+        }
+        let request = client.input.drain(..length).collect::<Vec<_>>();
+        if let Err(error) = self.handle_request(client_id, &request) {
+            self.send_error(client_id, &request, error);
+        }
+        true
+    }
+
+    fn setup(&self, client: ClientId) -> Setup {
+        let visual = Visualtype {
+            visual_id: VISUAL,
+            class: VisualClass::TRUE_COLOR,
+            bits_per_rgb_value: 8,
+            colormap_entries: 256,
+            red_mask: 0x00ff_0000,
+            green_mask: 0x0000_ff00,
+            blue_mask: 0x0000_00ff,
+        };
+        let screen = crate::protocol::xproto::Screen {
+            root: ROOT,
+            default_colormap: COLORMAP,
+            white_pixel: 0x00ff_ffff,
+            black_pixel: 0,
+            current_input_masks: 0,
+            width_in_pixels: self.width,
+            height_in_pixels: self.height,
+            // Pretend to have 96 DPI
+            width_in_millimeters: u16::try_from(u32::from(self.width) * 254 / 960).unwrap(),
+            height_in_millimeters: u16::try_from(u32::from(self.height) * 254 / 960).unwrap(),
+            min_installed_maps: 1,
+            max_installed_maps: 1,
+            root_visual: VISUAL,
+            backing_stores: BackingStore::NOT_USEFUL,
+            save_unders: false,
+            root_depth: DEPTH,
+            allowed_depths: vec![
+                Depth {
+                    depth: DEPTH,
+                    visuals: vec![visual],
+                },
+                Depth {
+                    depth: 1,
+                    visuals: Vec::new(),
+                },
+            ],
+        };
+        let image_byte_order = if cfg!(target_endian = "little") {
+            ImageOrder::LSB_FIRST
+        } else {
+            ImageOrder::MSB_FIRST
+        };
+        let mut setup = Setup {
+            status: 1,
+            protocol_major_version: 11,
+            protocol_minor_version: 0,
+            length: 0,
+            release_number: 0,
+            resource_id_base: resource_id_base(client),
+            resource_id_mask: RESOURCE_ID_MASK,
+            motion_buffer_size: 0,
+            maximum_request_length: u16::MAX,
+            image_byte_order,
+            bitmap_format_bit_order: ImageOrder::LSB_FIRST,
+            bitmap_format_scanline_unit: 32,
+            bitmap_format_scanline_pad: 32,
+            min_keycode: 8,
+            max_keycode: 255,
+            vendor: b"x11rb fake server".to_vec(),
+            pixmap_formats: vec![
+                Format {
+                    depth: 1,
+                    bits_per_pixel: 1,
+                    scanline_pad: 32,
+                },
+                Format {
+                    depth: DEPTH,
+                    bits_per_pixel: 32,
+                    scanline_pad: 32,
+                },
+            ],
+            roots: vec![screen],
+        };
+        setup.length = u16::try_from((setup.serialize().len() - 8) / 4).unwrap();
+        setup
+    }
+
+    fn send_error(&mut self, client: ClientId, request: &[u8], error: RequestError) {
+        let client = self.clients.get_mut(&client).unwrap();
+        let mut packet = [0; 32];
+        packet[1] = error.error_code;
+        packet[2..4].copy_from_slice(&client.sequence.to_ne_bytes());
+        packet[4..8].copy_from_slice(&error.bad_value.to_ne_bytes());
+        // Core requests have no minor opcode
+        packet[10] = request[0];
+        client.output.extend(packet.iter());
+    }
+
+    fn send_reply(&mut self, client: ClientId, reply: &impl Serialize) {
+        let client = self.clients.get_mut(&client).unwrap();
+        let mut bytes = Vec::new();
+        reply.serialize_into(&mut bytes);
+        bytes.resize(32.max(pad(bytes.len())), 0);
+        let length = u32::try_from((bytes.len() - 32) / 4).unwrap();
+        bytes[2..4].copy_from_slice(&client.sequence.to_ne_bytes());
+        bytes[4..8].copy_from_slice(&length.to_ne_bytes());
+        client.output.extend(bytes);
+    }
+
+    fn send_event(&mut self, client: ClientId, mut event: [u8; 32]) {
+        if let Some(client) = self.clients.get_mut(&client) {
+            event[2..4].copy_from_slice(&client.sequence.to_ne_bytes());
+            client.output.extend(event.iter());
+        }
+    }
+
+    /// Send an event to all clients that selected any of the events in `mask` on `window`.
+    fn deliver(&mut self, window: Window, mask: EventMask, event: [u8; 32]) {
+        let mask = u32::from(mask);
+        let clients = match self.windows.get(&window) {
+            Some(window) => window
+                .event_masks
+                .iter()
+                .filter(|(_, selected)| *selected & mask != 0)
+                .map(|(client, _)| *client)
+                .collect::<Vec<_>>(),
+            None => return,
+        };
+        for client in clients {
+            self.send_event(client, event);
+        }
+    }
+
+    /// Send a structure event to `window` and a substructure event to its parent.
+    ///
+    /// `event` is called with the window that the event is reported relative to.
+    fn deliver_structure_event(&mut self, window: Window, event: impl Fn(Window) -> [u8; 32]) {
+        self.deliver(window, EventMask::STRUCTURE_NOTIFY, event(window));
+        let parent = self.window(window).map_or(0, |window| window.parent);
+        if parent != 0 {
+            self.deliver(parent, EventMask::SUBSTRUCTURE_NOTIFY, event(parent));
+        }
+    }
+
+    /// Get the client that redirects the requests of `client` on children of `parent`.
+    fn redirecting_client(&self, parent: Window, client: ClientId) -> Option<ClientId> {
+        let mask = u32::from(EventMask::SUBSTRUCTURE_REDIRECT);
+        self.windows
+            .get(&parent)?
+            .event_masks
+            .iter()
+            .find(|(other, selected)| **other != client && *selected & mask != 0)
+            .map(|(other, _)| *other)
+    }
+
+    fn window(&self, window: Window) -> Result<&WindowState, RequestError> {
+        self.windows
+            .get(&window)
+            .ok_or_else(|| RequestError::new(WINDOW_ERROR, window))
+    }
+
+    fn window_mut(&mut self, window: Window) -> Result<&mut WindowState, RequestError> {
+        self.windows
+            .get_mut(&window)
+            .ok_or_else(|| RequestError::new(WINDOW_ERROR, window))
+    }
+
+    fn check_atom(&self, atom: Atom) -> RequestResult {
+        if atom == 0 || atom as usize > self.atoms.len() {
+            Err(RequestError::new(ATOM_ERROR, atom))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn is_viewable(&self, mut window: Window) -> bool {
+        while let Some(state) = self.windows.get(&window) {
+            if !state.mapped {
+                return false;
+            }
+            window = state.parent;
+        }
+        true
+    }
+
+    fn handle_request(&mut self, client: ClientId, request: &[u8]) -> RequestResult {
+        let (header, body) = parse_request_header(request, BigRequests::NotEnabled)
+            .map_err(|_| RequestError::new(LENGTH_ERROR, 0))?;
+        let parsed = Request::parse(header, body, &mut Vec::new(), &NoExtensions)
+            .map_err(|_| RequestError::new(LENGTH_ERROR, 0))?;
+        match parsed {
+            Request::CreateWindow(request) => self.create_window(client, &request),
+            Request::ChangeWindowAttributes(request) => {
+                self.change_window_attributes(client, request.window, &request.value_list)
+            }
+            Request::GetWindowAttributes(request) => {
+                self.get_window_attributes(client, request.window)
+            }
+            Request::DestroyWindow(request) => {
+                let _ = self.window(request.window)?;
+                if request.window != ROOT {
+                    self.destroy_window(request.window);
+                }
+                Ok(())
+            }
+            Request::MapWindow(request) => self.map_window(client, request.window),
+            Request::UnmapWindow(request) => {
+                let _ = self.window(request.window)?;
+                self.unmap_window(request.window);
+                Ok(())
+            }
+            Request::ConfigureWindow(request) => {
+                self.configure_window(client, request.window, &request.value_list)
+            }
+            Request::GetGeometry(request) => {
+                let window = self
+                    .windows
+                    .get(&request.drawable)
+                    .ok_or_else(|| RequestError::new(DRAWABLE_ERROR, request.drawable))?;
+                let reply = GetGeometryReply {
+                    depth: DEPTH,
+                    sequence: 0,
+                    length: 0,
+                    root: ROOT,
+                    x: window.x,
+                    y: window.y,
+                    width: window.width,
+                    height: window.height,
+                    border_width: window.border_width,
+                };
+                self.send_reply(client, &reply);
+                Ok(())
+            }
+            Request::QueryTree(request) => {
+                let window = self.window(request.window)?;
+                let reply = QueryTreeReply {
+                    sequence: 0,
+                    length: 0,
+                    root: ROOT,
+                    parent: window.parent,
+                    children: window.children.clone(),
+                };
+                self.send_reply(client, &reply);
+                Ok(())
+            }
+            Request::InternAtom(request) => {
+                let atom = match self.atoms.iter().position(|name| **name == *request.name) {
+                    Some(index) => index + 1,
+                    None if request.only_if_exists => 0,
+                    None => {
+                        self.atoms.push(request.name.to_vec());
+                        self.atoms.len()
+                    }
+                };
+                let reply = InternAtomReply {
+                    sequence: 0,
+                    length: 0,
+                    atom: u32::try_from(atom).unwrap(),
+                };
+                self.send_reply(client, &reply);
+                Ok(())
+            }
+            Request::GetAtomName(request) => {
+                self.check_atom(request.atom)?;
+                let reply = GetAtomNameReply {
+                    sequence: 0,
+                    length: 0,
+                    name: self.atoms[request.atom as usize - 1].clone(),
+                };
+                self.send_reply(client, &reply);
+                Ok(())
+            }
+            Request::ChangeProperty(request) => self.change_property(&request),
+            Request::DeleteProperty(request) => {
+                let _ = self.window(request.window)?;
+                self.check_atom(request.property)?;
+                self.delete_property(request.window, request.property);
+                Ok(())
+            }
+            Request::GetProperty(request) => self.get_property(client, &request),
+            Request::ListProperties(request) => {
+                let window = self.window(request.window)?;
+                let reply = ListPropertiesReply {
+                    sequence: 0,
+                    length: 0,
+                    atoms: window.properties.iter().map(|(atom, _)| *atom).collect(),
+                };
+                self.send_reply(client, &reply);
+                Ok(())
+            }
+            Request::SetSelectionOwner(request) => self.set_selection_owner(client, &request),
+            Request::GetSelectionOwner(request) => {
+                self.check_atom(request.selection)?;
+                let reply = GetSelectionOwnerReply {
+                    sequence: 0,
+                    length: 0,
+                    owner: self
+                        .selections
+                        .get(&request.selection)
+                        .map_or(0, |owner| owner.window),
+                };
+                self.send_reply(client, &reply);
+                Ok(())
+            }
+            Request::ConvertSelection(request) => self.convert_selection(client, &request),
+            Request::SendEvent(request) => self.send_event_request(&request),
+            Request::GetInputFocus(_) => {
+                let reply = GetInputFocusReply {
+                    revert_to: InputFocus::POINTER_ROOT,
+                    sequence: 0,
+                    length: 0,
+                    focus: InputFocus::POINTER_ROOT.into(),
+                };
+                self.send_reply(client, &reply);
+                Ok(())
+            }
+            Request::QueryExtension(_) => {
+                // No extensions are supported
+                let reply = QueryExtensionReply {
+                    sequence: 0,
+                    length: 0,
+                    present: false,
+                    major_opcode: 0,
+                    first_event: 0,
+                    first_error: 0,
+                };
+                self.send_reply(client, &reply);
+                Ok(())
+            }
+            Request::NoOperation(_) => Ok(()),
+            _ => Err(RequestError::new(IMPLEMENTATION_ERROR, 0)),
+        }
+    }
+
+    fn create_window(
+        &mut self,
+        client: ClientId,
+        request: &CreateWindowRequest<'_>,
+    ) -> RequestResult {
+        let wid = request.wid;
+        if wid & !RESOURCE_ID_MASK != resource_id_base(client) || self.windows.contains_key(&wid) {
+            return Err(RequestError::new(ID_CHOICE_ERROR, wid));
+        }
+        let parent = self.window(request.parent)?;
+        if request.width == 0 || request.height == 0 {
+            return Err(RequestError::new(VALUE_ERROR, 0));
+        }
+        let class = if request.class == WindowClass::COPY_FROM_PARENT {
+            parent.class
+        } else {
+            request.class
+        };
+        if class == WindowClass::INPUT_ONLY && request.border_width != 0 {
+            return Err(RequestError::new(MATCH_ERROR, 0));
+        }
+
+        let aux = &request.value_list;
+        let state = WindowState {
+            creator: Some(client),
+            parent: request.parent,
+            children: Vec::new(),
+            x: request.x,
+            y: request.y,
+            width: request.width,
+            height: request.height,
+            border_width: request.border_width,
+            class,
+            mapped: false,
+            override_redirect: false,
+            event_masks: HashMap::new(),
+            do_not_propagate_mask: 0,
+            properties: Vec::new(),
+        };
+        let _ = self.windows.insert(wid, state);
+        self.window_mut(request.parent)?.children.push(wid);
+        let attributes = ChangeWindowAttributesAux {
+            override_redirect: aux.override_redirect,
+            event_mask: aux.event_mask,
+            do_not_propogate_mask: aux.do_not_propogate_mask,
+            ..Default::default()
+        };
+        if let Err(error) = self.change_window_attributes(client, wid, &attributes) {
+            self.destroy_window(wid);
+            return Err(error);
+        }
+
+        let window = &self.windows[&wid];
+        let event = CreateNotifyEvent {
+            response_type: CREATE_NOTIFY_EVENT,
+            sequence: 0,
+            parent: request.parent,
+            window: wid,
+            x: window.x,
+            y: window.y,
+            width: window.width,
+            height: window.height,
+            border_width: window.border_width,
+            override_redirect: window.override_redirect,
+        };
+        self.deliver(request.parent, EventMask::SUBSTRUCTURE_NOTIFY, event.into());
+        Ok(())
+    }
+
+    fn change_window_attributes(
+        &mut self,
+        client: ClientId,
+        window: Window,
+        aux: &ChangeWindowAttributesAux,
+    ) -> RequestResult {
+        let _ = self.window(window)?;
+        if let Some(event_mask) = aux.event_mask {
+            // Only one client can select SubstructureRedirect on a window
+            let redirect = u32::from(EventMask::SUBSTRUCTURE_REDIRECT);
+            if event_mask & redirect != 0 && self.redirecting_client(window, client).is_some() {
+                return Err(RequestError::new(ACCESS_ERROR, 0));
+            }
+        }
+        let state = self.window_mut(window)?;
+        if let Some(event_mask) = aux.event_mask {
+            if event_mask == 0 {
+                let _ = state.event_masks.remove(&client);
+            } else {
+                let _ = state.event_masks.insert(client, event_mask);
+            }
+        }
+        if let Some(override_redirect) = aux.override_redirect {
+            state.override_redirect = override_redirect != 0;
+        }
+        if let Some(mask) = aux.do_not_propogate_mask {
+            state.do_not_propagate_mask = mask;
+        }
+        Ok(())
+    }
+
+    fn get_window_attributes(&mut self, client: ClientId, window: Window) -> RequestResult {
+        let state = self.window(window)?;
+        let map_state = if !state.mapped {
+            MapState::UNMAPPED
+        } else if self.is_viewable(window) {
+            MapState::VIEWABLE
+        } else {
+            MapState::UNVIEWABLE
+        };
+        let reply = GetWindowAttributesReply {
+            backing_store: BackingStore::NOT_USEFUL,
+            sequence: 0,
+            length: 0,
+            visual: VISUAL,
+            class: state.class,
+            bit_gravity: Gravity::BIT_FORGET,
+            win_gravity: Gravity::NORTH_WEST,
+            backing_planes: u32::MAX,
+            backing_pixel: 0,
+            save_under: false,
+            map_is_installed: true,
+            map_state,
+            override_redirect: state.override_redirect,
+            colormap: COLORMAP,
+            all_event_masks: state.all_event_masks(),
+            your_event_mask: state.event_masks.get(&client).copied().unwrap_or(0),
+            do_not_propagate_mask: u16::try_from(state.do_not_propagate_mask & 0xffff).unwrap(),
+        };
+        self.send_reply(client, &reply);
+        Ok(())
+    }
+
+    /// Destroy a window and all of its inferiors.
+    fn destroy_window(&mut self, window: Window) {
+        self.unmap_window(window);
+        self.destroy_subtree(window);
+        let parent = self.windows.remove(&window).map_or(0, |state| state.parent);
+        if let Some(parent) = self.windows.get_mut(&parent) {
+            parent.children.retain(|child| *child != window);
+        }
+    }
+
+    fn destroy_subtree(&mut self, window: Window) {
+        let children = self.windows[&window].children.clone();
+        for child in children {
+            self.destroy_subtree(child);
+            let _ = self.windows.remove(&child);
+        }
+        self.deliver_structure_event(window, |event| {
+            DestroyNotifyEvent {
+                response_type: DESTROY_NOTIFY_EVENT,
+                sequence: 0,
+                event,
+                window,
+            }
+            .into()
+        });
+        self.selections.retain(|_, owner| owner.window != window);
+    }
+
+    fn map_window(&mut self, client: ClientId, window: Window) -> RequestResult {
+        let state = self.window(window)?;
+        if state.mapped {
+            return Ok(());
+        }
+        let parent = state.parent;
+        if !state.override_redirect {
+            if let Some(redirect) = self.redirecting_client(parent, client) {
+                let event = MapRequestEvent {
+                    response_type: MAP_REQUEST_EVENT,
+                    sequence: 0,
+                    parent,
+                    window,
+                };
+                self.send_event(redirect, event.into());
+                return Ok(());
+            }
+        }
+        let state = self.window_mut(window)?;
+        state.mapped = true;
+        let override_redirect = state.override_redirect;
+        self.deliver_structure_event(window, |event| {
+            MapNotifyEvent {
+                response_type: MAP_NOTIFY_EVENT,
+                sequence: 0,
+                event,
+                window,
+                override_redirect,
+            }
+            .into()
+        });
+        if self.is_viewable(window) {
+            self.expose_tree(window);
+        }
+        Ok(())
+    }
+
+    /// Send Expose events for the window and all of its mapped inferiors.
+    fn expose_tree(&mut self, window: Window) {
+        let state = &self.windows[&window];
+        if !state.mapped {
+            return;
+        }
+        let children = state.children.clone();
+        if state.class != WindowClass::INPUT_ONLY {
+            let event = ExposeEvent {
+                response_type: EXPOSE_EVENT,
+                sequence: 0,
+                window,
+                x: 0,
+                y: 0,
+                width: state.width,
+                height: state.height,
+                count: 0,
+            };
+            self.deliver(window, EventMask::EXPOSURE, event.into());
+        }
+        for child in children {
+            self.expose_tree(child);
+        }
+    }
+
+    fn unmap_window(&mut self, window: Window) {
+        match self.windows.get_mut(&window) {
+            Some(state) if state.mapped && window != ROOT => state.mapped = false,
+            _ => return,
+        }
+        self.deliver_structure_event(window, |event| {
+            UnmapNotifyEvent {
+                response_type: UNMAP_NOTIFY_EVENT,
+                sequence: 0,
+                event,
+                window,
+                from_configure: false,
+            }
+            .into()
+        });
+    }
+
+    fn configure_window(
+        &mut self,
+        client: ClientId,
+        window: Window,
+        aux: &ConfigureWindowAux,
+    ) -> RequestResult {
+        let state = self.window(window)?;
+        if window == ROOT {
+            return Ok(());
+        }
+        if aux.sibling.is_some() && aux.stack_mode.is_none() {
+            return Err(RequestError::new(MATCH_ERROR, 0));
+        }
+        if aux.width == Some(0) || aux.height == Some(0) {
+            return Err(RequestError::new(VALUE_ERROR, 0));
+        }
+        let parent = state.parent;
+        if let Some(sibling) = aux.sibling {
+            if sibling == window || self.windows.get(&sibling).map(|s| s.parent) != Some(parent) {
+                return Err(RequestError::new(MATCH_ERROR, 0));
+            }
+        }
+
+        // Values are truncated the same way that the X11 server does
+        let x = aux.x.map_or(state.x, |x| x as i16);
+        let y = aux.y.map_or(state.y, |y| y as i16);
+        let width = aux.width.map_or(state.width, |width| width as u16);
+        let height = aux.height.map_or(state.height, |height| height as u16);
+        let border_width = aux
+            .border_width
+            .map_or(state.border_width, |border_width| border_width as u16);
+
+        if !state.override_redirect {
+            if let Some(redirect) = self.redirecting_client(parent, client) {
+                let value_mask = [
+                    (aux.x.is_some(), ConfigWindow::X),
+                    (aux.y.is_some(), ConfigWindow::Y),
+                    (aux.width.is_some(), ConfigWindow::WIDTH),
+                    (aux.height.is_some(), ConfigWindow::HEIGHT),
+                    (aux.border_width.is_some(), ConfigWindow::BORDER_WIDTH),
+                    (aux.sibling.is_some(), ConfigWindow::SIBLING),
+                    (aux.stack_mode.is_some(), ConfigWindow::STACK_MODE),
+                ]
+                .iter()
+                .filter(|(present, _)| *present)
+                .fold(0, |acc, (_, flag)| acc | u16::from(*flag));
+                let event = ConfigureRequestEvent {
+                    response_type: CONFIGURE_REQUEST_EVENT,
+                    stack_mode: aux.stack_mode.unwrap_or(StackMode::ABOVE),
+                    sequence: 0,
+                    parent,
+                    window,
+                    sibling: aux.sibling.unwrap_or(0),
+                    x,
+                    y,
+                    width,
+                    height,
+                    border_width,
+                    value_mask,
+                };
+                self.send_event(redirect, event.into());
+                return Ok(());
+            }
+        }
+
+        let state = self.window_mut(window)?;
+        state.x = x;
+        state.y = y;
+        state.width = width;
+        state.height = height;
+        state.border_width = border_width;
+        let override_redirect = state.override_redirect;
+        if let Some(stack_mode) = aux.stack_mode {
+            self.restack(window, parent, aux.sibling, stack_mode);
+        }
+        let children = &self.windows[&parent].children;
+        let position = children.iter().position(|child| *child == window).unwrap();
+        let above_sibling = if position == 0 {
+            0
+        } else {
+            children[position - 1]
+        };
+        self.deliver_structure_event(window, |event| {
+            ConfigureNotifyEvent {
+                response_type: CONFIGURE_NOTIFY_EVENT,
+                sequence: 0,
+                event,
+                window,
+                above_sibling,
+                x,
+                y,
+                width,
+                height,
+                border_width,
+                override_redirect,
+            }
+            .into()
+        });
+        Ok(())
+    }
+
+    /// Change the position of `window` in the stacking order of its parent.
+    ///
+    /// Since the fake server does not track which windows overlap, `TopIf` and `Opposite` are
+    /// handled like `Above` and `BottomIf` is handled like `Below`.
+    fn restack(
+        &mut self,
+        window: Window,
+        parent: Window,
+        sibling: Option<Window>,
+        stack_mode: StackMode,
+    ) {
+        let children = &mut self.windows.get_mut(&parent).unwrap().children;
+        children.retain(|child| *child != window);
+        let above = stack_mode != StackMode::BELOW && stack_mode != StackMode::BOTTOM_IF;
+        let index = match (sibling, above) {
+            (Some(sibling), above) => {
+                let index = children.iter().position(|child| *child == sibling).unwrap();
+                if above {
+                    index + 1
+                } else {
+                    index
+                }
+            }
+            (None, true) => children.len(),
+            (None, false) => 0,
+        };
+        children.insert(index, window);
+    }
+
+    fn change_property(&mut self, request: &ChangePropertyRequest<'_>) -> RequestResult {
+        let _ = self.window(request.window)?;
+        self.check_atom(request.property)?;
+        self.check_atom(request.type_)?;
+        if ![8, 16, 32].contains(&request.format) {
+            return Err(RequestError::new(VALUE_ERROR, request.format.into()));
+        }
+        if request.mode != PropMode::REPLACE
+            && request.mode != PropMode::PREPEND
+            && request.mode != PropMode::APPEND
+        {
+            return Err(RequestError::new(
+                VALUE_ERROR,
+                u8::from(request.mode).into(),
+            ));
+        }
+        let state = self.window_mut(request.window)?;
+        let existing = state
+            .properties
+            .iter_mut()
+            .find(|(atom, _)| *atom == request.property);
+        match existing {
+            Some((_, value)) if request.mode != PropMode::REPLACE => {
+                if value.type_ != request.type_ || value.format != request.format {
+                    return Err(RequestError::new(MATCH_ERROR, 0));
+                }
+                if request.mode == PropMode::APPEND {
+                    value.data.extend_from_slice(&request.data);
+                } else {
+                    let mut data = request.data.to_vec();
+                    data.extend_from_slice(&value.data);
+                    value.data = data;
+                }
+            }
+            Some((_, value)) => {
+                *value = PropertyValue {
+                    type_: request.type_,
+                    format: request.format,
+                    data: request.data.to_vec(),
+                };
+            }
+            None => state.properties.push((
+                request.property,
+                PropertyValue {
+                    type_: request.type_,
+                    format: request.format,
+                    data: request.data.to_vec(),
+                },
+            )),
+        }
+        self.property_notify(request.window, request.property, Property::NEW_VALUE);
+        Ok(())
+    }
+
+    fn delete_property(&mut self, window: Window, property: Atom) {
+        let state = self.windows.get_mut(&window).unwrap();
+        let len = state.properties.len();
+        state.properties.retain(|(atom, _)| *atom != property);
+        if state.properties.len() != len {
+            self.property_notify(window, property, Property::DELETE);
+        }
+    }
+
+    fn property_notify(&mut self, window: Window, atom: Atom, state: Property) {
+        let event = PropertyNotifyEvent {
+            response_type: PROPERTY_NOTIFY_EVENT,
+            sequence: 0,
+            window,
+            atom,
+            time: self.time,
+            state,
+        };
+        self.deliver(window, EventMask::PROPERTY_CHANGE, event.into());
+    }
+
+    fn get_property(&mut self, client: ClientId, request: &GetPropertyRequest) -> RequestResult {
+        let state = self.window(request.window)?;
+        self.check_atom(request.property)?;
+        if request.type_ != 0 {
+            self.check_atom(request.type_)?;
+        }
+        let value = match state.property(request.property) {
+            Some(value) => value,
+            None => {
+                let reply = GetPropertyReply {
+                    format: 0,
+                    sequence: 0,
+                    length: 0,
+                    type_: 0,
+                    bytes_after: 0,
+                    value_len: 0,
+                    value: Vec::new(),
+                };
+                self.send_reply(client, &reply);
+                return Ok(());
+            }
+        };
+        if request.type_ != 0 && request.type_ != value.type_ {
+            let reply = GetPropertyReply {
+                format: value.format,
+                sequence: 0,
+                length: 0,
+                type_: value.type_,
+                bytes_after: u32::try_from(value.data.len()).unwrap(),
+                value_len: 0,
+                value: Vec::new(),
+            };
+            self.send_reply(client, &reply);
+            return Ok(());
+        }
+
+        let length = value.data.len();
+        let offset = 4 * request.long_offset as usize;
+        if offset > length {
+            return Err(RequestError::new(VALUE_ERROR, request.long_offset));
+        }
+        let count = (length - offset).min(4 * request.long_length as usize);
+        let bytes_after = length - offset - count;
+        let reply = GetPropertyReply {
+            format: value.format,
+            sequence: 0,
+            length: 0,
+            type_: value.type_,
+            bytes_after: u32::try_from(bytes_after).unwrap(),
+            value_len: u32::try_from(count / usize::from(value.format / 8)).unwrap(),
+            value: value.data[offset..offset + count].to_vec(),
+        };
+        self.send_reply(client, &reply);
+        if request.delete && bytes_after == 0 {
+            self.delete_property(request.window, request.property);
+        }
+        Ok(())
+    }
+
+    fn set_selection_owner(
+        &mut self,
+        client: ClientId,
+        request: &SetSelectionOwnerRequest,
+    ) -> RequestResult {
+        self.check_atom(request.selection)?;
+        if request.owner != 0 {
+            let _ = self.window(request.owner)?;
+        }
+        let time = if request.time == 0 {
+            self.time
+        } else {
+            request.time
+        };
+        let previous = self.selections.get(&request.selection).copied();
+        if time > self.time || previous.map_or(false, |previous| time < previous.time) {
+            // The request is ignored
+            return Ok(());
+        }
+        if let Some(previous) = previous {
+            if request.owner == 0 || previous.client != client {
+                let event = SelectionClearEvent {
+                    response_type: SELECTION_CLEAR_EVENT,
+                    sequence: 0,
+                    time,
+                    owner: previous.window,
+                    selection: request.selection,
+                };
+                self.send_event(previous.client, event.into());
+            }
+        }
+        if request.owner == 0 {
+            let _ = self.selections.remove(&request.selection);
+        } else {
+            let owner = SelectionOwner {
+                window: request.owner,
+                client,
+                time,
+            };
+            let _ = self.selections.insert(request.selection, owner);
+        }
+        Ok(())
+    }
+
+    fn convert_selection(
+        &mut self,
+        client: ClientId,
+        request: &ConvertSelectionRequest,
+    ) -> RequestResult {
+        let _ = self.window(request.requestor)?;
+        self.check_atom(request.selection)?;
+        self.check_atom(request.target)?;
+        if request.property != 0 {
+            self.check_atom(request.property)?;
+        }
+        let time = if request.time == 0 {
+            self.time
+        } else {
+            request.time
+        };
+        match self.selections.get(&request.selection).copied() {
+            Some(owner) => {
+                let event = SelectionRequestEvent {
+                    response_type: SELECTION_REQUEST_EVENT,
+                    sequence: 0,
+                    time,
+                    owner: owner.window,
+                    requestor: request.requestor,
+                    selection: request.selection,
+                    target: request.target,
+                    property: request.property,
+                };
+                self.send_event(owner.client, event.into());
+            }
+            None => {
+                let event = SelectionNotifyEvent {
+                    response_type: SELECTION_NOTIFY_EVENT,
+                    sequence: 0,
+                    time,
+                    requestor: request.requestor,
+                    selection: request.selection,
+                    target: request.target,
+                    property: 0,
+                };
+                self.send_event(client, event.into());
+            }
+        }
+        Ok(())
+    }
+
+    fn send_event_request(&mut self, request: &SendEventRequest<'_>) -> RequestResult {
+        // PointerWindow and InputFocus are both treated as the root window
+        let destination = match request.destination {
+            0 | 1 => ROOT,
+            window => window,
+        };
+        let _ = self.window(destination)?;
+        let mut event = *request.event;
+        if event[0] & 0x7f < 2 {
+            return Err(RequestError::new(VALUE_ERROR, event[0].into()));
+        }
+        event[0] |= 0x80;
+
+        if request.event_mask == 0 {
+            if let Some(creator) = self.windows[&destination].creator {
+                self.send_event(creator, event);
+            }
+            return Ok(());
+        }
+
+        let mut window = destination;
+        loop {
+            let state = &self.windows[&window];
+            let clients = state
+                .event_masks
+                .iter()
+                .filter(|(_, selected)| *selected & request.event_mask != 0)
+                .map(|(client, _)| *client)
+                .collect::<Vec<_>>();
+            if !clients.is_empty() {
+                for client in clients {
+                    self.send_event(client, event);
+                }
+                break;
+            }
+            if !request.propagate
+                || state.do_not_propagate_mask & request.event_mask != 0
+                || state.parent == 0
+            {
+                break;
+            }
+            window = state.parent;
+        }
+        Ok(())
+    }
+}
+
+fn resource_id_base(client: ClientId) -> u32 {
+    (u32::try_from(client).unwrap() + 1) << 21
+}
+
+fn pad(length: usize) -> usize {
+    (length + 3) & !3
+}
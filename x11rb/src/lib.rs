@@ -104,6 +104,13 @@
 //! * `resource_manager`: Enable the code in [crate::resource_manager] for loading and querying the
 //!   X11 resource database.
 //! * `image`: Enable the code in [crate::image] for working with pixel image data.
+//! * `fake_server`: Enable the code in [crate::fake_server], an in-process fake X11 server for
+//!   tests.
+//! * `test_server`: Enable the code in [crate::test_server] for spawning X11 servers like `Xvfb`
+//!   in tests (only on unix).
+//! * `serde`: Implement `serde::Serialize` and `serde::Deserialize` for the types in
+//!   [crate::protocol], including the `Request`, `Reply`, `Event` and `ErrorKind` enums, and for
+//!   [crate::x11_utils::X11Error]. Requests and replies with file descriptors are skipped.
 //! * `dl-libxcb`: Enabling this feature will prevent from libxcb being linked to the
 //!   resulting executable. Instead libxcb will be dynamically loaded at runtime.
 //!   This feature adds the [`crate::xcb_ffi::load_libxcb`] function, that allows to load
@@ -151,16 +158,28 @@ pub mod utils;
 pub mod xcb_ffi;
 #[macro_use]
 pub mod x11_utils;
+pub mod async_connection;
 pub mod connection;
 pub mod cookie;
 #[cfg(feature = "cursor")]
 pub mod cursor;
+pub mod dyn_connection;
+pub mod error_handler;
 pub mod errors;
+pub mod event_compression;
+pub mod event_dispatcher;
 pub mod extension_manager;
+pub mod extension_version;
+#[cfg(feature = "fake_server")]
+pub mod fake_server;
 #[cfg(feature = "image")]
 pub mod image;
 pub mod properties;
+pub mod reconnect;
 pub mod rust_connection;
+pub mod statistics;
+#[cfg(all(unix, feature = "test_server"))]
+pub mod test_server;
 pub mod wrapper;
 #[rustfmt::skip]
 #[allow(missing_docs)]
@@ -183,6 +202,19 @@ pub fn connect(
     rust_connection::RustConnection::connect(dpy_name)
 }
 
+/// Establish a new connection to an X11 server, preferring an inherited socket.
+///
+/// If the environment variable `$X11_SOCKET` contains the number of a file descriptor, the
+/// already connected socket with that number is used. Otherwise, this works like [connect].
+///
+/// This function is identical to
+/// [RustConnection::connect_from_env](crate::rust_connection::RustConnection::connect_from_env).
+pub fn connect_from_env(
+    dpy_name: Option<&str>,
+) -> Result<(rust_connection::RustConnection, usize), ConnectError> {
+    rust_connection::RustConnection::connect_from_env(dpy_name)
+}
+
 /// The universal null resource or null atom parameter value for many core X requests
 pub const NONE: u32 = 0;
 
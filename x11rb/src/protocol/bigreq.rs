@@ -32,6 +32,7 @@ fn major_opcode<Conn: RequestConnection + ?Sized>(conn: &Conn) -> Result<u8, Con
     Ok(info.major_opcode)
 }
 
+#[track_caller]
 pub fn enable<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, EnableReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -44,6 +45,7 @@ where
 
 /// Extension trait defining the requests of this extension.
 pub trait ConnectionExt: RequestConnection {
+    #[track_caller]
     fn bigreq_enable(&self) -> Result<Cookie<'_, Self, EnableReply>, ConnectionError>
     {
         enable(self)
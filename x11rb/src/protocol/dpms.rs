@@ -32,6 +32,7 @@ fn major_opcode<Conn: RequestConnection + ?Sized>(conn: &Conn) -> Result<u8, Con
     Ok(info.major_opcode)
 }
 
+#[track_caller]
 pub fn get_version<Conn>(conn: &Conn, client_major_version: u16, client_minor_version: u16) -> Result<Cookie<'_, Conn, GetVersionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -45,6 +46,7 @@ where
     conn.send_request_with_reply(&slices, fds)
 }
 
+#[track_caller]
 pub fn capable<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, CapableReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -55,6 +57,7 @@ where
     conn.send_request_with_reply(&slices, fds)
 }
 
+#[track_caller]
 pub fn get_timeouts<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, GetTimeoutsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -65,6 +68,7 @@ where
     conn.send_request_with_reply(&slices, fds)
 }
 
+#[track_caller]
 pub fn set_timeouts<Conn>(conn: &Conn, standby_timeout: u16, suspend_timeout: u16, off_timeout: u16) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -79,6 +83,7 @@ where
     conn.send_request_without_reply(&slices, fds)
 }
 
+#[track_caller]
 pub fn enable<Conn>(conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -89,6 +94,7 @@ where
     conn.send_request_without_reply(&slices, fds)
 }
 
+#[track_caller]
 pub fn disable<Conn>(conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -99,6 +105,7 @@ where
     conn.send_request_without_reply(&slices, fds)
 }
 
+#[track_caller]
 pub fn force_level<Conn>(conn: &Conn, power_level: DPMSMode) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -111,6 +118,7 @@ where
     conn.send_request_without_reply(&slices, fds)
 }
 
+#[track_caller]
 pub fn info<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, InfoReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -123,34 +131,42 @@ where
 
 /// Extension trait defining the requests of this extension.
 pub trait ConnectionExt: RequestConnection {
+    #[track_caller]
     fn dpms_get_version(&self, client_major_version: u16, client_minor_version: u16) -> Result<Cookie<'_, Self, GetVersionReply>, ConnectionError>
     {
         get_version(self, client_major_version, client_minor_version)
     }
+    #[track_caller]
     fn dpms_capable(&self) -> Result<Cookie<'_, Self, CapableReply>, ConnectionError>
     {
         capable(self)
     }
+    #[track_caller]
     fn dpms_get_timeouts(&self) -> Result<Cookie<'_, Self, GetTimeoutsReply>, ConnectionError>
     {
         get_timeouts(self)
     }
+    #[track_caller]
     fn dpms_set_timeouts(&self, standby_timeout: u16, suspend_timeout: u16, off_timeout: u16) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         set_timeouts(self, standby_timeout, suspend_timeout, off_timeout)
     }
+    #[track_caller]
     fn dpms_enable(&self) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         enable(self)
     }
+    #[track_caller]
     fn dpms_disable(&self) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         disable(self)
     }
+    #[track_caller]
     fn dpms_force_level(&self, power_level: DPMSMode) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         force_level(self, power_level)
     }
+    #[track_caller]
     fn dpms_info(&self) -> Result<Cookie<'_, Self, InfoReply>, ConnectionError>
     {
         info(self)
@@ -0,0 +1,228 @@
+//! Reconnecting to the X11 server after the connection was lost.
+//!
+//! When the X11 server shuts down or restarts, all further requests on a connection fail. Since
+//! all server-side resources like windows and graphics contexts are gone with the old server, an
+//! application has to establish a new connection and recreate everything.
+//!
+//! A [`Supervisor`] automates the first part: It runs a session of the application on a
+//! connection. When the session ends with an error because the connection was lost, a new
+//! connection is established and the session is started again. The [`Session`] argument tells the
+//! application whether it runs on a fresh connection after a reconnect, so it knows that it must
+//! recreate its server-side resources. Since each session gets a new connection, cached state
+//! like the extension information in [`crate::extension_manager::ExtensionManager`] starts empty
+//! and is queried again from the new server.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use x11rb::connection::Connection;
+//! use x11rb::reconnect::Supervisor;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut supervisor = Supervisor::for_display(None);
+//! supervisor.run(|conn, screen, session| {
+//!     if session.is_reconnect() {
+//!         println!("Reconnected after: {:?}", session.previous_disconnect());
+//!     }
+//!     // Create windows etc. and run the event loop
+//!     let _root = conn.setup().roots[screen].root;
+//!     loop {
+//!         let event = conn.wait_for_event()?;
+//!         println!("{:?}", event);
+//!     }
+//!     # #[allow(unreachable_code)]
+//!     # Ok::<(), x11rb::errors::ConnectionError>(())
+//! })?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::error::Error;
+use std::fmt;
+use std::thread::sleep;
+use std::time::Duration;
+
+use crate::errors::{ConnectError, DisconnectReason};
+use crate::rust_connection::{RustConnection, Stream};
+
+/// Information about the current session of a [`Supervisor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Session {
+    reconnects: usize,
+    previous_disconnect: Option<DisconnectReason>,
+}
+
+impl Session {
+    /// Is this session running after a reconnect?
+    ///
+    /// If this is `true`, all server-side resources of the previous session are gone.
+    pub fn is_reconnect(&self) -> bool {
+        self.reconnects > 0
+    }
+
+    /// Get the number of reconnects that happened before this session.
+    pub fn reconnects(&self) -> usize {
+        self.reconnects
+    }
+
+    /// Get the reason why the connection of the previous session was lost.
+    pub fn previous_disconnect(&self) -> Option<DisconnectReason> {
+        self.previous_disconnect
+    }
+}
+
+/// An error that ended the supervision by a [`Supervisor`].
+#[derive(Debug)]
+pub enum SupervisorError<E> {
+    /// Establishing a connection failed.
+    ///
+    /// For reconnects, this is the error of the last attempt. `disconnect` is the reason why the
+    /// previous connection was lost, or `None` if the initial connection failed.
+    Connect {
+        /// The error of the last attempt to connect.
+        error: ConnectError,
+        /// Why the previous connection was lost.
+        disconnect: Option<DisconnectReason>,
+    },
+
+    /// The session failed with an error while the connection was still intact.
+    Session(E),
+}
+
+impl<E: fmt::Display> fmt::Display for SupervisorError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SupervisorError::Connect {
+                error,
+                disconnect: None,
+            } => write!(f, "Connecting to the X11 server failed: {}", error),
+            SupervisorError::Connect {
+                error,
+                disconnect: Some(disconnect),
+            } => write!(f, "{}; reconnecting failed: {}", disconnect, error),
+            SupervisorError::Session(err) => err.fmt(f),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for SupervisorError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SupervisorError::Connect { error, .. } => Some(error),
+            SupervisorError::Session(err) => Some(err),
+        }
+    }
+}
+
+/// Runs sessions of an application and reconnects when the connection is lost.
+///
+/// See the [module documentation](self) for details.
+#[derive(Debug)]
+pub struct Supervisor<F> {
+    connect: F,
+    max_attempts: usize,
+    retry_delay: Duration,
+}
+
+impl Supervisor<()> {
+    /// Create a supervisor that connects to the given display with [`crate::connect`].
+    ///
+    /// If no `dpy_name` is provided, the value from `$DISPLAY` is used.
+    pub fn for_display(
+        dpy_name: Option<&str>,
+    ) -> Supervisor<impl FnMut() -> Result<(RustConnection, usize), ConnectError>> {
+        let dpy_name = dpy_name.map(str::to_string);
+        Supervisor::new(move || crate::connect(dpy_name.as_deref()))
+    }
+}
+
+impl<F, S> Supervisor<F>
+where
+    F: FnMut() -> Result<(RustConnection<S>, usize), ConnectError>,
+    S: Stream,
+{
+    /// Create a supervisor that uses `connect` to establish connections.
+    ///
+    /// `connect` returns a connection and the number of the screen to use. By default, up to ten
+    /// attempts to reconnect are made with one second between them.
+    pub fn new(connect: F) -> Self {
+        Self {
+            connect,
+            max_attempts: 10,
+            retry_delay: Duration::from_secs(1),
+        }
+    }
+
+    /// Set how often to try reconnecting before giving up.
+    pub fn max_attempts(mut self, max_attempts: usize) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Set how long to wait between two attempts to reconnect.
+    pub fn retry_delay(mut self, retry_delay: Duration) -> Self {
+        self.retry_delay = retry_delay;
+        self
+    }
+
+    /// Run `session` until it finishes while the connection is intact.
+    ///
+    /// `session` is called with a connection, the screen number, and information about the
+    /// session. If it returns an error after the connection was lost (see
+    /// [`RustConnection::disconnect_reason`]), a new connection is established and `session` is
+    /// called again. Otherwise, its result is returned.
+    ///
+    /// The initial connection is only attempted once.
+    pub fn run<T, E>(
+        &mut self,
+        mut session: impl FnMut(&RustConnection<S>, usize, &Session) -> Result<T, E>,
+    ) -> Result<T, SupervisorError<E>> {
+        let (mut conn, mut screen) =
+            (self.connect)().map_err(|error| SupervisorError::Connect {
+                error,
+                disconnect: None,
+            })?;
+        let mut info = Session {
+            reconnects: 0,
+            previous_disconnect: None,
+        };
+        loop {
+            let err = match session(&conn, screen, &info) {
+                Ok(result) => return Ok(result),
+                Err(err) => err,
+            };
+            let disconnect = match conn.disconnect_reason() {
+                Some(disconnect) => disconnect,
+                None => return Err(SupervisorError::Session(err)),
+            };
+            drop(conn);
+            let (new_conn, new_screen) = self.reconnect(disconnect)?;
+            conn = new_conn;
+            screen = new_screen;
+            info = Session {
+                reconnects: info.reconnects + 1,
+                previous_disconnect: Some(disconnect),
+            };
+        }
+    }
+
+    fn reconnect<E>(
+        &mut self,
+        disconnect: DisconnectReason,
+    ) -> Result<(RustConnection<S>, usize), SupervisorError<E>> {
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            match (self.connect)() {
+                Ok(result) => return Ok(result),
+                Err(error) if attempt >= self.max_attempts => {
+                    return Err(SupervisorError::Connect {
+                        error,
+                        disconnect: Some(disconnect),
+                    })
+                }
+                Err(_) => sleep(self.retry_delay),
+            }
+        }
+    }
+}
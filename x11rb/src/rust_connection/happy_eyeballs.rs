@@ -0,0 +1,173 @@
+//! Concurrent connection attempts to all addresses of a host name.
+//!
+//! This implements the connection part of "Happy Eyeballs" (RFC 8305): The addresses of the host
+//! are sorted so that IPv6 and IPv4 addresses alternate. A new connection attempt is started every
+//! [`CONNECTION_ATTEMPT_DELAY`] or as soon as the previous attempt failed, without waiting for
+//! earlier attempts to time out. The first attempt that succeeds wins.
+
+use std::io::{Error, ErrorKind, Result};
+use std::net::{SocketAddr, TcpStream, ToSocketAddrs};
+use std::sync::mpsc::{channel, RecvTimeoutError};
+use std::time::{Duration, Instant};
+
+/// The time to wait for a connection attempt before starting the next one in parallel.
+///
+/// This is the value recommended by RFC 8305.
+const CONNECTION_ATTEMPT_DELAY: Duration = Duration::from_millis(250);
+
+/// Connect to any address of `host`.
+///
+/// Connection attempts are made until `deadline`, if one is given. If no connection could be
+/// established, the attempted addresses and their errors are appended to `errors`.
+pub(crate) fn connect(
+    host: &str,
+    port: u16,
+    deadline: Option<Instant>,
+    errors: &mut Vec<(String, Error)>,
+) -> Option<TcpStream> {
+    let addrs = match (host, port).to_socket_addrs() {
+        Ok(addrs) => interleave_families(addrs.collect()),
+        Err(err) => {
+            errors.push((format!("{}:{}", host, port), err));
+            return None;
+        }
+    };
+    if addrs.is_empty() {
+        let err = Error::new(ErrorKind::NotFound, "The host name has no addresses");
+        errors.push((format!("{}:{}", host, port), err));
+        return None;
+    }
+
+    let (sender, receiver) = channel::<(SocketAddr, Result<TcpStream>)>();
+    let mut addrs = addrs.into_iter();
+    let mut in_flight = Vec::new();
+    let mut next_attempt = Instant::now();
+    loop {
+        let now = Instant::now();
+        if deadline.map_or(false, |deadline| now >= deadline) {
+            // Everything that is still running has timed out
+            errors.extend(in_flight.into_iter().map(|addr: SocketAddr| {
+                let err = Error::new(ErrorKind::TimedOut, "Connection attempt timed out");
+                (addr.to_string(), err)
+            }));
+            return None;
+        }
+
+        // Start the next attempt if it is due
+        if now >= next_attempt {
+            if let Some(addr) = addrs.next() {
+                let sender = sender.clone();
+                let _ = std::thread::spawn(move || {
+                    let result = match deadline {
+                        Some(deadline) => connect_until(addr, deadline),
+                        None => TcpStream::connect(addr),
+                    };
+                    // If the receiver is gone, another attempt won and this stream is dropped
+                    let _ = sender.send((addr, result));
+                });
+                in_flight.push(addr);
+                next_attempt = now + CONNECTION_ATTEMPT_DELAY;
+            }
+        }
+        if in_flight.is_empty() {
+            // All addresses were tried and failed
+            return None;
+        }
+
+        // Wait for a result until the next attempt is due or the deadline is reached
+        let mut wake_up = deadline;
+        if !addrs.as_slice().is_empty() {
+            wake_up = Some(wake_up.map_or(next_attempt, |deadline| deadline.min(next_attempt)));
+        }
+        let result = match wake_up {
+            Some(wake_up) => receiver.recv_timeout(wake_up.saturating_duration_since(now)),
+            None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+        match result {
+            Ok((_, Ok(stream))) => return Some(stream),
+            Ok((addr, Err(err))) => {
+                in_flight.retain(|&a| a != addr);
+                errors.push((addr.to_string(), err));
+                // Start the next attempt right away
+                next_attempt = Instant::now();
+            }
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => {
+                unreachable!("The sender is kept alive by this function")
+            }
+        }
+    }
+}
+
+/// Connect to `addr`, but give up at `deadline`.
+fn connect_until(addr: SocketAddr, deadline: Instant) -> Result<TcpStream> {
+    let timeout = deadline.saturating_duration_since(Instant::now());
+    if timeout == Duration::from_secs(0) {
+        return Err(Error::new(
+            ErrorKind::TimedOut,
+            "Connection attempt timed out",
+        ));
+    }
+    TcpStream::connect_timeout(&addr, timeout)
+}
+
+/// Sort addresses so that address families alternate, starting with the family of the first
+/// address. Within each family, the original order is kept.
+fn interleave_families(addrs: Vec<SocketAddr>) -> Vec<SocketAddr> {
+    let first_is_v6 = match addrs.first() {
+        Some(addr) => addr.is_ipv6(),
+        None => return addrs,
+    };
+    let (first, second): (Vec<_>, Vec<_>) = addrs
+        .into_iter()
+        .partition(|addr| addr.is_ipv6() == first_is_v6);
+    let mut result = Vec::with_capacity(first.len() + second.len());
+    let (mut first, mut second) = (first.into_iter(), second.into_iter());
+    loop {
+        match (first.next(), second.next()) {
+            (None, None) => return result,
+            (a, b) => result.extend(a.into_iter().chain(b)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::interleave_families;
+    use std::net::SocketAddr;
+
+    fn addrs(addrs: &[&str]) -> Vec<SocketAddr> {
+        addrs.iter().map(|addr| addr.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn interleave() {
+        let input = addrs(&[
+            "[::1]:6000",
+            "[::2]:6000",
+            "[::3]:6000",
+            "127.0.0.1:6000",
+            "127.0.0.2:6000",
+        ]);
+        let expected = addrs(&[
+            "[::1]:6000",
+            "127.0.0.1:6000",
+            "[::2]:6000",
+            "127.0.0.2:6000",
+            "[::3]:6000",
+        ]);
+        assert_eq!(interleave_families(input), expected);
+    }
+
+    #[test]
+    fn interleave_starts_with_first_family() {
+        let input = addrs(&["127.0.0.1:6000", "[::1]:6000", "[::2]:6000"]);
+        let expected = addrs(&["127.0.0.1:6000", "[::1]:6000", "[::2]:6000"]);
+        assert_eq!(interleave_families(input), expected);
+    }
+
+    #[test]
+    fn interleave_empty() {
+        assert_eq!(interleave_families(Vec::new()), Vec::new());
+    }
+}
@@ -3,31 +3,54 @@
 use std::convert::TryInto;
 use std::io::IoSlice;
 use std::mem::drop;
-use std::sync::{Condvar, Mutex, MutexGuard, TryLockError};
+use std::panic::Location;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, TryLockError};
+use std::time::{Duration, Instant};
 
 use crate::connection::{
     compute_length_field, Connection, ReplyOrError, RequestConnection, RequestKind,
 };
 use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
-pub use crate::errors::{ConnectError, ConnectionError, ParseError, ReplyError, ReplyOrIdError};
+use crate::error_handler::{ErrorHandler, ErrorHandling};
+pub use crate::errors::{
+    ConnectError, ConnectionError, DisconnectReason, ParseError, ReplyError, ReplyOrIdError,
+};
 use crate::extension_manager::ExtensionManager;
+use crate::extension_version::ExtensionVersions;
 use crate::protocol::bigreq::{ConnectionExt as _, EnableReply};
 use crate::protocol::xproto::{Setup, GET_INPUT_FOCUS_REQUEST};
+use crate::statistics::ConnectionStatistics;
 use crate::utils::RawFdContainer;
 use crate::x11_utils::{ExtensionInformation, TryParse, TryParseFd};
 use x11rb_protocol::connect::Connect;
 use x11rb_protocol::connection::{Connection as ProtoConnection, PollReply, ReplyFdKind};
 use x11rb_protocol::id_allocator::IdAllocator;
-use x11rb_protocol::{xauth::get_auth, DiscardMode, RawEventAndSeqNumber, SequenceNumber};
+use x11rb_protocol::{
+    xauth::get_auth_for_client, DiscardMode, RawEventAndSeqNumber, SequenceNumber,
+};
 
+mod happy_eyeballs;
 mod packet_reader;
+mod replay;
+mod special_event;
 mod stream;
+mod tracer;
 mod write_buffer;
 
 use packet_reader::PacketReader;
+pub use replay::{RecordingStream, ReplayStream};
+pub use special_event::SpecialEventQueue;
 pub use stream::{DefaultStream, PollMode, Stream};
+pub use tracer::{PrintTracer, Tracer};
 use write_buffer::WriteBuffer;
 
+/// The environment variable that [`RustConnection::connect_from_env`] checks for an inherited
+/// socket.
+///
+/// Its value is the number of a file descriptor that refers to a socket that is already connected
+/// to the X11 server. This mirrors `$WAYLAND_SOCKET` for Wayland clients.
+pub const SOCKET_ENV_VAR: &str = "X11_SOCKET";
+
 type Buffer = <RustConnection as RequestConnection>::Buf;
 /// A combination of a buffer and a list of file descriptors for use by [`RustConnection`].
 pub type BufWithFds = crate::connection::BufWithFds<Buffer>;
@@ -43,14 +66,73 @@ enum MaxRequestBytes {
 struct ConnectionInner {
     inner: ProtoConnection,
     write_buffer: WriteBuffer,
+    tracer: Option<Arc<dyn Tracer>>,
+    statistics: Option<ConnectionStatistics>,
+    error_handling: ErrorHandling,
+}
+
+impl ConnectionInner {
+    /// Inform the tracer and the statistics about a request that is being sent.
+    fn request_sent(&mut self, seqno: SequenceNumber, bufs: &[IoSlice<'_>]) {
+        if let Some(tracer) = &self.tracer {
+            tracer.request_sent(seqno, bufs);
+        }
+        if let Some(statistics) = &mut self.statistics {
+            statistics.record_request(bufs, seqno, self.inner.last_sequence_read());
+        }
+    }
+
+    /// Count a wait for a response in the statistics, if there was one.
+    fn record_wait(&mut self, wait_start: Option<Instant>) {
+        if let (Some(statistics), Some(wait_start)) = (&mut self.statistics, wait_start) {
+            statistics.record_wait(wait_start);
+        }
+    }
 }
 
 type MutexGuardInner<'a> = MutexGuard<'a, ConnectionInner>;
 
+/// A callback that is informed when the connection to the X11 server is lost.
+///
+/// See [`RustConnection::set_disconnect_handler`].
+pub type DisconnectHandler = Arc<dyn Fn(DisconnectReason) + Send + Sync>;
+
+#[derive(Default)]
+struct DisconnectState {
+    reason: Option<DisconnectReason>,
+    handler: Option<DisconnectHandler>,
+}
+
+impl std::fmt::Debug for DisconnectState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DisconnectState")
+            .field("reason", &self.reason)
+            .field("handler", &self.handler.as_ref().map(|_| "<handler>"))
+            .finish()
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub(crate) enum BlockingMode {
     Blocking,
     NonBlocking,
+    /// Block, but not beyond the given point in time.
+    Deadline(Instant),
+}
+
+impl BlockingMode {
+    /// Get the blocking mode for waiting until the given (optional) deadline.
+    fn until(deadline: Option<Instant>) -> Self {
+        deadline.map_or(BlockingMode::Blocking, BlockingMode::Deadline)
+    }
+}
+
+/// Check if the given deadline has already passed.
+fn check_deadline(deadline: Option<Instant>) -> Result<(), ConnectionError> {
+    match deadline {
+        Some(deadline) if Instant::now() >= deadline => Err(ConnectionError::Timeout),
+        _ => Ok(()),
+    }
 }
 
 /// A connection to an X11 server implemented in pure rust
@@ -71,8 +153,10 @@ pub struct RustConnection<S: Stream = DefaultStream> {
     reader_condition: Condvar,
     setup: Setup,
     extension_manager: Mutex<ExtensionManager>,
+    extension_versions: ExtensionVersions,
     maximum_request_bytes: Mutex<MaxRequestBytes>,
     id_allocator: Mutex<IdAllocator>,
+    disconnect: Mutex<DisconnectState>,
 }
 
 // Locking rules
@@ -83,6 +167,7 @@ pub struct RustConnection<S: Stream = DefaultStream> {
 // Mutexes that may be locked when no other mutex is held:
 // - maximum_request_bytes
 // - extension_manager
+// - extension_versions
 // - id_allocator
 //
 // Then comes `inner`. This mutex protects the information about in-flight requests and packets
@@ -93,6 +178,9 @@ pub struct RustConnection<S: Stream = DefaultStream> {
 // this mutex is locked for operations other than writing, the lock should be kept only for a
 // short time.
 //
+// `disconnect` may be locked while any other mutex is held, but no other mutex may be locked while
+// it is held.
+//
 // The inner level is `packet_reader`. This mutex is only locked when `inner` is already held and
 // only with `try_lock()`. This ensures that there is only one reader. While actually reading, the
 // lock on `inner` is released so that other threads can make progress. If more threads want to
@@ -110,48 +198,154 @@ impl RustConnection<DefaultStream> {
     ///
     /// If no `dpy_name` is provided, the value from `$DISPLAY` is used.
     pub fn connect(dpy_name: Option<&str>) -> Result<(Self, usize), ConnectError> {
+        Self::connect_wrapped(dpy_name, None, Ok)
+    }
+
+    /// Establish a new connection, but give up after `timeout`.
+    ///
+    /// This works like [`RustConnection::connect`]. If the server cannot be reached in time,
+    /// [`ConnectError::ConnectFailed`] is returned with a [`std::io::ErrorKind::TimedOut`] error
+    /// for the connection attempts that were still running. The timeout only applies to
+    /// establishing the connection, not to the setup handshake with the server.
+    pub fn connect_timeout(
+        dpy_name: Option<&str>,
+        timeout: Duration,
+    ) -> Result<(Self, usize), ConnectError> {
+        Self::connect_wrapped(dpy_name, Some(Instant::now() + timeout), Ok)
+    }
+
+    /// Establish a new connection over an already connected socket.
+    ///
+    /// This is useful when the socket was inherited from a parent process, for example from a
+    /// sandbox launcher. The socket may be a Unix domain socket or a TCP socket and is closed when
+    /// the connection is dropped. `screen` is the number of the screen that should be used.
+    ///
+    /// Authorization information is looked up via xauth for the peer address of the socket. If the
+    /// display number cannot be determined from the peer address, the one from `$DISPLAY` is used.
+    #[cfg(unix)]
+    pub fn connect_from_fd(fd: RawFdContainer, screen: usize) -> Result<Self, ConnectError> {
+        let display = x11rb_protocol::parse_display::parse_display(None).map(|d| d.display);
+        Self::connect_to_fd(fd, screen, display)
+    }
+
+    /// Establish a new connection, preferring an inherited socket.
+    ///
+    /// If the environment variable [`SOCKET_ENV_VAR`] is set, the socket with the file descriptor
+    /// number from it is used via [`RustConnection::connect_from_fd`]. The variable is removed
+    /// from the environment and the socket is marked close-on-exec, so that child processes do not
+    /// inherit either of them. The screen number is taken from `dpy_name` or `$DISPLAY` and
+    /// defaults to zero.
+    ///
+    /// Otherwise, or on platforms other than unix, this works like [`RustConnection::connect`].
+    pub fn connect_from_env(dpy_name: Option<&str>) -> Result<(Self, usize), ConnectError> {
+        #[cfg(unix)]
+        {
+            use nix::fcntl::{fcntl, FcntlArg, FdFlag};
+
+            if let Some(value) = std::env::var_os(SOCKET_ENV_VAR) {
+                std::env::remove_var(SOCKET_ENV_VAR);
+                let fd = value
+                    .to_str()
+                    .and_then(|value| value.parse().ok())
+                    .filter(|&fd| fd >= 0)
+                    .ok_or_else(|| {
+                        std::io::Error::new(
+                            std::io::ErrorKind::InvalidInput,
+                            format!("${} does not contain a file descriptor", SOCKET_ENV_VAR),
+                        )
+                    })?;
+                // Check that the FD is valid before taking ownership of it
+                let _ = fcntl(fd, FcntlArg::F_SETFD(FdFlag::FD_CLOEXEC))
+                    .map_err(std::io::Error::from)?;
+                let fd = RawFdContainer::new(fd);
+
+                let parsed_display = x11rb_protocol::parse_display::parse_display(dpy_name);
+                let screen = parsed_display.as_ref().map_or(0, |d| d.screen.into());
+                let display = parsed_display.map(|d| d.display);
+                return Ok((Self::connect_to_fd(fd, screen, display)?, screen));
+            }
+        }
+        Self::connect(dpy_name)
+    }
+
+    /// Establish a new connection over an already connected socket.
+    ///
+    /// `display` is used for looking up authorization information if the display number cannot be
+    /// determined from the peer address of the socket.
+    #[cfg(unix)]
+    fn connect_to_fd(
+        fd: RawFdContainer,
+        screen: usize,
+        display: Option<u16>,
+    ) -> Result<Self, ConnectError> {
+        let stream = DefaultStream::from_fd(fd)?;
+        let (auth_name, auth_data) = match stream.peer_display().or(display) {
+            Some(display) => {
+                let (family, address) = stream.peer_addr()?;
+                get_auth_for_client(family, &address, display, stream.local_addr()?)
+                    // Ignore all errors while determining auth; instead we just try without auth info.
+                    .unwrap_or(None)
+                    .unwrap_or_else(|| (Vec::new(), Vec::new()))
+            }
+            None => (Vec::new(), Vec::new()),
+        };
+        Self::connect_to_stream_with_auth_info(stream, screen, auth_name, auth_data)
+    }
+}
+
+impl<S: Stream> RustConnection<S> {
+    /// Establish a new connection with a stream that wraps a [`DefaultStream`].
+    ///
+    /// This works like [`RustConnection::connect`], but the stream to the X11 server is first
+    /// passed through `wrap`. No new connection attempts are made after `deadline`.
+    fn connect_wrapped(
+        dpy_name: Option<&str>,
+        deadline: Option<Instant>,
+        wrap: impl FnOnce(DefaultStream) -> std::io::Result<S>,
+    ) -> Result<(Self, usize), ConnectError> {
         // Parse display information
         let parsed_display = x11rb_protocol::parse_display::parse_display(dpy_name)
             .ok_or(ConnectError::DisplayParsingError)?;
         let screen = parsed_display.screen.into();
 
         // Establish connection by iterating over ConnectAddresses until we find one that
-        // works.
-        let mut error = None;
+        // works. All errors are collected for diagnostics.
+        let mut errors = Vec::new();
         for addr in parsed_display.connect_instruction() {
-            match DefaultStream::connect(addr) {
-                Ok(stream) => {
-                    // we found a stream, get auth information
-                    let (family, address) = stream.peer_addr()?;
-                    let (auth_name, auth_data) = get_auth(family, &address, parsed_display.display)
+            if deadline.map_or(false, |deadline| Instant::now() >= deadline) {
+                errors.push((
+                    stream::describe_address(&addr),
+                    std::io::Error::new(std::io::ErrorKind::TimedOut, "Connecting timed out"),
+                ));
+                break;
+            }
+            if let Some(stream) = DefaultStream::connect_with_errors(addr, deadline, &mut errors) {
+                // we found a stream, get auth information
+                let (family, address) = stream.peer_addr()?;
+                let client_address = stream.local_addr()?;
+                let (auth_name, auth_data) =
+                    get_auth_for_client(family, &address, parsed_display.display, client_address)
                         // Ignore all errors while determining auth; instead we just try without auth info.
                         .unwrap_or(None)
                         .unwrap_or_else(|| (Vec::new(), Vec::new()));
 
-                    // finish connecting to server
-                    return Ok((
-                        Self::connect_to_stream_with_auth_info(
-                            stream, screen, auth_name, auth_data,
-                        )?,
-                        screen,
-                    ));
-                }
-                Err(e) => {
-                    error = Some(e);
-                    continue;
-                }
+                // finish connecting to server
+                let stream = wrap(stream)?;
+                return Ok((
+                    Self::connect_to_stream_with_auth_info(stream, screen, auth_name, auth_data)?,
+                    screen,
+                ));
             }
         }
 
         // none of the addresses worked
-        Err(match error {
-            Some(e) => ConnectError::IoError(e),
-            None => ConnectError::DisplayParsingError,
-        })
+        if errors.is_empty() {
+            Err(ConnectError::DisplayParsingError)
+        } else {
+            Err(ConnectError::ConnectFailed(errors))
+        }
     }
-}
 
-impl<S: Stream> RustConnection<S> {
     /// Establish a new connection to the given streams.
     ///
     /// `read` is used for reading data from the X11 server and `write` is used for writing.
@@ -251,14 +445,19 @@ impl<S: Stream> RustConnection<S> {
             inner: Mutex::new(ConnectionInner {
                 inner,
                 write_buffer: WriteBuffer::new(),
+                tracer: None,
+                statistics: None,
+                error_handling: Default::default(),
             }),
             stream,
             packet_reader: Mutex::new(PacketReader::new()),
             reader_condition: Condvar::new(),
             setup,
             extension_manager: Default::default(),
+            extension_versions: Default::default(),
             maximum_request_bytes: Mutex::new(MaxRequestBytes::Unknown),
             id_allocator: Mutex::new(id_allocator),
+            disconnect: Default::default(),
         })
     }
 
@@ -266,12 +465,14 @@ impl<S: Stream> RustConnection<S> {
     ///
     /// This function "does the actual work" for `send_request_with_reply()` and
     /// `send_request_without_reply()`.
+    #[track_caller]
     fn send_request(
         &self,
         bufs: &[IoSlice<'_>],
         fds: Vec<RawFdContainer>,
         kind: ReplyFdKind,
     ) -> Result<SequenceNumber, ConnectionError> {
+        let location = Location::caller();
         let mut storage = Default::default();
         let bufs = compute_length_field(self, bufs, &mut storage)?;
 
@@ -285,6 +486,8 @@ impl<S: Stream> RustConnection<S> {
             let send_result = inner.inner.send_request(kind);
             match send_result {
                 Some(seqno) => {
+                    inner.request_sent(seqno, bufs);
+                    inner.error_handling.request_sent(seqno, location);
                     // Now actually send the buffers
                     let _inner = self.write_all_vectored(inner, bufs, fds)?;
                     return Ok(seqno);
@@ -320,7 +523,9 @@ impl<S: Stream> RustConnection<S> {
         inner
             .inner
             .discard_reply(seqno, DiscardMode::DiscardReplyAndError);
-        let inner = self.write_all_vectored(inner, &[IoSlice::new(&request)], Vec::new())?;
+        let bufs = [IoSlice::new(&request)];
+        inner.request_sent(seqno, &bufs);
+        let inner = self.write_all_vectored(inner, &bufs, Vec::new())?;
 
         Ok(inner)
     }
@@ -335,7 +540,9 @@ impl<S: Stream> RustConnection<S> {
     ) -> std::io::Result<MutexGuardInner<'a>> {
         let mut partial_buf: &[u8] = &[];
         while !partial_buf.is_empty() || !bufs.is_empty() || !fds.is_empty() {
-            self.stream.poll(PollMode::ReadAndWritable)?;
+            self.stream
+                .poll(PollMode::ReadAndWritable)
+                .map_err(|e| self.io_error(e))?;
             let write_result = if !partial_buf.is_empty() {
                 // "inner" is held, passed into this function, so this should never be held
                 inner
@@ -349,10 +556,10 @@ impl<S: Stream> RustConnection<S> {
             };
             match write_result {
                 Ok(0) => {
-                    return Err(std::io::Error::new(
+                    return Err(self.io_error(std::io::Error::new(
                         std::io::ErrorKind::WriteZero,
                         "failed to write anything",
-                    ));
+                    )));
                 }
                 Ok(mut count) => {
                     // Successful write
@@ -383,19 +590,36 @@ impl<S: Stream> RustConnection<S> {
                     // buffered replies have been read.
                     inner = self.read_packet_and_enqueue(inner, BlockingMode::NonBlocking)?;
                 }
-                Err(e) => return Err(e),
+                Err(e) => return Err(self.io_error(e)),
             }
         }
         Ok(inner)
     }
 
+    /// Flush the write buffer.
+    ///
+    /// If a deadline is given and it passes before everything was written,
+    /// `ConnectionError::Timeout` is returned. The remaining data stays in the write buffer.
     fn flush_impl<'a>(
         &'a self,
         mut inner: MutexGuardInner<'a>,
-    ) -> std::io::Result<MutexGuardInner<'a>> {
+        deadline: Option<Instant>,
+    ) -> Result<MutexGuardInner<'a>, ConnectionError> {
         // n.b. notgull: inner guard is held
         while inner.write_buffer.needs_flush() {
-            self.stream.poll(PollMode::ReadAndWritable)?;
+            match deadline {
+                None => self
+                    .stream
+                    .poll(PollMode::ReadAndWritable)
+                    .map_err(|e| self.io_error(e))?,
+                Some(deadline) => {
+                    check_deadline(Some(deadline))?;
+                    let timeout = deadline.saturating_duration_since(Instant::now());
+                    self.stream
+                        .poll_timeout(PollMode::ReadAndWritable, timeout)
+                        .map_err(|e| self.io_error(e))?;
+                }
+            }
             let flush_result = inner.write_buffer.flush(&self.stream);
             match flush_result {
                 // Flush completed
@@ -406,7 +630,7 @@ impl<S: Stream> RustConnection<S> {
                     // buffered replies have been read.
                     inner = self.read_packet_and_enqueue(inner, BlockingMode::NonBlocking)?;
                 }
-                Err(e) => return Err(e),
+                Err(e) => return Err(self.io_error(e).into()),
             }
         }
         Ok(inner)
@@ -419,6 +643,9 @@ impl<S: Stream> RustConnection<S> {
     /// reason, you need to pass in a `MutexGuard` to be dropped. This function locks the mutex
     /// again and returns a new `MutexGuard`.
     ///
+    /// If `mode` is `BlockingMode::Deadline`, this function might return without having read a
+    /// packet once the deadline passed. Callers have to check the deadline themselves.
+    ///
     /// Note: If `mode` is `BlockingMode::Blocking`, the lock on `inner` will be temporarily
     /// released. While sending a request, `inner` must be kept locked to avoid sending the data
     /// of different requests interleaved. So, when `read_packet_and_enqueue` is called as part
@@ -432,10 +659,11 @@ impl<S: Stream> RustConnection<S> {
         match self.packet_reader.try_lock() {
             Err(TryLockError::WouldBlock) => {
                 // In non-blocking mode, we just return immediately
-                match mode {
+                let deadline = match mode {
                     BlockingMode::NonBlocking => return Ok(inner),
-                    BlockingMode::Blocking => {}
-                }
+                    BlockingMode::Blocking => None,
+                    BlockingMode::Deadline(deadline) => Some(deadline),
+                };
 
                 // 1.1. Someone else is reading (other thread is at 2.2);
                 // wait for it. `Condvar::wait` will unlock `inner`, so
@@ -445,7 +673,17 @@ impl<S: Stream> RustConnection<S> {
                 // When `wait` finishes, other thread has enqueued a packet,
                 // so the purpose of this function has been fulfilled. `wait`
                 // will relock `inner` when it returns.
-                Ok(self.reader_condition.wait(inner).unwrap())
+                match deadline {
+                    None => Ok(self.reader_condition.wait(inner).unwrap()),
+                    Some(deadline) => {
+                        let timeout = deadline.saturating_duration_since(Instant::now());
+                        Ok(self
+                            .reader_condition
+                            .wait_timeout(inner, timeout)
+                            .unwrap()
+                            .0)
+                    }
+                }
             }
             Err(TryLockError::Poisoned(e)) => panic!("{}", e),
             Ok(mut packet_reader) => {
@@ -454,12 +692,23 @@ impl<S: Stream> RustConnection<S> {
                 let notify_on_drop = NotifyOnDrop(&self.reader_condition);
 
                 // 2.1. Poll for read if mode is blocking.
-                if mode == BlockingMode::Blocking {
+                if mode != BlockingMode::NonBlocking {
                     // 2.1.1. Unlock `inner`, so other threads can use it while
                     // during the poll.
                     drop(inner);
                     // 2.1.2. Do the actual poll
-                    self.stream.poll(PollMode::Readable)?;
+                    match mode {
+                        BlockingMode::Deadline(deadline) => {
+                            let timeout = deadline.saturating_duration_since(Instant::now());
+                            self.stream
+                                .poll_timeout(PollMode::Readable, timeout)
+                                .map_err(|e| self.io_error(e))?;
+                        }
+                        _ => self
+                            .stream
+                            .poll(PollMode::Readable)
+                            .map_err(|e| self.io_error(e))?,
+                    }
                     // 2.1.3. Relock inner
                     inner = self.inner.lock().unwrap();
                 }
@@ -467,7 +716,9 @@ impl<S: Stream> RustConnection<S> {
                 // 2.2. Try to read as many packets as possible without blocking.
                 let mut fds = Vec::new();
                 let mut packets = Vec::new();
-                packet_reader.try_read_packets(&self.stream, &mut packets, &mut fds)?;
+                packet_reader
+                    .try_read_packets(&self.stream, &mut packets, &mut fds)
+                    .map_err(|e| self.io_error(e))?;
 
                 // 2.3. Once `inner` has been relocked, drop the
                 // lock on `packet_reader`. While inner is locked, other
@@ -480,9 +731,27 @@ impl<S: Stream> RustConnection<S> {
 
                 // 2.4. Actually enqueue the read packets.
                 inner.inner.enqueue_fds(fds);
-                packets
-                    .into_iter()
-                    .for_each(|packet| inner.inner.enqueue_packet(packet));
+                if let Some(statistics) = &mut inner.statistics {
+                    packets
+                        .iter()
+                        .for_each(|packet| statistics.record_packet(packet));
+                }
+                let tracer = inner.tracer.clone();
+                for packet in packets {
+                    let is_error = packet[0] == 0;
+                    match &tracer {
+                        None => inner.inner.enqueue_packet(packet),
+                        Some(tracer) => {
+                            let copy = packet.clone();
+                            inner.inner.enqueue_packet(packet);
+                            tracer.packet_received(inner.inner.last_sequence_read(), &copy);
+                        }
+                    }
+                    let seqno = inner.inner.last_sequence_read();
+                    if let Some(origins) = &mut inner.error_handling.origins {
+                        origins.packet_received(seqno, is_error);
+                    }
+                }
 
                 // 2.5. Notify the condvar by dropping the `notify_on_drop` object.
                 // The object would have been dropped when the function returns, so
@@ -496,6 +765,107 @@ impl<S: Stream> RustConnection<S> {
         }
     }
 
+    fn check_for_raw_error_impl(
+        &self,
+        sequence: SequenceNumber,
+        deadline: Option<Instant>,
+    ) -> Result<Option<Buffer>, ConnectionError> {
+        let mut inner = self.inner.lock().unwrap();
+        if inner.inner.prepare_check_for_reply_or_error(sequence) {
+            inner = self.send_sync(inner)?;
+            assert!(!inner.inner.prepare_check_for_reply_or_error(sequence));
+        }
+        // Ensure the request is sent
+        inner = self.flush_impl(inner, deadline)?;
+        let mut wait_start = None;
+        let result = loop {
+            let poll_result = inner.inner.poll_check_for_reply_or_error(sequence);
+            match poll_result {
+                PollReply::TryAgain => {}
+                PollReply::NoReply => break None,
+                PollReply::Reply(buffer) => break Some(buffer),
+            }
+            check_deadline(deadline)?;
+            if wait_start.is_none() {
+                wait_start = Some(Instant::now());
+            }
+            inner = self.read_packet_and_enqueue(inner, BlockingMode::until(deadline))?;
+        };
+        inner.record_wait(wait_start);
+        inner.error_handling.response_consumed(sequence);
+        Ok(result)
+    }
+
+    fn wait_for_reply_with_fds_raw_impl(
+        &self,
+        sequence: SequenceNumber,
+        deadline: Option<Instant>,
+    ) -> Result<ReplyOrError<BufWithFds, Buffer>, ConnectionError> {
+        let mut inner = self.inner.lock().unwrap();
+        // Ensure the request is sent
+        inner = self.flush_impl(inner, deadline)?;
+        let mut wait_start = None;
+        let reply = loop {
+            if let Some(reply) = inner.inner.poll_for_reply_or_error(sequence) {
+                break reply;
+            }
+            check_deadline(deadline)?;
+            if wait_start.is_none() {
+                wait_start = Some(Instant::now());
+            }
+            inner = self.read_packet_and_enqueue(inner, BlockingMode::until(deadline))?;
+        };
+        inner.record_wait(wait_start);
+        inner.error_handling.response_consumed(sequence);
+        if reply.0[0] == 0 {
+            Ok(ReplyOrError::Error(reply.0))
+        } else {
+            Ok(ReplyOrError::Reply(reply))
+        }
+    }
+
+    fn wait_for_raw_event_with_sequence_impl(
+        &self,
+        deadline: Option<Instant>,
+    ) -> Result<RawEventAndSeqNumber<Vec<u8>>, ConnectionError> {
+        let mut inner = self.inner.lock().unwrap();
+        loop {
+            let (new_inner, event) = self.poll_for_unhandled_event(inner);
+            inner = new_inner;
+            if let Some(event) = event {
+                return Ok(event);
+            }
+            check_deadline(deadline)?;
+            inner = self.read_packet_and_enqueue(inner, BlockingMode::until(deadline))?;
+        }
+    }
+
+    /// Get an event from the queue that is not consumed by the error handler.
+    ///
+    /// If an error handler is installed, errors from the queue are passed to it. `inner` is
+    /// unlocked while the handler runs.
+    fn poll_for_unhandled_event<'a>(
+        &'a self,
+        mut inner: MutexGuardInner<'a>,
+    ) -> (MutexGuardInner<'a>, Option<RawEventAndSeqNumber<Vec<u8>>>) {
+        loop {
+            let (event, seqno) = match inner.inner.poll_for_event_with_sequence() {
+                Some(event) => event,
+                None => return (inner, None),
+            };
+            let (handler, origin) = match inner.error_handling.event_taken(&event, seqno) {
+                Some(handler) => handler,
+                None => return (inner, Some((event, seqno))),
+            };
+            drop(inner);
+            match self.parse_error(&event) {
+                Ok(error) => handler(&error, origin.as_ref()),
+                Err(_) => return (self.inner.lock().unwrap(), Some((event, seqno))),
+            }
+            inner = self.inner.lock().unwrap();
+        }
+    }
+
     fn prefetch_maximum_request_bytes_impl(&self, max_bytes: &mut MutexGuard<'_, MaxRequestBytes>) {
         if let MaxRequestBytes::Unknown = **max_bytes {
             let request = self
@@ -510,6 +880,122 @@ impl<S: Stream> RustConnection<S> {
     pub fn stream(&self) -> &S {
         &self.stream
     }
+
+    /// Install or remove a [`Tracer`] that is informed about all packets on this connection.
+    ///
+    /// Only packets that are sent or received after this call are reported to the tracer. Pass
+    /// `None` to remove a previously installed tracer.
+    pub fn set_tracer(&self, tracer: Option<Arc<dyn Tracer>>) {
+        self.inner.lock().unwrap().tracer = tracer;
+    }
+
+    /// Enable or disable counting the traffic on this connection.
+    ///
+    /// Passing `true` resets all counters to zero. While counting is enabled,
+    /// [`RequestConnection::statistics`] returns a snapshot of the counters.
+    pub fn set_statistics_enabled(&self, enabled: bool) {
+        self.inner.lock().unwrap().statistics = if enabled {
+            Some(Default::default())
+        } else {
+            None
+        };
+    }
+
+    /// Register a queue for events of the Generic Event Extension with the given event ID (EID).
+    ///
+    /// Extensions like Present and XInput 2 send their events as generic events that carry an
+    /// EID chosen by the client when selecting the events. Events from the extension called
+    /// `extension_name` with one of the given `event_types` and with the given `eid` are no longer
+    /// returned by [`Connection::poll_for_event`] and [`Connection::wait_for_event`], but only by
+    /// the returned [`SpecialEventQueue`]. An empty list of event types matches all events of the
+    /// extension. This allows a library to receive its events without interfering with the main
+    /// event loop of the application.
+    ///
+    /// Returns `None` if a queue for the same extension and EID is already registered. Returns
+    /// [`ConnectionError::UnsupportedExtension`] if the server does not support the extension.
+    ///
+    /// The queue is unregistered when it is dropped. Events that are still queued then are lost.
+    pub fn register_for_special_event(
+        &self,
+        extension_name: &'static str,
+        event_types: &[u16],
+        eid: u32,
+    ) -> Result<Option<SpecialEventQueue<'_, S>>, ConnectionError> {
+        let extension = self
+            .extension_information(extension_name)?
+            .ok_or(ConnectionError::UnsupportedExtension)?;
+        let id = self.inner.lock().unwrap().inner.register_special_event(
+            extension.major_opcode,
+            event_types,
+            eid,
+        );
+        Ok(id.map(|id| SpecialEventQueue::new(self, id)))
+    }
+
+    /// Install or remove a handler for X11 errors that would otherwise end up in the event queue.
+    ///
+    /// While a handler is installed, [`Connection::poll_for_event`] and
+    /// [`Connection::wait_for_event`] never return [`Event::Error`](crate::protocol::Event::Error).
+    /// Instead, the errors are passed to the handler when they are taken from the event queue.
+    /// See [`crate::error_handler`] for details.
+    pub fn set_error_handler(&self, handler: Option<ErrorHandler>) {
+        self.inner.lock().unwrap().error_handling.handler = handler;
+    }
+
+    /// Enable or disable recording where each request was sent from.
+    ///
+    /// While this is enabled, the error handler that was installed with
+    /// [`RustConnection::set_error_handler`] receives the origin of the failing request.
+    pub fn set_request_origin_tracking(&self, enabled: bool) {
+        self.inner
+            .lock()
+            .unwrap()
+            .error_handling
+            .set_origin_tracking(enabled);
+    }
+
+    /// Get the reason why the connection to the X11 server was lost.
+    ///
+    /// Returns `None` while the connection is still usable. Once the connection is lost, all
+    /// further requests fail and a new connection has to be established, for example with
+    /// [`crate::reconnect::Supervisor`].
+    pub fn disconnect_reason(&self) -> Option<DisconnectReason> {
+        self.disconnect.lock().unwrap().reason
+    }
+
+    /// Install or remove a handler that is called once the connection to the X11 server is lost.
+    ///
+    /// The handler is called at most once, from the thread that noticed the lost connection. If
+    /// the connection was already lost, the handler is called immediately. Since internal locks
+    /// might be held while the handler runs, it must not use this connection.
+    pub fn set_disconnect_handler(&self, handler: Option<DisconnectHandler>) {
+        let mut state = self.disconnect.lock().unwrap();
+        match (state.reason, handler) {
+            (Some(reason), Some(handler)) => {
+                drop(state);
+                handler(reason);
+            }
+            (_, handler) => state.handler = handler,
+        }
+    }
+
+    /// Record that an I/O error occurred on the stream.
+    ///
+    /// If the error means that the connection was lost, the disconnect handler is informed.
+    fn io_error(&self, err: std::io::Error) -> std::io::Error {
+        if let Some(reason) = DisconnectReason::from_io_error(&err) {
+            let mut state = self.disconnect.lock().unwrap();
+            if state.reason.is_none() {
+                state.reason = Some(reason);
+                let handler = state.handler.take();
+                drop(state);
+                if let Some(handler) = handler {
+                    handler(reason);
+                }
+            }
+        }
+        err
+    }
 }
 
 impl<S: Stream> RequestConnection for RustConnection<S> {
@@ -555,11 +1041,11 @@ impl<S: Stream> RequestConnection for RustConnection<S> {
     }
 
     fn discard_reply(&self, sequence: SequenceNumber, _kind: RequestKind, mode: DiscardMode) {
-        self.inner
-            .lock()
-            .unwrap()
-            .inner
-            .discard_reply(sequence, mode);
+        let mut inner = self.inner.lock().unwrap();
+        inner.inner.discard_reply(sequence, mode);
+        if let DiscardMode::DiscardReplyAndError = mode {
+            inner.error_handling.response_consumed(sequence);
+        }
     }
 
     fn prefetch_extension_information(
@@ -582,6 +1068,23 @@ impl<S: Stream> RequestConnection for RustConnection<S> {
             .extension_information(self, extension_name)
     }
 
+    fn extension_version(
+        &self,
+        extension_name: &'static str,
+    ) -> Result<Option<(u32, u32)>, ReplyError> {
+        self.extension_versions
+            .extension_version(self, extension_name)
+    }
+
+    fn check_extension_version(
+        &self,
+        extension_name: &'static str,
+        required: (u32, u32),
+    ) -> Result<(), ConnectionError> {
+        self.extension_versions
+            .check_extension_version(extension_name, required)
+    }
+
     fn wait_for_reply_or_raw_error(
         &self,
         sequence: SequenceNumber,
@@ -594,56 +1097,100 @@ impl<S: Stream> RequestConnection for RustConnection<S> {
 
     fn wait_for_reply(&self, sequence: SequenceNumber) -> Result<Option<Vec<u8>>, ConnectionError> {
         let mut inner = self.inner.lock().unwrap();
-        inner = self.flush_impl(inner)?;
-        loop {
+        inner = self.flush_impl(inner, None)?;
+        let mut wait_start = None;
+        let result = loop {
             let poll_result = inner.inner.poll_for_reply(sequence);
             match poll_result {
                 PollReply::TryAgain => {}
-                PollReply::NoReply => return Ok(None),
-                PollReply::Reply(buffer) => return Ok(Some(buffer)),
+                PollReply::NoReply => break None,
+                PollReply::Reply(buffer) => break Some(buffer),
+            }
+            if wait_start.is_none() {
+                wait_start = Some(Instant::now());
             }
             inner = self.read_packet_and_enqueue(inner, BlockingMode::Blocking)?;
-        }
+        };
+        inner.record_wait(wait_start);
+        Ok(result)
     }
 
-    fn check_for_raw_error(
+    fn wait_for_replies_or_raw_errors(
         &self,
-        sequence: SequenceNumber,
-    ) -> Result<Option<Buffer>, ConnectionError> {
+        sequences: &[SequenceNumber],
+    ) -> Result<Vec<ReplyOrError<Vec<u8>>>, ConnectionError> {
+        let mut results = sequences.iter().map(|_| None).collect::<Vec<_>>();
+        // Indices into `sequences` of the requests that are still unanswered
+        let mut missing = (0..sequences.len()).collect::<Vec<_>>();
         let mut inner = self.inner.lock().unwrap();
-        if inner.inner.prepare_check_for_reply_or_error(sequence) {
-            inner = self.send_sync(inner)?;
-            assert!(!inner.inner.prepare_check_for_reply_or_error(sequence));
-        }
-        // Ensure the request is sent
-        inner = self.flush_impl(inner)?;
+        inner = self.flush_impl(inner, None)?;
+        let mut wait_start = None;
         loop {
-            let poll_result = inner.inner.poll_check_for_reply_or_error(sequence);
-            match poll_result {
-                PollReply::TryAgain => {}
-                PollReply::NoReply => return Ok(None),
-                PollReply::Reply(buffer) => return Ok(Some(buffer)),
+            // Collect everything that arrived since the last wakeup
+            let mut received_fds = false;
+            let state = &mut *inner;
+            missing.retain(
+                |&index| match state.inner.poll_for_reply_or_error(sequences[index]) {
+                    Some((reply, fds)) => {
+                        received_fds |= !fds.is_empty();
+                        state.error_handling.response_consumed(sequences[index]);
+                        results[index] = Some(if reply[0] == 0 {
+                            ReplyOrError::Error(reply)
+                        } else {
+                            ReplyOrError::Reply(reply)
+                        });
+                        false
+                    }
+                    None => true,
+                },
+            );
+            if received_fds {
+                // The results have no place for file descriptors, so refuse to silently drop them
+                return Err(ConnectionError::FdPassingFailed);
+            }
+            if missing.is_empty() {
+                break;
+            }
+            if wait_start.is_none() {
+                wait_start = Some(Instant::now());
             }
             inner = self.read_packet_and_enqueue(inner, BlockingMode::Blocking)?;
         }
+        inner.record_wait(wait_start);
+        Ok(results.into_iter().map(Option::unwrap).collect())
+    }
+
+    fn check_for_raw_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<Option<Buffer>, ConnectionError> {
+        self.check_for_raw_error_impl(sequence, None)
+    }
+
+    fn check_for_raw_error_timeout(
+        &self,
+        sequence: SequenceNumber,
+        timeout: Duration,
+    ) -> Result<Option<Buffer>, ConnectionError> {
+        self.check_for_raw_error_impl(sequence, Some(Instant::now() + timeout))
     }
 
     fn wait_for_reply_with_fds_raw(
         &self,
         sequence: SequenceNumber,
     ) -> Result<ReplyOrError<BufWithFds, Buffer>, ConnectionError> {
-        let mut inner = self.inner.lock().unwrap();
-        // Ensure the request is sent
-        inner = self.flush_impl(inner)?;
-        loop {
-            if let Some(reply) = inner.inner.poll_for_reply_or_error(sequence) {
-                if reply.0[0] == 0 {
-                    return Ok(ReplyOrError::Error(reply.0));
-                } else {
-                    return Ok(ReplyOrError::Reply(reply));
-                }
-            }
-            inner = self.read_packet_and_enqueue(inner, BlockingMode::Blocking)?;
+        self.wait_for_reply_with_fds_raw_impl(sequence, None)
+    }
+
+    fn wait_for_reply_or_raw_error_timeout(
+        &self,
+        sequence: SequenceNumber,
+        timeout: Duration,
+    ) -> Result<ReplyOrError<Vec<u8>>, ConnectionError> {
+        let deadline = Some(Instant::now() + timeout);
+        match self.wait_for_reply_with_fds_raw_impl(sequence, deadline)? {
+            ReplyOrError::Reply((reply, _fds)) => Ok(ReplyOrError::Reply(reply)),
+            ReplyOrError::Error(e) => Ok(ReplyOrError::Error(e)),
         }
     }
 
@@ -691,36 +1238,42 @@ impl<S: Stream> RequestConnection for RustConnection<S> {
         let ext_mgr = self.extension_manager.lock().unwrap();
         crate::protocol::Event::parse(event, &*ext_mgr)
     }
+
+    fn statistics(&self) -> Option<ConnectionStatistics> {
+        self.inner.lock().unwrap().statistics.clone()
+    }
 }
 
 impl<S: Stream> Connection for RustConnection<S> {
     fn wait_for_raw_event_with_sequence(
         &self,
     ) -> Result<RawEventAndSeqNumber<Vec<u8>>, ConnectionError> {
-        let mut inner = self.inner.lock().unwrap();
-        loop {
-            if let Some(event) = inner.inner.poll_for_event_with_sequence() {
-                return Ok(event);
-            }
-            inner = self.read_packet_and_enqueue(inner, BlockingMode::Blocking)?;
-        }
+        self.wait_for_raw_event_with_sequence_impl(None)
+    }
+
+    fn wait_for_raw_event_with_sequence_timeout(
+        &self,
+        timeout: Duration,
+    ) -> Result<RawEventAndSeqNumber<Vec<u8>>, ConnectionError> {
+        self.wait_for_raw_event_with_sequence_impl(Some(Instant::now() + timeout))
     }
 
     fn poll_for_raw_event_with_sequence(
         &self,
     ) -> Result<Option<RawEventAndSeqNumber<Vec<u8>>>, ConnectionError> {
-        let mut inner = self.inner.lock().unwrap();
-        if let Some(event) = inner.inner.poll_for_event_with_sequence() {
-            Ok(Some(event))
+        let inner = self.inner.lock().unwrap();
+        let (inner, event) = self.poll_for_unhandled_event(inner);
+        if event.is_some() {
+            Ok(event)
         } else {
-            inner = self.read_packet_and_enqueue(inner, BlockingMode::NonBlocking)?;
-            Ok(inner.inner.poll_for_event_with_sequence())
+            let inner = self.read_packet_and_enqueue(inner, BlockingMode::NonBlocking)?;
+            Ok(self.poll_for_unhandled_event(inner).1)
         }
     }
 
     fn flush(&self) -> Result<(), ConnectionError> {
         let inner = self.inner.lock().unwrap();
-        let _inner = self.flush_impl(inner)?;
+        let _inner = self.flush_impl(inner, None)?;
         Ok(())
     }
 
@@ -49,6 +49,9 @@ impl PacketReader {
             // into the buffer
             if self.inner.remaining_capacity() >= self.read_buffer.len() {
                 match stream.read(self.inner.buffer(), fd_storage) {
+                    // Packets that were read before EOF are still returned. The next call then
+                    // reports EOF.
+                    Ok(0) if !out_packets.is_empty() => break,
                     Ok(0) => {
                         return Err(Error::new(
                             ErrorKind::UnexpectedEof,
@@ -66,6 +69,9 @@ impl PacketReader {
             } else {
                 // read into our buffer
                 let nread = match stream.read(&mut self.read_buffer, fd_storage) {
+                    // Packets that were read before EOF are still returned. The next call then
+                    // reports EOF.
+                    Ok(0) if !out_packets.is_empty() => break,
                     Ok(0) => {
                         return Err(Error::new(
                             ErrorKind::UnexpectedEof,
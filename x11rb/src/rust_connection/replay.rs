@@ -0,0 +1,499 @@
+//! Streams for recording the communication with an X11 server and replaying it later.
+//!
+//! A recording is a sequence of chunks. Each chunk starts with a byte describing its direction
+//! (`0` for data sent by the client, `1` for data sent by the X11 server), followed by the length
+//! of the data as a little endian `u32`, followed by the data itself. File descriptors are not
+//! part of a recording.
+
+use std::collections::VecDeque;
+use std::convert::TryInto;
+use std::fmt;
+use std::fs::File;
+use std::io::{BufReader, Error, ErrorKind, IoSlice, Read, Result, Write};
+use std::path::Path;
+use std::sync::Mutex;
+
+use super::{DefaultStream, PollMode, RustConnection, Stream};
+use crate::errors::ConnectError;
+use crate::utils::RawFdContainer;
+use x11rb_protocol::protocol::request_name;
+use x11rb_protocol::protocol::xproto::{GE_GENERIC_EVENT, QUERY_EXTENSION_REQUEST};
+
+const FROM_CLIENT: u8 = 0;
+const FROM_SERVER: u8 = 1;
+
+/// A [`Stream`] that records all data that is sent and received.
+///
+/// This wraps another stream and writes everything that goes through it into an output,
+/// including the setup exchange. The resulting recording can be fed back into a
+/// [`RustConnection`] with [`ReplayStream`].
+///
+/// File descriptors are passed through, but are not recorded.
+pub struct RecordingStream<S = DefaultStream> {
+    stream: S,
+    output: Mutex<Box<dyn Write + Send>>,
+}
+
+impl<S: fmt::Debug> fmt::Debug for RecordingStream<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RecordingStream")
+            .field("stream", &self.stream)
+            .finish()
+    }
+}
+
+impl<S: Stream> RecordingStream<S> {
+    /// Wrap the given stream and record its traffic into the given output.
+    pub fn new(stream: S, output: impl Write + Send + 'static) -> Self {
+        Self {
+            stream,
+            output: Mutex::new(Box::new(output)),
+        }
+    }
+
+    /// Wrap the given stream and record its traffic into a newly created file.
+    pub fn create(stream: S, path: impl AsRef<Path>) -> Result<Self> {
+        Ok(Self::new(stream, File::create(path)?))
+    }
+
+    /// Returns a reference to the wrapped stream.
+    pub fn stream(&self) -> &S {
+        &self.stream
+    }
+
+    fn record(&self, direction: u8, data: &[u8]) -> Result<()> {
+        if data.is_empty() {
+            return Ok(());
+        }
+        let length: u32 = data
+            .len()
+            .try_into()
+            .map_err(|_| Error::new(ErrorKind::InvalidInput, "chunk too large to be recorded"))?;
+        let mut output = self.output.lock().unwrap();
+        output.write_all(&[direction])?;
+        output.write_all(&length.to_le_bytes())?;
+        output.write_all(data)?;
+        output.flush()
+    }
+}
+
+impl<S: Stream> Stream for RecordingStream<S> {
+    fn poll(&self, mode: PollMode) -> Result<()> {
+        self.stream.poll(mode)
+    }
+
+    fn poll_timeout(&self, mode: PollMode, timeout: std::time::Duration) -> Result<()> {
+        self.stream.poll_timeout(mode, timeout)
+    }
+
+    fn read(&self, buf: &mut [u8], fd_storage: &mut Vec<RawFdContainer>) -> Result<usize> {
+        let n = self.stream.read(buf, fd_storage)?;
+        self.record(FROM_SERVER, &buf[..n])?;
+        Ok(n)
+    }
+
+    fn write(&self, buf: &[u8], fds: &mut Vec<RawFdContainer>) -> Result<usize> {
+        let n = self.stream.write(buf, fds)?;
+        self.record(FROM_CLIENT, &buf[..n])?;
+        Ok(n)
+    }
+
+    fn write_vectored(&self, bufs: &[IoSlice<'_>], fds: &mut Vec<RawFdContainer>) -> Result<usize> {
+        let n = self.stream.write_vectored(bufs, fds)?;
+        let mut written = Vec::with_capacity(n);
+        for buf in bufs {
+            let remaining = n - written.len();
+            if remaining == 0 {
+                break;
+            }
+            written.extend_from_slice(&buf[..remaining.min(buf.len())]);
+        }
+        self.record(FROM_CLIENT, &written)?;
+        Ok(n)
+    }
+}
+
+impl RustConnection<RecordingStream> {
+    /// Establish a new connection and record all of its traffic into a file.
+    ///
+    /// This works like [`RustConnection::connect`], but additionally records everything that is
+    /// sent and received into a newly created file at `path`. The file can later be replayed
+    /// with [`ReplayStream`].
+    pub fn connect_and_record(
+        dpy_name: Option<&str>,
+        path: impl AsRef<Path>,
+    ) -> std::result::Result<(Self, usize), ConnectError> {
+        let file = File::create(path)?;
+        Self::connect_wrapped(dpy_name, None, |stream| {
+            Ok(RecordingStream::new(stream, file))
+        })
+    }
+}
+
+/// A [`Stream`] that replays a recording made with [`RecordingStream`].
+///
+/// The replay checks that the client sends exactly the same bytes as in the recording. Data from
+/// the X11 server is handed to the client once the client sent everything that was sent before
+/// this data in the recording.
+///
+/// When the client sends something different, all further I/O fails with an error describing the
+/// first divergence, including the names of the expected and the actual request. Use
+/// [`ReplayStream::check_complete`] at the end of a test to check that the client sent everything
+/// that is contained in the recording.
+#[derive(Debug)]
+pub struct ReplayStream {
+    state: Mutex<ReplayState>,
+}
+
+#[derive(Debug)]
+struct ReplayState {
+    /// Everything that the client is expected to send.
+    expected: Vec<u8>,
+    /// The number of bytes from `expected` that the client already sent.
+    written: usize,
+    /// Data from the server together with the number of bytes that the client has to send before
+    /// this data becomes available.
+    pending: VecDeque<(usize, Vec<u8>)>,
+    /// Data that the client can read.
+    to_client: VecDeque<u8>,
+    /// Everything that the server sent, used for decoding requests.
+    from_server: Vec<u8>,
+    /// Description of the first divergence from the recording.
+    divergence: Option<String>,
+}
+
+impl ReplayStream {
+    /// Load a recording from the given reader.
+    pub fn new(mut recording: impl Read) -> Result<Self> {
+        let mut expected = Vec::new();
+        let mut pending = VecDeque::new();
+        let mut from_server = Vec::new();
+        loop {
+            let mut header = [0; 5];
+            match recording.read_exact(&mut header[..1]) {
+                Ok(()) => {}
+                Err(ref e) if e.kind() == ErrorKind::UnexpectedEof => break,
+                Err(e) => return Err(e),
+            }
+            recording.read_exact(&mut header[1..])?;
+            let length = u32::from_le_bytes([header[1], header[2], header[3], header[4]]);
+            let mut data = vec![0; length as usize];
+            recording.read_exact(&mut data)?;
+            match header[0] {
+                FROM_CLIENT => expected.extend_from_slice(&data),
+                FROM_SERVER => {
+                    from_server.extend_from_slice(&data);
+                    pending.push_back((expected.len(), data));
+                }
+                _ => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidData,
+                        "invalid chunk direction in recording",
+                    ))
+                }
+            }
+        }
+        let mut state = ReplayState {
+            expected,
+            written: 0,
+            pending,
+            to_client: VecDeque::new(),
+            from_server,
+            divergence: None,
+        };
+        state.release_server_data();
+        Ok(Self {
+            state: Mutex::new(state),
+        })
+    }
+
+    /// Load a recording from a file.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Self::new(BufReader::new(File::open(path)?))
+    }
+
+    /// Check that the client sent everything that the recording expects.
+    ///
+    /// Returns an error if the client diverged from the recording or did not send all requests
+    /// yet.
+    pub fn check_complete(&self) -> Result<()> {
+        let state = self.state.lock().unwrap();
+        state.check_divergence()?;
+        if state.written < state.expected.len() {
+            let decoder = Decoder::new(&state.expected, &state.from_server);
+            let message = format!(
+                "the client stopped before sending {}",
+                decoder.describe_expected(state.written)
+            );
+            return Err(Error::new(ErrorKind::Other, message));
+        }
+        Ok(())
+    }
+}
+
+impl ReplayState {
+    /// Make all data from the server available that the client may see.
+    fn release_server_data(&mut self) {
+        while let Some((required, _)) = self.pending.front() {
+            if *required > self.written {
+                break;
+            }
+            let (_, data) = self.pending.pop_front().unwrap();
+            self.to_client.extend(data);
+        }
+    }
+
+    fn check_divergence(&self) -> Result<()> {
+        match &self.divergence {
+            Some(divergence) => Err(Error::new(ErrorKind::Other, divergence.clone())),
+            None => Ok(()),
+        }
+    }
+
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        self.check_divergence()?;
+        let expected = &self.expected[self.written..];
+        let matching = expected
+            .iter()
+            .zip(buf.iter())
+            .take_while(|(a, b)| a == b)
+            .count();
+        if matching < buf.len() {
+            let decoder = Decoder::new(&self.expected, &self.from_server);
+            let offset = self.written + matching;
+            let divergence = if offset >= self.expected.len() {
+                format!(
+                    "the recording ended, but the client sent {}",
+                    decoder.describe_actual(offset, &buf[matching..])
+                )
+            } else {
+                format!(
+                    "expected {}, but the client sent {}",
+                    decoder.describe_expected(offset),
+                    decoder.describe_actual(offset, &buf[matching..])
+                )
+            };
+            self.divergence = Some(format!("replay diverged: {}", divergence));
+            self.check_divergence()?;
+        }
+        self.written += buf.len();
+        self.release_server_data();
+        Ok(buf.len())
+    }
+}
+
+impl Stream for ReplayStream {
+    fn poll(&self, mode: PollMode) -> Result<()> {
+        let state = self.state.lock().unwrap();
+        state.check_divergence()?;
+        // Waiting for data that will never become available would block forever
+        if mode.writable() || !state.to_client.is_empty() {
+            Ok(())
+        } else if state.pending.is_empty() {
+            Err(Error::new(
+                ErrorKind::UnexpectedEof,
+                "replay ended: the client waits for data from the server, but the recording \
+                 contains no more data",
+            ))
+        } else {
+            let decoder = Decoder::new(&state.expected, &state.from_server);
+            let message = format!(
+                "replay diverged: the client waits for data from the server, but expected {}",
+                decoder.describe_expected(state.written)
+            );
+            Err(Error::new(ErrorKind::Other, message))
+        }
+    }
+
+    fn poll_timeout(&self, mode: PollMode, _timeout: std::time::Duration) -> Result<()> {
+        // poll() never blocks, so there is nothing to time out
+        self.poll(mode)
+    }
+
+    fn read(&self, buf: &mut [u8], _fd_storage: &mut Vec<RawFdContainer>) -> Result<usize> {
+        let mut state = self.state.lock().unwrap();
+        state.check_divergence()?;
+        if state.to_client.is_empty() {
+            return Err(Error::new(ErrorKind::WouldBlock, "no data available"));
+        }
+        let len = state.to_client.len().min(buf.len());
+        for (target, source) in buf.iter_mut().zip(state.to_client.drain(..len)) {
+            *target = source;
+        }
+        Ok(len)
+    }
+
+    fn write(&self, buf: &[u8], fds: &mut Vec<RawFdContainer>) -> Result<usize> {
+        // File descriptors are not part of the recording
+        fds.clear();
+        self.state.lock().unwrap().write(buf)
+    }
+
+    fn write_vectored(&self, bufs: &[IoSlice<'_>], fds: &mut Vec<RawFdContainer>) -> Result<usize> {
+        fds.clear();
+        let mut state = self.state.lock().unwrap();
+        let mut written = 0;
+        for buf in bufs {
+            written += state.write(buf)?;
+        }
+        Ok(written)
+    }
+}
+
+/// Helper for turning byte offsets in a recording into descriptions of requests.
+#[derive(Debug)]
+struct Decoder<'a> {
+    expected: &'a [u8],
+    /// The start of all packets in `expected`. The first packet is the setup request.
+    packet_starts: Vec<usize>,
+    /// Major opcodes of extensions that were queried in the recording.
+    extensions: Vec<(u8, String)>,
+}
+
+impl<'a> Decoder<'a> {
+    fn new(expected: &'a [u8], from_server: &[u8]) -> Self {
+        let packet_starts = split_requests(expected);
+        let extensions = find_extensions(expected, &packet_starts, from_server);
+        Self {
+            expected,
+            packet_starts,
+            extensions,
+        }
+    }
+
+    /// Find the index of the packet containing the byte at `offset`.
+    fn packet_index(&self, offset: usize) -> usize {
+        match self.packet_starts.binary_search(&offset) {
+            Ok(index) => index,
+            Err(index) => index.saturating_sub(1),
+        }
+    }
+
+    fn describe_expected(&self, offset: usize) -> String {
+        let index = self.packet_index(offset);
+        let start = self.packet_starts.get(index).copied().unwrap_or(0);
+        self.describe(index, &self.expected[start..])
+    }
+
+    fn describe_actual(&self, offset: usize, remaining: &[u8]) -> String {
+        if offset >= self.expected.len() {
+            // Everything beyond the recording is a new request
+            return self.describe(self.packet_starts.len(), remaining);
+        }
+        let index = self.packet_index(offset);
+        let start = self.packet_starts.get(index).copied().unwrap_or(0);
+        let mut request = self.expected[start..offset].to_vec();
+        request.extend_from_slice(remaining);
+        self.describe(index, &request)
+    }
+
+    fn describe(&self, index: usize, request: &[u8]) -> String {
+        if index == 0 {
+            return "the setup request".to_string();
+        }
+        // The first request has sequence number 1, which is also the index of the packet
+        let (major_opcode, minor_opcode) = match request {
+            [major, minor, ..] => (*major, *minor),
+            [major] => (*major, 0),
+            [] => return format!("request {} (empty)", index),
+        };
+        let extension = self
+            .extensions
+            .iter()
+            .find(|(opcode, _)| *opcode == major_opcode)
+            .map(|(_, name)| name.as_str());
+        match request_name(extension, major_opcode, minor_opcode.into()) {
+            Some(name) => format!("request {} ({})", index, name),
+            None => format!(
+                "request {} (unknown, major opcode {}, minor opcode {})",
+                index, major_opcode, minor_opcode
+            ),
+        }
+    }
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_ne_bytes(
+        data.get(offset..offset + 2)?.try_into().unwrap(),
+    ))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_ne_bytes(
+        data.get(offset..offset + 4)?.try_into().unwrap(),
+    ))
+}
+
+fn pad(length: usize) -> usize {
+    (length + 3) & !3
+}
+
+/// Get the start offsets of the setup request and all requests that the client sent.
+fn split_requests(data: &[u8]) -> Vec<usize> {
+    let mut starts = vec![0];
+    let setup_length = match (read_u16(data, 6), read_u16(data, 8)) {
+        (Some(name_len), Some(data_len)) => 12 + pad(name_len.into()) + pad(data_len.into()),
+        _ => return starts,
+    };
+    let mut offset = setup_length;
+    while offset < data.len() {
+        starts.push(offset);
+        let length = match read_u16(data, offset + 2) {
+            // BIG-REQUESTS encoding
+            Some(0) => read_u32(data, offset + 4).map(|len| len as usize * 4),
+            length => length.map(|len| usize::from(len) * 4),
+        };
+        match length {
+            Some(length) if length > 0 => offset += length,
+            _ => break,
+        }
+    }
+    starts
+}
+
+/// Find the extensions that were queried and present in a recording.
+fn find_extensions(
+    expected: &[u8],
+    packet_starts: &[usize],
+    from_server: &[u8],
+) -> Vec<(u8, String)> {
+    // Get the sequence numbers and names of all QueryExtension requests
+    let queries = packet_starts
+        .iter()
+        .enumerate()
+        .skip(1)
+        .filter(|(_, start)| expected[**start] == QUERY_EXTENSION_REQUEST)
+        .filter_map(|(seqno, start)| {
+            let name_len = usize::from(read_u16(expected, start + 4)?);
+            let name = expected.get(start + 8..start + 8 + name_len)?;
+            Some((seqno as u16, String::from_utf8_lossy(name).into_owned()))
+        })
+        .collect::<Vec<_>>();
+
+    // Find the replies to these requests
+    let mut extensions = Vec::new();
+    let mut offset = match read_u16(from_server, 6) {
+        Some(length) => 8 + usize::from(length) * 4,
+        None => return extensions,
+    };
+    while offset + 32 <= from_server.len() {
+        let packet = &from_server[offset..];
+        let response_type = packet[0] & 0x7f;
+        let mut length = 32;
+        if response_type == 1 || response_type == GE_GENERIC_EVENT {
+            length += read_u32(packet, 4).unwrap() as usize * 4;
+        }
+        if response_type == 1 {
+            let sequence = read_u16(packet, 2).unwrap();
+            let present = packet[8] != 0;
+            if let (true, Some((_, name))) = (
+                present,
+                queries.iter().find(|(seqno, _)| *seqno == sequence),
+            ) {
+                extensions.push((packet[9], name.clone()));
+            }
+        }
+        offset += length;
+    }
+    extensions
+}
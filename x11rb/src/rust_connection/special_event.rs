@@ -0,0 +1,104 @@
+//! Queues for events of the Generic Event Extension that are kept apart from other events.
+
+use std::time::{Duration, Instant};
+
+use super::{check_deadline, BlockingMode, RustConnection, Stream};
+use crate::connection::RequestConnection;
+use crate::errors::ConnectionError;
+use crate::protocol::Event;
+use x11rb_protocol::connection::SpecialEventId;
+use x11rb_protocol::RawEventAndSeqNumber;
+
+/// A queue that receives the events of the Generic Event Extension with a specific event ID.
+///
+/// Such a queue is created with [`RustConnection::register_for_special_event`]. The events in
+/// the queue do not show up in the normal event queue of the connection. Errors and all other
+/// events still go to the normal event queue.
+///
+/// Dropping the queue unregisters it.
+#[derive(Debug)]
+pub struct SpecialEventQueue<'a, S: Stream> {
+    conn: &'a RustConnection<S>,
+    id: SpecialEventId,
+}
+
+impl<'a, S: Stream> SpecialEventQueue<'a, S> {
+    pub(super) fn new(conn: &'a RustConnection<S>, id: SpecialEventId) -> Self {
+        Self { conn, id }
+    }
+
+    /// Get the connection that this queue belongs to.
+    pub fn connection(&self) -> &'a RustConnection<S> {
+        self.conn
+    }
+
+    /// Wait for a new event in this queue.
+    pub fn wait_for_event(&self) -> Result<Event, ConnectionError> {
+        let (event, _) = self.wait_for_raw_event_with_sequence()?;
+        Ok(self.conn.parse_event(&event)?)
+    }
+
+    /// Wait for a new event in this queue, but give up after `timeout`.
+    ///
+    /// If no event arrives in time, [`ConnectionError::Timeout`] is returned.
+    pub fn wait_for_event_timeout(&self, timeout: Duration) -> Result<Event, ConnectionError> {
+        let (event, _) = self.wait_impl(Some(Instant::now() + timeout))?;
+        Ok(self.conn.parse_event(&event)?)
+    }
+
+    /// Poll for a new event in this queue.
+    ///
+    /// This function returns `None` if no event is available.
+    pub fn poll_for_event(&self) -> Result<Option<Event>, ConnectionError> {
+        Ok(match self.poll_for_raw_event_with_sequence()? {
+            Some((event, _)) => Some(self.conn.parse_event(&event)?),
+            None => None,
+        })
+    }
+
+    /// Wait for a new raw/unparsed event in this queue and return it with its sequence number.
+    pub fn wait_for_raw_event_with_sequence(
+        &self,
+    ) -> Result<RawEventAndSeqNumber<Vec<u8>>, ConnectionError> {
+        self.wait_impl(None)
+    }
+
+    /// Poll for a new raw/unparsed event in this queue and return it with its sequence number.
+    pub fn poll_for_raw_event_with_sequence(
+        &self,
+    ) -> Result<Option<RawEventAndSeqNumber<Vec<u8>>>, ConnectionError> {
+        let mut inner = self.conn.inner.lock().unwrap();
+        if let Some(event) = inner.inner.poll_for_special_event(self.id) {
+            Ok(Some(event))
+        } else {
+            inner = self
+                .conn
+                .read_packet_and_enqueue(inner, BlockingMode::NonBlocking)?;
+            Ok(inner.inner.poll_for_special_event(self.id))
+        }
+    }
+
+    fn wait_impl(
+        &self,
+        deadline: Option<Instant>,
+    ) -> Result<RawEventAndSeqNumber<Vec<u8>>, ConnectionError> {
+        let mut inner = self.conn.inner.lock().unwrap();
+        loop {
+            if let Some(event) = inner.inner.poll_for_special_event(self.id) {
+                return Ok(event);
+            }
+            check_deadline(deadline)?;
+            inner = self
+                .conn
+                .read_packet_and_enqueue(inner, BlockingMode::until(deadline))?;
+        }
+    }
+}
+
+impl<S: Stream> Drop for SpecialEventQueue<'_, S> {
+    fn drop(&mut self) {
+        if let Ok(mut inner) = self.conn.inner.lock() {
+            inner.inner.unregister_special_event(self.id);
+        }
+    }
+}
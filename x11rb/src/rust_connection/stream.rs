@@ -1,6 +1,7 @@
+use std::convert::TryFrom;
 use std::io::{IoSlice, IoSliceMut, Result};
 use std::net::{Ipv4Addr, SocketAddr, TcpStream};
-#[cfg(any(target_os = "linux", target_os = "android"))]
+#[cfg(unix)]
 use std::os::unix::ffi::OsStrExt as _;
 #[cfg(unix)]
 use std::os::unix::io::{AsRawFd, IntoRawFd, RawFd};
@@ -8,11 +9,17 @@ use std::os::unix::io::{AsRawFd, IntoRawFd, RawFd};
 use std::os::unix::net::UnixStream;
 #[cfg(windows)]
 use std::os::windows::io::{AsRawSocket, IntoRawSocket, RawSocket};
+use std::time::{Duration, Instant};
 
+use super::happy_eyeballs;
+use crate::errors::UnsupportedTimeoutError;
 use crate::utils::RawFdContainer;
 use x11rb_protocol::parse_display::ConnectAddress;
 use x11rb_protocol::xauth::Family;
 
+/// The TCP port of display 0. Display `n` listens on port `TCP_PORT_BASE + n`.
+const TCP_PORT_BASE: u16 = 6000;
+
 /// The kind of operation that one want to poll for.
 #[derive(Debug, Clone, Copy)]
 pub enum PollMode {
@@ -66,6 +73,20 @@ pub trait Stream {
     /// `read` is `true`) or writable (when `write` is `true`).
     fn poll(&self, mode: PollMode) -> Result<()>;
 
+    /// Waits for level-triggered read and/or write events on the stream, but at most for the
+    /// given timeout.
+    ///
+    /// This function works like [`Stream::poll`], but returns successfully once the timeout
+    /// expired. Callers have to check themselves whether the timeout expired.
+    ///
+    /// The default implementation returns an error without waiting. `RustConnection` reports
+    /// this error as [`ConnectionError::UnsupportedTimeout`](crate::errors::ConnectionError::UnsupportedTimeout).
+    /// Streams that do not override this function thus do not support timeouts.
+    fn poll_timeout(&self, mode: PollMode, timeout: Duration) -> Result<()> {
+        let _ = (mode, timeout);
+        Err(UnsupportedTimeoutError.into_io_error())
+    }
+
     /// Read some bytes and FDs from this reader without blocking, returning how many bytes
     /// were read.
     ///
@@ -187,46 +208,107 @@ enum DefaultStreamInner {
     UnixStream(UnixStream),
     #[cfg(any(target_os = "linux", target_os = "android"))]
     AbstractUnix(RawFdContainer),
+    #[cfg(unix)]
+    InheritedUnix(RawFdContainer),
+    #[cfg(unix)]
+    InheritedTcp(RawFdContainer),
 }
 
 impl DefaultStream {
     /// Try to connect to the X11 server described by the given arguments.
+    ///
+    /// If a host name has multiple addresses, connection attempts to them are made concurrently
+    /// as described in RFC 8305 ("Happy Eyeballs"). If all attempts fail, the last error is
+    /// returned.
     pub fn connect(addr: ConnectAddress<'_>) -> Result<Self> {
+        Self::connect_until(addr, None)
+    }
+
+    /// Try to connect to the X11 server described by the given arguments, but give up after
+    /// `timeout`.
+    ///
+    /// The timeout applies to TCP connections. Connecting to a local Unix domain socket does not
+    /// block for long and is not interrupted.
+    pub fn connect_timeout(addr: ConnectAddress<'_>, timeout: Duration) -> Result<Self> {
+        Self::connect_until(addr, Some(Instant::now() + timeout))
+    }
+
+    fn connect_until(addr: ConnectAddress<'_>, deadline: Option<Instant>) -> Result<Self> {
+        let mut errors = Vec::new();
+        Self::connect_with_errors(addr, deadline, &mut errors).ok_or_else(|| match errors.pop() {
+            Some((_, err)) => err,
+            None => std::io::Error::new(std::io::ErrorKind::Other, "No connection was attempted"),
+        })
+    }
+
+    /// Try to connect to the X11 server described by the given arguments.
+    ///
+    /// If no connection could be established, every address that was tried is appended to
+    /// `errors` together with the error that occurred while connecting to it.
+    pub(crate) fn connect_with_errors(
+        addr: ConnectAddress<'_>,
+        deadline: Option<Instant>,
+        errors: &mut Vec<(String, std::io::Error)>,
+    ) -> Option<Self> {
         match addr {
             ConnectAddress::Hostname(host, port) => {
                 // connect over TCP
-                let stream = TcpStream::connect((host, port))?;
-                Self::from_tcp_stream(stream)
+                let stream = happy_eyeballs::connect(host, port, deadline, errors)?;
+                match Self::from_tcp_stream(stream) {
+                    Ok(stream) => Some(stream),
+                    Err(err) => {
+                        errors.push((format!("{}:{}", host, port), err));
+                        None
+                    }
+                }
             }
             #[cfg(unix)]
             ConnectAddress::Socket(path) => {
                 // Try abstract unix socket first. If that fails, fall back to normal unix socket
                 #[cfg(any(target_os = "linux", target_os = "android"))]
-                if let Ok(stream) = connect_abstract_unix_stream(path.as_os_str().as_bytes()) {
-                    // TODO: Does it make sense to add a constructor similar to from_unix_stream()?
-                    // If this is done: Move the set_nonblocking() from
-                    // connect_abstract_unix_stream() to that new function.
-                    return Ok(Self {
-                        inner: DefaultStreamInner::AbstractUnix(stream),
-                    });
+                match connect_abstract_unix_stream(path.as_os_str().as_bytes()) {
+                    Ok(stream) => {
+                        // TODO: Does it make sense to add a constructor similar to from_unix_stream()?
+                        // If this is done: Move the set_nonblocking() from
+                        // connect_abstract_unix_stream() to that new function.
+                        return Some(Self {
+                            inner: DefaultStreamInner::AbstractUnix(stream),
+                        });
+                    }
+                    Err(err) => errors.push((format!("@{}", path.display()), err.into())),
                 }
 
                 // connect over Unix domain socket
-                let stream = UnixStream::connect(path)?;
-                Self::from_unix_stream(stream)
+                match UnixStream::connect(&path).and_then(Self::from_unix_stream) {
+                    Ok(stream) => Some(stream),
+                    Err(err) => {
+                        errors.push((path.display().to_string(), err));
+                        None
+                    }
+                }
             }
             #[cfg(not(unix))]
-            ConnectAddress::Socket(_) => {
+            ConnectAddress::Socket(path) => {
                 // Unix domain sockets are not supported on Windows
-                Err(std::io::Error::new(
-                    std::io::ErrorKind::Other,
-                    "Unix domain sockets are not supported on Windows",
-                ))
+                errors.push((
+                    path.display().to_string(),
+                    std::io::Error::new(
+                        std::io::ErrorKind::Other,
+                        "Unix domain sockets are not supported on Windows",
+                    ),
+                ));
+                None
+            }
+            addr => {
+                errors.push((
+                    describe_address(&addr),
+                    std::io::Error::new(
+                        std::io::ErrorKind::Other,
+                        "The given address family is not implemented",
+                    ),
+                ));
+                None
             }
-            _ => Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                "The given address family is not implemented",
-            )),
         }
     }
 
@@ -251,15 +333,158 @@ impl DefaultStream {
         })
     }
 
+    /// Creates a new `Stream` from an already connected socket, for example one that was inherited
+    /// from the parent process.
+    ///
+    /// Both Unix domain sockets and TCP sockets are supported. FD passing is only possible over
+    /// Unix domain sockets. The stream will be set in non-blocking mode.
+    #[cfg(unix)]
+    pub fn from_fd(fd: RawFdContainer) -> Result<Self> {
+        use nix::fcntl::{fcntl, FcntlArg, OFlag};
+        use nix::sys::socket::{getsockname, AddressFamily, SockaddrLike, SockaddrStorage};
+
+        let family = getsockname::<SockaddrStorage>(fd.as_raw_fd())?.family();
+        let inner = match family {
+            Some(AddressFamily::Unix) => DefaultStreamInner::InheritedUnix(fd),
+            Some(AddressFamily::Inet) | Some(AddressFamily::Inet6) => {
+                DefaultStreamInner::InheritedTcp(fd)
+            }
+            _ => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    "The given socket is neither a Unix domain socket nor a TCP socket",
+                ))
+            }
+        };
+        let stream = Self { inner };
+
+        // Make the FD non-blocking
+        let fd = stream.as_raw_fd();
+        let flags = fcntl(fd, FcntlArg::F_GETFL)?;
+        let _ = fcntl(
+            fd,
+            FcntlArg::F_SETFL(OFlag::from_bits_truncate(flags) | OFlag::O_NONBLOCK),
+        )?;
+
+        Ok(stream)
+    }
+
+    /// Can FDs be sent over this stream?
+    #[cfg(unix)]
+    fn supports_fd_passing(&self) -> bool {
+        !matches!(
+            self.inner,
+            DefaultStreamInner::TcpStream(_) | DefaultStreamInner::InheritedTcp(_)
+        )
+    }
+
+    /// Get the address of the other end of a TCP connection.
+    #[cfg(unix)]
+    fn inherited_tcp_peer_addr(fd: &RawFdContainer) -> Result<SocketAddr> {
+        use nix::sys::socket::{getpeername, SockaddrStorage};
+
+        Self::to_socket_addr(getpeername::<SockaddrStorage>(fd.as_raw_fd())?)
+    }
+
+    /// Get the address of our end of a TCP connection.
+    #[cfg(unix)]
+    fn inherited_tcp_local_addr(fd: &RawFdContainer) -> Result<SocketAddr> {
+        use nix::sys::socket::{getsockname, SockaddrStorage};
+
+        Self::to_socket_addr(getsockname::<SockaddrStorage>(fd.as_raw_fd())?)
+    }
+
+    #[cfg(unix)]
+    fn to_socket_addr(addr: nix::sys::socket::SockaddrStorage) -> Result<SocketAddr> {
+        if let Some(addr) = addr.as_sockaddr_in() {
+            Ok(SocketAddr::from((Ipv4Addr::from(addr.ip()), addr.port())))
+        } else if let Some(addr) = addr.as_sockaddr_in6() {
+            Ok(SocketAddr::from((addr.ip(), addr.port())))
+        } else {
+            Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "A TCP socket has an unexpected address family",
+            ))
+        }
+    }
+
+    /// Guess the display number of the X11 server from the peer's address.
+    ///
+    /// This works for the usual Unix domain socket paths like `/tmp/.X11-unix/X0` and for TCP
+    /// connections to the usual ports. `None` is returned if the display cannot be determined.
+    pub(crate) fn peer_display(&self) -> Option<u16> {
+        fn from_socket_name(name: &[u8]) -> Option<u16> {
+            let name = name.rsplit(|&c| c == b'/').next()?;
+            match name.split_first() {
+                Some((b'X', number)) => std::str::from_utf8(number).ok()?.parse().ok(),
+                _ => None,
+            }
+        }
+        fn from_port(port: u16) -> Option<u16> {
+            port.checked_sub(TCP_PORT_BASE)
+        }
+
+        match self.inner {
+            DefaultStreamInner::TcpStream(ref stream) => from_port(stream.peer_addr().ok()?.port()),
+            #[cfg(unix)]
+            DefaultStreamInner::UnixStream(ref stream) => from_socket_name(
+                stream
+                    .peer_addr()
+                    .ok()?
+                    .as_pathname()?
+                    .as_os_str()
+                    .as_bytes(),
+            ),
+            #[cfg(any(target_os = "linux", target_os = "android"))]
+            DefaultStreamInner::AbstractUnix(ref fd) => unix_peer_name(fd)
+                .ok()
+                .and_then(|name| from_socket_name(&name)),
+            #[cfg(unix)]
+            DefaultStreamInner::InheritedUnix(ref fd) => unix_peer_name(fd)
+                .ok()
+                .and_then(|name| from_socket_name(&name)),
+            #[cfg(unix)]
+            DefaultStreamInner::InheritedTcp(ref fd) => {
+                from_port(Self::inherited_tcp_peer_addr(fd).ok()?.port())
+            }
+        }
+    }
+
+    /// Get the address of our end of the connection.
+    ///
+    /// This is needed for XDM-AUTHORIZATION-1. `None` is returned for Unix domain sockets.
+    pub(crate) fn local_addr(&self) -> Result<Option<SocketAddr>> {
+        match self.inner {
+            DefaultStreamInner::TcpStream(ref stream) => stream.local_addr().map(Some),
+            #[cfg(unix)]
+            DefaultStreamInner::InheritedTcp(ref fd) => {
+                Self::inherited_tcp_local_addr(fd).map(Some)
+            }
+            #[cfg(unix)]
+            DefaultStreamInner::UnixStream(_) | DefaultStreamInner::InheritedUnix(_) => Ok(None),
+            #[cfg(any(target_os = "linux", target_os = "android"))]
+            DefaultStreamInner::AbstractUnix(_) => Ok(None),
+        }
+    }
+
     /// Get the peer's address in a format suitable for xauth.
     ///
-    /// The returned values can be directly given to `super::xauth::get_auth` as `family` and
-    /// `address`.
+    /// The returned values can be directly given to `super::xauth::get_auth_for_client` as
+    /// `family` and `address`.
     pub(crate) fn peer_addr(&self) -> Result<(Family, Vec<u8>)> {
-        match self.inner {
-            DefaultStreamInner::TcpStream(ref stream) => {
+        let tcp_peer_addr = match self.inner {
+            DefaultStreamInner::TcpStream(ref stream) => Some(stream.peer_addr()?),
+            #[cfg(unix)]
+            DefaultStreamInner::InheritedTcp(ref fd) => Some(Self::inherited_tcp_peer_addr(fd)?),
+            #[cfg(unix)]
+            DefaultStreamInner::UnixStream(_) | DefaultStreamInner::InheritedUnix(_) => None,
+            #[cfg(any(target_os = "linux", target_os = "android"))]
+            DefaultStreamInner::AbstractUnix(_) => None,
+        };
+        match tcp_peer_addr {
+            Some(peer_addr) => {
                 // Get the v4 address of the other end (if there is one)
-                let ip = match stream.peer_addr()? {
+                let ip = match peer_addr {
                     SocketAddr::V4(addr) => *addr.ip(),
                     SocketAddr::V6(addr) => {
                         let ip = addr.ip();
@@ -284,13 +509,8 @@ impl DefaultStream {
                     // This is only reached for loopback addresses. The code below handles this.
                 }
             }
-            #[cfg(unix)]
-            DefaultStreamInner::UnixStream(_) => {
-                // Fall through to the code below.
-            }
-            #[cfg(any(target_os = "linux", target_os = "android"))]
-            DefaultStreamInner::AbstractUnix(_) => {
-                // Fall through to the code below.
+            None => {
+                // Unix domain socket. Fall through to the code below.
             }
         };
 
@@ -303,6 +523,15 @@ impl DefaultStream {
     }
 }
 
+/// Get a human-readable description of `addr` for error messages.
+pub(crate) fn describe_address(addr: &ConnectAddress<'_>) -> String {
+    match addr {
+        ConnectAddress::Hostname(host, port) => format!("{}:{}", host, port),
+        ConnectAddress::Socket(path) => path.display().to_string(),
+        addr => format!("{:?}", addr),
+    }
+}
+
 #[cfg(unix)]
 impl AsRawFd for DefaultStream {
     fn as_raw_fd(&self) -> RawFd {
@@ -311,6 +540,8 @@ impl AsRawFd for DefaultStream {
             DefaultStreamInner::UnixStream(ref stream) => stream.as_raw_fd(),
             #[cfg(any(target_os = "linux", target_os = "android"))]
             DefaultStreamInner::AbstractUnix(ref stream) => stream.as_raw_fd(),
+            DefaultStreamInner::InheritedUnix(ref fd) => fd.as_raw_fd(),
+            DefaultStreamInner::InheritedTcp(ref fd) => fd.as_raw_fd(),
         }
     }
 }
@@ -323,6 +554,8 @@ impl IntoRawFd for DefaultStream {
             DefaultStreamInner::UnixStream(stream) => stream.into_raw_fd(),
             #[cfg(any(target_os = "linux", target_os = "android"))]
             DefaultStreamInner::AbstractUnix(stream) => stream.into_raw_fd(),
+            DefaultStreamInner::InheritedUnix(fd) => fd.into_raw_fd(),
+            DefaultStreamInner::InheritedTcp(fd) => fd.into_raw_fd(),
         }
     }
 }
@@ -370,6 +603,13 @@ fn do_write(
         }
     }
 
+    if !fds.is_empty() && !stream.supports_fd_passing() {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::Other,
+            "FD passing is unsupported",
+        ));
+    }
+
     let fd = stream.as_raw_fd();
 
     let res = if !fds.is_empty() {
@@ -386,8 +626,14 @@ fn do_write(
     Ok(res)
 }
 
-impl Stream for DefaultStream {
-    fn poll(&self, mode: PollMode) -> Result<()> {
+impl DefaultStream {
+    /// Poll the stream. A `timeout` of `None` means that there is no timeout.
+    fn poll_impl(&self, mode: PollMode, timeout: Option<Duration>) -> Result<()> {
+        // Round the timeout up to full milliseconds so that we do not wake up too early
+        let timeout = timeout.map_or(-1, |timeout| {
+            let millis = timeout.as_millis() + u128::from(timeout.subsec_nanos() % 1_000_000 != 0);
+            i32::try_from(millis).unwrap_or(i32::MAX)
+        });
         #[cfg(unix)]
         {
             use nix::poll::{poll, PollFd, PollFlags};
@@ -402,7 +648,7 @@ impl Stream for DefaultStream {
             let fd = self.as_raw_fd();
             let mut poll_fds = [PollFd::new(fd, poll_flags)];
             loop {
-                match poll(&mut poll_fds, -1) {
+                match poll(&mut poll_fds, timeout) {
                     Ok(_) => break,
                     Err(nix::Error::EINTR) => {}
                     Err(e) => return Err(e.into()),
@@ -429,11 +675,21 @@ impl Stream for DefaultStream {
                 events,
                 revents: 0,
             }];
-            let _ = wsa_poll(&mut poll_fds, -1)?;
+            let _ = wsa_poll(&mut poll_fds, timeout)?;
             // Let the errors (POLLERR) be handled when trying to read or write.
             Ok(())
         }
     }
+}
+
+impl Stream for DefaultStream {
+    fn poll(&self, mode: PollMode) -> Result<()> {
+        self.poll_impl(mode, None)
+    }
+
+    fn poll_timeout(&self, mode: PollMode, timeout: Duration) -> Result<()> {
+        self.poll_impl(mode, Some(timeout))
+    }
 
     fn read(&self, buf: &mut [u8], fd_storage: &mut Vec<RawFdContainer>) -> Result<usize> {
         #[cfg(unix)]
@@ -576,6 +832,24 @@ fn connect_abstract_unix_stream(path: &[u8]) -> nix::Result<RawFdContainer> {
     Ok(socket)
 }
 
+/// Get the name of the Unix domain socket that `fd` is connected to.
+///
+/// For abstract sockets, the name without the leading null byte is returned.
+#[cfg(unix)]
+fn unix_peer_name(fd: &RawFdContainer) -> nix::Result<Vec<u8>> {
+    use nix::sys::socket::{getpeername, UnixAddr};
+
+    let addr = getpeername::<UnixAddr>(fd.as_raw_fd())?;
+    if let Some(path) = addr.path() {
+        return Ok(path.as_os_str().as_bytes().to_vec());
+    }
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    if let Some(name) = addr.as_abstract() {
+        return Ok(name.to_vec());
+    }
+    Ok(Vec::new())
+}
+
 /// Helper code to make sure that received FDs are marked as CLOEXEC
 #[cfg(any(
     target_os = "android",
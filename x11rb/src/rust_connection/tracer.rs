@@ -0,0 +1,236 @@
+//! Hooks for tracing the packets that a `RustConnection` sends and receives.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::io::{IoSlice, Write};
+use std::sync::Mutex;
+
+use crate::errors::ParseError;
+use crate::protocol::{Event, Reply, Request};
+use crate::x11_utils::{
+    parse_request_header, BigRequests, ExtInfoProvider, ExtensionInformation, ReplyParsingFunction,
+    X11Error,
+};
+use x11rb_protocol::SequenceNumber;
+
+/// A hook that is informed about all packets that a [`RustConnection`](super::RustConnection)
+/// sends and receives.
+///
+/// A tracer can be installed and removed at runtime with
+/// [`RustConnection::set_tracer`](super::RustConnection::set_tracer). When no tracer is
+/// installed, the only overhead is a check for `None`.
+///
+/// The methods of this trait are called while internal locks of the connection are held. Thus,
+/// a tracer must not use the connection that it is installed on.
+pub trait Tracer: fmt::Debug + Send + Sync {
+    /// A request was sent.
+    ///
+    /// `request` contains the raw bytes of the request, already in the form in which it is sent
+    /// to the X11 server. This means that large requests use the BIG-REQUESTS encoding.
+    ///
+    /// This function is also called for the requests that the connection sends internally, for
+    /// example for synchronisation.
+    fn request_sent(&self, sequence: SequenceNumber, request: &[IoSlice<'_>]);
+
+    /// A packet was received from the X11 server.
+    ///
+    /// The packet is a reply, an error, or an event. `sequence` is the full sequence number that
+    /// the connection reconstructed for the packet.
+    fn packet_received(&self, sequence: SequenceNumber, packet: &[u8]);
+}
+
+/// A [`Tracer`] that decodes all packets and writes a human-readable line for each of them.
+///
+/// By default, the output goes to stderr. This tracer remembers enough about sent requests to
+/// decode their replies. It also tracks `QueryExtension` requests so that requests, events, and
+/// errors of extensions can be decoded. Thus, it should be installed before anything is sent on
+/// the connection.
+pub struct PrintTracer {
+    state: Mutex<PrintTracerState>,
+}
+
+struct PrintTracerState {
+    output: Box<dyn Write + Send>,
+    ext_info: ExtInfo,
+    pending_replies: HashMap<SequenceNumber, PendingReply>,
+}
+
+impl fmt::Debug for PrintTracer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PrintTracer").finish()
+    }
+}
+
+impl Default for PrintTracer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PrintTracer {
+    /// Create a new tracer that writes to stderr.
+    pub fn new() -> Self {
+        Self::with_output(std::io::stderr())
+    }
+
+    /// Create a new tracer that writes to the given output.
+    pub fn with_output(output: impl Write + Send + 'static) -> Self {
+        Self {
+            state: Mutex::new(PrintTracerState {
+                output: Box::new(output),
+                ext_info: Default::default(),
+                pending_replies: Default::default(),
+            }),
+        }
+    }
+}
+
+impl PrintTracerState {
+    fn request_sent(&mut self, sequence: SequenceNumber, request: &[u8]) -> Result<(), ParseError> {
+        let (header, remaining) = parse_request_header(request, BigRequests::Enabled)?;
+        let request = Request::parse(header, remaining, &mut Vec::new(), &self.ext_info)?;
+        let _ = writeln!(self.output, "request ({}): {:?}", sequence, request);
+
+        // Does the request have a reply? If so, remember it.
+        if let Some(parser) = request.reply_parser() {
+            let queried_extension = match request {
+                Request::QueryExtension(request) => {
+                    Some(String::from_utf8_lossy(&request.name).into_owned())
+                }
+                _ => None,
+            };
+            let _ = self.pending_replies.insert(
+                sequence,
+                PendingReply {
+                    parser,
+                    queried_extension,
+                },
+            );
+        }
+        Ok(())
+    }
+
+    fn reply_received(
+        &mut self,
+        sequence: SequenceNumber,
+        packet: &[u8],
+    ) -> Result<(), ParseError> {
+        let pending = match self.pending_replies.remove(&sequence) {
+            Some(pending) => pending,
+            None => {
+                let _ = writeln!(self.output, "reply ({}): {:?}", sequence, packet);
+                return Ok(());
+            }
+        };
+        let (reply, _remaining) = (pending.parser)(packet, &mut Vec::new())?;
+        let _ = writeln!(self.output, "reply ({}): {:?}", sequence, reply);
+
+        match reply {
+            Reply::QueryExtension(reply) => {
+                if let (true, Some(name)) = (reply.present, pending.queried_extension) {
+                    let info = ExtensionInformation {
+                        major_opcode: reply.major_opcode,
+                        first_event: reply.first_event,
+                        first_error: reply.first_error,
+                    };
+                    self.ext_info.exts.push((name, info));
+                }
+            }
+            // ListFontsWithInfo generates multiple replies; the last one has an empty name.
+            Reply::ListFontsWithInfo(reply) if !reply.name.is_empty() => {
+                let _ = self.pending_replies.insert(sequence, pending);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn packet_received(
+        &mut self,
+        sequence: SequenceNumber,
+        packet: &[u8],
+    ) -> Result<(), ParseError> {
+        match packet[0] {
+            0 => {
+                let _ = self.pending_replies.remove(&sequence);
+                let error = X11Error::try_parse(packet, &self.ext_info)?;
+                let _ = writeln!(self.output, "error ({}): {:?}", sequence, error);
+            }
+            1 => self.reply_received(sequence, packet)?,
+            _ => {
+                let event = Event::parse(packet, &self.ext_info)?;
+                let _ = writeln!(self.output, "event ({}): {:?}", sequence, event);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Tracer for PrintTracer {
+    fn request_sent(&self, sequence: SequenceNumber, request: &[IoSlice<'_>]) {
+        let request = request
+            .iter()
+            .flat_map(|buf| buf.iter().copied())
+            .collect::<Vec<u8>>();
+        let mut state = self.state.lock().unwrap();
+        if let Err(e) = state.request_sent(sequence, &request) {
+            let _ = writeln!(
+                state.output,
+                "request ({}): failed to parse {:?}: {:?}",
+                sequence, request, e
+            );
+        }
+    }
+
+    fn packet_received(&self, sequence: SequenceNumber, packet: &[u8]) {
+        let mut state = self.state.lock().unwrap();
+        if let Err(e) = state.packet_received(sequence, packet) {
+            let _ = writeln!(
+                state.output,
+                "packet ({}): failed to parse {:?}: {:?}",
+                sequence, packet, e
+            );
+        }
+    }
+}
+
+/// Representation of a request that was not yet answered.
+struct PendingReply {
+    /// A function that can parse the reply.
+    parser: ReplyParsingFunction,
+
+    /// For QueryExtension requests: The extension that was queried
+    queried_extension: Option<String>,
+}
+
+/// Information about known extensions.
+#[derive(Debug, Default)]
+struct ExtInfo {
+    /// A list of extension names and their information
+    exts: Vec<(String, ExtensionInformation)>,
+}
+
+impl ExtInfoProvider for ExtInfo {
+    fn get_from_major_opcode(&self, major_opcode: u8) -> Option<(&str, ExtensionInformation)> {
+        self.exts
+            .iter()
+            .find(|(_, ext)| ext.major_opcode == major_opcode)
+            .map(|(s, ext)| (s.as_ref(), *ext))
+    }
+
+    fn get_from_event_code(&self, event_code: u8) -> Option<(&str, ExtensionInformation)> {
+        self.exts
+            .iter()
+            .filter(|(_, ext)| ext.first_event <= event_code)
+            .max_by_key(|(_, ext)| ext.first_event)
+            .map(|(s, ext)| (s.as_ref(), *ext))
+    }
+
+    fn get_from_error_code(&self, error_code: u8) -> Option<(&str, ExtensionInformation)> {
+        self.exts
+            .iter()
+            .filter(|(_, ext)| ext.first_error <= error_code)
+            .max_by_key(|(_, ext)| ext.first_error)
+            .map(|(s, ext)| (s.as_ref(), *ext))
+    }
+}
@@ -0,0 +1,165 @@
+//! Counters that describe the traffic on a connection.
+//!
+//! Both [`RustConnection`](crate::rust_connection::RustConnection) and `XCBConnection` can
+//! optionally count what they send and receive. Counting is disabled by default and has to be
+//! enabled with `set_statistics_enabled()` on the connection. Afterwards,
+//! [`RequestConnection::statistics`] returns a snapshot of the counters.
+//!
+//! ```no_run
+//! use x11rb::connection::{Connection, RequestConnection};
+//! use x11rb::protocol::xproto::ConnectionExt;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let (conn, _) = x11rb::rust_connection::RustConnection::connect(None)?;
+//! conn.set_statistics_enabled(true);
+//! conn.get_input_focus()?.reply()?;
+//! let stats = conn.statistics().unwrap();
+//! println!("{} round-trips took {:?}", stats.round_trips, stats.blocked_time);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`RequestConnection::statistics`]: crate::connection::RequestConnection::statistics
+
+use std::collections::HashMap;
+use std::io::IoSlice;
+use std::time::{Duration, Instant};
+
+use x11rb_protocol::SequenceNumber;
+
+/// A snapshot of the counters of a connection.
+///
+/// All counters start at zero when counting is enabled.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConnectionStatistics {
+    /// The number of requests sent, keyed by their major and minor opcode.
+    ///
+    /// Core requests do not have a minor opcode. For them, the second byte of the request is
+    /// ignored and the minor opcode is always zero.
+    pub requests: HashMap<(u8, u8), u64>,
+
+    /// The number of bytes of requests that were sent.
+    ///
+    /// This counts the requests in the form in which they are sent to the X11 server. Data that is
+    /// still buffered counts as sent. `RustConnection` also counts the requests that it sends
+    /// internally, while those of libxcb are invisible to `XCBConnection`.
+    pub bytes_written: u64,
+
+    /// The number of bytes of replies, errors, and events that were received.
+    pub bytes_read: u64,
+
+    /// The number of times that the connection had to wait for a reply or an error from the X11
+    /// server.
+    ///
+    /// `RustConnection` only counts waits where the response was not received already.
+    /// `XCBConnection` cannot know this and counts every call that waits for a response.
+    pub round_trips: u64,
+
+    /// The total time that was spent waiting for replies or errors.
+    pub blocked_time: Duration,
+
+    /// The number of events received, keyed by their response type.
+    ///
+    /// The "sent event" bit is ignored. All events of the Generic Event Extension are counted
+    /// under [`GE_GENERIC_EVENT`](crate::protocol::xproto::GE_GENERIC_EVENT).
+    pub events: HashMap<u8, u64>,
+
+    /// The maximum number of requests that were in flight at the same time.
+    ///
+    /// A request is in flight when it was sent, but no response to it or to a later request was
+    /// received yet.
+    pub max_in_flight_requests: u64,
+}
+
+impl ConnectionStatistics {
+    /// The total number of requests that were sent.
+    pub fn total_requests(&self) -> u64 {
+        self.requests.values().sum()
+    }
+
+    /// The total number of events that were received.
+    pub fn total_events(&self) -> u64 {
+        self.events.values().sum()
+    }
+
+    /// Count a request that was sent with the given sequence number.
+    ///
+    /// `last_sequence_read` is the sequence number of the last packet that was received.
+    pub(crate) fn record_request(
+        &mut self,
+        request: &[IoSlice<'_>],
+        sequence: SequenceNumber,
+        last_sequence_read: SequenceNumber,
+    ) {
+        let mut header = request.iter().flat_map(|buf| buf.iter().copied());
+        let major = header.next().unwrap_or(0);
+        // Core requests use major opcodes below 128
+        let minor = match header.next() {
+            Some(minor) if major >= 128 => minor,
+            _ => 0,
+        };
+        *self.requests.entry((major, minor)).or_default() += 1;
+        self.bytes_written += request.iter().map(|buf| buf.len() as u64).sum::<u64>();
+        self.max_in_flight_requests = self
+            .max_in_flight_requests
+            .max(sequence.saturating_sub(last_sequence_read));
+    }
+
+    /// Count a reply, error, or event that was received.
+    pub(crate) fn record_packet(&mut self, packet: &[u8]) {
+        self.bytes_read += packet.len() as u64;
+        match packet.first().map(|kind| kind & 0x7f) {
+            // Errors and replies
+            None | Some(0) | Some(1) => {}
+            Some(kind) => *self.events.entry(kind).or_default() += 1,
+        }
+    }
+
+    /// Count a wait for a response that started at `start`.
+    pub(crate) fn record_wait(&mut self, start: Instant) {
+        self.round_trips += 1;
+        self.blocked_time += start.elapsed();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ConnectionStatistics;
+    use crate::protocol::xproto::GE_GENERIC_EVENT;
+    use std::io::IoSlice;
+
+    #[test]
+    fn record_request() {
+        let mut stats = ConnectionStatistics::default();
+        // A core request with its data byte set
+        stats.record_request(&[IoSlice::new(&[20, 5, 1, 0])], 3, 1);
+        // An extension request that is split over several slices
+        stats.record_request(
+            &[
+                IoSlice::new(&[]),
+                IoSlice::new(&[130]),
+                IoSlice::new(&[7, 1, 0]),
+            ],
+            4,
+            4,
+        );
+        assert_eq!(stats.requests.get(&(20, 0)), Some(&1));
+        assert_eq!(stats.requests.get(&(130, 7)), Some(&1));
+        assert_eq!(stats.total_requests(), 2);
+        assert_eq!(stats.bytes_written, 8);
+        assert_eq!(stats.max_in_flight_requests, 2);
+    }
+
+    #[test]
+    fn record_packet() {
+        let mut stats = ConnectionStatistics::default();
+        stats.record_packet(&[0; 32]);
+        stats.record_packet(&[1; 40]);
+        stats.record_packet(&[12; 32]);
+        stats.record_packet(&[12 | 0x80; 32]);
+        stats.record_packet(&[GE_GENERIC_EVENT; 36]);
+        assert_eq!(stats.bytes_read, 32 + 40 + 32 + 32 + 36);
+        assert_eq!(stats.events.get(&12), Some(&2));
+        assert_eq!(stats.events.get(&GE_GENERIC_EVENT), Some(&1));
+        assert_eq!(stats.total_events(), 3);
+    }
+}
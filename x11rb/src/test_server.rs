@@ -0,0 +1,388 @@
+//! Spawning X11 servers for integration tests.
+//!
+//! [`TestServer`] starts a local X11 server binary like `Xvfb` or `Xephyr` and connects to it.
+//! The display number is allocated by the server itself via its `-displayfd` argument, so that
+//! multiple tests can start servers concurrently without racing for a free display. Optionally, a
+//! MIT-MAGIC-COOKIE-1 authorization file with a random cookie is generated and passed to the
+//! server via `-auth`. The server is killed when the [`TestServer`] is dropped.
+//!
+//! The code in this module is only available when the `test_server` feature of the library is
+//! enabled. It is only available on unix.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use x11rb::connection::Connection;
+//! use x11rb::test_server::TestServer;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let server = TestServer::xvfb()?;
+//! let conn = server.connect()?;
+//! println!("Xvfb is running on {}", server.display_name());
+//! println!("The root window is {}", conn.setup().roots[0].root);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::convert::TryFrom;
+use std::error::Error;
+use std::ffi::OsStr;
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::{Read, Write};
+use std::os::unix::io::AsRawFd;
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use nix::errno::Errno;
+use nix::poll::{poll, PollFd, PollFlags};
+
+use crate::errors::ConnectError;
+use crate::rust_connection::{DefaultStream, RustConnection};
+use crate::utils::RawFdContainer;
+
+/// The name of the only authorization protocol that is supported.
+const MIT_MAGIC_COOKIE: &[u8] = b"MIT-MAGIC-COOKIE-1";
+
+/// The family of an Xauthority entry that matches any address.
+const FAMILY_WILD: u16 = 0xffff;
+
+/// Used to generate unique names for authorization files.
+static AUTH_FILE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// An error that occurred while starting a [`TestServer`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum TestServerError {
+    /// An I/O error occurred, for example because the server binary was not found.
+    IoError(std::io::Error),
+
+    /// The server exited before reporting its display number.
+    Exited(ExitStatus),
+
+    /// The server did not report its display number in time.
+    Timeout,
+
+    /// The server reported something that is not a display number.
+    InvalidDisplay(String),
+}
+
+impl Error for TestServerError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            TestServerError::IoError(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for TestServerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TestServerError::IoError(err) => err.fmt(f),
+            TestServerError::Exited(status) => {
+                write!(f, "The X11 server exited during startup: {}", status)
+            }
+            TestServerError::Timeout => {
+                f.write_str("The X11 server did not report its display number in time")
+            }
+            TestServerError::InvalidDisplay(display) => {
+                write!(
+                    f,
+                    "The X11 server reported an invalid display {:?}",
+                    display
+                )
+            }
+        }
+    }
+}
+
+impl From<std::io::Error> for TestServerError {
+    fn from(err: std::io::Error) -> Self {
+        TestServerError::IoError(err)
+    }
+}
+
+impl From<nix::Error> for TestServerError {
+    fn from(err: nix::Error) -> Self {
+        TestServerError::IoError(err.into())
+    }
+}
+
+/// A builder for a [`TestServer`].
+///
+/// The arguments `-displayfd` and, if authorization is enabled, `-auth` are appended to the
+/// command line of the server.
+#[derive(Debug)]
+pub struct TestServerBuilder {
+    command: Command,
+    auth: bool,
+    timeout: Duration,
+}
+
+impl TestServerBuilder {
+    /// Create a new builder for the given X11 server binary.
+    ///
+    /// By default, authorization is enabled and the server has ten seconds to start.
+    pub fn new(program: impl AsRef<OsStr>) -> Self {
+        let mut command = Command::new(program);
+        let _ = command
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null());
+        Self {
+            command,
+            auth: true,
+            timeout: Duration::from_secs(10),
+        }
+    }
+
+    /// Add an argument to the command line of the server.
+    pub fn arg(mut self, arg: impl AsRef<OsStr>) -> Self {
+        let _ = self.command.arg(arg);
+        self
+    }
+
+    /// Add arguments to the command line of the server.
+    pub fn args<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<OsStr>,
+    {
+        let _ = self.command.args(args);
+        self
+    }
+
+    /// Set whether a MIT-MAGIC-COOKIE-1 authorization file should be generated.
+    ///
+    /// Without authorization, the server accepts any local client.
+    pub fn auth(mut self, auth: bool) -> Self {
+        self.auth = auth;
+        self
+    }
+
+    /// Set how long to wait for the server to report its display number.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Start the server and wait until it is ready to accept connections.
+    pub fn spawn(mut self) -> Result<TestServer, TestServerError> {
+        let cookie = if self.auth {
+            let cookie = generate_cookie()?;
+            let path = write_auth_file(&cookie)?;
+            let _ = self.command.arg("-auth").arg(&path);
+            Some((path, cookie))
+        } else {
+            None
+        };
+
+        // The write end of the pipe must be inherited by the server
+        let (read_end, write_end) = nix::unistd::pipe()?;
+        let (read_end, write_end) = (
+            RawFdContainer::new(read_end),
+            RawFdContainer::new(write_end),
+        );
+        let _ = self
+            .command
+            .arg("-displayfd")
+            .arg(write_end.as_raw_fd().to_string());
+        let child = self.command.spawn();
+        drop(write_end);
+
+        let (auth_file, cookie) = match cookie {
+            Some((path, cookie)) => (Some(path), Some(cookie)),
+            None => (None, None),
+        };
+        let mut server = TestServer {
+            child: match child {
+                Ok(child) => child,
+                Err(err) => {
+                    if let Some(path) = auth_file {
+                        let _ = fs::remove_file(path);
+                    }
+                    return Err(err.into());
+                }
+            },
+            display: 0,
+            auth_file,
+            cookie,
+        };
+        server.display = server.read_display(&read_end, Instant::now() + self.timeout)?;
+        Ok(server)
+    }
+}
+
+/// A running X11 server for tests.
+///
+/// The server is killed when this value is dropped.
+#[derive(Debug)]
+pub struct TestServer {
+    child: Child,
+    display: u16,
+    auth_file: Option<PathBuf>,
+    cookie: Option<Vec<u8>>,
+}
+
+impl TestServer {
+    /// Start `Xvfb` with authorization.
+    pub fn xvfb() -> Result<Self, TestServerError> {
+        TestServerBuilder::new("Xvfb").spawn()
+    }
+
+    /// Start `Xephyr` with authorization.
+    ///
+    /// `Xephyr` opens a window on the X11 server from `$DISPLAY`.
+    pub fn xephyr() -> Result<Self, TestServerError> {
+        TestServerBuilder::new("Xephyr").spawn()
+    }
+
+    /// Get the display number of the server.
+    pub fn display(&self) -> u16 {
+        self.display
+    }
+
+    /// Get the display name of the server, for example `:1`.
+    ///
+    /// This can be used as `$DISPLAY` for child processes. These also need
+    /// [`auth_file`](Self::auth_file) as `$XAUTHORITY` if authorization is enabled.
+    pub fn display_name(&self) -> String {
+        format!(":{}", self.display)
+    }
+
+    /// Get the path of the generated authorization file, if authorization is enabled.
+    pub fn auth_file(&self) -> Option<&Path> {
+        self.auth_file.as_deref()
+    }
+
+    /// Get the authorization name and data for connecting to the server.
+    ///
+    /// Both are empty if authorization is disabled.
+    pub fn auth_info(&self) -> (&[u8], &[u8]) {
+        match &self.cookie {
+            Some(cookie) => (MIT_MAGIC_COOKIE, cookie),
+            None => (&[], &[]),
+        }
+    }
+
+    /// Get the process ID of the server.
+    pub fn id(&self) -> u32 {
+        self.child.id()
+    }
+
+    /// Establish a new connection to the first screen of the server.
+    pub fn connect(&self) -> Result<RustConnection, ConnectError> {
+        let display_name = self.display_name();
+        let parsed_display = x11rb_protocol::parse_display::parse_display(Some(&display_name))
+            .ok_or(ConnectError::DisplayParsingError)?;
+        let (auth_name, auth_data) = self.auth_info();
+        let mut error = None;
+        for addr in parsed_display.connect_instruction() {
+            match DefaultStream::connect(addr) {
+                Ok(stream) => {
+                    return RustConnection::connect_to_stream_with_auth_info(
+                        stream,
+                        0,
+                        auth_name.to_vec(),
+                        auth_data.to_vec(),
+                    );
+                }
+                Err(e) => error = Some(e),
+            }
+        }
+        Err(match error {
+            Some(e) => ConnectError::IoError(e),
+            None => ConnectError::DisplayParsingError,
+        })
+    }
+
+    /// Read the display number that the server writes to the `-displayfd` pipe.
+    fn read_display(
+        &mut self,
+        pipe: &RawFdContainer,
+        deadline: Instant,
+    ) -> Result<u16, TestServerError> {
+        let mut output = Vec::new();
+        // Other child processes might have inherited the pipe as well, so EOF is not reliable.
+        // The server terminates the display number with a newline.
+        while !output.contains(&b'\n') {
+            if let Some(status) = self.child.try_wait()? {
+                return Err(TestServerError::Exited(status));
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(TestServerError::Timeout);
+            }
+            // Wake up regularly to check whether the server is still alive
+            let timeout = (deadline - now).min(Duration::from_millis(100));
+            let mut poll_fds = [PollFd::new(pipe.as_raw_fd(), PollFlags::POLLIN)];
+            match poll(&mut poll_fds, timeout.as_millis() as _) {
+                Ok(0) | Err(Errno::EINTR) => continue,
+                Ok(_) => {}
+                Err(err) => return Err(err.into()),
+            }
+            let mut buffer = [0; 16];
+            match nix::unistd::read(pipe.as_raw_fd(), &mut buffer)? {
+                0 => {
+                    // The server closed the pipe, so it likely exited
+                    let status = self.child.wait()?;
+                    return Err(TestServerError::Exited(status));
+                }
+                n => output.extend_from_slice(&buffer[..n]),
+            }
+        }
+        let output = String::from_utf8_lossy(&output);
+        let display = output.trim();
+        display
+            .parse()
+            .map_err(|_| TestServerError::InvalidDisplay(display.to_string()))
+    }
+}
+
+impl Drop for TestServer {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+        if let Some(path) = &self.auth_file {
+            let _ = fs::remove_file(path);
+        }
+    }
+}
+
+/// Generate a random MIT-MAGIC-COOKIE-1.
+fn generate_cookie() -> std::io::Result<Vec<u8>> {
+    let mut cookie = vec![0; 16];
+    File::open("/dev/urandom")?.read_exact(&mut cookie)?;
+    Ok(cookie)
+}
+
+/// Write an authorization file that contains `cookie` for any display.
+fn write_auth_file(cookie: &[u8]) -> std::io::Result<PathBuf> {
+    fn write_field(entry: &mut Vec<u8>, field: &[u8]) {
+        let len = u16::try_from(field.len()).expect("field of xauth entry too long");
+        entry.extend_from_slice(&len.to_be_bytes());
+        entry.extend_from_slice(field);
+    }
+
+    let mut entry = FAMILY_WILD.to_be_bytes().to_vec();
+    // Address and display number are empty; the server ignores them anyway
+    write_field(&mut entry, b"");
+    write_field(&mut entry, b"");
+    write_field(&mut entry, MIT_MAGIC_COOKIE);
+    write_field(&mut entry, cookie);
+
+    let path = std::env::temp_dir().join(format!(
+        "x11rb-test-server-{}-{}.Xauthority",
+        std::process::id(),
+        AUTH_FILE_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&path)?;
+    file.write_all(&entry)?;
+    Ok(path)
+}
@@ -4,7 +4,19 @@ use std::convert::TryInto;
 
 use super::cookie::VoidCookie;
 use super::errors::{ConnectionError, ReplyError};
-use super::protocol::xproto::{Atom, ConnectionExt as XProtoConnectionExt, PropMode, Window};
+use super::protocol::xproto::{
+    Atom, AtomEnum, ConnectionExt as XProtoConnectionExt, GetPropertyReply, PropMode, Window,
+};
+
+/// The size of a `ChangeProperty` request without its data, including the extra length field
+/// of BIG-REQUESTS.
+const CHANGE_PROPERTY_HEADER: usize = 28;
+
+/// The number of 32 bit units that [`ConnectionExt::get_property_full`] requests at once.
+const GET_PROPERTY_CHUNK: u32 = 64 * 1024;
+
+/// How often [`ConnectionExt::get_property_full`] starts over when the property is replaced.
+const GET_PROPERTY_ATTEMPTS: usize = 10;
 
 /// Extension trait that simplifies API use
 pub trait ConnectionExt: XProtoConnectionExt {
@@ -88,6 +100,104 @@ pub trait ConnectionExt: XProtoConnectionExt {
         )
     }
 
+    /// Replace a property on a window with format 8, using several requests if necessary.
+    ///
+    /// Unlike [`change_property8`](Self::change_property8), this function respects the
+    /// server's maximum request length. The first part of `data` is sent with
+    /// [`PropMode::REPLACE`] and the remaining parts with [`PropMode::APPEND`]. Other clients can
+    /// observe the incomplete property in between. Use [`GrabServer`] to prevent this.
+    ///
+    /// If the maximum request length is too small for a request with any data,
+    /// [`ConnectionError::MaximumRequestLengthExceeded`] is returned.
+    fn replace_property8<A, B>(
+        &self,
+        window: Window,
+        property: A,
+        type_: B,
+        data: &[u8],
+    ) -> Result<Vec<VoidCookie<'_, Self>>, ConnectionError>
+    where
+        A: Into<Atom>,
+        B: Into<Atom>,
+    {
+        replace_property(self, window, property.into(), type_.into(), 8, data)
+    }
+
+    /// Replace a property on a window with format 16, using several requests if necessary.
+    ///
+    /// See [`replace_property8`](Self::replace_property8) for details.
+    fn replace_property16<A, B>(
+        &self,
+        window: Window,
+        property: A,
+        type_: B,
+        data: &[u16],
+    ) -> Result<Vec<VoidCookie<'_, Self>>, ConnectionError>
+    where
+        A: Into<Atom>,
+        B: Into<Atom>,
+    {
+        let mut data_u8 = Vec::with_capacity(data.len() * 2);
+        for item in data {
+            data_u8.extend(&item.to_ne_bytes());
+        }
+        replace_property(self, window, property.into(), type_.into(), 16, &data_u8)
+    }
+
+    /// Replace a property on a window with format 32, using several requests if necessary.
+    ///
+    /// See [`replace_property8`](Self::replace_property8) for details.
+    fn replace_property32<A, B>(
+        &self,
+        window: Window,
+        property: A,
+        type_: B,
+        data: &[u32],
+    ) -> Result<Vec<VoidCookie<'_, Self>>, ConnectionError>
+    where
+        A: Into<Atom>,
+        B: Into<Atom>,
+    {
+        let mut data_u8 = Vec::with_capacity(data.len() * 4);
+        for item in data {
+            data_u8.extend(&item.to_ne_bytes());
+        }
+        replace_property(self, window, property.into(), type_.into(), 32, &data_u8)
+    }
+
+    /// Get the complete value of a property, using several requests if necessary.
+    ///
+    /// This sends `GetProperty` requests until the server reports no remaining bytes via
+    /// `bytes_after`. The returned reply contains the whole value, with `value_len` and `length`
+    /// adjusted accordingly and `bytes_after` set to zero. If `delete` is true, the property is
+    /// deleted with the last request.
+    ///
+    /// If the property does not exist or its type does not match `type_`, the reply is returned
+    /// as the server sent it, just like for [`get_property`](XProtoConnectionExt::get_property).
+    ///
+    /// If the property is replaced with a different type or format while it is read, reading
+    /// starts over. When this happens too often, [`ConnectionError::PropertyKeptChanging`] is
+    /// returned.
+    fn get_property_full<A, B>(
+        &self,
+        delete: bool,
+        window: Window,
+        property: A,
+        type_: B,
+    ) -> Result<GetPropertyReply, ReplyError>
+    where
+        A: Into<Atom>,
+        B: Into<Atom>,
+    {
+        let (property, type_) = (property.into(), type_.into());
+        for _ in 0..GET_PROPERTY_ATTEMPTS {
+            if let Some(result) = try_get_property_full(self, delete, window, property, type_)? {
+                return Ok(result);
+            }
+        }
+        Err(ConnectionError::PropertyKeptChanging.into())
+    }
+
     /// Synchronise with the X11 server.
     ///
     /// This function synchronises with the X11 server. This means that all requests that are still
@@ -103,6 +213,88 @@ pub trait ConnectionExt: XProtoConnectionExt {
 }
 impl<C: XProtoConnectionExt + ?Sized> ConnectionExt for C {}
 
+/// Read the complete value of a property, see [`ConnectionExt::get_property_full`].
+///
+/// Returns `None` if the property was replaced while it was read.
+fn try_get_property_full<C: XProtoConnectionExt + ?Sized>(
+    conn: &C,
+    delete: bool,
+    window: Window,
+    property: Atom,
+    type_: Atom,
+) -> Result<Option<GetPropertyReply>, ReplyError> {
+    let mut result = conn
+        .get_property(delete, window, property, type_, 0, GET_PROPERTY_CHUNK)?
+        .reply()?;
+    let type_matches = type_ == u32::from(AtomEnum::ANY) || type_ == result.type_;
+    if result.format == 0 || !type_matches {
+        return Ok(Some(result));
+    }
+    while result.bytes_after != 0 {
+        // The value always has a length that is a multiple of four, except for its end
+        let offset = (result.value.len() / 4).try_into().unwrap_or(u32::MAX);
+        let reply = conn
+            .get_property(delete, window, property, type_, offset, GET_PROPERTY_CHUNK)?
+            .reply()?;
+        if reply.format != result.format || reply.type_ != result.type_ {
+            return Ok(None);
+        }
+        if reply.value.is_empty() {
+            break;
+        }
+        result.value.extend_from_slice(&reply.value);
+        result.sequence = reply.sequence;
+        result.bytes_after = reply.bytes_after;
+    }
+    result.value_len = (result.value.len() / usize::from(result.format / 8))
+        .try_into()
+        .unwrap_or(u32::MAX);
+    result.length = ((result.value.len() + 3) / 4)
+        .try_into()
+        .unwrap_or(u32::MAX);
+    Ok(Some(result))
+}
+
+/// Send `data` via `ChangeProperty` requests that do not exceed the maximum request length.
+fn replace_property<'c, C: XProtoConnectionExt + ?Sized>(
+    conn: &'c C,
+    window: Window,
+    property: Atom,
+    type_: Atom,
+    format: u8,
+    data: &[u8],
+) -> Result<Vec<VoidCookie<'c, C>>, ConnectionError> {
+    // Chunks are a multiple of four bytes, so that they never split an element of the property
+    let chunk_size = conn
+        .maximum_request_bytes()
+        .checked_sub(CHANGE_PROPERTY_HEADER)
+        .map(|size| size / 4 * 4)
+        .filter(|&size| size > 0)
+        .ok_or(ConnectionError::MaximumRequestLengthExceeded)?;
+    let element_size = usize::from(format / 8);
+    let mut result = Vec::with_capacity((data.len() + chunk_size - 1) / chunk_size);
+    let mut mode = PropMode::REPLACE;
+    let mut chunks = data.chunks(chunk_size);
+    // An empty `data` still needs one request to replace the property
+    let first = chunks.next().unwrap_or(&[]);
+    for chunk in std::iter::once(first).chain(chunks) {
+        let num_elements = (chunk.len() / element_size)
+            .try_into()
+            .expect("`data` has too many elements");
+        result.push(conn.change_property(
+            mode,
+            window,
+            property,
+            type_,
+            format,
+            num_elements,
+            chunk,
+        )?);
+        mode = PropMode::APPEND;
+    }
+    Ok(result)
+}
+
 /// A RAII-like wrapper around [super::protocol::xproto::grab_server] and
 /// [super::protocol::xproto::ungrab_server].
 ///
@@ -1,8 +1,9 @@
 //! Some utilities for working with X11.
 
 pub use x11rb_protocol::x11_utils::{
-    parse_request_header, BigRequests, ExtInfoProvider, ExtensionInformation, ReplyParsingFunction,
-    Request, RequestHeader, Serialize, TryParse, TryParseFd, X11Error,
+    parse_request_header, BigRequests, BorrowedReply, ExtInfoProvider, ExtensionInformation,
+    ListRef, ListRefIter, ReplyParsingFunction, Request, RequestHeader, Serialize, TryParse,
+    TryParseFd, X11Error,
 };
 
 /// A helper macro for managing atoms
@@ -8,11 +8,12 @@ use std::io::{Error as IOError, ErrorKind, IoSlice};
 use std::os::raw::c_int;
 #[cfg(unix)]
 use std::os::unix::io::{AsRawFd, RawFd};
-use std::ptr::{null, null_mut};
+use std::ptr::{null, null_mut, NonNull};
 use std::sync::{
     atomic::{AtomicU64, Ordering},
     Mutex,
 };
+use std::time::Instant;
 
 use libc::c_void;
 
@@ -20,9 +21,12 @@ use crate::connection::{
     compute_length_field, Connection, ReplyOrError, RequestConnection, RequestKind,
 };
 use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
+use crate::error_handler::{ErrorHandler, ErrorHandling};
 pub use crate::errors::{ConnectError, ConnectionError, ParseError, ReplyError, ReplyOrIdError};
 use crate::extension_manager::ExtensionManager;
+use crate::extension_version::ExtensionVersions;
 use crate::protocol::xproto::Setup;
+use crate::statistics::ConnectionStatistics;
 use crate::utils::{CSlice, RawFdContainer};
 use crate::x11_utils::{ExtensionInformation, TryParse, TryParseFd};
 
@@ -30,6 +34,9 @@ use x11rb_protocol::{DiscardMode, SequenceNumber};
 
 mod pending_errors;
 mod raw_ffi;
+mod special_event;
+
+pub use special_event::SpecialEventQueue;
 
 #[cfg(all(not(test), feature = "dl-libxcb"))]
 pub use raw_ffi::libxcb_library::load_libxcb;
@@ -50,8 +57,13 @@ pub struct XCBConnection {
     conn: raw_ffi::XcbConnectionWrapper,
     setup: Setup,
     ext_mgr: Mutex<ExtensionManager>,
+    extension_versions: ExtensionVersions,
     errors: pending_errors::PendingErrors,
     maximum_sequence_received: AtomicU64,
+    statistics: Mutex<Option<ConnectionStatistics>>,
+    special_extensions: special_event::Extensions,
+    requeued_events: special_event::RequeuedEvents,
+    error_handling: Mutex<ErrorHandling>,
 }
 
 impl XCBConnection {
@@ -114,8 +126,13 @@ impl XCBConnection {
                     conn: connection,
                     setup: Self::parse_setup(setup)?,
                     ext_mgr: Default::default(),
+                    extension_versions: Default::default(),
                     errors: Default::default(),
                     maximum_sequence_received: AtomicU64::new(0),
+                    statistics: Default::default(),
+                    special_extensions: Default::default(),
+                    requeued_events: Default::default(),
+                    error_handling: Default::default(),
                 };
                 Ok((conn, screen as usize))
             }
@@ -144,8 +161,13 @@ impl XCBConnection {
             conn,
             setup: Self::parse_setup(setup)?,
             ext_mgr: Default::default(),
+            extension_versions: Default::default(),
             errors: Default::default(),
             maximum_sequence_received: AtomicU64::new(0),
+            statistics: Default::default(),
+            special_extensions: Default::default(),
+            requeued_events: Default::default(),
+            error_handling: Default::default(),
         })
     }
 
@@ -172,6 +194,7 @@ impl XCBConnection {
     // usize (where this warning is correct) and sometimes c_int (where we need the conversion). We
     // need this here due to https://github.com/rust-lang/rust/issues/60681.
     #[allow(clippy::useless_conversion)]
+    #[track_caller]
     fn send_request(
         &self,
         bufs: &[IoSlice<'_>],
@@ -179,6 +202,7 @@ impl XCBConnection {
         has_reply: bool,
         reply_has_fds: bool,
     ) -> Result<SequenceNumber, ConnectionError> {
+        let location = std::panic::Location::caller();
         let mut storage = Default::default();
         let new_bufs = compute_length_field(self, bufs, &mut storage)?;
 
@@ -247,10 +271,93 @@ impl XCBConnection {
         if seqno == 0 {
             unsafe { Err(Self::connection_error_from_connection(self.conn.as_ptr())) }
         } else {
+            self.with_statistics(|statistics| {
+                let last_read = self.maximum_sequence_received.load(Ordering::Relaxed);
+                statistics.record_request(new_bufs, seqno, last_read);
+            });
+            self.error_handling
+                .lock()
+                .unwrap()
+                .request_sent(seqno, location);
             Ok(seqno)
         }
     }
 
+    /// Enable or disable counting the traffic on this connection.
+    ///
+    /// Passing `true` resets all counters to zero. While counting is enabled,
+    /// [`RequestConnection::statistics`] returns a snapshot of the counters.
+    pub fn set_statistics_enabled(&self, enabled: bool) {
+        *self.statistics.lock().unwrap() = if enabled {
+            Some(Default::default())
+        } else {
+            None
+        };
+    }
+
+    /// Register a queue for events of the Generic Event Extension with the given event ID (EID).
+    ///
+    /// Extensions like Present and XInput 2 send their events as generic events that carry an
+    /// EID chosen by the client when selecting the events. Events from the extension called
+    /// `extension_name` with the given `eid` are no longer returned by
+    /// [`Connection::poll_for_event`] and [`Connection::wait_for_event`], but only by the returned
+    /// [`SpecialEventQueue`]. If `event_types` is not empty, events of other event types are
+    /// moved back to the normal event queue when the special queue encounters them.
+    ///
+    /// Returns `None` if a queue for the same extension and EID is already registered. Returns
+    /// [`ConnectionError::UnsupportedExtension`] if the server does not support the extension.
+    ///
+    /// The queue is unregistered when it is dropped. Events that are still queued then are lost.
+    pub fn register_for_special_event(
+        &self,
+        extension_name: &'static str,
+        event_types: &[u16],
+        eid: u32,
+    ) -> Result<Option<SpecialEventQueue<'_>>, ConnectionError> {
+        if self.extension_information(extension_name)?.is_none() {
+            return Err(ConnectionError::UnsupportedExtension);
+        }
+        let ext = self.special_extensions.get(extension_name);
+        let raw = unsafe {
+            raw_ffi::xcb_register_for_special_xge(self.conn.as_ptr(), ext, eid, null_mut())
+        };
+        match NonNull::new(raw) {
+            Some(raw) => Ok(Some(SpecialEventQueue::new(self, raw, event_types))),
+            None => match self.has_error() {
+                Some(error) => Err(error),
+                None => Ok(None),
+            },
+        }
+    }
+
+    /// Install or remove a handler for X11 errors that would otherwise end up in the event queue.
+    ///
+    /// While a handler is installed, [`Connection::poll_for_event`] and
+    /// [`Connection::wait_for_event`] never return [`Event::Error`](crate::protocol::Event::Error).
+    /// Instead, the errors are passed to the handler when they are taken from the event queue.
+    /// See [`crate::error_handler`] for details.
+    pub fn set_error_handler(&self, handler: Option<ErrorHandler>) {
+        self.error_handling.lock().unwrap().handler = handler;
+    }
+
+    /// Enable or disable recording where each request was sent from.
+    ///
+    /// While this is enabled, the error handler that was installed with
+    /// [`XCBConnection::set_error_handler`] receives the origin of the failing request.
+    pub fn set_request_origin_tracking(&self, enabled: bool) {
+        self.error_handling
+            .lock()
+            .unwrap()
+            .set_origin_tracking(enabled);
+    }
+
+    /// Call `f` with the statistics if counting is enabled.
+    fn with_statistics(&self, f: impl FnOnce(&mut ConnectionStatistics)) {
+        if let Some(statistics) = &mut *self.statistics.lock().unwrap() {
+            f(statistics);
+        }
+    }
+
     /// Check if the underlying XCB connection is in an error state.
     pub fn has_error(&self) -> Option<ConnectionError> {
         unsafe {
@@ -309,7 +416,9 @@ impl XCBConnection {
             .expect("usize should have at least 32 bits");
 
         let length = 32 + length_field * 4;
-        CSlice::new(header.into_ptr(), length)
+        let reply = CSlice::new(header.into_ptr(), length);
+        self.with_statistics(|statistics| statistics.record_packet(&reply));
+        reply
     }
 
     unsafe fn wrap_error(&self, error: *const u8, sequence: SequenceNumber) -> CSlice {
@@ -318,7 +427,9 @@ impl XCBConnection {
             .maximum_sequence_received
             .fetch_max(sequence, Ordering::Relaxed);
 
-        CSlice::new(error, 32)
+        let error = CSlice::new(error, 32);
+        self.with_statistics(|statistics| statistics.record_packet(&error));
+        error
     }
 
     unsafe fn wrap_event(&self, event: *mut u8) -> Result<RawEventAndSeqNumber, ParseError> {
@@ -340,7 +451,72 @@ impl XCBConnection {
             // the 32-byte boundary.
             std::ptr::copy(event.add(36), event.add(32), length_field * 4);
         }
-        Ok((CSlice::new(header.into_ptr(), length), seqno))
+        let event = CSlice::new(header.into_ptr(), length);
+        self.with_statistics(|statistics| statistics.record_packet(&event));
+        Ok((event, seqno))
+    }
+
+    /// Wait for an event without passing errors to the error handler.
+    fn wait_for_raw_event_impl(&self) -> Result<RawEventAndSeqNumber, ConnectionError> {
+        if let Some(error) = self.errors.get(self) {
+            return Ok((error.1, error.0));
+        }
+        if let Some(event) = self.requeued_events.pop() {
+            return Ok(event);
+        }
+        unsafe {
+            let event = raw_ffi::xcb_wait_for_event(self.conn.as_ptr());
+            if event.is_null() {
+                return Err(Self::connection_error_from_connection(self.conn.as_ptr()));
+            }
+            Ok(self.wrap_event(event as _)?)
+        }
+    }
+
+    /// Poll for an event without passing errors to the error handler.
+    fn poll_for_raw_event_impl(&self) -> Result<Option<RawEventAndSeqNumber>, ConnectionError> {
+        if let Some(error) = self.errors.get(self) {
+            return Ok(Some((error.1, error.0)));
+        }
+        if let Some(event) = self.requeued_events.pop() {
+            return Ok(Some(event));
+        }
+        unsafe {
+            let event = raw_ffi::xcb_poll_for_event(self.conn.as_ptr());
+            if event.is_null() {
+                let err = raw_ffi::xcb_connection_has_error(self.conn.as_ptr());
+                if err == 0 {
+                    return Ok(None);
+                } else {
+                    return Err(Self::connection_error_from_c_error(err));
+                }
+            }
+            Ok(Some(self.wrap_event(event as _)?))
+        }
+    }
+
+    /// Pass the event to the error handler if it is an error and a handler is installed.
+    ///
+    /// Returns the event if it was not handled.
+    fn unhandled_event(
+        &self,
+        (event, seqno): RawEventAndSeqNumber,
+    ) -> Option<RawEventAndSeqNumber> {
+        let handler = self
+            .error_handling
+            .lock()
+            .unwrap()
+            .event_taken(&event, seqno);
+        match handler {
+            Some((handler, origin)) => match self.parse_error(&event) {
+                Ok(error) => {
+                    handler(&error, origin.as_ref());
+                    None
+                }
+                Err(_) => Some((event, seqno)),
+            },
+            None => Some((event, seqno)),
+        }
     }
 
     /// Reconstruct a full sequence number based on a partial value.
@@ -429,13 +605,32 @@ impl RequestConnection for XCBConnection {
             .extension_information(self, extension_name)
     }
 
+    fn extension_version(
+        &self,
+        extension_name: &'static str,
+    ) -> Result<Option<(u32, u32)>, ReplyError> {
+        self.extension_versions
+            .extension_version(self, extension_name)
+    }
+
+    fn check_extension_version(
+        &self,
+        extension_name: &'static str,
+        required: (u32, u32),
+    ) -> Result<(), ConnectionError> {
+        self.extension_versions
+            .check_extension_version(extension_name, required)
+    }
+
     fn wait_for_reply_or_raw_error(
         &self,
         sequence: SequenceNumber,
     ) -> Result<ReplyOrError<CSlice>, ConnectionError> {
         unsafe {
             let mut error = null_mut();
+            let wait_start = Instant::now();
             let reply = raw_ffi::xcb_wait_for_reply64(self.conn.as_ptr(), sequence, &mut error);
+            self.with_statistics(|statistics| statistics.record_wait(wait_start));
             match (reply.is_null(), error.is_null()) {
                 (true, true) => Err(Self::connection_error_from_connection(self.conn.as_ptr())),
                 (false, true) => Ok(ReplyOrError::Reply(self.wrap_reply(reply as _, sequence))),
@@ -495,7 +690,9 @@ impl RequestConnection for XCBConnection {
         let cookie = raw_ffi::xcb_void_cookie_t {
             sequence: sequence as _,
         };
+        let wait_start = Instant::now();
         let error = unsafe { raw_ffi::xcb_request_check(self.conn.as_ptr(), cookie) };
+        self.with_statistics(|statistics| statistics.record_wait(wait_start));
         if error.is_null() {
             Ok(None)
         } else {
@@ -520,40 +717,30 @@ impl RequestConnection for XCBConnection {
         let ext_mgr = self.ext_mgr.lock().unwrap();
         crate::protocol::Event::parse(event, &*ext_mgr)
     }
+
+    fn statistics(&self) -> Option<ConnectionStatistics> {
+        self.statistics.lock().unwrap().clone()
+    }
 }
 
 impl Connection for XCBConnection {
     fn wait_for_raw_event_with_sequence(&self) -> Result<RawEventAndSeqNumber, ConnectionError> {
-        if let Some(error) = self.errors.get(self) {
-            return Ok((error.1, error.0));
-        }
-        unsafe {
-            let event = raw_ffi::xcb_wait_for_event(self.conn.as_ptr());
-            if event.is_null() {
-                return Err(Self::connection_error_from_connection(self.conn.as_ptr()));
+        loop {
+            if let Some(event) = self.unhandled_event(self.wait_for_raw_event_impl()?) {
+                return Ok(event);
             }
-            Ok(self.wrap_event(event as _)?)
         }
     }
 
     fn poll_for_raw_event_with_sequence(
         &self,
     ) -> Result<Option<RawEventAndSeqNumber>, ConnectionError> {
-        if let Some(error) = self.errors.get(self) {
-            return Ok(Some((error.1, error.0)));
-        }
-        unsafe {
-            let event = raw_ffi::xcb_poll_for_event(self.conn.as_ptr());
-            if event.is_null() {
-                let err = raw_ffi::xcb_connection_has_error(self.conn.as_ptr());
-                if err == 0 {
-                    return Ok(None);
-                } else {
-                    return Err(Self::connection_error_from_c_error(err));
-                }
+        while let Some(event) = self.poll_for_raw_event_impl()? {
+            if let Some(event) = self.unhandled_event(event) {
+                return Ok(Some(event));
             }
-            Ok(Some(self.wrap_event(event as _)?))
         }
+        Ok(None)
     }
 
     fn flush(&self) -> Result<(), ConnectionError> {
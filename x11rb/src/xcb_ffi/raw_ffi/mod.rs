@@ -108,6 +108,12 @@ pub(crate) struct xcb_protocol_request_t {
     pub(crate) isvoid: u8,
 }
 
+#[allow(non_camel_case_types)]
+#[repr(C)]
+pub(crate) struct xcb_special_event_t {
+    _unused: [u8; 0],
+}
+
 #[allow(non_camel_case_types)]
 #[repr(C)]
 pub(crate) struct xcb_setup_t {
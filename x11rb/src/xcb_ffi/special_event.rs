@@ -0,0 +1,245 @@
+//! Queues for events of the Generic Event Extension that are kept apart from other events.
+//!
+//! libxcb implements such queues with `xcb_register_for_special_xge()`. It identifies extensions
+//! via `xcb_extension_t` structs with a stable address, which this module provides.
+
+use std::collections::{HashMap, VecDeque};
+use std::ffi::CString;
+use std::ptr::NonNull;
+use std::sync::Mutex;
+
+use super::{raw_ffi, RawEventAndSeqNumber, XCBConnection};
+use crate::connection::RequestConnection;
+use crate::errors::{ConnectionError, ParseError};
+use crate::protocol::Event;
+use x11rb_protocol::SequenceNumber;
+
+/// An `xcb_extension_t` together with the name that it points to.
+struct Extension {
+    ext: raw_ffi::xcb_extension_t,
+    // `ext.name` points into this string
+    _name: CString,
+}
+
+// The raw pointer in `ext` only points to `_name`, which is never modified. libxcb modifies
+// `ext.global_id` while holding a global lock.
+unsafe impl Send for Extension {}
+unsafe impl Sync for Extension {}
+
+/// The `xcb_extension_t`s for the extensions that special event queues were registered for.
+#[derive(Default)]
+pub(super) struct Extensions(Mutex<HashMap<&'static str, Box<Extension>>>);
+
+impl std::fmt::Debug for Extensions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let extensions = self.0.lock().unwrap();
+        f.debug_list().entries(extensions.keys()).finish()
+    }
+}
+
+impl Extensions {
+    /// Get the `xcb_extension_t` for the extension with the given name.
+    ///
+    /// The returned pointer stays valid for as long as `self` exists.
+    pub(super) fn get(&self, name: &'static str) -> *mut raw_ffi::xcb_extension_t {
+        let mut extensions = self.0.lock().unwrap();
+        let extension = extensions.entry(name).or_insert_with(|| {
+            let name = CString::new(name).expect("extension names do not contain NUL bytes");
+            Box::new(Extension {
+                ext: raw_ffi::xcb_extension_t {
+                    name: name.as_ptr(),
+                    global_id: 0,
+                },
+                _name: name,
+            })
+        });
+        &mut extension.ext
+    }
+}
+
+/// Events that were taken out of libxcb's event queue and have to be returned before the events
+/// that are still in there.
+///
+/// This is used for events that libxcb put into a special event queue, but that were not
+/// requested for it.
+#[derive(Debug, Default)]
+pub(super) struct RequeuedEvents(Mutex<VecDeque<RawEventAndSeqNumber>>);
+
+impl RequeuedEvents {
+    /// Get the next event, if any.
+    pub(super) fn pop(&self) -> Option<RawEventAndSeqNumber> {
+        self.0.lock().unwrap().pop_front()
+    }
+
+    /// Move an event that was received for a special event queue to the normal event queue.
+    ///
+    /// Everything that libxcb has already queued is moved in front of the event, since it was
+    /// received earlier. An exception are events with a larger sequence number, which were
+    /// received after the event, but before it was taken out of the special event queue.
+    fn requeue(&self, conn: &XCBConnection, event: RawEventAndSeqNumber) -> Result<(), ParseError> {
+        let mut queue = self.0.lock().unwrap();
+        loop {
+            let queued = unsafe { raw_ffi::xcb_poll_for_queued_event(conn.conn.as_ptr()) };
+            if queued.is_null() {
+                break;
+            }
+            queue.push_back(unsafe { conn.wrap_event(queued as _)? });
+        }
+        insert_by_sequence(&mut queue, event);
+        Ok(())
+    }
+}
+
+/// Insert an event behind all events whose sequence number is not larger.
+fn insert_by_sequence<B>(queue: &mut VecDeque<(B, SequenceNumber)>, event: (B, SequenceNumber)) {
+    let mut index = queue.len();
+    while index > 0 && queue[index - 1].1 > event.1 {
+        index -= 1;
+    }
+    queue.insert(index, event);
+}
+
+/// A queue that receives the events of the Generic Event Extension with a specific event ID.
+///
+/// Such a queue is created with [`XCBConnection::register_for_special_event`]. The events in the
+/// queue do not show up in the normal event queue of the connection.
+///
+/// libxcb only filters by extension and event ID. Events for the same event ID, but of an event
+/// type that was not requested, are moved to the normal event queue when they are encountered
+/// while polling or waiting on this queue. They are returned after the events that the normal
+/// event queue already contains with a sequence number that is not larger. Thus, they are only
+/// reordered relative to events that were sent with the same sequence number. X11 errors that
+/// are reported as events are an exception: they can still be returned before such an event.
+///
+/// Dropping the queue unregisters it.
+#[derive(Debug)]
+pub struct SpecialEventQueue<'a> {
+    conn: &'a XCBConnection,
+    raw: NonNull<raw_ffi::xcb_special_event_t>,
+    event_types: Vec<u16>,
+}
+
+impl<'a> SpecialEventQueue<'a> {
+    pub(super) fn new(
+        conn: &'a XCBConnection,
+        raw: NonNull<raw_ffi::xcb_special_event_t>,
+        event_types: &[u16],
+    ) -> Self {
+        Self {
+            conn,
+            raw,
+            event_types: event_types.to_vec(),
+        }
+    }
+
+    /// Get the connection that this queue belongs to.
+    pub fn connection(&self) -> &'a XCBConnection {
+        self.conn
+    }
+
+    /// Wait for a new event in this queue.
+    pub fn wait_for_event(&self) -> Result<Event, ConnectionError> {
+        let (event, _) = self.wait_for_raw_event_with_sequence()?;
+        Ok(self.conn.parse_event(&event)?)
+    }
+
+    /// Poll for a new event in this queue.
+    ///
+    /// This function returns `None` if no event is available.
+    pub fn poll_for_event(&self) -> Result<Option<Event>, ConnectionError> {
+        Ok(match self.poll_for_raw_event_with_sequence()? {
+            Some((event, _)) => Some(self.conn.parse_event(&event)?),
+            None => None,
+        })
+    }
+
+    /// Wait for a new raw/unparsed event in this queue and return it with its sequence number.
+    pub fn wait_for_raw_event_with_sequence(
+        &self,
+    ) -> Result<RawEventAndSeqNumber, ConnectionError> {
+        loop {
+            let event = unsafe {
+                raw_ffi::xcb_wait_for_special_event(self.conn.conn.as_ptr(), self.raw.as_ptr())
+            };
+            if event.is_null() {
+                return Err(unsafe {
+                    XCBConnection::connection_error_from_connection(self.conn.conn.as_ptr())
+                });
+            }
+            if let Some(event) = self.filter(unsafe { self.conn.wrap_event(event as _)? })? {
+                return Ok(event);
+            }
+        }
+    }
+
+    /// Poll for a new raw/unparsed event in this queue and return it with its sequence number.
+    pub fn poll_for_raw_event_with_sequence(
+        &self,
+    ) -> Result<Option<RawEventAndSeqNumber>, ConnectionError> {
+        loop {
+            let event = unsafe {
+                raw_ffi::xcb_poll_for_special_event(self.conn.conn.as_ptr(), self.raw.as_ptr())
+            };
+            if event.is_null() {
+                return match self.conn.has_error() {
+                    Some(error) => Err(error),
+                    None => Ok(None),
+                };
+            }
+            if let Some(event) = self.filter(unsafe { self.conn.wrap_event(event as _)? })? {
+                return Ok(Some(event));
+            }
+        }
+    }
+
+    /// Move events of event types that were not requested to the normal event queue.
+    fn filter(
+        &self,
+        (event, seqno): RawEventAndSeqNumber,
+    ) -> Result<Option<RawEventAndSeqNumber>, ParseError> {
+        let event_type = u16::from_ne_bytes([event[8], event[9]]);
+        if self.event_types.is_empty() || self.event_types.contains(&event_type) {
+            Ok(Some((event, seqno)))
+        } else {
+            self.conn
+                .requeued_events
+                .requeue(self.conn, (event, seqno))?;
+            Ok(None)
+        }
+    }
+}
+
+impl Drop for SpecialEventQueue<'_> {
+    fn drop(&mut self) {
+        unsafe {
+            raw_ffi::xcb_unregister_for_special_event(self.conn.conn.as_ptr(), self.raw.as_ptr());
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::VecDeque;
+
+    use super::insert_by_sequence;
+
+    #[test]
+    fn insert_keeps_order_of_earlier_events() {
+        let mut queue = VecDeque::new();
+        insert_by_sequence(&mut queue, ("a", 1));
+        insert_by_sequence(&mut queue, ("b", 3));
+        insert_by_sequence(&mut queue, ("c", 3));
+        insert_by_sequence(&mut queue, ("d", 2));
+        insert_by_sequence(&mut queue, ("e", 5));
+        insert_by_sequence(&mut queue, ("f", 4));
+        let order = queue.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+        assert_eq!(order, ["a", "d", "b", "c", "f", "e"]);
+    }
+
+    #[test]
+    fn insert_into_empty_queue() {
+        let mut queue = VecDeque::new();
+        insert_by_sequence(&mut queue, ((), 7));
+        assert_eq!(queue, [((), 7)]);
+    }
+}
@@ -0,0 +1,350 @@
+mod common;
+
+use std::collections::VecDeque;
+use std::future::Future;
+use std::io::Result;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+use x11rb::async_connection::{
+    AsyncConnection, AsyncRequestConnection, AsyncRustConnection, AsyncStream,
+};
+use x11rb::errors::ConnectionError;
+use x11rb::protocol::xproto::{
+    ClientMessageEvent, EventMask, GetInputFocusRequest, InputFocus, SendEventRequest,
+    CLIENT_MESSAGE_EVENT, GET_INPUT_FOCUS_REQUEST, QUERY_EXTENSION_REQUEST, SEND_EVENT_REQUEST,
+};
+use x11rb::protocol::Event;
+use x11rb::utils::RawFdContainer;
+use x11rb::x11_utils::Serialize;
+
+use common::setup_with_screen;
+
+/// Run a future to completion by polling it in a loop.
+///
+/// The fake stream below never needs to be woken up: Once a request was written, its reply is
+/// immediately available.
+fn block_on<F: Future>(future: F) -> F::Output {
+    fn noop_raw_waker() -> RawWaker {
+        fn clone(_: *const ()) -> RawWaker {
+            noop_raw_waker()
+        }
+        fn noop(_: *const ()) {}
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+        RawWaker::new(std::ptr::null(), &VTABLE)
+    }
+
+    let waker = unsafe { Waker::from_raw(noop_raw_waker()) };
+    let mut cx = Context::from_waker(&waker);
+    let mut future = Box::pin(future);
+    loop {
+        if let Poll::Ready(result) = future.as_mut().poll(&mut cx) {
+            return result;
+        }
+    }
+}
+
+/// A fake X11 server that answers some requests.
+///
+/// Every other call to `poll_read` and `poll_write` returns `Poll::Pending` to check that the
+/// connection correctly retries.
+#[derive(Debug)]
+struct FakeStream(Mutex<FakeStreamInner>);
+
+#[derive(Debug)]
+struct FakeStreamInner {
+    setup_request_received: bool,
+    to_client: VecDeque<u8>,
+    from_client: Vec<u8>,
+    seqno: u16,
+    pending: bool,
+}
+
+impl FakeStream {
+    fn new() -> Self {
+        Self(Mutex::new(FakeStreamInner {
+            setup_request_received: false,
+            to_client: setup_with_screen().serialize().into(),
+            from_client: Vec::new(),
+            seqno: 0,
+            pending: false,
+        }))
+    }
+}
+
+impl FakeStreamInner {
+    fn reply(&mut self, mut packet: [u8; 32]) {
+        packet[2..4].copy_from_slice(&self.seqno.to_ne_bytes());
+        self.to_client.extend(packet.iter());
+    }
+
+    fn handle_requests(&mut self) {
+        if !self.setup_request_received {
+            // The setup request without authorization has a length of 12 bytes
+            if self.from_client.len() < 12 {
+                return;
+            }
+            let _ = self.from_client.drain(..12);
+            self.setup_request_received = true;
+        }
+        while self.from_client.len() >= 4 {
+            let length = usize::from(u16::from_ne_bytes([
+                self.from_client[2],
+                self.from_client[3],
+            ])) * 4;
+            if self.from_client.len() < length {
+                return;
+            }
+            let request = self.from_client.drain(..length).collect::<Vec<_>>();
+            self.seqno = self.seqno.wrapping_add(1);
+            match request[0] {
+                GET_INPUT_FOCUS_REQUEST => {
+                    let mut reply = [0; 32];
+                    reply[0] = 1;
+                    reply[1] = u8::from(InputFocus::PARENT);
+                    reply[8..12].copy_from_slice(&42u32.to_ne_bytes());
+                    self.reply(reply);
+                }
+                QUERY_EXTENSION_REQUEST => {
+                    // No extensions are present
+                    let mut reply = [0; 32];
+                    reply[0] = 1;
+                    self.reply(reply);
+                }
+                SEND_EVENT_REQUEST => {
+                    let mut event = [0; 32];
+                    event.copy_from_slice(&request[12..44]);
+                    self.reply(event);
+                }
+                opcode => panic!("Unexpected request {}", opcode),
+            }
+        }
+    }
+
+    fn should_block(&mut self) -> bool {
+        self.pending = !self.pending;
+        self.pending
+    }
+}
+
+impl AsyncStream for FakeStream {
+    fn poll_read(
+        &self,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+        _fd_storage: &mut Vec<RawFdContainer>,
+    ) -> Poll<Result<usize>> {
+        let mut inner = self.0.lock().unwrap();
+        if inner.to_client.is_empty() || inner.should_block() {
+            cx.waker().wake_by_ref();
+            return Poll::Pending;
+        }
+        let len = inner.to_client.len().min(buf.len());
+        for (target, source) in buf.iter_mut().zip(inner.to_client.drain(..len)) {
+            *target = source;
+        }
+        Poll::Ready(Ok(len))
+    }
+
+    fn poll_write(
+        &self,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+        fds: &mut Vec<RawFdContainer>,
+    ) -> Poll<Result<usize>> {
+        assert!(fds.is_empty());
+        let mut inner = self.0.lock().unwrap();
+        if inner.should_block() {
+            cx.waker().wake_by_ref();
+            return Poll::Pending;
+        }
+        // Only accept a part of the data to check that partial writes work
+        let len = buf.len().min(7);
+        inner.from_client.extend_from_slice(&buf[..len]);
+        inner.handle_requests();
+        Poll::Ready(Ok(len))
+    }
+}
+
+fn connect() -> AsyncRustConnection<FakeStream> {
+    block_on(AsyncRustConnection::connect_to_stream(FakeStream::new(), 0)).unwrap()
+}
+
+#[test]
+fn async_setup() {
+    let conn = connect();
+    assert_eq!(conn.setup(), &setup_with_screen());
+}
+
+#[test]
+fn async_reply() {
+    let conn = connect();
+    let reply = block_on(async {
+        let cookie1 = conn
+            .send_trait_request_with_reply(GetInputFocusRequest)
+            .await?;
+        let cookie2 = conn
+            .send_trait_request_with_reply(GetInputFocusRequest)
+            .await?;
+        assert_eq!(cookie1.sequence_number() + 1, cookie2.sequence_number());
+        // Replies can be received in any order
+        let reply2 = cookie2.reply().await?;
+        let reply1 = cookie1.reply().await?;
+        assert_eq!(reply1.sequence + 1, reply2.sequence);
+        assert_eq!(reply1.focus, reply2.focus);
+        Ok::<_, x11rb::errors::ReplyError>(reply1)
+    })
+    .unwrap();
+    assert_eq!(reply.focus, 42);
+    assert_eq!(reply.revert_to, InputFocus::PARENT);
+}
+
+#[test]
+fn async_event() {
+    let conn = connect();
+    let event = ClientMessageEvent::new(32, 1, 2u32, [3, 4, 5, 6, 7]);
+    let request = SendEventRequest {
+        propagate: false,
+        destination: 42,
+        event_mask: EventMask::NO_EVENT.into(),
+        event: std::borrow::Cow::Owned(event.into()),
+    };
+    let received = block_on(async {
+        conn.send_trait_request_without_reply(request)
+            .await?
+            .check()
+            .await?;
+        Ok::<_, x11rb::errors::ReplyError>(conn.wait_for_event().await?)
+    })
+    .unwrap();
+    match received {
+        Event::ClientMessage(received) => {
+            assert_eq!(received.response_type, CLIENT_MESSAGE_EVENT);
+            assert_eq!(received.window, 1);
+            assert_eq!(received.type_, 2);
+            assert_eq!(received.data.as_data32(), [3, 4, 5, 6, 7]);
+        }
+        event => panic!("Unexpected event {:?}", event),
+    }
+    assert!(conn.poll_for_event().unwrap().is_none());
+}
+
+#[test]
+fn async_missing_extension() {
+    let conn = connect();
+    let result = block_on(conn.send_trait_request_with_reply(
+        x11rb::protocol::xc_misc::GetVersionRequest {
+            client_major_version: 1,
+            client_minor_version: 1,
+        },
+    ));
+    match result {
+        Err(ConnectionError::UnsupportedExtension) => {}
+        result => panic!("Unexpected result {:?}", result),
+    }
+    // The cached information is used the second time
+    assert_eq!(
+        block_on(conn.extension_information("XC-MISC")).unwrap(),
+        None
+    );
+    assert_eq!(block_on(conn.maximum_request_bytes()), 4 * 0xffff);
+}
+
+/// Create a waker that sets the given flag when it is woken.
+fn flag_waker(flag: &'static AtomicBool) -> Waker {
+    fn raw_waker(flag: *const ()) -> RawWaker {
+        fn clone(flag: *const ()) -> RawWaker {
+            raw_waker(flag)
+        }
+        fn wake(flag: *const ()) {
+            unsafe { &*(flag as *const AtomicBool) }.store(true, Ordering::SeqCst);
+        }
+        fn noop(_: *const ()) {}
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, wake, wake, noop);
+        RawWaker::new(flag, &VTABLE)
+    }
+    unsafe { Waker::from_raw(raw_waker(flag as *const AtomicBool as *const ())) }
+}
+
+/// A stream that, like many real streams, only remembers the waker of the last read attempt.
+#[derive(Debug, Default)]
+struct LastWakerStream(Mutex<(VecDeque<u8>, Option<Waker>)>);
+
+impl LastWakerStream {
+    fn send_to_client(&self, packet: &[u8]) {
+        let mut inner = self.0.lock().unwrap();
+        inner.0.extend(packet);
+        if let Some(waker) = inner.1.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl AsyncStream for LastWakerStream {
+    fn poll_read(
+        &self,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+        _fd_storage: &mut Vec<RawFdContainer>,
+    ) -> Poll<Result<usize>> {
+        let mut inner = self.0.lock().unwrap();
+        if inner.0.is_empty() {
+            inner.1 = Some(cx.waker().clone());
+            return Poll::Pending;
+        }
+        let len = inner.0.len().min(buf.len());
+        for (target, source) in buf.iter_mut().zip(inner.0.drain(..len)) {
+            *target = source;
+        }
+        Poll::Ready(Ok(len))
+    }
+
+    fn poll_write(
+        &self,
+        _cx: &mut Context<'_>,
+        buf: &[u8],
+        _fds: &mut Vec<RawFdContainer>,
+    ) -> Poll<Result<usize>> {
+        Poll::Ready(Ok(buf.len()))
+    }
+}
+
+#[test]
+fn async_cancelled_waiter_wakes_others() {
+    static WOKEN1: AtomicBool = AtomicBool::new(false);
+    static WOKEN2: AtomicBool = AtomicBool::new(false);
+    let waker1 = flag_waker(&WOKEN1);
+    let waker2 = flag_waker(&WOKEN2);
+
+    let conn =
+        AsyncRustConnection::for_connected_stream(LastWakerStream::default(), setup_with_screen())
+            .unwrap();
+    let mut waiter1 = conn.wait_for_event();
+    let mut waiter2 = conn.wait_for_event();
+    assert!(waiter1
+        .as_mut()
+        .poll(&mut Context::from_waker(&waker1))
+        .is_pending());
+    assert!(waiter2
+        .as_mut()
+        .poll(&mut Context::from_waker(&waker2))
+        .is_pending());
+
+    // The stream only knows about the second waiter. Cancelling it wakes up the first one.
+    drop(waiter2);
+    assert!(WOKEN1.swap(false, Ordering::SeqCst));
+    assert!(waiter1
+        .as_mut()
+        .poll(&mut Context::from_waker(&waker1))
+        .is_pending());
+
+    // The first waiter registered itself with the stream again and receives the event
+    let event = ClientMessageEvent::new(32, 1, 2u32, [3, 4, 5, 6, 7]);
+    conn.stream().send_to_client(&event.serialize());
+    assert!(WOKEN1.load(Ordering::SeqCst));
+    match waiter1.as_mut().poll(&mut Context::from_waker(&waker1)) {
+        Poll::Ready(Ok(Event::ClientMessage(received))) => assert_eq!(received.window, 1),
+        result => panic!("Unexpected result {:?}", result),
+    }
+}
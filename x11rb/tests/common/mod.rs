@@ -0,0 +1,94 @@
+//! Helpers shared by the integration tests that play the X11 server themselves.
+
+// Not every test uses every helper
+#![allow(dead_code)]
+
+#[cfg(unix)]
+use std::os::unix::net::UnixStream;
+
+use x11rb::protocol::xproto::{BackingStore, ImageOrder, Screen, Setup};
+#[cfg(unix)]
+use x11rb::rust_connection::{DefaultStream, RustConnection};
+use x11rb::x11_utils::Serialize;
+
+/// Get a minimal `Setup` without any screens.
+pub fn setup() -> Setup {
+    Setup {
+        status: 0,
+        protocol_major_version: 0,
+        protocol_minor_version: 0,
+        length: 0,
+        release_number: 0,
+        resource_id_base: 0,
+        resource_id_mask: 0xff,
+        motion_buffer_size: 0,
+        maximum_request_length: 0,
+        image_byte_order: ImageOrder::LSB_FIRST,
+        bitmap_format_bit_order: ImageOrder::LSB_FIRST,
+        bitmap_format_scanline_unit: 0,
+        bitmap_format_scanline_pad: 0,
+        min_keycode: 0,
+        max_keycode: 0,
+        vendor: Vec::new(),
+        pixmap_formats: Vec::new(),
+        roots: Vec::new(),
+    }
+}
+
+/// Get a complete `Setup` with a single screen whose root window is 42.
+///
+/// Unlike [`setup`], this can be sent to a client as the server's answer to the connection setup.
+pub fn setup_with_screen() -> Setup {
+    let root = Screen {
+        root: 42,
+        default_colormap: 0,
+        white_pixel: 0,
+        black_pixel: 0,
+        current_input_masks: 0,
+        width_in_pixels: 0,
+        height_in_pixels: 0,
+        width_in_millimeters: 0,
+        height_in_millimeters: 0,
+        min_installed_maps: 0,
+        max_installed_maps: 0,
+        root_visual: 0,
+        backing_stores: BackingStore::NOT_USEFUL,
+        save_unders: false,
+        root_depth: 0,
+        allowed_depths: Vec::new(),
+    };
+    let mut setup = Setup {
+        status: 1,
+        protocol_major_version: 11,
+        protocol_minor_version: 0,
+        length: 0,
+        release_number: 0,
+        resource_id_base: 0,
+        resource_id_mask: 0xff,
+        motion_buffer_size: 0,
+        maximum_request_length: 0xffff,
+        image_byte_order: ImageOrder::LSB_FIRST,
+        bitmap_format_bit_order: ImageOrder::LSB_FIRST,
+        bitmap_format_scanline_unit: 0,
+        bitmap_format_scanline_pad: 0,
+        min_keycode: 8,
+        max_keycode: 255,
+        vendor: Vec::new(),
+        pixmap_formats: Vec::new(),
+        roots: vec![root],
+    };
+    setup.length = ((setup.serialize().len() - 8) / 4) as u16;
+    setup
+}
+
+/// Create a connection whose server side is the returned socket.
+///
+/// The connection setup already happened, so the first thing the server side receives is the
+/// first request of the test.
+#[cfg(unix)]
+pub fn connect() -> (RustConnection, UnixStream) {
+    let (client, server) = UnixStream::pair().unwrap();
+    let stream = DefaultStream::from_unix_stream(client).unwrap();
+    let conn = RustConnection::for_connected_stream(stream, setup()).unwrap();
+    (conn, server)
+}
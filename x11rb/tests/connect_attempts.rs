@@ -0,0 +1,86 @@
+mod common;
+
+use std::io::{ErrorKind, Read, Write};
+use std::net::TcpListener;
+use std::time::Duration;
+
+use x11rb::connection::Connection;
+use x11rb::errors::ConnectError;
+use x11rb::rust_connection::RustConnection;
+use x11rb::x11_utils::Serialize;
+
+use common::setup_with_screen;
+
+/// Bind a TCP listener on localhost and get the display name that refers to it.
+fn listen() -> (TcpListener, String) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let display = listener.local_addr().unwrap().port() - 6000;
+    (listener, format!("127.0.0.1:{}", display))
+}
+
+#[test]
+fn connect_over_tcp() {
+    let (listener, display) = listen();
+    let server = std::thread::spawn(move || {
+        let (mut server, _) = listener.accept().unwrap();
+        let mut setup_request = [0; 12];
+        server.read_exact(&mut setup_request).unwrap();
+        let auth_len = |offset: usize| {
+            let len = u16::from_ne_bytes([setup_request[offset], setup_request[offset + 1]]);
+            (usize::from(len) + 3) & !3
+        };
+        let mut auth = vec![0; auth_len(6) + auth_len(8)];
+        server.read_exact(&mut auth).unwrap();
+        server.write_all(&setup_with_screen().serialize()).unwrap();
+    });
+    let (conn, screen) = RustConnection::connect(Some(&display)).unwrap();
+    assert_eq!(screen, 0);
+    assert_eq!(conn.setup().roots[0].root, 42);
+    server.join().unwrap();
+}
+
+#[test]
+fn all_attempts_are_reported() {
+    let (listener, display) = listen();
+    let address = listener.local_addr().unwrap().to_string();
+    drop(listener);
+
+    match RustConnection::connect(Some(&display)) {
+        Err(ConnectError::ConnectFailed(attempts)) => {
+            assert_eq!(attempts.len(), 1, "{:?}", attempts);
+            assert_eq!(attempts[0].0, address);
+            assert_eq!(attempts[0].1.kind(), ErrorKind::ConnectionRefused);
+        }
+        other => panic!("unexpected result {:?}", other.map(|_| ())),
+    }
+}
+
+#[test]
+fn error_message_lists_attempts() {
+    let err = ConnectError::ConnectFailed(vec![
+        (
+            "[::1]:6000".to_string(),
+            std::io::Error::new(ErrorKind::ConnectionRefused, "refused"),
+        ),
+        (
+            "127.0.0.1:6000".to_string(),
+            std::io::Error::new(ErrorKind::TimedOut, "timed out"),
+        ),
+    ]);
+    assert_eq!(
+        err.to_string(),
+        "Connecting to the X11 server failed: [::1]:6000: refused; 127.0.0.1:6000: timed out"
+    );
+}
+
+#[test]
+fn timeout() {
+    let (_listener, display) = listen();
+    match RustConnection::connect_timeout(Some(&display), Duration::from_secs(0)) {
+        Err(ConnectError::ConnectFailed(attempts)) => {
+            assert_eq!(attempts.len(), 1, "{:?}", attempts);
+            assert_eq!(attempts[0].1.kind(), ErrorKind::TimedOut);
+        }
+        other => panic!("unexpected result {:?}", other.map(|_| ())),
+    }
+}
@@ -0,0 +1,105 @@
+#![cfg(unix)]
+
+mod common;
+
+use std::io::{IoSlice, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::os::unix::io::{AsRawFd, IntoRawFd};
+use std::os::unix::net::UnixStream;
+use std::thread::JoinHandle;
+
+use x11rb::connection::Connection;
+use x11rb::rust_connection::{DefaultStream, RustConnection, Stream, SOCKET_ENV_VAR};
+use x11rb::utils::RawFdContainer;
+use x11rb::x11_utils::Serialize;
+
+use common::setup_with_screen;
+
+/// Answer the setup request on `server`.
+fn serve_setup(mut server: impl Read + Write + Send + 'static) -> JoinHandle<()> {
+    std::thread::spawn(move || {
+        let mut setup_request = [0; 12];
+        server.read_exact(&mut setup_request).unwrap();
+        let auth_len = |offset: usize| {
+            let len = u16::from_ne_bytes([setup_request[offset], setup_request[offset + 1]]);
+            (usize::from(len) + 3) & !3
+        };
+        let mut auth = vec![0; auth_len(6) + auth_len(8)];
+        server.read_exact(&mut auth).unwrap();
+        server.write_all(&setup_with_screen().serialize()).unwrap();
+    })
+}
+
+#[test]
+fn unix_socket() {
+    let (client, server) = UnixStream::pair().unwrap();
+    let server = serve_setup(server);
+    let fd = RawFdContainer::new(client.into_raw_fd());
+    let conn = RustConnection::connect_from_fd(fd, 0).unwrap();
+    assert_eq!(conn.setup().roots[0].root, 42);
+    server.join().unwrap();
+}
+
+#[test]
+fn tcp_socket() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+    let (server, _) = listener.accept().unwrap();
+    let server = serve_setup(server);
+    let fd = RawFdContainer::new(client.into_raw_fd());
+    let conn = RustConnection::connect_from_fd(fd, 0).unwrap();
+    assert_eq!(conn.setup().roots[0].root, 42);
+    server.join().unwrap();
+}
+
+#[test]
+fn fd_passing_depends_on_socket_type() {
+    let (unix_client, mut unix_server) = UnixStream::pair().unwrap();
+    let stream = DefaultStream::from_fd(RawFdContainer::new(unix_client.into_raw_fd())).unwrap();
+    let mut fds = vec![RawFdContainer::new(nix::unistd::dup(0).unwrap())];
+    assert_eq!(
+        stream
+            .write_vectored(&[IoSlice::new(&[1])], &mut fds)
+            .unwrap(),
+        1
+    );
+    assert!(fds.is_empty());
+    let mut buf = [0];
+    unix_server.read_exact(&mut buf).unwrap();
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let tcp_client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+    let stream = DefaultStream::from_fd(RawFdContainer::new(tcp_client.into_raw_fd())).unwrap();
+    let mut fds = vec![RawFdContainer::new(nix::unistd::dup(0).unwrap())];
+    assert!(stream
+        .write_vectored(&[IoSlice::new(&[1])], &mut fds)
+        .is_err());
+    assert_eq!(fds.len(), 1);
+}
+
+#[test]
+fn not_a_socket() {
+    let (read_end, write_end) = nix::unistd::pipe().unwrap();
+    let _write_end = RawFdContainer::new(write_end);
+    let result = DefaultStream::from_fd(RawFdContainer::new(read_end));
+    assert!(result.is_err());
+}
+
+#[test]
+fn socket_from_environment() {
+    let (client, server) = UnixStream::pair().unwrap();
+    let server = serve_setup(server);
+    std::env::set_var(SOCKET_ENV_VAR, client.as_raw_fd().to_string());
+    // The connection takes ownership of the FD
+    let _ = client.into_raw_fd();
+
+    let (conn, screen) = x11rb::connect_from_env(Some(":0")).unwrap();
+    assert_eq!(screen, 0);
+    assert_eq!(conn.setup().roots[0].root, 42);
+    assert!(std::env::var_os(SOCKET_ENV_VAR).is_none());
+    server.join().unwrap();
+
+    std::env::set_var(SOCKET_ENV_VAR, "not a number");
+    assert!(x11rb::connect_from_env(Some(":0")).is_err());
+    assert!(std::env::var_os(SOCKET_ENV_VAR).is_none());
+}
@@ -0,0 +1,77 @@
+#![cfg(unix)]
+
+mod common;
+
+use std::io::{Read, Write};
+use std::os::unix::net::UnixStream;
+
+use x11rb::connection::Connection;
+use x11rb::dyn_connection::DynConnection;
+use x11rb::protocol::xproto::ConnectionExt as _;
+use x11rb::protocol::Event;
+
+use common::connect;
+
+/// Read a request and return its major opcode.
+fn read_request(server: &mut UnixStream) -> u8 {
+    let mut header = [0; 4];
+    server.read_exact(&mut header).unwrap();
+    let length = u16::from_ne_bytes([header[2], header[3]]);
+    let mut body = vec![0; 4 * usize::from(length) - 4];
+    server.read_exact(&mut body).unwrap();
+    header[0]
+}
+
+/// Send requests and receive replies, errors and events through `&dyn DynConnection`.
+fn exercise(conn: &dyn DynConnection, server: &mut UnixStream) {
+    let window = conn.generate_id().unwrap();
+    assert_eq!(window, 0);
+    let destroy = conn.destroy_window(window).unwrap();
+    let focus = conn.get_input_focus().unwrap();
+    conn.flush().unwrap();
+    assert_eq!(read_request(server), 4);
+    assert_eq!(read_request(server), 43);
+
+    // A Window error for DestroyWindow, a reply to GetInputFocus and a MapNotify event
+    let mut error = [0; 32];
+    error[1] = 3;
+    error[2..4].copy_from_slice(&1u16.to_ne_bytes());
+    let mut reply = [0; 32];
+    reply[0] = 1;
+    reply[2..4].copy_from_slice(&2u16.to_ne_bytes());
+    reply[8..12].copy_from_slice(&42u32.to_ne_bytes());
+    let mut event = [0; 32];
+    event[0] = 19;
+    event[2..4].copy_from_slice(&2u16.to_ne_bytes());
+    server.write_all(&error).unwrap();
+    server.write_all(&reply).unwrap();
+    server.write_all(&event).unwrap();
+
+    assert!(destroy.check().is_err());
+    assert_eq!(focus.reply().unwrap().focus, 42);
+    match conn.wait_for_event().unwrap() {
+        Event::MapNotify(_) => {}
+        event => panic!("unexpected event {:?}", event),
+    }
+}
+
+#[test]
+fn works_through_trait_object() {
+    let (conn, mut server) = connect();
+    let conn: Box<dyn DynConnection> = Box::new(conn);
+    exercise(&*conn, &mut server);
+}
+
+#[test]
+fn works_through_shared_trait_object() {
+    let (conn, mut server) = connect();
+    let conn: std::sync::Arc<dyn DynConnection + Send + Sync> = std::sync::Arc::new(conn);
+    let thread_conn = conn.clone();
+    let _server = std::thread::spawn(move || {
+        exercise(&*thread_conn, &mut server);
+        server
+    })
+    .join()
+    .unwrap();
+    assert!(conn.poll_for_event().unwrap().is_none());
+}
@@ -0,0 +1,100 @@
+#![cfg(unix)]
+
+mod common;
+
+use std::io::{Read, Write};
+use std::os::unix::net::UnixStream;
+use std::sync::{Arc, Mutex};
+
+use x11rb::connection::Connection;
+use x11rb::protocol::xproto::{ConnectionExt as _, MAP_NOTIFY_EVENT};
+use x11rb::protocol::{ErrorKind, Event};
+use x11rb::rust_connection::RustConnection;
+
+use common::connect;
+
+/// Read a `DestroyWindow` request and answer it with a `Window` error and a `MapNotify` event.
+fn fail_destroy_window(server: &mut UnixStream) {
+    let mut request = [0; 8];
+    server.read_exact(&mut request).unwrap();
+
+    let mut error = [0; 32];
+    error[1] = 3; // Window error
+    error[2..4].copy_from_slice(&1u16.to_ne_bytes());
+    error[4..8].copy_from_slice(&request[4..8]);
+    error[10] = request[0];
+    let mut event = [0; 32];
+    event[0] = MAP_NOTIFY_EVENT;
+    event[2..4].copy_from_slice(&1u16.to_ne_bytes());
+    server.write_all(&error).unwrap();
+    server.write_all(&event).unwrap();
+}
+
+type Errors = Arc<Mutex<Vec<(ErrorKind, Option<(String, u32)>)>>>;
+
+fn install_handler(conn: &RustConnection) -> Errors {
+    let errors = Errors::default();
+    let errors2 = Arc::clone(&errors);
+    conn.set_error_handler(Some(Arc::new(move |error, origin| {
+        let origin = origin.map(|origin| {
+            let location = origin.location();
+            (location.file().to_string(), location.line())
+        });
+        errors2.lock().unwrap().push((error.error_kind, origin));
+    })));
+    errors
+}
+
+#[test]
+fn handler_receives_errors_with_origin() {
+    let (conn, mut server) = connect();
+    let errors = install_handler(&conn);
+    conn.set_request_origin_tracking(true);
+
+    conn.destroy_window(42).unwrap();
+    let line = line!() - 1;
+    conn.flush().unwrap();
+    fail_destroy_window(&mut server);
+
+    // The error is not returned as an event
+    assert!(matches!(
+        conn.wait_for_event().unwrap(),
+        Event::MapNotify(_)
+    ));
+    assert!(conn.poll_for_event().unwrap().is_none());
+
+    let errors = errors.lock().unwrap();
+    assert_eq!(errors.len(), 1);
+    let (kind, origin) = &errors[0];
+    assert_eq!(*kind, ErrorKind::Window);
+    let (file, origin_line) = origin.as_ref().unwrap();
+    assert!(file.ends_with("error_handler.rs"), "{}", file);
+    assert_eq!(*origin_line, line);
+}
+
+#[test]
+fn origins_are_optional() {
+    let (conn, mut server) = connect();
+    let errors = install_handler(&conn);
+
+    conn.destroy_window(42).unwrap();
+    conn.flush().unwrap();
+    fail_destroy_window(&mut server);
+    assert!(matches!(
+        conn.wait_for_event().unwrap(),
+        Event::MapNotify(_)
+    ));
+    assert_eq!(*errors.lock().unwrap(), [(ErrorKind::Window, None)]);
+
+    // Without a handler, errors are events again
+    conn.set_error_handler(None);
+    conn.destroy_window(42).unwrap();
+    conn.flush().unwrap();
+    let mut request = [0; 8];
+    server.read_exact(&mut request).unwrap();
+    let mut error = [0; 32];
+    error[1] = 3;
+    error[2..4].copy_from_slice(&2u16.to_ne_bytes());
+    server.write_all(&error).unwrap();
+    assert!(matches!(conn.wait_for_event().unwrap(), Event::Error(_)));
+}
@@ -0,0 +1,163 @@
+use x11rb::errors::SerializeError;
+use x11rb::protocol::xproto::{GetAtomNameReply, GetInputFocusReply, InputFocus};
+use x11rb::protocol::{Event, Reply};
+use x11rb::x11_utils::{ExtInfoProvider, ExtensionInformation};
+
+/// Knows a fixed list of extensions.
+struct Provider(Vec<(&'static str, ExtensionInformation)>);
+
+impl Provider {
+    fn new() -> Self {
+        Self(vec![
+            (
+                "RANDR",
+                ExtensionInformation {
+                    major_opcode: 140,
+                    first_event: 89,
+                    first_error: 147,
+                },
+            ),
+            (
+                "Present",
+                ExtensionInformation {
+                    major_opcode: 148,
+                    first_event: 0,
+                    first_error: 0,
+                },
+            ),
+        ])
+    }
+}
+
+impl ExtInfoProvider for Provider {
+    fn get_from_major_opcode(&self, major_opcode: u8) -> Option<(&str, ExtensionInformation)> {
+        self.0
+            .iter()
+            .copied()
+            .find(|(_, info)| info.major_opcode == major_opcode)
+    }
+    fn get_from_event_code(&self, event_code: u8) -> Option<(&str, ExtensionInformation)> {
+        self.0
+            .iter()
+            .copied()
+            .filter(|(_, info)| info.first_event != 0 && info.first_event <= event_code)
+            .max_by_key(|(_, info)| info.first_event)
+    }
+    fn get_from_error_code(&self, _error_code: u8) -> Option<(&str, ExtensionInformation)> {
+        unimplemented!()
+    }
+}
+
+/// Parse `bytes` as an event, serialize it again and check that nothing changed.
+fn round_trip(bytes: &[u8]) -> Event {
+    let event = Event::parse(bytes, &Provider::new()).unwrap();
+    assert_eq!(event.serialize(&Provider::new()).unwrap(), bytes);
+    event
+}
+
+#[test]
+fn core_event() {
+    let mut bytes = [0; 32];
+    bytes[0] = 19;
+    bytes[2..4].copy_from_slice(&42u16.to_ne_bytes());
+    bytes[4..8].copy_from_slice(&1u32.to_ne_bytes());
+    match round_trip(&bytes) {
+        Event::MapNotify(event) => assert_eq!(event.sequence, 42),
+        event => panic!("unexpected event {:?}", event),
+    }
+}
+
+#[test]
+fn sent_event() {
+    let mut bytes = [0; 32];
+    bytes[0] = 0x80 | 19;
+    assert!(round_trip(&bytes).sent_event());
+}
+
+#[test]
+fn error() {
+    let mut bytes = [0; 32];
+    bytes[1] = 3;
+    bytes[4..8].copy_from_slice(&0x1234u32.to_ne_bytes());
+    bytes[10] = 4;
+    let _ = round_trip(&bytes);
+}
+
+#[cfg(feature = "randr")]
+#[test]
+fn extension_event() {
+    let mut bytes = [0; 32];
+    bytes[0] = 89;
+    bytes[1] = 1;
+    match round_trip(&bytes) {
+        Event::RandrScreenChangeNotify(event) => assert_eq!(event.rotation, 1),
+        event => panic!("unexpected event {:?}", event),
+    }
+}
+
+#[cfg(feature = "randr")]
+#[test]
+fn missing_extension() {
+    let mut bytes = [0; 32];
+    bytes[0] = 89;
+    let event = Event::parse(&bytes, &Provider::new()).unwrap();
+    match event.serialize(&Provider(Vec::new())) {
+        Err(SerializeError::MissingExtension(name)) => assert_eq!(name, "RANDR"),
+        other => panic!("unexpected result {:?}", other),
+    }
+}
+
+#[cfg(feature = "present")]
+#[test]
+fn generic_event() {
+    // A CompleteNotify event is eight bytes longer than normal events
+    let mut bytes = [0; 40];
+    bytes[0] = 35;
+    bytes[1] = 148;
+    bytes[4..8].copy_from_slice(&2u32.to_ne_bytes());
+    bytes[8..10].copy_from_slice(&1u16.to_ne_bytes());
+    bytes[32..40].copy_from_slice(&7u64.to_ne_bytes());
+    match round_trip(&bytes) {
+        Event::PresentCompleteNotify(event) => assert_eq!(event.msc, 7),
+        event => panic!("unexpected event {:?}", event),
+    }
+}
+
+#[test]
+fn reply() {
+    let reply = Reply::from(GetInputFocusReply {
+        revert_to: InputFocus::PARENT,
+        sequence: 0,
+        length: 0,
+        focus: 0x1234,
+    });
+    let (bytes, fds) = reply.serialize(7).unwrap();
+    assert!(fds.is_empty());
+    let mut expected = vec![1, 2];
+    expected.extend_from_slice(&7u16.to_ne_bytes());
+    expected.extend_from_slice(&0u32.to_ne_bytes());
+    expected.extend_from_slice(&0x1234u32.to_ne_bytes());
+    expected.resize(32, 0);
+    assert_eq!(bytes, expected);
+}
+
+#[test]
+fn long_reply() {
+    let reply = Reply::from(GetAtomNameReply {
+        sequence: 0,
+        length: 0,
+        name: vec![b'x'; 30],
+    });
+    let (bytes, _) = reply.serialize(1).unwrap();
+    // 32 bytes header and 30 bytes name, padded to 64 bytes
+    assert_eq!(bytes.len(), 64);
+    assert_eq!(bytes[4..8], 8u32.to_ne_bytes());
+    assert_eq!(bytes[32..62], [b'x'; 30]);
+}
+
+#[test]
+fn void_reply() {
+    let (bytes, fds) = Reply::Void.serialize(1).unwrap();
+    assert!(bytes.is_empty());
+    assert!(fds.is_empty());
+}
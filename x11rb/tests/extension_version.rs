@@ -0,0 +1,96 @@
+#![cfg(all(unix, feature = "randr"))]
+
+mod common;
+
+use std::io::{Read, Write};
+use std::os::unix::net::UnixStream;
+
+use x11rb::connection::{Connection, RequestConnection};
+use x11rb::errors::ConnectionError;
+use x11rb::protocol::randr::{self, ConnectionExt as _};
+
+use common::connect;
+
+const RANDR_OPCODE: u8 = 140;
+
+/// Read a request and return its major and minor opcode.
+fn read_request(server: &mut UnixStream) -> (u8, u8) {
+    let mut header = [0; 4];
+    server.read_exact(&mut header).unwrap();
+    let length = u16::from_ne_bytes([header[2], header[3]]);
+    let mut body = vec![0; 4 * usize::from(length) - 4];
+    server.read_exact(&mut body).unwrap();
+    (header[0], header[1])
+}
+
+/// Answer the `QueryExtension` request and the `QueryVersion` request of RandR.
+fn answer_handshake(server: &mut UnixStream, present: bool, version: (u32, u32)) {
+    assert_eq!(read_request(server).0, 98);
+    let mut reply = [0; 32];
+    reply[0] = 1;
+    reply[2..4].copy_from_slice(&1u16.to_ne_bytes());
+    reply[8] = present.into();
+    reply[9] = RANDR_OPCODE;
+    server.write_all(&reply).unwrap();
+    if !present {
+        return;
+    }
+
+    assert_eq!(read_request(server), (RANDR_OPCODE, 0));
+    let mut reply = [0; 32];
+    reply[0] = 1;
+    reply[2..4].copy_from_slice(&2u16.to_ne_bytes());
+    reply[8..12].copy_from_slice(&version.0.to_ne_bytes());
+    reply[12..16].copy_from_slice(&version.1.to_ne_bytes());
+    server.write_all(&reply).unwrap();
+}
+
+#[test]
+fn negotiates_once() {
+    let (conn, mut server) = connect();
+    let server = std::thread::spawn(move || {
+        answer_handshake(&mut server, true, (1, 2));
+        server
+    });
+    assert_eq!(
+        conn.extension_version(randr::X11_EXTENSION_NAME).unwrap(),
+        Some((1, 2))
+    );
+    let mut server = server.join().unwrap();
+    // The second call is answered from the cache
+    assert_eq!(
+        conn.extension_version(randr::X11_EXTENSION_NAME).unwrap(),
+        Some((1, 2))
+    );
+
+    // Requests up to the negotiated version are sent
+    let _ = conn.randr_get_screen_resources(1).unwrap();
+    conn.flush().unwrap();
+    assert_eq!(read_request(&mut server), (RANDR_OPCODE, 8));
+
+    // Newer requests fail without being sent
+    let result = conn.randr_get_monitors(1, true).map(|_| ());
+    match result {
+        Err(ConnectionError::ExtensionVersionTooOld {
+            extension,
+            required,
+            negotiated,
+        }) => {
+            assert_eq!(extension, randr::X11_EXTENSION_NAME);
+            assert_eq!(required, (1, 5));
+            assert_eq!(negotiated, (1, 2));
+        }
+        other => panic!("unexpected result {:?}", other),
+    }
+}
+
+#[test]
+fn unsupported_extension() {
+    let (conn, mut server) = connect();
+    let server = std::thread::spawn(move || answer_handshake(&mut server, false, (0, 0)));
+    assert_eq!(
+        conn.extension_version(randr::X11_EXTENSION_NAME).unwrap(),
+        None
+    );
+    server.join().unwrap();
+}
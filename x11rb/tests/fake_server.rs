@@ -0,0 +1,529 @@
+#![cfg(feature = "fake_server")]
+
+use std::time::Duration;
+
+use x11rb::connection::Connection;
+use x11rb::errors::ReplyError;
+use x11rb::fake_server::{FakeServer, FakeStream};
+use x11rb::protocol::xproto::{
+    AtomEnum, ChangeWindowAttributesAux, ClientMessageEvent, ConfigureWindowAux,
+    ConnectionExt as _, CreateWindowAux, EventMask, PropMode, Property, StackMode, Window,
+    WindowClass,
+};
+use x11rb::protocol::{ErrorKind, Event};
+use x11rb::rust_connection::RustConnection;
+use x11rb::wrapper::ConnectionExt as _;
+
+type Conn = RustConnection<FakeStream>;
+
+fn root(conn: &Conn) -> Window {
+    conn.setup().roots[0].root
+}
+
+fn create_window(conn: &Conn, parent: Window, aux: &CreateWindowAux) -> Window {
+    let window = conn.generate_id().unwrap();
+    conn.create_window(
+        0,
+        window,
+        parent,
+        10,
+        20,
+        100,
+        50,
+        0,
+        WindowClass::INPUT_OUTPUT,
+        0,
+        aux,
+    )
+    .unwrap()
+    .check()
+    .unwrap();
+    window
+}
+
+/// Wait for the next event, but fail instead of hanging forever.
+fn next_event(conn: &Conn) -> Event {
+    conn.wait_for_event_timeout(Duration::from_secs(5)).unwrap()
+}
+
+fn x11_error_kind<T: std::fmt::Debug>(result: Result<T, ReplyError>) -> ErrorKind {
+    match result {
+        Err(ReplyError::X11Error(error)) => error.error_kind,
+        other => panic!("expected an X11 error, got {:?}", other),
+    }
+}
+
+#[test]
+fn setup() {
+    let conn = FakeServer::with_screen_size(800, 600).connect().unwrap();
+    let screen = &conn.setup().roots[0];
+    assert_eq!(
+        (screen.width_in_pixels, screen.height_in_pixels),
+        (800, 600)
+    );
+    assert_eq!(screen.root_depth, 24);
+    let reply = conn
+        .query_extension(b"BIG-REQUESTS")
+        .unwrap()
+        .reply()
+        .unwrap();
+    assert!(!reply.present);
+}
+
+#[test]
+fn atoms() {
+    let server = FakeServer::new();
+    let conn1 = server.connect().unwrap();
+    let conn2 = server.connect().unwrap();
+
+    let reply = conn1.intern_atom(true, b"STRING").unwrap().reply().unwrap();
+    assert_eq!(reply.atom, u32::from(AtomEnum::STRING));
+    let reply = conn1.intern_atom(true, b"FOO").unwrap().reply().unwrap();
+    assert_eq!(reply.atom, 0);
+
+    let foo = conn1
+        .intern_atom(false, b"FOO")
+        .unwrap()
+        .reply()
+        .unwrap()
+        .atom;
+    assert_ne!(foo, 0);
+    let reply = conn2.intern_atom(true, b"FOO").unwrap().reply().unwrap();
+    assert_eq!(reply.atom, foo);
+    assert_eq!(
+        conn2.get_atom_name(foo).unwrap().reply().unwrap().name,
+        b"FOO"
+    );
+
+    let result = conn1.get_atom_name(foo + 1).unwrap().reply();
+    assert_eq!(x11_error_kind(result), ErrorKind::Atom);
+}
+
+#[test]
+fn window_lifecycle() {
+    let server = FakeServer::new();
+    let conn = server.connect().unwrap();
+    let root = root(&conn);
+    let aux = ChangeWindowAttributesAux::new().event_mask(EventMask::SUBSTRUCTURE_NOTIFY);
+    conn.change_window_attributes(root, &aux).unwrap();
+
+    let aux = CreateWindowAux::new().event_mask(EventMask::STRUCTURE_NOTIFY | EventMask::EXPOSURE);
+    let window = create_window(&conn, root, &aux);
+    match next_event(&conn) {
+        Event::CreateNotify(event) => {
+            assert_eq!((event.parent, event.window), (root, window));
+            assert_eq!(
+                (event.x, event.y, event.width, event.height),
+                (10, 20, 100, 50)
+            );
+        }
+        event => panic!("unexpected event {:?}", event),
+    }
+
+    let geometry = conn.get_geometry(window).unwrap().reply().unwrap();
+    assert_eq!(
+        (geometry.root, geometry.width, geometry.height),
+        (root, 100, 50)
+    );
+    let tree = conn.query_tree(root).unwrap().reply().unwrap();
+    assert_eq!(tree.children, [window]);
+
+    conn.map_window(window).unwrap();
+    conn.flush().unwrap();
+    for _ in 0..2 {
+        // One MapNotify for the window's StructureNotify and one for the root's SubstructureNotify
+        match next_event(&conn) {
+            Event::MapNotify(event) => assert_eq!(event.window, window),
+            event => panic!("unexpected event {:?}", event),
+        }
+    }
+    match next_event(&conn) {
+        Event::Expose(event) => {
+            assert_eq!(event.window, window);
+            assert_eq!((event.width, event.height, event.count), (100, 50, 0));
+        }
+        event => panic!("unexpected event {:?}", event),
+    }
+
+    let aux = ConfigureWindowAux::new().x(5).width(200);
+    conn.configure_window(window, &aux).unwrap();
+    conn.flush().unwrap();
+    for _ in 0..2 {
+        match next_event(&conn) {
+            Event::ConfigureNotify(event) => {
+                assert_eq!(event.window, window);
+                assert_eq!(
+                    (event.x, event.y, event.width, event.height),
+                    (5, 20, 200, 50)
+                );
+            }
+            event => panic!("unexpected event {:?}", event),
+        }
+    }
+
+    conn.destroy_window(window).unwrap();
+    conn.flush().unwrap();
+    let mut events = Vec::new();
+    for _ in 0..4 {
+        events.push(next_event(&conn));
+    }
+    assert!(matches!(events[0], Event::UnmapNotify(_)));
+    assert!(matches!(events[1], Event::UnmapNotify(_)));
+    assert!(matches!(events[2], Event::DestroyNotify(_)));
+    assert!(matches!(events[3], Event::DestroyNotify(_)));
+    assert!(conn
+        .query_tree(root)
+        .unwrap()
+        .reply()
+        .unwrap()
+        .children
+        .is_empty());
+
+    let result = conn.get_geometry(window).unwrap().reply();
+    assert_eq!(x11_error_kind(result), ErrorKind::Drawable);
+}
+
+#[test]
+fn windows_are_destroyed_on_disconnect() {
+    let server = FakeServer::new();
+    let conn1 = server.connect().unwrap();
+    let root = root(&conn1);
+    let _ = create_window(&conn1, root, &CreateWindowAux::new());
+    drop(conn1);
+
+    let conn2 = server.connect().unwrap();
+    let tree = conn2.query_tree(root).unwrap().reply().unwrap();
+    assert!(tree.children.is_empty());
+}
+
+#[test]
+fn stacking_order() {
+    let conn = FakeServer::new().connect().unwrap();
+    let root = root(&conn);
+    let window1 = create_window(&conn, root, &CreateWindowAux::new());
+    let window2 = create_window(&conn, root, &CreateWindowAux::new());
+    let tree = conn.query_tree(root).unwrap().reply().unwrap();
+    assert_eq!(tree.children, [window1, window2]);
+
+    let aux = ConfigureWindowAux::new().stack_mode(StackMode::ABOVE);
+    conn.configure_window(window1, &aux).unwrap();
+    let tree = conn.query_tree(root).unwrap().reply().unwrap();
+    assert_eq!(tree.children, [window2, window1]);
+}
+
+#[test]
+fn substructure_redirect() {
+    let server = FakeServer::new();
+    let wm = server.connect().unwrap();
+    let client = server.connect().unwrap();
+    let root = root(&wm);
+    let aux = ChangeWindowAttributesAux::new().event_mask(EventMask::SUBSTRUCTURE_REDIRECT);
+    wm.change_window_attributes(root, &aux)
+        .unwrap()
+        .check()
+        .unwrap();
+
+    // Only one client can redirect
+    let result = client.change_window_attributes(root, &aux).unwrap().check();
+    assert_eq!(x11_error_kind(result), ErrorKind::Access);
+
+    let window = create_window(&client, root, &CreateWindowAux::new());
+    client.map_window(window).unwrap();
+    client.flush().unwrap();
+    match next_event(&wm) {
+        Event::MapRequest(event) => assert_eq!((event.parent, event.window), (root, window)),
+        event => panic!("unexpected event {:?}", event),
+    }
+    let attributes = client
+        .get_window_attributes(window)
+        .unwrap()
+        .reply()
+        .unwrap();
+    assert_eq!(
+        attributes.map_state,
+        x11rb::protocol::xproto::MapState::UNMAPPED
+    );
+
+    let aux = ConfigureWindowAux::new().width(300);
+    client.configure_window(window, &aux).unwrap();
+    client.flush().unwrap();
+    match next_event(&wm) {
+        Event::ConfigureRequest(event) => {
+            assert_eq!(event.window, window);
+            assert_eq!(event.width, 300);
+            assert_eq!(event.value_mask, 4);
+        }
+        event => panic!("unexpected event {:?}", event),
+    }
+
+    wm.map_window(window).unwrap().check().unwrap();
+    let attributes = client
+        .get_window_attributes(window)
+        .unwrap()
+        .reply()
+        .unwrap();
+    assert_eq!(
+        attributes.map_state,
+        x11rb::protocol::xproto::MapState::VIEWABLE
+    );
+}
+
+#[test]
+fn properties() {
+    let server = FakeServer::new();
+    let conn = server.connect().unwrap();
+    let root = root(&conn);
+    let aux = CreateWindowAux::new().event_mask(EventMask::PROPERTY_CHANGE);
+    let window = create_window(&conn, root, &aux);
+    let name = AtomEnum::WM_NAME;
+    let string = AtomEnum::STRING;
+
+    conn.change_property8(PropMode::REPLACE, window, name, string, b"Hello")
+        .unwrap();
+    conn.change_property8(PropMode::APPEND, window, name, string, b" World")
+        .unwrap();
+    let result = conn
+        .change_property32(PropMode::APPEND, window, name, string, &[0])
+        .unwrap()
+        .check();
+    assert_eq!(x11_error_kind(result), ErrorKind::Match);
+
+    let reply = conn
+        .get_property(false, window, name, string, 0, 2)
+        .unwrap()
+        .reply()
+        .unwrap();
+    assert_eq!(reply.value, b"Hello Wo");
+    assert_eq!(
+        (reply.format, reply.value_len, reply.bytes_after),
+        (8, 8, 3)
+    );
+    let reply = conn
+        .get_property(false, window, name, AtomEnum::ANY, 2, 2)
+        .unwrap()
+        .reply()
+        .unwrap();
+    assert_eq!(reply.value, b"rld");
+    assert_eq!(reply.bytes_after, 0);
+    let reply = conn
+        .get_property(false, window, name, AtomEnum::ATOM, 0, 100)
+        .unwrap()
+        .reply()
+        .unwrap();
+    assert_eq!(reply.type_, u32::from(string));
+    assert!(reply.value.is_empty());
+    assert_eq!(reply.bytes_after, 11);
+
+    let properties = conn.list_properties(window).unwrap().reply().unwrap();
+    assert_eq!(properties.atoms, [u32::from(name)]);
+
+    let reply = conn
+        .get_property(true, window, name, string, 0, 100)
+        .unwrap()
+        .reply()
+        .unwrap();
+    assert_eq!(reply.value, b"Hello World");
+    let properties = conn.list_properties(window).unwrap().reply().unwrap();
+    assert!(properties.atoms.is_empty());
+
+    let mut states = Vec::new();
+    while let Some(event) = conn.poll_for_event().unwrap() {
+        match event {
+            Event::PropertyNotify(event) => {
+                assert_eq!((event.window, event.atom), (window, u32::from(name)));
+                states.push(event.state);
+            }
+            event => panic!("unexpected event {:?}", event),
+        }
+    }
+    assert_eq!(
+        states,
+        [Property::NEW_VALUE, Property::NEW_VALUE, Property::DELETE]
+    );
+}
+
+#[test]
+fn large_properties() {
+    let server = FakeServer::new();
+    let conn = server.connect().unwrap();
+    let window = create_window(&conn, root(&conn), &CreateWindowAux::new());
+    let name = AtomEnum::WM_ICON_NAME;
+    let cardinal = AtomEnum::CARDINAL;
+
+    // Too large for a single request without BIG-REQUESTS
+    let data = (0..100_000).collect::<Vec<u32>>();
+    let cookies = conn
+        .replace_property32(window, name, cardinal, &data)
+        .unwrap();
+    assert_eq!(cookies.len(), 2);
+    for cookie in cookies {
+        cookie.check().unwrap();
+    }
+
+    let reply = conn
+        .get_property_full(false, window, name, cardinal)
+        .unwrap();
+    assert_eq!(
+        (reply.format, reply.value_len, reply.bytes_after),
+        (32, 100_000, 0)
+    );
+    assert_eq!(reply.value32().unwrap().collect::<Vec<_>>(), data);
+
+    // Replacing shortens the property again
+    let cookies = conn
+        .replace_property8(window, name, cardinal, b"short")
+        .unwrap();
+    assert_eq!(cookies.len(), 1);
+    let reply = conn
+        .get_property_full(true, window, name, AtomEnum::ANY)
+        .unwrap();
+    assert_eq!(reply.value, b"short");
+    let properties = conn.list_properties(window).unwrap().reply().unwrap();
+    assert!(properties.atoms.is_empty());
+}
+
+#[test]
+fn borrowed_replies() {
+    let server = FakeServer::new();
+    let conn = server.connect().unwrap();
+    let root = root(&conn);
+    let window = create_window(&conn, root, &CreateWindowAux::new());
+    conn.change_property8(
+        PropMode::REPLACE,
+        window,
+        AtomEnum::WM_NAME,
+        AtomEnum::STRING,
+        b"Hello",
+    )
+    .unwrap();
+
+    let value = conn
+        .get_property(false, window, AtomEnum::WM_NAME, AtomEnum::STRING, 0, 100)
+        .unwrap()
+        .with_reply(|reply| {
+            assert_eq!(reply.format, 8);
+            reply.value.to_vec()
+        })
+        .unwrap();
+    assert_eq!(value, b"Hello");
+
+    let (parent, children) = conn
+        .query_tree(root)
+        .unwrap()
+        .with_reply(|reply| (reply.parent, reply.children.iter().collect::<Vec<_>>()))
+        .unwrap();
+    assert_eq!(parent, 0);
+    assert_eq!(children, [window]);
+
+    let result = conn
+        .query_tree(window + 1)
+        .unwrap()
+        .with_reply(|reply| reply.children.len());
+    assert_eq!(x11_error_kind(result), ErrorKind::Window);
+}
+
+#[test]
+fn send_event() {
+    let server = FakeServer::new();
+    let conn1 = server.connect().unwrap();
+    let conn2 = server.connect().unwrap();
+    let root = root(&conn1);
+    let aux = ChangeWindowAttributesAux::new().event_mask(EventMask::SUBSTRUCTURE_NOTIFY);
+    conn1.change_window_attributes(root, &aux).unwrap();
+    conn1.sync().unwrap();
+
+    let window = create_window(&conn2, root, &CreateWindowAux::new());
+    let _ = next_event(&conn1);
+
+    // The event is propagated to the root window
+    let event = ClientMessageEvent::new(32, window, AtomEnum::STRING, [1, 2, 3, 4, 5]);
+    conn2
+        .send_event(true, window, EventMask::SUBSTRUCTURE_NOTIFY, event)
+        .unwrap();
+    conn2.flush().unwrap();
+    match next_event(&conn1) {
+        Event::ClientMessage(event) => {
+            assert_eq!(event.response_type, 0x80 | 33);
+            assert_eq!(event.window, window);
+            assert_eq!(event.data.as_data32(), [1, 2, 3, 4, 5]);
+        }
+        event => panic!("unexpected event {:?}", event),
+    }
+
+    // An empty event mask sends the event to the creator of the window
+    conn1
+        .send_event(false, window, EventMask::NO_EVENT, event)
+        .unwrap();
+    conn1.flush().unwrap();
+    assert!(matches!(next_event(&conn2), Event::ClientMessage(_)));
+}
+
+#[test]
+fn selections() {
+    let server = FakeServer::new();
+    let owner = server.connect().unwrap();
+    let requestor = server.connect().unwrap();
+    let root = root(&owner);
+    let owner_window = create_window(&owner, root, &CreateWindowAux::new());
+    let requestor_window = create_window(&requestor, root, &CreateWindowAux::new());
+    let primary = AtomEnum::PRIMARY.into();
+    let string = AtomEnum::STRING.into();
+
+    requestor
+        .convert_selection(requestor_window, primary, string, string, 0u32)
+        .unwrap();
+    requestor.flush().unwrap();
+    match next_event(&requestor) {
+        Event::SelectionNotify(event) => assert_eq!(event.property, 0),
+        event => panic!("unexpected event {:?}", event),
+    }
+
+    owner
+        .set_selection_owner(owner_window, primary, 0u32)
+        .unwrap();
+    owner.flush().unwrap();
+    let reply = requestor
+        .get_selection_owner(primary)
+        .unwrap()
+        .reply()
+        .unwrap();
+    assert_eq!(reply.owner, owner_window);
+
+    requestor
+        .convert_selection(requestor_window, primary, string, string, 0u32)
+        .unwrap();
+    requestor.flush().unwrap();
+    match next_event(&owner) {
+        Event::SelectionRequest(event) => {
+            assert_eq!(event.owner, owner_window);
+            assert_eq!(event.requestor, requestor_window);
+            assert_eq!(event.property, string);
+        }
+        event => panic!("unexpected event {:?}", event),
+    }
+
+    requestor
+        .set_selection_owner(requestor_window, primary, 0u32)
+        .unwrap();
+    requestor.flush().unwrap();
+    match next_event(&owner) {
+        Event::SelectionClear(event) => assert_eq!(event.owner, owner_window),
+        event => panic!("unexpected event {:?}", event),
+    }
+
+    // Destroying the owner window clears the selection
+    requestor.destroy_window(requestor_window).unwrap();
+    let reply = requestor
+        .get_selection_owner(primary)
+        .unwrap()
+        .reply()
+        .unwrap();
+    assert_eq!(reply.owner, 0);
+}
+
+#[test]
+fn unsupported_request() {
+    let conn = FakeServer::new().connect().unwrap();
+    let result = conn.list_fonts(10, b"*").unwrap().reply();
+    assert_eq!(x11_error_kind(result), ErrorKind::Implementation);
+}
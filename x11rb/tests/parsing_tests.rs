@@ -166,3 +166,63 @@ fn parse_xi_get_property_reply_format_0() {
         )),
     );
 }
+
+#[test]
+fn parse_borrowed_get_image_reply() {
+    use x11rb::protocol::xproto::{GetImageReply, GetImageReplyRef};
+    use x11rb::protocol::Reply;
+    use x11rb::x11_utils::BorrowedReply;
+
+    let reply = GetImageReply {
+        depth: 24,
+        sequence: 10,
+        visual: 0x21,
+        data: vec![1, 2, 3, 4, 5, 6, 7, 8],
+    };
+    let (bytes, _) = Reply::from(reply.clone()).serialize(10).unwrap();
+    let (reply_ref, remaining): (GetImageReplyRef<'_>, _) =
+        GetImageReply::try_parse_ref(&bytes).unwrap();
+    assert!(remaining.is_empty());
+    assert_eq!(reply_ref.data, &bytes[32..]);
+    assert_eq!(reply_ref.data.as_ptr(), bytes[32..].as_ptr());
+    assert_eq!(GetImageReply::from(reply_ref), reply);
+}
+
+#[test]
+fn parse_borrowed_list_fonts_reply() {
+    use x11rb::protocol::xproto::{ListFontsReply, Str};
+    use x11rb::protocol::Reply;
+    use x11rb::x11_utils::BorrowedReply;
+
+    let names = vec![
+        Str {
+            name: b"fixed".to_vec(),
+        },
+        Str {
+            name: b"cursor".to_vec(),
+        },
+    ];
+    let reply = ListFontsReply {
+        sequence: 3,
+        length: 4,
+        names,
+    };
+    let (bytes, _) = Reply::from(reply.clone()).serialize(3).unwrap();
+    let (reply_ref, _) = ListFontsReply::try_parse_ref(&bytes).unwrap();
+    assert_eq!(reply_ref.names.len(), 2);
+    let names = reply_ref
+        .names
+        .iter()
+        .map(|name| name.name)
+        .collect::<Vec<_>>();
+    assert_eq!(names, [b"fixed".to_vec(), b"cursor".to_vec()]);
+    assert_eq!(ListFontsReply::from(reply_ref), reply);
+
+    // The list of names is checked when the reply is parsed
+    let mut bytes = bytes;
+    bytes[32] = 100;
+    assert_eq!(
+        ListFontsReply::try_parse_ref(&bytes).unwrap_err(),
+        ParseError::InsufficientData
+    );
+}
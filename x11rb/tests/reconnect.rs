@@ -0,0 +1,158 @@
+#![cfg(unix)]
+
+mod common;
+
+use std::io::{Read, Write};
+use std::os::unix::net::UnixStream;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use x11rb::errors::{ConnectError, ConnectionError, DisconnectReason};
+use x11rb::protocol::xproto::ConnectionExt as _;
+use x11rb::reconnect::{Supervisor, SupervisorError};
+use x11rb::rust_connection::{DefaultStream, RustConnection};
+use x11rb::x11_utils::Serialize;
+
+use common::setup_with_screen;
+
+/// Start a server that sends the setup and then answers `replies` `GetInputFocus` requests
+/// before closing the connection.
+fn start_server(replies: u16) -> (RustConnection, JoinHandle<()>) {
+    let (client, mut server) = UnixStream::pair().unwrap();
+    let thread = std::thread::spawn(move || {
+        // The setup request without authorization has a length of 12 bytes
+        let mut setup_request = [0; 12];
+        server.read_exact(&mut setup_request).unwrap();
+        server.write_all(&setup_with_screen().serialize()).unwrap();
+
+        for sequence in 1..=replies {
+            let mut request = [0; 4];
+            server.read_exact(&mut request).unwrap();
+            let mut reply = [0; 32];
+            reply[0] = 1;
+            reply[2..4].copy_from_slice(&sequence.to_ne_bytes());
+            reply[8..12].copy_from_slice(&42u32.to_ne_bytes());
+            server.write_all(&reply).unwrap();
+        }
+    });
+    let stream = DefaultStream::from_unix_stream(client).unwrap();
+    let conn = RustConnection::connect_to_stream(stream, 0).unwrap();
+    (conn, thread)
+}
+
+#[test]
+fn disconnect_is_reported() {
+    let (conn, server) = start_server(1);
+    let reasons = Arc::new(Mutex::new(Vec::new()));
+    let reasons2 = Arc::clone(&reasons);
+    conn.set_disconnect_handler(Some(Arc::new(move |reason| {
+        reasons2.lock().unwrap().push(reason)
+    })));
+
+    assert_eq!(conn.get_input_focus().unwrap().reply().unwrap().focus, 42);
+    assert_eq!(conn.disconnect_reason(), None);
+    server.join().unwrap();
+
+    let err = conn.get_input_focus().unwrap().reply().unwrap_err();
+    assert_eq!(
+        err.disconnect_reason(),
+        Some(DisconnectReason::ServerShutdown)
+    );
+    assert_eq!(
+        conn.disconnect_reason(),
+        Some(DisconnectReason::ServerShutdown)
+    );
+
+    // The handler is only called once
+    let _ = conn.get_input_focus().unwrap().reply().unwrap_err();
+    assert_eq!(*reasons.lock().unwrap(), [DisconnectReason::ServerShutdown]);
+
+    // A handler that is installed later is called immediately
+    let called = Arc::new(AtomicUsize::new(0));
+    let called2 = Arc::clone(&called);
+    conn.set_disconnect_handler(Some(Arc::new(move |_| {
+        let _ = called2.fetch_add(1, Ordering::SeqCst);
+    })));
+    assert_eq!(called.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn io_errors_are_distinguished() {
+    let err = ConnectionError::from(std::io::Error::from(std::io::ErrorKind::UnexpectedEof));
+    assert_eq!(
+        err.disconnect_reason(),
+        Some(DisconnectReason::ServerShutdown)
+    );
+    let err = ConnectionError::from(std::io::Error::from(std::io::ErrorKind::PermissionDenied));
+    assert_eq!(
+        err.disconnect_reason(),
+        Some(DisconnectReason::IoError(
+            std::io::ErrorKind::PermissionDenied
+        ))
+    );
+    assert_eq!(ConnectionError::Timeout.disconnect_reason(), None);
+}
+
+#[test]
+fn supervisor_reconnects() {
+    // The first server dies after one request, the second one survives two requests
+    let mut servers = vec![2, 1];
+    let mut supervisor = Supervisor::new(|| {
+        let replies = servers.pop().ok_or(ConnectError::UnknownError)?;
+        Ok((start_server(replies).0, 0))
+    });
+
+    let mut sessions = Vec::new();
+    let result = supervisor.run(|conn, screen, session| {
+        sessions.push(*session);
+        assert_eq!(screen, 0);
+        for _ in 0..2 {
+            let _ = conn.get_input_focus()?.reply()?;
+        }
+        Ok::<_, x11rb::errors::ReplyError>(session.reconnects())
+    });
+    assert_eq!(result.unwrap(), 1);
+    assert_eq!(sessions.len(), 2);
+    assert!(!sessions[0].is_reconnect());
+    assert!(sessions[1].is_reconnect());
+    assert_eq!(
+        sessions[1].previous_disconnect(),
+        Some(DisconnectReason::ServerShutdown)
+    );
+}
+
+#[test]
+fn supervisor_returns_session_errors() {
+    let mut supervisor = Supervisor::new(|| Ok((start_server(1).0, 0)));
+    let result = supervisor.run(|_, _, _| Err::<(), _>("application error"));
+    assert!(matches!(
+        result,
+        Err(SupervisorError::Session("application error"))
+    ));
+}
+
+#[test]
+fn supervisor_gives_up_reconnecting() {
+    let attempts = AtomicUsize::new(0);
+    let mut supervisor = Supervisor::new(|| {
+        if attempts.fetch_add(1, Ordering::SeqCst) == 0 {
+            Ok((start_server(0).0, 0))
+        } else {
+            Err(ConnectError::UnknownError)
+        }
+    })
+    .max_attempts(3)
+    .retry_delay(Duration::from_millis(1));
+
+    let result = supervisor.run(|conn, _, _| conn.get_input_focus()?.reply().map(|_| ()));
+    match result {
+        Err(SupervisorError::Connect {
+            error: ConnectError::UnknownError,
+            disconnect: Some(DisconnectReason::ServerShutdown),
+        }) => {}
+        other => panic!("unexpected result {:?}", other),
+    }
+    assert_eq!(attempts.load(Ordering::SeqCst), 4);
+}
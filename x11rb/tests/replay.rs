@@ -0,0 +1,118 @@
+#![cfg(unix)]
+
+mod common;
+
+use std::io::{Read, Write};
+use std::os::unix::net::UnixStream;
+use std::sync::{Arc, Mutex};
+
+use x11rb::connection::Connection;
+use x11rb::protocol::xproto::{ConnectionExt as _, InputFocus};
+use x11rb::rust_connection::{DefaultStream, RecordingStream, ReplayStream, RustConnection};
+use x11rb::x11_utils::Serialize;
+
+use common::setup_with_screen;
+
+#[derive(Debug, Clone, Default)]
+struct SharedOutput(Arc<Mutex<Vec<u8>>>);
+
+impl Write for SharedOutput {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Record a session in which the client sends two `GetInputFocus` requests.
+fn record_session() -> Vec<u8> {
+    let (client, mut server) = UnixStream::pair().unwrap();
+    let server = std::thread::spawn(move || {
+        // The setup request without authorization has a length of 12 bytes
+        let mut setup_request = [0; 12];
+        server.read_exact(&mut setup_request).unwrap();
+        server.write_all(&setup_with_screen().serialize()).unwrap();
+
+        for sequence in 1u16..=2 {
+            let mut request = [0; 4];
+            server.read_exact(&mut request).unwrap();
+            assert_eq!(request, [43, 0, 1, 0]);
+
+            let mut reply = [0; 32];
+            reply[0] = 1;
+            reply[1] = u8::from(InputFocus::PARENT);
+            reply[2..4].copy_from_slice(&sequence.to_ne_bytes());
+            reply[8..12].copy_from_slice(&42u32.to_ne_bytes());
+            server.write_all(&reply).unwrap();
+        }
+        // Keep the connection open until the client is done
+        server
+    });
+
+    let output = SharedOutput::default();
+    let stream = DefaultStream::from_unix_stream(client).unwrap();
+    let stream = RecordingStream::new(stream, output.clone());
+    let conn = RustConnection::connect_to_stream(stream, 0).unwrap();
+    for _ in 0..2 {
+        assert_eq!(conn.get_input_focus().unwrap().reply().unwrap().focus, 42);
+    }
+    server.join().unwrap();
+
+    let recording = output.0.lock().unwrap().clone();
+    recording
+}
+
+fn replay(recording: &[u8]) -> RustConnection<ReplayStream> {
+    let stream = ReplayStream::new(recording).unwrap();
+    RustConnection::connect_to_stream(stream, 0).unwrap()
+}
+
+#[test]
+fn replay_session() {
+    let recording = record_session();
+    let conn = replay(&recording);
+    assert_eq!(conn.setup(), &setup_with_screen());
+    for sequence in 1..=2 {
+        let reply = conn.get_input_focus().unwrap().reply().unwrap();
+        assert_eq!(reply.sequence, sequence);
+        assert_eq!(reply.focus, 42);
+    }
+    conn.stream().check_complete().unwrap();
+}
+
+#[test]
+fn replay_reports_divergence() {
+    let recording = record_session();
+    let conn = replay(&recording);
+    let _ = conn.get_input_focus().unwrap().reply().unwrap();
+    let error = conn.get_atom_name(1).unwrap().reply().unwrap_err();
+    let message = error.to_string();
+    assert!(
+        message.contains("expected request 2 (GetInputFocus)"),
+        "{}",
+        message
+    );
+    assert!(
+        message.contains("client sent request 2 (GetAtomName)"),
+        "{}",
+        message
+    );
+
+    let message = conn.stream().check_complete().unwrap_err().to_string();
+    assert!(message.contains("GetAtomName"), "{}", message);
+}
+
+#[test]
+fn replay_reports_incomplete_session() {
+    let recording = record_session();
+    let conn = replay(&recording);
+    let _ = conn.get_input_focus().unwrap().reply().unwrap();
+    let message = conn.stream().check_complete().unwrap_err().to_string();
+    assert!(
+        message.contains("stopped before sending request 2 (GetInputFocus)"),
+        "{}",
+        message
+    );
+}
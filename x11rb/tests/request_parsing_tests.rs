@@ -275,3 +275,93 @@ fn test_randr_get_output_property() {
         },
     );
 }
+
+mod serialize {
+    use x11rb::errors::SerializeError;
+    use x11rb::protocol::xproto::{ChangePropertyRequest, PropMode};
+    use x11rb::protocol::Request;
+    use x11rb::x11_utils::{
+        parse_request_header, BigRequests, ExtInfoProvider, ExtensionInformation,
+    };
+
+    const RANDR_INFO: ExtensionInformation = ExtensionInformation {
+        major_opcode: 140,
+        first_event: 89,
+        first_error: 147,
+    };
+
+    /// Knows RandR and nothing else.
+    struct Provider;
+
+    impl ExtInfoProvider for Provider {
+        fn get_from_major_opcode(&self, major_opcode: u8) -> Option<(&str, ExtensionInformation)> {
+            Some(("RANDR", RANDR_INFO)).filter(|_| major_opcode == RANDR_INFO.major_opcode)
+        }
+        fn get_from_event_code(&self, _event_code: u8) -> Option<(&str, ExtensionInformation)> {
+            unimplemented!()
+        }
+        fn get_from_error_code(&self, _error_code: u8) -> Option<(&str, ExtensionInformation)> {
+            unimplemented!()
+        }
+    }
+
+    /// Parse `bytes` as a request, serialize it again and check that nothing changed.
+    fn round_trip(bytes: &[u8]) {
+        let (header, body) = parse_request_header(bytes, BigRequests::Enabled).unwrap();
+        let request = Request::parse(header, body, &mut Vec::new(), &Provider).unwrap();
+        let (serialized, fds) = request.serialize(&Provider).unwrap();
+        assert_eq!(serialized, bytes);
+        assert!(fds.is_empty());
+    }
+
+    #[test]
+    fn core_request() {
+        // GetInputFocus
+        round_trip(&[43, 0, 1, 0]);
+        // MapWindow
+        round_trip(&[8, 0, 2, 0, 1, 2, 3, 4]);
+    }
+
+    #[cfg(feature = "randr")]
+    #[test]
+    fn extension_request() {
+        // RandR GetScreenResources
+        round_trip(&[140, 8, 2, 0, 1, 2, 3, 4]);
+    }
+
+    #[cfg(feature = "xinerama")]
+    #[test]
+    fn missing_extension() {
+        let request = Request::XineramaIsActive(x11rb::protocol::xinerama::IsActiveRequest);
+        match request.serialize(&Provider) {
+            Err(SerializeError::MissingExtension(name)) => assert_eq!(name, "XINERAMA"),
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+
+    #[test]
+    fn unknown_request() {
+        round_trip(&[200, 1, 3, 0, 1, 2, 3, 4, 5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn big_request() {
+        let data = vec![0x42; 4 * usize::from(u16::MAX)];
+        let request = Request::ChangeProperty(ChangePropertyRequest {
+            mode: PropMode::REPLACE,
+            window: 1,
+            property: 2,
+            type_: 3,
+            format: 8,
+            data_len: data.len() as u32,
+            data: data.into(),
+        });
+        let (serialized, _) = request.serialize(&Provider).unwrap();
+        // The normal length field is zero and the real length follows the first four bytes
+        assert_eq!(serialized[2..4], [0, 0]);
+        let length =
+            u32::from_ne_bytes([serialized[4], serialized[5], serialized[6], serialized[7]]);
+        assert_eq!(length as usize * 4, serialized.len());
+        round_trip(&serialized);
+    }
+}
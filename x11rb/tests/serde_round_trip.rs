@@ -0,0 +1,115 @@
+#![cfg(feature = "serde")]
+
+use std::borrow::Cow;
+
+use x11rb::protocol::xproto::{
+    GetAtomNameReply, GetPropertyRequest, InternAtomRequest, MapNotifyEvent,
+};
+use x11rb::protocol::{ErrorKind, Event, Reply, Request};
+use x11rb::x11_utils::{RequestHeader, X11Error};
+
+/// Serialize `value` to JSON and deserialize it again.
+fn round_trip<T>(value: &T) -> T
+where
+    T: serde::Serialize + serde::de::DeserializeOwned,
+{
+    let json = serde_json::to_string(value).unwrap();
+    serde_json::from_str(&json).unwrap()
+}
+
+#[test]
+fn request() {
+    let request = Request::GetProperty(GetPropertyRequest {
+        delete: true,
+        window: 0x1234,
+        property: 1,
+        type_: 2,
+        long_offset: 3,
+        long_length: 4,
+    });
+    match round_trip(&request) {
+        Request::GetProperty(req) => {
+            assert_eq!((req.window, req.long_length), (0x1234, 4));
+            assert!(req.delete);
+        }
+        other => panic!("unexpected request {:?}", other),
+    }
+
+    let request = Request::InternAtom(InternAtomRequest {
+        only_if_exists: false,
+        name: Cow::Borrowed(b"WM_NAME"),
+    });
+    match round_trip(&request) {
+        Request::InternAtom(req) => assert_eq!(&*req.name, b"WM_NAME"),
+        other => panic!("unexpected request {:?}", other),
+    }
+}
+
+#[test]
+fn unknown_request() {
+    let header = RequestHeader {
+        major_opcode: 200,
+        minor_opcode: 1,
+        remaining_length: 1,
+    };
+    let request = Request::Unknown(header, Cow::Borrowed(&[1, 2, 3, 4]));
+    match round_trip(&request) {
+        Request::Unknown(header, body) => {
+            assert_eq!((header.major_opcode, header.remaining_length), (200, 1));
+            assert_eq!(&*body, [1, 2, 3, 4]);
+        }
+        other => panic!("unexpected request {:?}", other),
+    }
+}
+
+#[test]
+fn reply() {
+    let reply = GetAtomNameReply {
+        sequence: 1,
+        length: 2,
+        name: b"WM_NAME".to_vec(),
+    };
+    match round_trip(&Reply::GetAtomName(reply.clone())) {
+        Reply::GetAtomName(parsed) => assert_eq!(parsed, reply),
+        other => panic!("unexpected reply {:?}", other),
+    }
+    assert!(matches!(round_trip(&Reply::Void), Reply::Void));
+}
+
+#[test]
+fn event() {
+    let event = MapNotifyEvent {
+        response_type: 19,
+        sequence: 7,
+        event: 1,
+        window: 2,
+        override_redirect: true,
+    };
+    match round_trip(&Event::MapNotify(event)) {
+        Event::MapNotify(parsed) => assert_eq!(parsed, event),
+        other => panic!("unexpected event {:?}", other),
+    }
+}
+
+#[test]
+fn error() {
+    let error = X11Error {
+        error_kind: ErrorKind::Window,
+        error_code: 3,
+        sequence: 10,
+        bad_value: 0x1234,
+        minor_opcode: 0,
+        major_opcode: 20,
+        extension_name: None,
+        request_name: Some("GetProperty"),
+    };
+    assert_eq!(round_trip(&error), error);
+    match round_trip(&Event::Error(error.clone())) {
+        Event::Error(parsed) => assert_eq!(parsed, error),
+        other => panic!("unexpected event {:?}", other),
+    }
+    assert_eq!(
+        round_trip(&ErrorKind::Unknown(200)),
+        ErrorKind::Unknown(200)
+    );
+}
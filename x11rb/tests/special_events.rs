@@ -0,0 +1,104 @@
+#![cfg(all(unix, feature = "present"))]
+
+mod common;
+
+use std::io::{Read, Write};
+use std::os::unix::net::UnixStream;
+
+use x11rb::connection::Connection;
+use x11rb::errors::ConnectionError;
+use x11rb::protocol::present::COMPLETE_NOTIFY_EVENT;
+use x11rb::protocol::xproto::GE_GENERIC_EVENT;
+use x11rb::protocol::Event;
+
+use common::connect;
+
+const PRESENT_OPCODE: u8 = 140;
+
+/// Read a `QueryExtension` request and answer it.
+fn answer_query_extension(server: &mut UnixStream, present: bool) {
+    let mut header = [0; 4];
+    server.read_exact(&mut header).unwrap();
+    let length = u16::from_ne_bytes([header[2], header[3]]);
+    let mut body = vec![0; 4 * usize::from(length) - 4];
+    server.read_exact(&mut body).unwrap();
+
+    let mut reply = [0; 32];
+    reply[0] = 1;
+    reply[2..4].copy_from_slice(&1u16.to_ne_bytes());
+    reply[8] = present.into();
+    reply[9] = PRESENT_OPCODE;
+    server.write_all(&reply).unwrap();
+}
+
+/// A Present event of the given type for the given event ID.
+fn present_event(event_type: u16, eid: u32) -> [u8; 40] {
+    let mut event = [0; 40];
+    event[0] = GE_GENERIC_EVENT;
+    event[1] = PRESENT_OPCODE;
+    event[2..4].copy_from_slice(&1u16.to_ne_bytes());
+    event[4..8].copy_from_slice(&2u32.to_ne_bytes());
+    event[8..10].copy_from_slice(&event_type.to_ne_bytes());
+    event[12..16].copy_from_slice(&eid.to_ne_bytes());
+    event
+}
+
+fn eid_and_type(event: &[u8]) -> (u32, u16) {
+    let eid = u32::from_ne_bytes([event[12], event[13], event[14], event[15]]);
+    (eid, u16::from_ne_bytes([event[8], event[9]]))
+}
+
+#[test]
+fn routes_events_by_eid() {
+    let (conn, mut server) = connect();
+    let server = std::thread::spawn(move || {
+        answer_query_extension(&mut server, true);
+        server
+    });
+    let queue = conn
+        .register_for_special_event("Present", &[COMPLETE_NOTIFY_EVENT], 7)
+        .unwrap()
+        .unwrap();
+    assert!(conn
+        .register_for_special_event("Present", &[], 7)
+        .unwrap()
+        .is_none());
+
+    // Events that arrive before the registration are not routed, so send them only now
+    let mut server = server.join().unwrap();
+    server.write_all(&present_event(2, 7)).unwrap();
+    server.write_all(&present_event(1, 8)).unwrap();
+    server
+        .write_all(&present_event(COMPLETE_NOTIFY_EVENT, 7))
+        .unwrap();
+
+    match queue.wait_for_event().unwrap() {
+        Event::PresentCompleteNotify(event) => assert_eq!(event.event, 7),
+        event => panic!("unexpected event {:?}", event),
+    }
+    assert!(queue.poll_for_event().unwrap().is_none());
+
+    // Other event types and other event IDs go to the normal event queue
+    let (event, _) = conn.poll_for_raw_event_with_sequence().unwrap().unwrap();
+    assert_eq!(eid_and_type(&event), (7, 2));
+    let (event, _) = conn.poll_for_raw_event_with_sequence().unwrap().unwrap();
+    assert_eq!(eid_and_type(&event), (8, 1));
+    assert!(conn.poll_for_event().unwrap().is_none());
+
+    // After unregistering, events go to the normal event queue again
+    drop(queue);
+    server.write_all(&present_event(1, 7)).unwrap();
+    let (event, _) = conn.wait_for_raw_event_with_sequence().unwrap();
+    assert_eq!(eid_and_type(&event), (7, 1));
+}
+
+#[test]
+fn unsupported_extension() {
+    let (conn, mut server) = connect();
+    let server = std::thread::spawn(move || answer_query_extension(&mut server, false));
+    match conn.register_for_special_event("Present", &[], 1) {
+        Err(ConnectionError::UnsupportedExtension) => {}
+        other => panic!("unexpected result {:?}", other.map(|queue| queue.is_some())),
+    }
+    server.join().unwrap();
+}
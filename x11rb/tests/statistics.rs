@@ -0,0 +1,83 @@
+#![cfg(unix)]
+
+mod common;
+
+use std::io::{Read, Write};
+use std::os::unix::net::UnixStream;
+use std::time::Duration;
+
+use x11rb::connection::{Connection, RequestConnection};
+use x11rb::protocol::xproto::{ConnectionExt as _, MAP_NOTIFY_EVENT};
+
+use common::connect;
+
+/// Answer `count` `GetInputFocus` requests after `delay`, sending a `MapNotify` event first.
+fn answer_get_input_focus(server: &mut UnixStream, count: u16, delay: Duration) {
+    let mut requests = vec![0; 4 * usize::from(count)];
+    server.read_exact(&mut requests).unwrap();
+    std::thread::sleep(delay);
+
+    let mut event = [0; 32];
+    event[0] = MAP_NOTIFY_EVENT;
+    server.write_all(&event).unwrap();
+    for sequence in 1..=count {
+        let mut reply = [0; 32];
+        reply[0] = 1;
+        reply[2..4].copy_from_slice(&sequence.to_ne_bytes());
+        server.write_all(&reply).unwrap();
+    }
+}
+
+#[test]
+fn disabled_by_default() {
+    let (conn, _server) = connect();
+    assert_eq!(conn.statistics(), None);
+}
+
+#[test]
+fn counts_round_trip() {
+    let (conn, mut server) = connect();
+    conn.set_statistics_enabled(true);
+
+    let delay = Duration::from_millis(20);
+    let server = std::thread::spawn(move || answer_get_input_focus(&mut server, 1, delay));
+    conn.get_input_focus().unwrap().reply().unwrap();
+    server.join().unwrap();
+    let event = conn.poll_for_event().unwrap();
+    assert!(event.is_some());
+
+    let stats = conn.statistics().unwrap();
+    assert_eq!(stats.requests.len(), 1);
+    assert_eq!(stats.requests.get(&(43, 0)), Some(&1));
+    assert_eq!(stats.bytes_written, 4);
+    assert_eq!(stats.bytes_read, 64);
+    assert_eq!(stats.round_trips, 1);
+    assert!(stats.blocked_time >= delay, "{:?}", stats.blocked_time);
+    assert_eq!(stats.events.len(), 1);
+    assert_eq!(stats.events.get(&MAP_NOTIFY_EVENT), Some(&1));
+    assert_eq!(stats.max_in_flight_requests, 1);
+}
+
+#[test]
+fn counts_in_flight_requests() {
+    let (conn, mut server) = connect();
+    conn.set_statistics_enabled(true);
+
+    let first = conn.get_input_focus().unwrap();
+    let second = conn.get_input_focus().unwrap();
+    conn.flush().unwrap();
+    answer_get_input_focus(&mut server, 2, Duration::from_secs(0));
+    first.reply().unwrap();
+    second.reply().unwrap();
+
+    let stats = conn.statistics().unwrap();
+    assert_eq!(stats.total_requests(), 2);
+    assert_eq!(stats.max_in_flight_requests, 2);
+    assert_eq!(stats.total_events(), 1);
+
+    // Disabling throws away the counters, enabling starts from zero
+    conn.set_statistics_enabled(false);
+    assert_eq!(conn.statistics(), None);
+    conn.set_statistics_enabled(true);
+    assert_eq!(conn.statistics(), Some(Default::default()));
+}
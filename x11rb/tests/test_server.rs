@@ -0,0 +1,114 @@
+#![cfg(all(unix, feature = "test_server"))]
+
+use std::time::Duration;
+
+use x11rb::connection::Connection;
+use x11rb::test_server::{TestServer, TestServerBuilder, TestServerError};
+
+/// A fake X11 server binary that runs the given shell script.
+///
+/// The arguments that `TestServerBuilder` appends are available as `$1`, `$2`, and so on.
+fn shell(script: &str) -> TestServerBuilder {
+    TestServerBuilder::new("sh").arg("-c").arg(script).arg("sh")
+}
+
+fn process_exists(pid: u32) -> bool {
+    std::path::Path::new(&format!("/proc/{}", pid)).exists()
+}
+
+#[test]
+fn display_from_displayfd() {
+    let server = shell("echo 42 >&\"$2\"; exec sleep 60")
+        .auth(false)
+        .spawn()
+        .unwrap();
+    assert_eq!(server.display(), 42);
+    assert_eq!(server.display_name(), ":42");
+    assert_eq!(server.auth_info(), (&[][..], &[][..]));
+    assert!(server.auth_file().is_none());
+}
+
+#[test]
+fn auth_file() {
+    // With authorization, the arguments are: -auth <file> -displayfd <fd>
+    let server = shell("test \"$1\" = -auth && echo 7 >&\"$4\"; exec sleep 60")
+        .spawn()
+        .unwrap();
+    assert_eq!(server.display(), 7);
+
+    let (name, cookie) = server.auth_info();
+    assert_eq!(name, b"MIT-MAGIC-COOKIE-1");
+    assert_eq!(cookie.len(), 16);
+
+    let path = server.auth_file().unwrap().to_path_buf();
+    let content = std::fs::read(&path).unwrap();
+    let mut expected = vec![0xff, 0xff, 0, 0, 0, 0, 0, 18];
+    expected.extend_from_slice(b"MIT-MAGIC-COOKIE-1");
+    expected.extend_from_slice(&[0, 16]);
+    expected.extend_from_slice(cookie);
+    assert_eq!(content, expected);
+
+    drop(server);
+    assert!(!path.exists());
+}
+
+#[test]
+fn server_is_killed_on_drop() {
+    let server = shell("echo 1 >&\"$2\"; exec sleep 60")
+        .auth(false)
+        .spawn()
+        .unwrap();
+    let pid = server.id();
+    assert!(process_exists(pid));
+    drop(server);
+    assert!(!process_exists(pid));
+}
+
+#[test]
+fn server_exits_early() {
+    match shell("exit 3").auth(false).spawn() {
+        Err(TestServerError::Exited(status)) => assert_eq!(status.code(), Some(3)),
+        other => panic!("unexpected result {:?}", other),
+    }
+}
+
+#[test]
+fn server_timeout() {
+    let result = shell("exec sleep 60")
+        .auth(false)
+        .timeout(Duration::from_millis(200))
+        .spawn();
+    assert!(matches!(result, Err(TestServerError::Timeout)));
+}
+
+#[test]
+fn invalid_display() {
+    let result = shell("echo foo >&\"$2\"; exec sleep 60")
+        .auth(false)
+        .spawn();
+    match result {
+        Err(TestServerError::InvalidDisplay(display)) => assert_eq!(display, "foo"),
+        other => panic!("unexpected result {:?}", other),
+    }
+}
+
+#[test]
+fn missing_binary() {
+    let result = TestServerBuilder::new("/nonexistent/Xvfb").spawn();
+    assert!(matches!(result, Err(TestServerError::IoError(_))));
+}
+
+#[test]
+fn xvfb() {
+    let server = match TestServer::xvfb() {
+        Ok(server) => server,
+        Err(TestServerError::IoError(e)) if e.kind() == std::io::ErrorKind::NotFound => {
+            eprintln!("Skipping test since Xvfb is not installed");
+            return;
+        }
+        Err(e) => panic!("Failed to start Xvfb: {}", e),
+    };
+    let conn = server.connect().unwrap();
+    assert!(!conn.setup().roots.is_empty());
+    conn.flush().unwrap();
+}
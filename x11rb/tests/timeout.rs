@@ -0,0 +1,144 @@
+#![cfg(unix)]
+
+mod common;
+
+use std::io::{Read, Write};
+use std::os::unix::net::UnixStream;
+use std::time::Duration;
+
+use x11rb::connection::{Connection, RequestConnection};
+use x11rb::errors::{ConnectionError, ReplyError, TimeoutError};
+use x11rb::protocol::xproto::{ConnectionExt as _, CLIENT_MESSAGE_EVENT};
+use x11rb::protocol::Event;
+use x11rb::rust_connection::{DefaultStream, PollMode, RustConnection, Stream};
+use x11rb::utils::RawFdContainer;
+
+use common::{connect, setup};
+
+const TIMEOUT: Duration = Duration::from_millis(50);
+
+fn assert_timeout<T: std::fmt::Debug>(result: Result<T, ReplyError>) {
+    match result {
+        Err(ReplyError::ConnectionError(ConnectionError::Timeout)) => {}
+        result => panic!("Expected a timeout, got {:?}", result),
+    }
+}
+
+fn send_packet(server: &mut UnixStream, response_type: u8, sequence: u16) {
+    let mut packet = [0; 32];
+    packet[0] = response_type;
+    packet[2..4].copy_from_slice(&sequence.to_ne_bytes());
+    server.write_all(&packet).unwrap();
+}
+
+fn assert_timed_out<T: std::fmt::Debug, C>(result: Result<T, TimeoutError<C>>) -> C {
+    match result {
+        Err(TimeoutError::TimedOut(cookie)) => cookie,
+        Err(TimeoutError::ReplyError(err)) => panic!("Expected a timeout, got {:?}", err),
+        Ok(value) => panic!("Expected a timeout, got {:?}", value),
+    }
+}
+
+#[test]
+fn reply_timeout() {
+    let (conn, mut server) = connect();
+    let cookie = conn.get_input_focus().unwrap();
+    assert_eq!(cookie.sequence_number(), 1);
+    let _ = assert_timed_out(cookie.reply_timeout(TIMEOUT));
+
+    // The request was sent nevertheless
+    let mut request = [0; 4];
+    server.read_exact(&mut request).unwrap();
+    assert_eq!(request, [43, 0, 1, 0]);
+}
+
+#[test]
+fn cookie_usable_after_timeout() {
+    let (conn, mut server) = connect();
+    let cookie = conn.get_input_focus().unwrap();
+    let cookie = assert_timed_out(cookie.reply_timeout(TIMEOUT));
+    assert_eq!(cookie.sequence_number(), 1);
+
+    // The server now answers and the same cookie receives the reply
+    send_packet(&mut server, 1, 1);
+    let reply = cookie.reply_timeout(Duration::from_secs(10)).unwrap();
+    assert_eq!(reply.sequence, 1);
+}
+
+#[test]
+fn check_usable_after_timeout() {
+    let (conn, mut server) = connect();
+    let cookie = conn.no_operation().unwrap();
+    let cookie = assert_timed_out(cookie.check_timeout(TIMEOUT));
+
+    // The server answers a later request, so the first one cannot have caused an error
+    let _ = conn.get_input_focus().unwrap();
+    send_packet(&mut server, 1, 2);
+    cookie.check_timeout(Duration::from_secs(10)).unwrap();
+}
+
+#[test]
+fn reply_available_after_timeout() {
+    let (conn, mut server) = connect();
+    let cookie = conn.get_input_focus().unwrap();
+    let sequence = cookie.sequence_number();
+    assert_timeout(
+        conn.wait_for_reply_or_raw_error_timeout(sequence, TIMEOUT)
+            .map_err(ReplyError::from),
+    );
+
+    // The server now answers and the reply can still be received
+    send_packet(&mut server, 1, 1);
+    assert_eq!(cookie.reply().unwrap().sequence, 1);
+}
+
+#[test]
+fn event_timeout() {
+    let (conn, mut server) = connect();
+    assert_timeout(
+        conn.wait_for_event_timeout(TIMEOUT)
+            .map_err(ReplyError::from),
+    );
+
+    send_packet(&mut server, CLIENT_MESSAGE_EVENT, 0);
+    match conn
+        .wait_for_event_timeout(Duration::from_secs(10))
+        .unwrap()
+    {
+        Event::ClientMessage(_) => {}
+        event => panic!("Unexpected event {:?}", event),
+    }
+}
+
+/// A stream that does not implement `Stream::poll_timeout`.
+#[derive(Debug)]
+struct NoTimeoutStream(DefaultStream);
+
+impl Stream for NoTimeoutStream {
+    fn poll(&self, mode: PollMode) -> std::io::Result<()> {
+        self.0.poll(mode)
+    }
+
+    fn read(&self, buf: &mut [u8], fd_storage: &mut Vec<RawFdContainer>) -> std::io::Result<usize> {
+        self.0.read(buf, fd_storage)
+    }
+
+    fn write(&self, buf: &[u8], fds: &mut Vec<RawFdContainer>) -> std::io::Result<usize> {
+        self.0.write(buf, fds)
+    }
+}
+
+#[test]
+fn unsupported_timeout() {
+    let (client, _server) = UnixStream::pair().unwrap();
+    let stream = NoTimeoutStream(DefaultStream::from_unix_stream(client).unwrap());
+    let conn = RustConnection::for_connected_stream(stream, setup()).unwrap();
+    let cookie = conn.get_input_focus().unwrap();
+    match cookie.reply_timeout(TIMEOUT) {
+        Err(TimeoutError::ReplyError(ReplyError::ConnectionError(
+            ConnectionError::UnsupportedTimeout,
+        ))) => {}
+        result => panic!("Expected an unsupported timeout, got {:?}", result),
+    }
+    assert!(conn.disconnect_reason().is_none());
+}
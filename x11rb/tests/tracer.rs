@@ -0,0 +1,119 @@
+#![cfg(unix)]
+
+mod common;
+
+use std::io::{IoSlice, Read, Write};
+use std::os::unix::net::UnixStream;
+use std::sync::{Arc, Mutex};
+
+use x11rb::connection::{Connection, SequenceNumber};
+use x11rb::protocol::xproto::ConnectionExt as _;
+use x11rb::rust_connection::{PrintTracer, Tracer};
+
+use common::connect;
+
+/// Answer a `GetInputFocus` request with the given sequence number.
+fn answer_get_input_focus(server: &mut UnixStream, sequence: u16) {
+    let mut request = [0; 4];
+    server.read_exact(&mut request).unwrap();
+    assert_eq!(request, [43, 0, 1, 0]);
+
+    let mut reply = [0; 32];
+    reply[0] = 1;
+    reply[2..4].copy_from_slice(&sequence.to_ne_bytes());
+    reply[8..12].copy_from_slice(&42u32.to_ne_bytes());
+    server.write_all(&reply).unwrap();
+}
+
+#[derive(Debug, Default)]
+struct RecordingTracer {
+    sent: Mutex<Vec<(SequenceNumber, Vec<u8>)>>,
+    received: Mutex<Vec<(SequenceNumber, Vec<u8>)>>,
+}
+
+impl Tracer for RecordingTracer {
+    fn request_sent(&self, sequence: SequenceNumber, request: &[IoSlice<'_>]) {
+        let request = request.iter().flat_map(|buf| buf.iter().copied()).collect();
+        self.sent.lock().unwrap().push((sequence, request));
+    }
+
+    fn packet_received(&self, sequence: SequenceNumber, packet: &[u8]) {
+        self.received
+            .lock()
+            .unwrap()
+            .push((sequence, packet.to_vec()));
+    }
+}
+
+#[test]
+fn tracer_sees_request_and_reply() {
+    let (conn, mut server) = connect();
+    let tracer = Arc::new(RecordingTracer::default());
+    conn.set_tracer(Some(tracer.clone()));
+
+    let cookie = conn.get_input_focus().unwrap();
+    conn.flush().unwrap();
+    answer_get_input_focus(&mut server, 1);
+    assert_eq!(cookie.reply().unwrap().focus, 42);
+
+    assert_eq!(*tracer.sent.lock().unwrap(), [(1, vec![43, 0, 1, 0])]);
+    let received = tracer.received.lock().unwrap();
+    assert_eq!(received.len(), 1);
+    assert_eq!(received[0].0, 1);
+    assert_eq!(received[0].1[0], 1);
+}
+
+#[test]
+fn tracer_can_be_removed() {
+    let (conn, mut server) = connect();
+    let tracer = Arc::new(RecordingTracer::default());
+    conn.set_tracer(Some(tracer.clone()));
+    conn.set_tracer(None);
+
+    let cookie = conn.get_input_focus().unwrap();
+    conn.flush().unwrap();
+    answer_get_input_focus(&mut server, 1);
+    let _ = cookie.reply().unwrap();
+
+    assert!(tracer.sent.lock().unwrap().is_empty());
+    assert!(tracer.received.lock().unwrap().is_empty());
+}
+
+#[derive(Debug, Clone, Default)]
+struct SharedOutput(Arc<Mutex<Vec<u8>>>);
+
+impl Write for SharedOutput {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn print_tracer_decodes_packets() {
+    let (conn, mut server) = connect();
+    let output = SharedOutput::default();
+    conn.set_tracer(Some(Arc::new(PrintTracer::with_output(output.clone()))));
+
+    let cookie = conn.get_input_focus().unwrap();
+    conn.flush().unwrap();
+    answer_get_input_focus(&mut server, 1);
+    let _ = cookie.reply().unwrap();
+
+    let output = String::from_utf8(output.0.lock().unwrap().clone()).unwrap();
+    let lines = output.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 2, "{}", output);
+    assert!(
+        lines[0].starts_with("request (1): GetInputFocus"),
+        "{}",
+        output
+    );
+    assert!(
+        lines[1].starts_with("reply (1): GetInputFocus"),
+        "{}",
+        output
+    );
+}
@@ -0,0 +1,121 @@
+#![cfg(unix)]
+
+mod common;
+
+use std::fs::File;
+use std::io::{IoSlice, Read, Write};
+use std::os::unix::io::IntoRawFd;
+use std::os::unix::net::UnixStream;
+
+use x11rb::connection::RequestConnection;
+use x11rb::cookie::{wait_for_replies, Cookie};
+use x11rb::errors::{ConnectionError, ParseError, ReplyError};
+use x11rb::protocol::xproto::{ConnectionExt as _, GetInputFocusReply, GET_INPUT_FOCUS_REQUEST};
+use x11rb::protocol::ErrorKind;
+use x11rb::rust_connection::{DefaultStream, RustConnection, Stream};
+use x11rb::utils::RawFdContainer;
+use x11rb::x11_utils::TryParseFd;
+
+use common::connect;
+
+/// Read `count` `GetInputFocus` requests and answer them all at once.
+///
+/// The request with sequence number `error_sequence` gets a `Window` error, the other requests
+/// get a reply whose focus is the sequence number.
+fn answer_get_input_focus(mut server: UnixStream, count: u16, error_sequence: u16) {
+    let mut requests = vec![0; 4 * usize::from(count)];
+    server.read_exact(&mut requests).unwrap();
+
+    let mut responses = Vec::new();
+    for sequence in 1..=count {
+        let mut packet = [0; 32];
+        if sequence == error_sequence {
+            packet[1] = 3; // Window error
+        } else {
+            packet[0] = 1;
+            packet[8..12].copy_from_slice(&u32::from(sequence).to_ne_bytes());
+        }
+        packet[2..4].copy_from_slice(&sequence.to_ne_bytes());
+        responses.extend_from_slice(&packet);
+    }
+    server.write_all(&responses).unwrap();
+}
+
+#[test]
+fn per_cookie_results() {
+    let (conn, server) = connect();
+    conn.set_statistics_enabled(true);
+
+    let cookies = (0..3)
+        .map(|_| conn.get_input_focus())
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    let last = conn.get_input_focus().unwrap();
+    let server = std::thread::spawn(move || answer_get_input_focus(server, 4, 2));
+
+    let (replies, last) = wait_for_replies(&conn, (cookies, last)).unwrap();
+    server.join().unwrap();
+
+    assert_eq!(replies.len(), 3);
+    assert_eq!(replies[0].as_ref().unwrap().focus, 1);
+    match &replies[1] {
+        Err(ReplyError::X11Error(error)) => {
+            assert_eq!(error.error_kind, ErrorKind::Window);
+            assert_eq!(error.sequence, 2);
+        }
+        other => panic!("unexpected result {:?}", other),
+    }
+    assert_eq!(replies[2].as_ref().unwrap().focus, 3);
+    assert_eq!(last.unwrap().focus, 4);
+
+    // All replies were collected with a single wait
+    assert_eq!(conn.statistics().unwrap().round_trips, 1);
+}
+
+#[test]
+fn empty_batch() {
+    let (conn, _server) = connect();
+    let batch: Vec<Cookie<'_, RustConnection, GetInputFocusReply>> = Vec::new();
+    assert!(wait_for_replies(&conn, batch).unwrap().is_empty());
+}
+
+/// A reply that is accompanied by file descriptors.
+#[derive(Debug)]
+struct ReplyWithFds;
+
+impl TryParseFd for ReplyWithFds {
+    fn try_parse_fd<'a>(
+        value: &'a [u8],
+        _fds: &mut Vec<RawFdContainer>,
+    ) -> Result<(Self, &'a [u8]), ParseError> {
+        Ok((ReplyWithFds, value))
+    }
+}
+
+#[test]
+fn replies_with_fds_are_rejected() {
+    let (conn, mut server) = connect();
+    let request = [GET_INPUT_FOCUS_REQUEST, 0, 1, 0];
+    let cookie = conn
+        .send_request_with_reply_with_fds::<ReplyWithFds>(&[IoSlice::new(&request)], Vec::new())
+        .unwrap();
+    let server = std::thread::spawn(move || {
+        let mut request = [0; 4];
+        server.read_exact(&mut request).unwrap();
+        let server = DefaultStream::from_unix_stream(server).unwrap();
+        let mut reply = [0; 32];
+        reply[0] = 1;
+        reply[1] = 1; // Number of file descriptors
+        reply[2..4].copy_from_slice(&1u16.to_ne_bytes());
+        let fd = File::open("/dev/null").unwrap().into_raw_fd();
+        let mut fds = vec![RawFdContainer::new(fd)];
+        assert_eq!(server.write(&reply, &mut fds).unwrap(), reply.len());
+    });
+
+    let result = conn.wait_for_replies_or_raw_errors(&[cookie.sequence_number()]);
+    server.join().unwrap();
+    match result {
+        Err(ConnectionError::FdPassingFailed) => {}
+        other => panic!("unexpected result {:?}", other),
+    }
+}
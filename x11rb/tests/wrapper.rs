@@ -0,0 +1,90 @@
+#![cfg(unix)]
+
+mod common;
+
+use std::io::{Read, Write};
+use std::os::unix::net::UnixStream;
+
+use x11rb::errors::{ConnectionError, ReplyError};
+use x11rb::protocol::xproto::AtomEnum;
+use x11rb::wrapper::ConnectionExt as _;
+
+use common::connect;
+
+/// Answer `attempts` reads of a property that is replaced during the first `changes` reads.
+///
+/// Each read takes two `GetProperty` requests. When the property changes, the second reply has a
+/// different format. Otherwise, the value is `abcdefgh`.
+fn answer_get_property(mut server: UnixStream, attempts: usize, changes: usize) {
+    let mut sequence = 0u16;
+    for attempt in 0..attempts {
+        for part in 0..2 {
+            let mut request = [0; 24];
+            server.read_exact(&mut request).unwrap();
+            sequence += 1;
+            let offset = u32::from_ne_bytes([request[16], request[17], request[18], request[19]]);
+            assert_eq!(offset, part);
+
+            let (format, value, bytes_after) = match part {
+                0 => (8, b"abcd", 4u32),
+                _ if attempt < changes => (32, b"\0\0\0\0", 0),
+                _ => (8, b"efgh", 0),
+            };
+            let mut reply = [0; 36];
+            reply[0] = 1;
+            reply[1] = format;
+            reply[2..4].copy_from_slice(&sequence.to_ne_bytes());
+            reply[4..8].copy_from_slice(&1u32.to_ne_bytes());
+            reply[8..12].copy_from_slice(&u32::from(AtomEnum::STRING).to_ne_bytes());
+            reply[12..16].copy_from_slice(&bytes_after.to_ne_bytes());
+            reply[16..20].copy_from_slice(&(32 / u32::from(format)).to_ne_bytes());
+            reply[32..36].copy_from_slice(value);
+            server.write_all(&reply).unwrap();
+        }
+    }
+}
+
+#[test]
+fn get_property_full_retries_after_change() {
+    let (conn, server) = connect();
+    let server = std::thread::spawn(move || answer_get_property(server, 3, 2));
+    let reply = conn
+        .get_property_full(false, 42, AtomEnum::WM_NAME, AtomEnum::STRING)
+        .unwrap();
+    server.join().unwrap();
+    assert_eq!((reply.format, reply.value_len), (8, 8));
+    assert_eq!(reply.value, b"abcdefgh");
+}
+
+#[test]
+fn get_property_full_gives_up() {
+    let (conn, server) = connect();
+    // The property changes during every read that get_property_full tries
+    let server = std::thread::spawn(move || answer_get_property(server, 10, 10));
+    let result = conn.get_property_full(false, 42, AtomEnum::WM_NAME, AtomEnum::STRING);
+    server.join().unwrap();
+    match result {
+        Err(ReplyError::ConnectionError(ConnectionError::PropertyKeptChanging)) => {}
+        other => panic!("unexpected result {:?}", other),
+    }
+}
+
+#[test]
+fn replace_property_with_tiny_maximum_request_length() {
+    let (conn, mut server) = connect();
+    // Answer the BIG-REQUESTS query, so that the setup's maximum request length of zero is used
+    let server = std::thread::spawn(move || {
+        let mut request = [0; 20];
+        server.read_exact(&mut request).unwrap();
+        let mut reply = [0; 32];
+        reply[0] = 1;
+        reply[2..4].copy_from_slice(&1u16.to_ne_bytes());
+        server.write_all(&reply).unwrap();
+    });
+    let result = conn.replace_property8(42, AtomEnum::WM_NAME, AtomEnum::STRING, b"name");
+    server.join().unwrap();
+    match result {
+        Err(ConnectionError::MaximumRequestLengthExceeded) => {}
+        other => panic!("unexpected result {:?}", other.map(|cookies| cookies.len())),
+    }
+}
@@ -0,0 +1,57 @@
+mod common;
+
+use std::io::{Read, Write};
+use std::net::TcpListener;
+
+use x11rb::connection::Connection;
+use x11rb::rust_connection::RustConnection;
+use x11rb::x11_utils::Serialize;
+
+use common::setup_with_screen;
+
+/// Serialize an `.Xauthority` entry that matches any address and display.
+fn wildcard_entry(name: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut entry = vec![0xff, 0xff, 0, 0, 0, 0];
+    entry.extend_from_slice(&(name.len() as u16).to_be_bytes());
+    entry.extend_from_slice(name);
+    entry.extend_from_slice(&(data.len() as u16).to_be_bytes());
+    entry.extend_from_slice(data);
+    entry
+}
+
+#[test]
+fn xdm_authorization_is_preferred() {
+    let mut xauthority = wildcard_entry(b"MIT-MAGIC-COOKIE-1", &[1; 16]);
+    xauthority.extend(wildcard_entry(b"XDM-AUTHORIZATION-1", &[2; 16]));
+    let path = std::env::temp_dir().join(format!("x11rb-xdm-test-{}", std::process::id()));
+    std::fs::write(&path, xauthority).unwrap();
+    std::env::set_var("XAUTHORITY", &path);
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let display = listener.local_addr().unwrap().port() - 6000;
+    let server = std::thread::spawn(move || {
+        let (mut server, _) = listener.accept().unwrap();
+        let mut setup_request = [0; 12];
+        server.read_exact(&mut setup_request).unwrap();
+        let name_len = u16::from_ne_bytes([setup_request[6], setup_request[7]]);
+        let data_len = u16::from_ne_bytes([setup_request[8], setup_request[9]]);
+        let pad = |len: u16| (usize::from(len) + 3) & !3;
+        let mut auth = vec![0; pad(name_len) + pad(data_len)];
+        server.read_exact(&mut auth).unwrap();
+        server.write_all(&setup_with_screen().serialize()).unwrap();
+        let name = auth[..usize::from(name_len)].to_vec();
+        let data = auth[pad(name_len)..][..usize::from(data_len)].to_vec();
+        (name, data)
+    });
+
+    let result = RustConnection::connect(Some(&format!("127.0.0.1:{}", display)));
+    std::fs::remove_file(&path).unwrap();
+    let (conn, _) = result.unwrap();
+    assert_eq!(conn.setup().roots[0].root, 42);
+
+    let (name, data) = server.join().unwrap();
+    assert_eq!(name, b"XDM-AUTHORIZATION-1");
+    assert_eq!(data.len(), 24);
+    // The authenticator is encrypted, so the entry data does not appear in it
+    assert_ne!(&data[..8], &[2; 8]);
+}